@@ -15,6 +15,27 @@ const_ordinary! { EVENTLOG: u16;
 	WARNING_TYPE 0x0002
 }
 
+const_ordinary! { EXTENDED_NAME_FORMAT: u32;
+	/// [`EXTENDED_NAME_FORMAT`](https://learn.microsoft.com/en-us/windows/win32/api/secext/ne-secext-extended_name_format)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `Name` prefix.
+	=>
+	=>
+	Unknown 0
+	FullyQualifiedDN 1
+	SamCompatible 2
+	Display 3
+	UniqueId 6
+	Canonical 7
+	UserPrincipal 8
+	CanonicalEx 9
+	ServicePrincipal 10
+	DnsDomain 12
+	GivenName 13
+	Surname 14
+}
+
 const_bitflag! { KEY: u32;
 	/// [Registry access rights](https://learn.microsoft.com/en-us/windows/win32/sysinfo/registry-key-security-and-access-rights)
 	/// (`u32`).
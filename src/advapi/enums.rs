@@ -235,29 +235,33 @@ impl<'a> SvcCtl<'a> {
 	}
 }
 
-/// Notification content for [`SvcCtl`](crate::SvcCtl).
+/// Notification content for [`SvcCtl`](crate::SvcCtl) and
+/// [`wm::DeviceChange`](crate::msg::wm::DeviceChange).
 pub enum SvcCtlDeviceEvent<'a> {
 	Interface(&'a DEV_BROADCAST_DEVICEINTERFACE),
 	Handle(&'a DEV_BROADCAST_HANDLE),
 	Oem(&'a DEV_BROADCAST_OEM),
 	Port(&'a DEV_BROADCAST_PORT),
 	Volume(&'a DEV_BROADCAST_VOLUME),
+	/// A device type without a specific struct, like
+	/// [`DBT_DEVTYP::NET`](crate::co::DBT_DEVTYP::NET) or
+	/// [`DBT_DEVTYP::DEVNODE`](crate::co::DBT_DEVTYP::DEVNODE).
+	Unknown(&'a DEV_BROADCAST_HDR),
 }
 
 impl<'a> SvcCtlDeviceEvent<'a> {
 	/// Constructs the enum according to the raw data.
 	///
-	/// # Panics
-	///
-	/// Panics if `dbch_devicetype` field is invalid.
-	///
 	/// # Safety
 	///
 	/// This enum is constructed when building the output of
 	/// [`HSERVICESTATUS::RegisterServiceCtrlHandlerEx`](crate::prelude::advapi_Hservicestatus::RegisterServiceCtrlHandlerEx)
-	/// callback, make sure all parameters are correct.
+	/// callback and
+	/// [`wm::DeviceChange::device_event`](crate::msg::wm::DeviceChange::device_event),
+	/// make sure the header is the beginning of a complete struct of the type
+	/// given by `dbch_devicetype`.
 	#[must_use]
-	pub unsafe fn from_raw(event_data: &DEV_BROADCAST_HDR) -> Self {
+	pub unsafe fn from_raw(event_data: &'a DEV_BROADCAST_HDR) -> Self {
 		let ptr = event_data as *const DEV_BROADCAST_HDR;
 		match event_data.dbch_devicetype {
 			co::DBT_DEVTYP::DEVICEINTERFACE => Self::Interface(&*(ptr as *const _)),
@@ -265,7 +269,7 @@ impl<'a> SvcCtlDeviceEvent<'a> {
 			co::DBT_DEVTYP::OEM => Self::Oem(&*(ptr as *const _)),
 			co::DBT_DEVTYP::PORT => Self::Port(&*(ptr as *const _)),
 			co::DBT_DEVTYP::VOLUME => Self::Volume(&*(ptr as *const _)),
-			_ => panic!("Invalid co::DBT_DEVTYP."),
+			_ => Self::Unknown(event_data),
 		}
 	}
 }
@@ -15,6 +15,7 @@ extern_sys! { "advapi32";
 	DeleteService(HANDLE) -> BOOL
 	DeregisterEventSource(HANDLE) -> BOOL
 	DuplicateToken(HANDLE, u32, *mut HANDLE) -> BOOL
+	DuplicateTokenEx(HANDLE, u32, PCVOID, u32, u32, *mut HANDLE) -> BOOL
 	EncryptFileW(PCSTR) -> BOOL
 	EncryptionDisable(PCSTR, BOOL) -> BOOL
 	EqualDomainSid(PVOID, PVOID, *mut BOOL) -> BOOL
@@ -81,6 +82,7 @@ extern_sys! { "advapi32";
 	RegSetValueExW(HANDLE, PCSTR, u32, u32, *const u8, u32) -> i32
 	RegUnLoadKeyW(HANDLE, PCSTR) -> i32
 	ReportEventW(HANDLE, u16, u16, u32, PCVOID, u16, u32, *const PCSTR, PCVOID) -> BOOL
+	RevertToSelf() -> BOOL
 	SetServiceStatus(HANDLE, PCVOID) -> BOOL
 }
 
@@ -91,3 +93,7 @@ extern_sys! { "ktmw32";
 	OpenTransaction(u32, PVOID) -> HANDLE
 	RollbackTransaction(HANDLE) -> BOOL
 }
+
+extern_sys! { "secur32";
+	GetUserNameExW(u32, PSTR, *mut u32) -> u8
+}
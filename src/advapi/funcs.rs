@@ -480,6 +480,38 @@ pub fn GetUserName() -> SysResult<String> {
 	).map(|_| name_buf.to_string())
 }
 
+/// [`GetUserNameEx`](https://learn.microsoft.com/en-us/windows/win32/api/secext/nf-secext-getusernameexw)
+/// function.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let upn = w::GetUserNameEx(co::EXTENDED_NAME_FORMAT::UserPrincipal)?;
+/// println!("{}", upn);
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn GetUserNameEx(format: co::EXTENDED_NAME_FORMAT) -> SysResult<String> {
+	let mut name_sz = u32::default();
+
+	unsafe { ffi::GetUserNameExW(format.raw(), std::ptr::null_mut(), &mut name_sz); }
+	let get_size_err = GetLastError();
+	if get_size_err != co::ERROR::MORE_DATA {
+		return Err(get_size_err);
+	}
+
+	let mut name_buf = WString::new_alloc_buf(name_sz as _);
+
+	match unsafe {
+		ffi::GetUserNameExW(format.raw(), name_buf.as_mut_ptr(), &mut name_sz)
+	} {
+		0 => Err(GetLastError()),
+		_ => Ok(name_buf.to_string()),
+	}
+}
+
 /// [`GetWindowsAccountDomainSid`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getwindowsaccountdomainsid)
 /// function.
 ///
@@ -598,6 +630,32 @@ pub fn InitiateSystemShutdownEx(
 	)
 }
 
+/// Tells whether the current process runs elevated, by calling
+/// [`HACCESSTOKEN::GetTokenInformation`](crate::prelude::advapi_Haccesstoken::GetTokenInformation)
+/// with
+/// [`TOKEN_INFORMATION_CLASS::Elevation`](crate::co::TOKEN_INFORMATION_CLASS::Elevation)
+/// on the process token.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// if !w::is_elevated()? {
+///     eprintln!("Please run as administrator.");
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn is_elevated() -> SysResult<bool> {
+	let htoken = HPROCESS::GetCurrentProcess()
+		.OpenProcessToken(co::TOKEN::QUERY)?;
+	match htoken.GetTokenInformation(co::TOKEN_INFORMATION_CLASS::Elevation)? {
+		TokenInfo::Elevation(elevation) => Ok(elevation.TokenIsElevated()),
+		_ => Err(co::ERROR::INVALID_DATA), // never happens
+	}
+}
+
 /// [`IsValidSecurityDescriptor`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-isvalidsecuritydescriptor)
 /// function.
 #[must_use]
@@ -874,3 +932,13 @@ pub fn LookupPrivilegeValue(
 		},
 	).map(|_| luid)
 }
+
+/// [`RevertToSelf`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-reverttoself)
+/// function.
+///
+/// In most cases, you won't need to call this function, because
+/// [`HACCESSTOKEN::impersonate`](crate::prelude::advapi_Haccesstoken::impersonate)
+/// returns a guard which calls it automatically.
+pub fn RevertToSelf() -> SysResult<()> {
+	bool_to_sysresult(unsafe { ffi::RevertToSelf() })
+}
@@ -311,3 +311,35 @@ impl TokenPrivilegesGuard {
 		Ok(new_self)
 	}
 }
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation for impersonation which automatically calls
+/// [`RevertToSelf`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-reverttoself)
+/// when the object goes out of scope.
+///
+/// Impersonation applies to the calling thread, so the guard must be dropped
+/// in the same thread.
+pub struct RevertToSelfGuard {
+	_thread: PhantomData<*const ()>,
+}
+
+impl Drop for RevertToSelfGuard {
+	fn drop(&mut self) {
+		unsafe { ffi::RevertToSelf(); } // ignore errors
+	}
+}
+
+impl RevertToSelfGuard {
+	/// Constructs the guard.
+	///
+	/// # Safety
+	///
+	/// Be sure you must call
+	/// [`RevertToSelf`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-reverttoself)
+	/// at the end of scope.
+	#[must_use]
+	pub const unsafe fn new() -> Self {
+		Self { _thread: PhantomData }
+	}
+}
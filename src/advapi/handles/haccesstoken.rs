@@ -24,6 +24,66 @@ impl advapi_Haccesstoken for HACCESSTOKEN {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait advapi_Haccesstoken: Handle {
+	/// Enables the given privilege in the token, by calling
+	/// [`LookupPrivilegeValue`](crate::LookupPrivilegeValue) and
+	/// [`HACCESSTOKEN::AdjustTokenPrivileges`](crate::prelude::advapi_Haccesstoken::AdjustTokenPrivileges).
+	///
+	/// The token must have been opened with
+	/// [`TOKEN::ADJUST_PRIVILEGES`](crate::co::TOKEN::ADJUST_PRIVILEGES) and
+	/// [`TOKEN::QUERY`](crate::co::TOKEN::QUERY) access rights. If the token
+	/// doesn't hold the privilege, fails with
+	/// [`ERROR::NOT_ALL_ASSIGNED`](crate::co::ERROR::NOT_ALL_ASSIGNED).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let htoken = w::HPROCESS::GetCurrentProcess()
+	///     .OpenProcessToken(co::TOKEN::ADJUST_PRIVILEGES | co::TOKEN::QUERY)?;
+	///
+	/// htoken.enable_privilege(co::SE_PRIV::BACKUP_NAME)?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn enable_privilege(&self, name: co::SE_PRIV) -> SysResult<()> {
+		let luid = LookupPrivilegeValue(None, name)?;
+		let privs = TOKEN_PRIVILEGES::new(&[
+			LUID_AND_ATTRIBUTES::new(luid, co::SE_PRIV_ATTR::ENABLED),
+		])?;
+		self.AdjustTokenPrivileges(DisabPriv::Privs(&privs))?;
+
+		match GetLastError() { // the function succeeds even if not assigned
+			co::ERROR::NOT_ALL_ASSIGNED => Err(co::ERROR::NOT_ALL_ASSIGNED),
+			_ => Ok(()),
+		}
+	}
+
+	/// Calls
+	/// [`HACCESSTOKEN::ImpersonateLoggedOnUser`](crate::prelude::advapi_Haccesstoken::ImpersonateLoggedOnUser),
+	/// returning a guard which stops the impersonation of the calling thread,
+	/// by calling [`RevertToSelf`](crate::RevertToSelf), when it goes out of
+	/// scope.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let htoken: w::HACCESSTOKEN; // initialized somewhere
+	/// # let htoken = w::HACCESSTOKEN::NULL;
+	///
+	/// {
+	///     let _impersonation = htoken.impersonate()?;
+	///     // access resources as the user...
+	/// } // RevertToSelf called here
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn impersonate(&self) -> SysResult<RevertToSelfGuard> {
+		self.ImpersonateLoggedOnUser()
+			.map(|_| unsafe { RevertToSelfGuard::new() })
+	}
+
 	/// [`AdjustTokenPrivileges`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-adjusttokenprivileges)
 	/// function.
 	///
@@ -119,6 +179,50 @@ pub trait advapi_Haccesstoken: Handle {
 		}
 	}
 
+	/// [`DuplicateTokenEx`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-duplicatetokenex)
+	/// function.
+	///
+	/// # Examples
+	///
+	/// Creating a primary token, which can be used to start a process:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let htoken = w::HPROCESS::GetCurrentProcess()
+	///     .OpenProcessToken(co::TOKEN::DUPLICATE | co::TOKEN::QUERY)?;
+	///
+	/// let hprimary = htoken.DuplicateTokenEx(
+	///     co::TOKEN::ALL_ACCESS,
+	///     None,
+	///     co::SECURITY_IMPERSONATION::Impersonation,
+	///     co::TOKEN_TYPE::Primary,
+	/// )?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn DuplicateTokenEx(&self,
+		desired_access: co::TOKEN,
+		token_attributes: Option<&SECURITY_ATTRIBUTES>,
+		impersonation_level: co::SECURITY_IMPERSONATION,
+		token_type: co::TOKEN_TYPE,
+	) -> SysResult<CloseHandleGuard<HACCESSTOKEN>>
+	{
+		let mut handle = HACCESSTOKEN::NULL;
+		unsafe {
+			bool_to_sysresult(
+				ffi::DuplicateTokenEx(
+					self.ptr(),
+					desired_access.raw(),
+					token_attributes.map_or(std::ptr::null(), |sa| sa as *const _ as _),
+					impersonation_level.raw(),
+					token_type.raw(),
+					handle.as_mut(),
+				),
+			).map(|_| CloseHandleGuard::new(handle))
+		}
+	}
+
 	/// [`GetCurrentProcessToken`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getcurrentprocesstoken)
 	/// function.
 	#[must_use]
@@ -298,6 +402,9 @@ pub trait advapi_Haccesstoken: Handle {
 
 	/// [`ImpersonateLoggedOnUser`](https://learn.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-impersonateloggedonuser)
 	/// function.
+	///
+	/// To have [`RevertToSelf`](crate::RevertToSelf) called automatically, see
+	/// [`HACCESSTOKEN::impersonate`](crate::prelude::advapi_Haccesstoken::impersonate).
 	fn ImpersonateLoggedOnUser(&self) -> SysResult<()> {
 		bool_to_sysresult(unsafe { ffi::ImpersonateLoggedOnUser(self.ptr()) })
 	}
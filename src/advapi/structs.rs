@@ -42,6 +42,10 @@ pub struct DEV_BROADCAST_DEVICEINTERFACE {
 
 impl DEV_BROADCAST_DEVICEINTERFACE {
 	/// Returns the `dbcc_name` field.
+	///
+	/// This is the device path, like
+	/// `\\?\USB#VID_0403&PID_6001#A12345#{a5dcbf10-6530-11d2-901f-00c04fb951ed}`,
+	/// which can be passed to [`HFILE::CreateFile`](crate::prelude::kernel_Hfile::CreateFile).
 	#[must_use]
 	pub fn dbcc_name(&self) -> String {
 		unsafe { WString::from_wchars_nullt(self.dbcc_name.as_ptr()) }
@@ -91,6 +95,7 @@ impl DEV_BROADCAST_PORT {
 
 /// [`DEV_BROADCAST_VOLUME`](https://learn.microsoft.com/en-us/windows/win32/api/dbt/ns-dbt-dev_broadcast_volume)
 /// struct.
+#[repr(C)]
 #[derive(Default)]
 pub struct DEV_BROADCAST_VOLUME {
 	pub hdr: DEV_BROADCAST_HDR,
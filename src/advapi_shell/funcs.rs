@@ -7,6 +7,60 @@ use crate::guard::*;
 use crate::kernel::privs::*;
 use crate::ole::privs::*;
 use crate::prelude::*;
+use crate::shell::ffi as shell_ffi;
+
+/// [`CreateEnvironmentBlock`](https://learn.microsoft.com/en-us/windows/win32/api/userenv/nf-userenv-createenvironmentblock)
+/// function.
+///
+/// If `token` is `None`, the block contains only the system variables.
+/// Otherwise, the token must have been opened with
+/// [`TOKEN::QUERY`](crate::co::TOKEN::QUERY) and
+/// [`TOKEN::DUPLICATE`](crate::co::TOKEN::DUPLICATE) access rights.
+///
+/// # Examples
+///
+/// Building the environment of another user to launch a process:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let htoken: w::HACCESSTOKEN; // initialized somewhere
+/// # let htoken = w::HACCESSTOKEN::NULL;
+///
+/// let env_block = w::CreateEnvironmentBlock(Some(&htoken), false)?;
+/// let entries = env_block.entries();
+///
+/// let mut si = w::STARTUPINFO::default();
+/// let _pi = w::HPROCESS::CreateProcess(
+///     None,
+///     Some("C:\\Tools\\worker.exe"),
+///     None,
+///     None,
+///     false,
+///     co::CREATE::NoValue,
+///     Some(entries.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect()),
+///     None,
+///     &mut si,
+/// )?;
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn CreateEnvironmentBlock(
+	token: Option<&HACCESSTOKEN>,
+	inherit: bool,
+) -> SysResult<DestroyEnvironmentBlockGuard>
+{
+	let mut ptr = std::ptr::null_mut::<std::ffi::c_void>();
+	bool_to_sysresult(
+		unsafe {
+			shell_ffi::CreateEnvironmentBlock(
+				&mut ptr,
+				token.map_or(std::ptr::null_mut(), |t| t.ptr()),
+				inherit as _,
+			)
+		},
+	).map(|_| unsafe { DestroyEnvironmentBlockGuard::new(ptr) })
+}
 
 /// [`ShellExecuteEx`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shellexecuteexw)
 /// function.
@@ -15,6 +69,10 @@ use crate::prelude::*;
 /// leave the others as default. The needed mask flags will be automatically
 /// set.
 ///
+/// If [`SEE_MASK::NOCLOSEPROCESS`](crate::co::SEE_MASK::NOCLOSEPROCESS) is
+/// set, returns the handle to the launched process, if any – it may not exist
+/// if the operation was handled by an already running instance, or by DDE.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -27,11 +85,50 @@ use crate::prelude::*;
 /// })?;
 /// # w::SysResult::Ok(())
 /// ```
-pub fn ShellExecuteEx(exec_info: &SHELLEXECUTEINFO) -> SysResult<()> {
+///
+/// Launching a process with administrator privileges, through the UAC prompt,
+/// and waiting for it to finish:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// let hprocess = w::ShellExecuteEx(&w::SHELLEXECUTEINFO {
+///     mask: co::SEE_MASK::NOCLOSEPROCESS | co::SEE_MASK::NOASYNC,
+///     hwnd: Some(&hwnd),
+///     verb: Some("runas"),
+///     file: "C:\\Temp\\setup.exe",
+///     parameters: Some("/quiet"),
+///     show: co::SW::SHOWNORMAL,
+///     ..Default::default()
+/// })?;
+///
+/// if let Some(hprocess) = hprocess {
+///     hprocess.WaitForSingleObject(None)?;
+///     let exit_code = hprocess.GetExitCodeProcess()?;
+/// }
+/// // CloseHandle() called automatically
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// If the user declines the UAC prompt, the function fails with
+/// [`ERROR::CANCELLED`](crate::co::ERROR::CANCELLED).
+pub fn ShellExecuteEx(
+	exec_info: &SHELLEXECUTEINFO,
+) -> SysResult<Option<CloseHandleGuard<HPROCESS>>>
+{
 	let mut buf = exec_info.to_raw();
 	bool_to_sysresult(
 		unsafe { ffi::ShellExecuteExW(&mut buf.raw as *mut _ as _) },
-	)
+	).map(|_| {
+		if buf.raw.hProcess == HPROCESS::NULL {
+			None
+		} else {
+			Some(unsafe { CloseHandleGuard::new(buf.raw.hProcess.raw_copy()) })
+		}
+	})
 }
 
 /// [`SHGetKnownFolderPath`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shgetknownfolderpath)
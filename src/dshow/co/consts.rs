@@ -1,5 +1,57 @@
 #![allow(non_camel_case_types, non_upper_case_globals)]
 
+const_ordinary! { EVENT_CODE: i32;
+	/// DirectShow
+	/// [event notification codes](https://learn.microsoft.com/en-us/windows/win32/directshow/event-notification-codes)
+	/// (`i32`).
+	///
+	/// Originally has `EC` prefix.
+	=>
+	=>
+	COMPLETE 0x01
+	USERABORT 0x02
+	ERRORABORT 0x03
+	TIME 0x04
+	REPAINT 0x05
+	STREAM_ERROR_STOPPED 0x06
+	STREAM_ERROR_STILLPLAYING 0x07
+	ERROR_STILLPLAYING 0x08
+	PALETTE_CHANGED 0x09
+	VIDEO_SIZE_CHANGED 0x0a
+	QUALITY_CHANGE 0x0b
+	SHUTTING_DOWN 0x0c
+	CLOCK_CHANGED 0x0d
+	PAUSED 0x0e
+	OPENING_FILE 0x10
+	BUFFERING_DATA 0x11
+	FULLSCREEN_LOST 0x12
+	ACTIVATE 0x13
+	NEED_RESTART 0x14
+	WINDOW_DESTROYED 0x15
+	DISPLAY_CHANGED 0x16
+	STARVATION 0x17
+	OLE_EVENT 0x18
+	NOTIFY_WINDOW 0x19
+	STREAM_CONTROL_STOPPED 0x1a
+	STREAM_CONTROL_STARTED 0x1b
+	END_OF_SEGMENT 0x1c
+	SEGMENT_STARTED 0x1d
+	LENGTH_CHANGED 0x1e
+	DEVICE_LOST 0x1f
+	STEP_COMPLETE 0x24
+	TIMECODE_AVAILABLE 0x30
+	EXTDEVICE_MODE_CHANGE 0x31
+	STATE_CHANGE 0x32
+	GRAPH_CHANGED 0x50
+	CLOCK_UNSET 0x51
+	VMR_RENDERDEVICE_SET 0x53
+	VMR_SURFACE_FLIPPED 0x54
+	VMR_RECONNECTION_FAILED 0x55
+	PREPROCESS_COMPLETE 0x56
+	CODECAPI_EVENT 0x57
+	USER 0x8000
+}
+
 const_ordinary! { FILTER_STATE: u32;
 	/// [`FILTER_STATE`](https://learn.microsoft.com/en-us/windows/win32/api/strmif/ne-strmif-filter_state)
 	/// enumeration (`u32`).
@@ -24,11 +76,18 @@ const_ordinary! { SEEKING_FLAGS: u32;
 	/// flags (`u32`).
 	///
 	/// Originally `AM_SEEKING_SeekingFlags` enum.
+	///
+	/// The default value is `NoPositioning`.
 	=>
 	=>
+	/// Leaves the position untouched; the value passed along is ignored.
 	NoPositioning 0x0
+	/// The value is an absolute position.
 	AbsolutePositioning 0x1
+	/// The value is relative to the current position.
 	RelativePositioning 0x2
+	/// The stop value is relative to the current position. Only valid for the
+	/// stop flags.
 	IncrementalPositioning 0x3
 	SeekToKeyFrame 0x4
 	ReturnTime 0x8
@@ -95,6 +95,34 @@ pub trait dshow_IGraphBuilder: dshow_IFilterGraph {
 
 	/// [`IGraphBuilder::RenderFile`](https://learn.microsoft.com/en-us/windows/win32/api/strmif/nf-strmif-igraphbuilder-renderfile)
 	/// method.
+	///
+	/// # Examples
+	///
+	/// Building a playback graph from scratch, then obtaining the control,
+	/// seeking and event interfaces from it:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let _com_guard = w::CoInitializeEx(
+	///     co::COINIT::APARTMENTTHREADED | co::COINIT::DISABLE_OLE1DDE)?;
+	///
+	/// let graph = w::CoCreateInstance::<w::IGraphBuilder>(
+	///     &co::CLSID::FilterGraph,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	/// graph.RenderFile("C:\\Temp\\movie.avi")?;
+	///
+	/// let control = graph.QueryInterface::<w::IMediaControl>()?;
+	/// let seeking = graph.QueryInterface::<w::IMediaSeeking>()?;
+	/// let events = graph.QueryInterface::<w::IMediaEventEx>()?;
+	///
+	/// control.Run()?;
+	/// let ev_code = events.WaitForCompletion(None)?;
+	/// println!("{:?} {}", ev_code, seeking.GetDuration()?);
+	/// # w::HrResult::Ok(())
+	/// ```
 	fn RenderFile(&self, file: &str) -> HrResult<()> {
 		ok_to_hrresult(
 			unsafe {
@@ -0,0 +1,187 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::dshow::vts::*;
+use crate::guard::*;
+use crate::kernel::privs::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+
+com_interface! { IMediaEvent: "56a868b6-0ad4-11ce-b03a-0020af0ba770";
+	/// [`IMediaEvent`](https://learn.microsoft.com/en-us/windows/win32/api/control/nn-control-imediaevent)
+	/// COM interface.
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let graph_builder: w::IGraphBuilder; // initialized somewhere
+	/// # let graph_builder = unsafe { w::IGraphBuilder::null() };
+	///
+	/// let media_event = graph_builder
+	///     .QueryInterface::<w::IMediaEvent>()?;
+	/// # w::HrResult::Ok(())
+	/// ```
+}
+
+impl oleaut_IDispatch for IMediaEvent {}
+impl dshow_IMediaEvent for IMediaEvent {}
+
+/// This trait is enabled with the `dshow` feature, and provides methods for
+/// [`IMediaEvent`](crate::IMediaEvent).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait dshow_IMediaEvent: oleaut_IDispatch {
+	/// [`IMediaEvent::CancelDefaultHandling`](https://learn.microsoft.com/en-us/windows/win32/api/control/nf-control-imediaevent-canceldefaulthandling)
+	/// method.
+	fn CancelDefaultHandling(&self, ev_code: co::EVENT_CODE) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IMediaEventVT>(self).CancelDefaultHandling)(
+					self.ptr(),
+					ev_code.raw(),
+				)
+			},
+		)
+	}
+
+	/// [`IMediaEvent::FreeEventParams`](https://learn.microsoft.com/en-us/windows/win32/api/control/nf-control-imediaevent-freeeventparams)
+	/// method.
+	///
+	/// **Note:** Prefer using
+	/// [`IMediaEvent::GetEvent`](crate::prelude::dshow_IMediaEvent::GetEvent),
+	/// whose returned guard calls this method automatically.
+	///
+	/// # Safety
+	///
+	/// The parameters must have been retrieved by a previous call to
+	/// `GetEvent`, and must not be freed twice.
+	unsafe fn FreeEventParams(&self,
+		ev_code: co::EVENT_CODE,
+		param1: isize,
+		param2: isize,
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			(vt::<IMediaEventVT>(self).FreeEventParams)(
+				self.ptr(),
+				ev_code.raw(),
+				param1,
+				param2,
+			),
+		)
+	}
+
+	/// [`IMediaEvent::GetEvent`](https://learn.microsoft.com/en-us/windows/win32/api/control/nf-control-imediaevent-getevent)
+	/// method.
+	///
+	/// Returns `None` if no event arrived before the timeout; pass `Some(0)` to
+	/// not wait at all. The event parameters are freed when the returned guard
+	/// goes out of scope.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let media_event: w::IMediaEvent; // initialized somewhere
+	/// # let media_event = unsafe { w::IMediaEvent::null() };
+	///
+	/// while let Some(ev) = media_event.GetEvent(Some(0))? {
+	///     if ev.ev_code() == co::EVENT_CODE::COMPLETE {
+	///         println!("Playback finished.");
+	///     }
+	/// }
+	/// # w::HrResult::Ok(())
+	/// ```
+	#[must_use]
+	fn GetEvent(&self,
+		ms_timeout: Option<i32>,
+	) -> HrResult<Option<FreeEventParamsGuard<'_, Self>>>
+	{
+		let mut ev_code = co::EVENT_CODE::default();
+		let (mut param1, mut param2) = (isize::default(), isize::default());
+
+		match unsafe {
+			co::HRESULT::from_raw(
+				(vt::<IMediaEventVT>(self).GetEvent)(
+					self.ptr(),
+					ev_code.as_mut(),
+					&mut param1,
+					&mut param2,
+					ms_timeout.unwrap_or(INFINITE as _),
+				),
+			)
+		} {
+			co::HRESULT::S_OK => Ok(Some(
+				unsafe { FreeEventParamsGuard::new(self, ev_code, param1, param2) },
+			)),
+			co::HRESULT::E_ABORT => Ok(None), // timeout expired
+			hr => Err(hr),
+		}
+	}
+
+	/// [`IMediaEvent::GetEventHandle`](https://learn.microsoft.com/en-us/windows/win32/api/control/nf-control-imediaevent-geteventhandle)
+	/// method.
+	///
+	/// The returned event is owned by the filter graph manager, and must not be
+	/// closed.
+	#[must_use]
+	fn GetEventHandle(&self) -> HrResult<HEVENT> {
+		let mut hevent = isize::default();
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IMediaEventVT>(self).GetEventHandle)(self.ptr(), &mut hevent)
+			},
+		).map(|_| unsafe { HEVENT::from_ptr(hevent as _) })
+	}
+
+	/// [`IMediaEvent::RestoreDefaultHandling`](https://learn.microsoft.com/en-us/windows/win32/api/control/nf-control-imediaevent-restoredefaulthandling)
+	/// method.
+	fn RestoreDefaultHandling(&self, ev_code: co::EVENT_CODE) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IMediaEventVT>(self).RestoreDefaultHandling)(
+					self.ptr(),
+					ev_code.raw(),
+				)
+			},
+		)
+	}
+
+	/// [`IMediaEvent::WaitForCompletion`](https://learn.microsoft.com/en-us/windows/win32/api/control/nf-control-imediaevent-waitforcompletion)
+	/// method.
+	///
+	/// Returns the event which terminated the playback, or `None` if the
+	/// timeout expired.
+	#[must_use]
+	fn WaitForCompletion(&self,
+		ms_timeout: Option<i32>,
+	) -> HrResult<Option<co::EVENT_CODE>>
+	{
+		let mut ev_code = co::EVENT_CODE::default();
+		match unsafe {
+			co::HRESULT::from_raw(
+				(vt::<IMediaEventVT>(self).WaitForCompletion)(
+					self.ptr(),
+					ms_timeout.unwrap_or(INFINITE as _),
+					ev_code.as_mut(),
+				),
+			)
+		} {
+			co::HRESULT::S_OK => Ok(Some(ev_code)),
+			co::HRESULT::E_ABORT => Ok(None), // timeout expired
+			hr => Err(hr),
+		}
+	}
+}
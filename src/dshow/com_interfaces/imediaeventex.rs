@@ -0,0 +1,129 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::dshow::vts::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+
+com_interface! { IMediaEventEx: "56a868c0-0ad4-11ce-b03a-0020af0ba770";
+	/// [`IMediaEventEx`](https://learn.microsoft.com/en-us/windows/win32/api/control/nn-control-imediaeventex)
+	/// COM interface.
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let graph_builder: w::IGraphBuilder; // initialized somewhere
+	/// # let graph_builder = unsafe { w::IGraphBuilder::null() };
+	///
+	/// let media_event = graph_builder
+	///     .QueryInterface::<w::IMediaEventEx>()?;
+	/// # w::HrResult::Ok(())
+	/// ```
+}
+
+impl oleaut_IDispatch for IMediaEventEx {}
+impl dshow_IMediaEvent for IMediaEventEx {}
+impl dshow_IMediaEventEx for IMediaEventEx {}
+
+/// This trait is enabled with the `dshow` feature, and provides methods for
+/// [`IMediaEventEx`](crate::IMediaEventEx).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait dshow_IMediaEventEx: dshow_IMediaEvent {
+	/// [`IMediaEventEx::GetNotifyFlags`](https://learn.microsoft.com/en-us/windows/win32/api/control/nf-control-imediaeventex-getnotifyflags)
+	/// method.
+	///
+	/// Returns `true` if event notification is disabled.
+	#[must_use]
+	fn GetNotifyFlags(&self) -> HrResult<bool> {
+		let mut flags = i32::default();
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IMediaEventExVT>(self).GetNotifyFlags)(self.ptr(), &mut flags)
+			},
+		).map(|_| flags & 0x01 != 0) // AM_MEDIAEVENT_NONOTIFY
+	}
+
+	/// [`IMediaEventEx::SetNotifyFlags`](https://learn.microsoft.com/en-us/windows/win32/api/control/nf-control-imediaeventex-setnotifyflags)
+	/// method.
+	///
+	/// If `no_notify` is `true`, event notification is disabled, and the
+	/// filter graph manager discards the events.
+	fn SetNotifyFlags(&self, no_notify: bool) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IMediaEventExVT>(self).SetNotifyFlags)(
+					self.ptr(),
+					if no_notify { 0x01 } else { 0 }, // AM_MEDIAEVENT_NONOTIFY
+				)
+			},
+		)
+	}
+
+	/// [`IMediaEventEx::SetNotifyWindow`](https://learn.microsoft.com/en-us/windows/win32/api/control/nf-control-imediaeventex-setnotifywindow)
+	/// method.
+	///
+	/// The given message is posted to the window whenever a new event is
+	/// queued, and `instance_data` is passed as its `lparam`. The events must
+	/// then be retrieved with
+	/// [`IMediaEvent::GetEvent`](crate::prelude::dshow_IMediaEvent::GetEvent).
+	///
+	/// Before the window is destroyed, call this method again with
+	/// [`HWND::NULL`](crate::HWND::NULL) to stop the notifications.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui, msg};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// let media_event: w::IMediaEventEx;
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let media_event = unsafe { w::IMediaEventEx::null() };
+	///
+	/// let WM_GRAPHNOTIFY = unsafe { co::WM::from_raw(co::WM::APP.raw() + 1) };
+	///
+	/// media_event.SetNotifyWindow(wnd.hwnd(), WM_GRAPHNOTIFY, 0)?;
+	///
+	/// wnd.on().wm(
+	///     WM_GRAPHNOTIFY,
+	///     move |_: msg::WndMsg| -> w::AnyResult<gui::WmRet> {
+	///         while let Some(ev) = media_event.GetEvent(Some(0))? {
+	///             if ev.ev_code() == co::EVENT_CODE::COMPLETE {
+	///                 println!("Playback finished.");
+	///             }
+	///         }
+	///         Ok(gui::WmRet::HandledOk)
+	///     },
+	/// );
+	/// # w::HrResult::Ok(())
+	/// ```
+	fn SetNotifyWindow(&self,
+		hwnd: &HWND,
+		msg: co::WM,
+		instance_data: isize,
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IMediaEventExVT>(self).SetNotifyWindow)(
+					self.ptr(),
+					hwnd.ptr() as _,
+					msg.raw() as _,
+					instance_data,
+				)
+			},
+		)
+	}
+}
@@ -178,6 +178,29 @@ pub trait dshow_IMediaSeeking: ole_IUnknown {
 
 	/// [`IMediaSeeking::SetPositions`](https://learn.microsoft.com/en-us/windows/win32/api/strmif/nf-strmif-imediaseeking-setpositions)
 	/// method.
+	///
+	/// To change only one of the positions, pass
+	/// [`SEEKING_FLAGS::NoPositioning`](crate::co::SEEKING_FLAGS::NoPositioning)
+	/// – the default value – as the flags of the other one.
+	///
+	/// # Examples
+	///
+	/// Seeking to 10 seconds, leaving the stop position untouched:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let seeking: w::IMediaSeeking; // initialized somewhere
+	/// # let seeking = unsafe { w::IMediaSeeking::null() };
+	///
+	/// seeking.SetPositions(
+	///     10 * 10_000_000, // 100-nanosecond units
+	///     co::SEEKING_FLAGS::AbsolutePositioning,
+	///     0,
+	///     co::SEEKING_FLAGS::default(),
+	/// )?;
+	/// # w::HrResult::Ok(())
+	/// ```
 	fn SetPositions(&self,
 		current: i64,
 		current_flags: co::SEEKING_FLAGS,
@@ -7,6 +7,8 @@ mod ifiltergraph;
 mod ifiltergraph2;
 mod igraphbuilder;
 mod imediacontrol;
+mod imediaevent;
+mod imediaeventex;
 mod imediafilter;
 mod imediaseeking;
 mod ipin;
@@ -21,6 +23,8 @@ pub mod decl {
 	pub use super::ifiltergraph2::IFilterGraph2;
 	pub use super::igraphbuilder::IGraphBuilder;
 	pub use super::imediacontrol::IMediaControl;
+	pub use super::imediaevent::IMediaEvent;
+	pub use super::imediaeventex::IMediaEventEx;
 	pub use super::imediafilter::IMediaFilter;
 	pub use super::imediaseeking::IMediaSeeking;
 	pub use super::ipin::IPin;
@@ -36,6 +40,8 @@ pub mod traits {
 	pub use super::ifiltergraph2::dshow_IFilterGraph2;
 	pub use super::igraphbuilder::dshow_IGraphBuilder;
 	pub use super::imediacontrol::dshow_IMediaControl;
+	pub use super::imediaevent::dshow_IMediaEvent;
+	pub use super::imediaeventex::dshow_IMediaEventEx;
 	pub use super::imediafilter::dshow_IMediaFilter;
 	pub use super::imediaseeking::dshow_IMediaSeeking;
 	pub use super::ipin::dshow_IPin;
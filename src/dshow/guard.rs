@@ -0,0 +1,70 @@
+use crate::co;
+use crate::prelude::*;
+
+/// RAII implementation for the parameters returned by
+/// [`IMediaEvent::GetEvent`](crate::prelude::dshow_IMediaEvent::GetEvent),
+/// which automatically calls
+/// [`IMediaEvent::FreeEventParams`](crate::prelude::dshow_IMediaEvent::FreeEventParams)
+/// when the object goes out of scope.
+pub struct FreeEventParamsGuard<'a, T>
+	where T: dshow_IMediaEvent,
+{
+	media_event: &'a T,
+	ev_code: co::EVENT_CODE,
+	param1: isize,
+	param2: isize,
+}
+
+impl<'a, T> Drop for FreeEventParamsGuard<'a, T>
+	where T: dshow_IMediaEvent,
+{
+	fn drop(&mut self) {
+		unsafe {
+			let _ = self.media_event.FreeEventParams( // ignore errors
+				self.ev_code, self.param1, self.param2);
+		}
+	}
+}
+
+impl<'a, T> FreeEventParamsGuard<'a, T>
+	where T: dshow_IMediaEvent,
+{
+	/// Constructs the guard by keeping the reference to the COM pointer and
+	/// taking ownership of the event parameters.
+	///
+	/// # Safety
+	///
+	/// Be sure the parameters must be freed with
+	/// [`IMediaEvent::FreeEventParams`](crate::prelude::dshow_IMediaEvent::FreeEventParams)
+	/// at the end of scope.
+	#[must_use]
+	pub const unsafe fn new(
+		media_event: &'a T,
+		ev_code: co::EVENT_CODE,
+		param1: isize,
+		param2: isize,
+	) -> Self
+	{
+		Self { media_event, ev_code, param1, param2 }
+	}
+
+	/// Returns the event code.
+	#[must_use]
+	pub const fn ev_code(&self) -> co::EVENT_CODE {
+		self.ev_code
+	}
+
+	/// Returns the first event parameter, whose meaning depends on the event
+	/// code.
+	#[must_use]
+	pub const fn param1(&self) -> isize {
+		self.param1
+	}
+
+	/// Returns the second event parameter, whose meaning depends on the event
+	/// code.
+	#[must_use]
+	pub const fn param2(&self) -> isize {
+		self.param2
+	}
+}
@@ -6,6 +6,7 @@ mod structs;
 pub (in crate::dshow) mod iterators;
 pub (in crate::dshow) mod vts;
 pub mod co;
+pub mod guard;
 
 pub mod decl {
 	pub use super::com_interfaces::decl::*;
@@ -95,6 +95,25 @@ pub struct IMediaControlVT {
 	pub StopWhenReady: fn(COMPTR) -> HRES,
 }
 
+#[repr(C)]
+pub struct IMediaEventVT {
+	pub IDispatchVT: IDispatchVT,
+	pub GetEventHandle: fn(COMPTR, *mut isize) -> HRES,
+	pub GetEvent: fn(COMPTR, *mut i32, *mut isize, *mut isize, i32) -> HRES,
+	pub WaitForCompletion: fn(COMPTR, i32, *mut i32) -> HRES,
+	pub CancelDefaultHandling: fn(COMPTR, i32) -> HRES,
+	pub RestoreDefaultHandling: fn(COMPTR, i32) -> HRES,
+	pub FreeEventParams: fn(COMPTR, i32, isize, isize) -> HRES,
+}
+
+#[repr(C)]
+pub struct IMediaEventExVT {
+	pub IMediaEventVT: IMediaEventVT,
+	pub SetNotifyWindow: fn(COMPTR, isize, i32, isize) -> HRES,
+	pub SetNotifyFlags: fn(COMPTR, i32) -> HRES,
+	pub GetNotifyFlags: fn(COMPTR, *mut i32) -> HRES,
+}
+
 #[repr(C)]
 pub struct IMediaFilterVT {
 	pub IPersistVT: IPersistVT,
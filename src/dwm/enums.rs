@@ -0,0 +1,65 @@
+use crate::co;
+
+/// Variant parameter for:
+///
+/// * [`HWND::DwmSetWindowAttribute`](crate::prelude::dwm_Hwnd::DwmSetWindowAttribute).
+///
+/// The enum values match those in
+/// [`co::DWMWA`](crate::co::DWMWA) constant type.
+#[derive(Clone, Copy)]
+pub enum DwmAttr {
+	/// [`co::DWMWA::TRANSITIONS_FORCEDISABLED`](crate::co::DWMWA::TRANSITIONS_FORCEDISABLED),
+	/// disables the window transitions, like minimizing and restoring.
+	TransitionsForceDisabled(bool),
+	/// [`co::DWMWA::ALLOW_NCPAINT`](crate::co::DWMWA::ALLOW_NCPAINT), lets
+	/// the content rendered in the non-client area be visible on the frame
+	/// drawn by DWM.
+	AllowNcPaint(bool),
+	/// [`co::DWMWA::NONCLIENT_RTL_LAYOUT`](crate::co::DWMWA::NONCLIENT_RTL_LAYOUT),
+	/// lays out the non-client area right-to-left.
+	NonClientRtlLayout(bool),
+	/// [`co::DWMWA::FORCE_ICONIC_REPRESENTATION`](crate::co::DWMWA::FORCE_ICONIC_REPRESENTATION),
+	/// forces the window to display an iconic thumbnail or peek
+	/// representation, instead of its actual content.
+	ForceIconicRepresentation(bool),
+	/// [`co::DWMWA::HAS_ICONIC_BITMAP`](crate::co::DWMWA::HAS_ICONIC_BITMAP),
+	/// tells DWM that the window provides its own iconic thumbnail or peek
+	/// bitmap.
+	HasIconicBitmap(bool),
+	/// [`co::DWMWA::DISALLOW_PEEK`](crate::co::DWMWA::DISALLOW_PEEK), prevents
+	/// peek from being shown when hovering the taskbar thumbnail.
+	DisallowPeek(bool),
+	/// [`co::DWMWA::EXCLUDED_FROM_PEEK`](crate::co::DWMWA::EXCLUDED_FROM_PEEK),
+	/// keeps the window visible, instead of fading it out, when peek is
+	/// invoked.
+	ExcludedFromPeek(bool),
+	/// [`co::DWMWA::CLOAK`](crate::co::DWMWA::CLOAK), hides the window
+	/// without removing it from the composition.
+	Cloak(bool),
+	/// [`co::DWMWA::FREEZE_REPRESENTATION`](crate::co::DWMWA::FREEZE_REPRESENTATION),
+	/// freezes the thumbnail image of the window with its current visuals.
+	FreezeRepresentation(bool),
+	/// [`co::DWMWA::USE_IMMERSIVE_DARK_MODE`](crate::co::DWMWA::USE_IMMERSIVE_DARK_MODE),
+	/// draws the window frame in dark mode colors. Requires Windows 11 build
+	/// 22000 or later.
+	UseImmersiveDarkMode(bool),
+}
+
+impl DwmAttr {
+	/// Returns the attribute identifier and its value, as a `BOOL`.
+	#[must_use]
+	pub(in crate::dwm) const fn attr_and_val(&self) -> (co::DWMWA, i32) {
+		match *self {
+			Self::TransitionsForceDisabled(b) => (co::DWMWA::TRANSITIONS_FORCEDISABLED, b as _),
+			Self::AllowNcPaint(b) => (co::DWMWA::ALLOW_NCPAINT, b as _),
+			Self::NonClientRtlLayout(b) => (co::DWMWA::NONCLIENT_RTL_LAYOUT, b as _),
+			Self::ForceIconicRepresentation(b) => (co::DWMWA::FORCE_ICONIC_REPRESENTATION, b as _),
+			Self::HasIconicBitmap(b) => (co::DWMWA::HAS_ICONIC_BITMAP, b as _),
+			Self::DisallowPeek(b) => (co::DWMWA::DISALLOW_PEEK, b as _),
+			Self::ExcludedFromPeek(b) => (co::DWMWA::EXCLUDED_FROM_PEEK, b as _),
+			Self::Cloak(b) => (co::DWMWA::CLOAK, b as _),
+			Self::FreezeRepresentation(b) => (co::DWMWA::FREEZE_REPRESENTATION, b as _),
+			Self::UseImmersiveDarkMode(b) => (co::DWMWA::USE_IMMERSIVE_DARK_MODE, b as _),
+		}
+	}
+}
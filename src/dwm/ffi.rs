@@ -9,4 +9,5 @@ extern_sys! { "dwmapi";
 	DwmIsCompositionEnabled(*mut BOOL) -> HRES
 	DwmSetIconicLivePreviewBitmap(HANDLE, HANDLE, PCVOID, u32) -> HRES
 	DwmSetIconicThumbnail(HANDLE, HANDLE, u32) -> HRES
+	DwmSetWindowAttribute(HANDLE, u32, PCVOID, u32) -> HRES
 }
@@ -35,6 +35,17 @@ pub trait dwm_Hwnd: uxtheme_Hwnd {
 
 	/// [`DwmInvalidateIconicBitmaps`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwminvalidateiconicbitmaps)
 	/// function.
+	///
+	/// Discards the bitmaps set with
+	/// [`HWND::DwmSetIconicThumbnail`](crate::prelude::dwm_Hwnd::DwmSetIconicThumbnail)
+	/// and
+	/// [`HWND::DwmSetIconicLivePreviewBitmap`](crate::prelude::dwm_Hwnd::DwmSetIconicLivePreviewBitmap),
+	/// so the window will receive the
+	/// [`WM::DWMSENDICONICTHUMBNAIL`](crate::co::WM::DWMSENDICONICTHUMBNAIL)
+	/// and
+	/// [`WM::DWMSENDICONICLIVEPREVIEWBITMAP`](crate::co::WM::DWMSENDICONICLIVEPREVIEWBITMAP)
+	/// messages again when they're needed. Call it whenever the window content
+	/// changes.
 	fn DwmInvalidateIconicBitmaps(&self) -> HrResult<()> {
 		ok_to_hrresult(
 			unsafe { ffi::DwmInvalidateIconicBitmaps(self.ptr()) },
@@ -43,6 +54,11 @@ pub trait dwm_Hwnd: uxtheme_Hwnd {
 
 	/// [`DwmSetIconicLivePreviewBitmap`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwmseticoniclivepreviewbitmap)
 	/// function.
+	///
+	/// Should be called in response to
+	/// [`WM::DWMSENDICONICLIVEPREVIEWBITMAP`](crate::co::WM::DWMSENDICONICLIVEPREVIEWBITMAP).
+	/// Requires the same setup of
+	/// [`HWND::DwmSetIconicThumbnail`](crate::prelude::dwm_Hwnd::DwmSetIconicThumbnail).
 	fn DwmSetIconicLivePreviewBitmap(&self,
 		hbmp: HBITMAP,
 		pt_client: Option<POINT>,
@@ -54,7 +70,7 @@ pub trait dwm_Hwnd: uxtheme_Hwnd {
 				ffi::DwmSetIconicLivePreviewBitmap(
 					self.ptr(),
 					hbmp.ptr(),
-					pt_client.map_or(std::ptr::null(), |pt| &pt as *const _ as _),
+					pt_client.as_ref().map_or(std::ptr::null(), |pt| pt as *const _ as _),
 					sit_flags.unwrap_or_default().raw(),
 				)
 			},
@@ -63,6 +79,39 @@ pub trait dwm_Hwnd: uxtheme_Hwnd {
 
 	/// [`DwmSetIconicThumbnail`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwmseticonicthumbnail)
 	/// function.
+	///
+	/// Sets the bitmap shown in the taskbar thumbnail, and should be called in
+	/// response to
+	/// [`WM::DWMSENDICONICTHUMBNAIL`](crate::co::WM::DWMSENDICONICTHUMBNAIL),
+	/// whose `lParam` carries the maximum width (high word) and height (low
+	/// word) of the bitmap. The bitmap must be a 32-bit DIB section.
+	///
+	/// The window will only receive this message after both
+	/// [`DwmAttr::ForceIconicRepresentation`](crate::DwmAttr::ForceIconicRepresentation)
+	/// and [`DwmAttr::HasIconicBitmap`](crate::DwmAttr::HasIconicBitmap) are
+	/// set.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	/// let hbmp: w::HBITMAP; // 32-bit DIB section with the thumbnail
+	/// # let hbmp = w::HBITMAP::NULL;
+	///
+	/// // Once, after the window is created:
+	/// hwnd.DwmSetWindowAttribute(w::DwmAttr::ForceIconicRepresentation(true))?;
+	/// hwnd.DwmSetWindowAttribute(w::DwmAttr::HasIconicBitmap(true))?;
+	///
+	/// // When processing WM_DWMSENDICONICTHUMBNAIL:
+	/// hwnd.DwmSetIconicThumbnail(hbmp, None)?;
+	///
+	/// // When the content changes, and the thumbnail is stale:
+	/// hwnd.DwmInvalidateIconicBitmaps()?;
+	/// # w::HrResult::Ok(())
+	/// ```
 	fn DwmSetIconicThumbnail(&self,
 		hbmp: HBITMAP,
 		sit_flags: Option<co::DWM_SIT>,
@@ -78,4 +127,32 @@ pub trait dwm_Hwnd: uxtheme_Hwnd {
 			},
 		)
 	}
+
+	/// [`DwmSetWindowAttribute`](https://learn.microsoft.com/en-us/windows/win32/api/dwmapi/nf-dwmapi-dwmsetwindowattribute)
+	/// function.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.DwmSetWindowAttribute(w::DwmAttr::UseImmersiveDarkMode(true))?;
+	/// # w::HrResult::Ok(())
+	/// ```
+	fn DwmSetWindowAttribute(&self, attr: DwmAttr) -> HrResult<()> {
+		let (attr, val) = attr.attr_and_val();
+		ok_to_hrresult(
+			unsafe {
+				ffi::DwmSetWindowAttribute(
+					self.ptr(),
+					attr.raw(),
+					&val as *const _ as _,
+					std::mem::size_of_val(&val) as _,
+				)
+			},
+		)
+	}
 }
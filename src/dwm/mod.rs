@@ -1,5 +1,6 @@
 #![cfg(feature = "dwm")]
 
+mod enums;
 mod funcs;
 mod handles;
 
@@ -7,6 +8,7 @@ pub(in crate::dwm) mod ffi;
 pub mod co;
 
 pub mod decl {
+	pub use super::enums::*;
 	pub use super::funcs::*;
 }
 
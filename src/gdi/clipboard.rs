@@ -0,0 +1,91 @@
+use crate::co;
+use crate::decl::*;
+use crate::gdi::ffi;
+use crate::guard::*;
+use crate::kernel::privs::*;
+use crate::prelude::*;
+
+/// Replaces the clipboard contents with a copy of the given bitmap, as
+/// [`CF::BITMAP`](crate::co::CF::BITMAP).
+///
+/// Since the system takes ownership of the bitmap handed to the clipboard, a
+/// copy is made with
+/// [`CopyImage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-copyimage),
+/// and the given `hbmp` remains owned by the caller. The system will also
+/// provide the bitmap as [`CF::DIB`](crate::co::CF::DIB) to other
+/// applications.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+/// let hbmp: w::HBITMAP; // initialized somewhere
+/// # let hbmp = w::HBITMAP::NULL;
+///
+/// w::clipboard::set_bitmap(&hwnd, &hbmp)?;
+/// # w::SysResult::Ok(())
+/// ```
+pub fn set_bitmap(hwnd: &HWND, hbmp: &HBITMAP) -> SysResult<()> {
+	let mut hbmp_copy = copy_bitmap(hbmp)?;
+
+	let _clip_guard = crate::clipboard::open(hwnd)?;
+	EmptyClipboard()?;
+	unsafe {
+		SetClipboardData(co::CF::BITMAP, hbmp_copy.ptr() as _)
+			.map(|_| { let _ = hbmp_copy.leak(); }) // now owned by the system
+	}
+}
+
+/// Retrieves a copy of the bitmap in the clipboard, if
+/// [`CF::BITMAP`](crate::co::CF::BITMAP) is available. The system converts
+/// [`CF::DIB`](crate::co::CF::DIB) data automatically.
+///
+/// The bitmap owned by the clipboard is copied with
+/// [`CopyImage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-copyimage),
+/// so the returned handle remains valid after the clipboard is closed.
+///
+/// Returns `None` if the clipboard has no bitmap.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// if let Some(hbmp) = w::clipboard::get_bitmap(&hwnd)? {
+///     let mut bmp = w::BITMAP::default();
+///     hbmp.GetObject(&mut bmp)?;
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn get_bitmap(hwnd: &HWND) -> SysResult<Option<DeleteObjectGuard<HBITMAP>>> {
+	let _clip_guard = crate::clipboard::open(hwnd)?;
+	if !IsClipboardFormatAvailable(co::CF::BITMAP) {
+		return Ok(None);
+	}
+
+	let hbmp = unsafe {
+		HBITMAP::from_ptr(GetClipboardData(co::CF::BITMAP)? as _) // owned by the clipboard
+	};
+	copy_bitmap(&hbmp).map(Some)
+}
+
+#[must_use]
+fn copy_bitmap(hbmp: &HBITMAP) -> SysResult<DeleteObjectGuard<HBITMAP>> {
+	unsafe {
+		ptr_to_sysresult_handle(
+			ffi::CopyImage(
+				hbmp.ptr(),
+				co::IMAGE_TYPE::BITMAP.raw() as _,
+				0, 0,
+				co::LR::DEFAULTCOLOR.raw(),
+			),
+		).map(|h| DeleteObjectGuard::new(h))
+	}
+}
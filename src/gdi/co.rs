@@ -105,6 +105,16 @@ const_ordinary! { CUR_OBJ: u32;
 	PEN 1
 }
 
+const_bitflag! { DI: u32;
+	/// [`DOCINFO`](crate::DOCINFO) `fwType` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	APPBANDING 0x0000_0001
+	ROPS_READ_DESTINATION 0x0000_0002
+}
+
 const_ordinary! { DIB: u32;
 	/// [`LOGBRUSH`](crate::LOGBRUSH) `lbColor` (`u32`).
 	=>
@@ -298,6 +308,75 @@ impl PITCH {
 	}
 }
 
+const_bitflag! { PRINTER_ATTRIBUTE: u32;
+	/// [`PrinterInfo`](crate::PrinterInfo) `attributes` (`u32`).
+	=>
+	=>
+	QUEUED 0x0000_0001
+	DIRECT 0x0000_0002
+	DEFAULT 0x0000_0004
+	SHARED 0x0000_0008
+	NETWORK 0x0000_0010
+	HIDDEN 0x0000_0020
+	LOCAL 0x0000_0040
+	ENABLE_DEVQ 0x0000_0080
+	KEEPPRINTEDJOBS 0x0000_0100
+	DO_COMPLETE_FIRST 0x0000_0200
+	WORK_OFFLINE 0x0000_0400
+	ENABLE_BIDI 0x0000_0800
+	RAW_ONLY 0x0000_1000
+	PUBLISHED 0x0000_2000
+	FAX 0x0000_4000
+	TS 0x0000_8000
+}
+
+const_bitflag! { PRINTER_ENUM: u32;
+	/// [`EnumPrinters`](crate::EnumPrinters) `flags` (`u32`).
+	=>
+	=>
+	DEFAULT 0x0000_0001
+	LOCAL 0x0000_0002
+	CONNECTIONS 0x0000_0004
+	FAVORITE 0x0000_0004
+	NAME 0x0000_0008
+	REMOTE 0x0000_0010
+	SHARED 0x0000_0020
+	NETWORK 0x0000_0040
+}
+
+const_bitflag! { PRINTER_STATUS: u32;
+	/// [`PrinterInfo`](crate::PrinterInfo) `status` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	PAUSED 0x0000_0001
+	ERROR 0x0000_0002
+	PENDING_DELETION 0x0000_0004
+	PAPER_JAM 0x0000_0008
+	PAPER_OUT 0x0000_0010
+	MANUAL_FEED 0x0000_0020
+	PAPER_PROBLEM 0x0000_0040
+	OFFLINE 0x0000_0080
+	IO_ACTIVE 0x0000_0100
+	BUSY 0x0000_0200
+	PRINTING 0x0000_0400
+	OUTPUT_BIN_FULL 0x0000_0800
+	NOT_AVAILABLE 0x0000_1000
+	WAITING 0x0000_2000
+	PROCESSING 0x0000_4000
+	INITIALIZING 0x0000_8000
+	WARMING_UP 0x0001_0000
+	TONER_LOW 0x0002_0000
+	NO_TONER 0x0004_0000
+	PAGE_PUNT 0x0008_0000
+	USER_INTERVENTION 0x0010_0000
+	OUT_OF_MEMORY 0x0020_0000
+	DOOR_OPEN 0x0040_0000
+	SERVER_UNKNOWN 0x0080_0000
+	POWER_SAVE 0x0100_0000
+}
+
 const_ordinary! { PS: i32;
 	/// [`HPEN::CreatePen`](crate::prelude::gdi_Hpen::CreatePen) `style`
 	/// (`i32`).
@@ -1,6 +1,7 @@
 use crate::kernel::ffi_types::*;
 
 extern_sys! { "gdi32";
+	AbortDoc(HANDLE) -> i32
 	AbortPath(HANDLE) -> BOOL
 	AngleArc(HANDLE, i32, i32, u32, f32, f32) -> BOOL
 	Arc(HANDLE, i32, i32, i32, i32, i32, i32, i32, i32) -> BOOL
@@ -14,6 +15,8 @@ extern_sys! { "gdi32";
 	CreateBrushIndirect(PCVOID) -> HANDLE
 	CreateCompatibleBitmap(HANDLE, i32, i32) -> HANDLE
 	CreateCompatibleDC(HANDLE) -> HANDLE
+	CreateDCW(PCSTR, PCSTR, PCSTR, PCVOID) -> HANDLE
+	CreateDIBSection(HANDLE, PCVOID, u32, *mut PVOID, HANDLE, u32) -> HANDLE
 	CreateFontIndirectW(PCVOID) -> HANDLE
 	CreateFontW(i32, i32, i32, i32, i32, u32, u32, u32, u32, u32, u32, u32, u32, PCSTR) -> HANDLE
 	CreateHalftonePalette(HANDLE) -> HANDLE
@@ -29,6 +32,8 @@ extern_sys! { "gdi32";
 	DeleteDC(HANDLE) -> BOOL
 	DeleteObject(HANDLE) -> BOOL
 	Ellipse(HANDLE, i32, i32, i32, i32) -> BOOL
+	EndDoc(HANDLE) -> i32
+	EndPage(HANDLE) -> i32
 	EndPath(HANDLE) -> BOOL
 	FillPath(HANDLE) -> BOOL
 	FillRect(HANDLE, PCVOID, HANDLE) -> i32
@@ -96,6 +101,8 @@ extern_sys! { "gdi32";
 	SetViewportOrgEx(HANDLE, i32, i32, PVOID) -> BOOL
 	SetWindowExtEx(HANDLE, i32, i32, PVOID) -> BOOL
 	SetWindowOrgEx(HANDLE, i32, i32, PVOID) -> BOOL
+	StartDocW(HANDLE, PCVOID) -> i32
+	StartPage(HANDLE) -> i32
 	StretchBlt(HANDLE, i32, i32, i32, i32, HANDLE, i32, i32, i32, i32, u32) -> BOOL
 	StrokeAndFillPath(HANDLE) -> BOOL
 	StrokePath(HANDLE) -> BOOL
@@ -110,6 +117,19 @@ extern_sys! { "msimg32";
 	TransparentBlt(HANDLE, i32, i32, i32, i32, HANDLE, i32, i32, i32, i32, u32) -> BOOL
 }
 
+// The import library is winspool.lib, but the DLL is winspool.drv.
+#[cfg_attr(not(feature = "raw-dylib"), link(name = "winspool"))]
+#[cfg_attr(feature = "raw-dylib", link(name = "winspool.drv", kind = "raw-dylib"))]
+extern "system" {
+	one_func!(ClosePrinter(HANDLE) -> BOOL);
+	one_func!(DocumentPropertiesW(HANDLE, HANDLE, PCSTR, PVOID, PCVOID, u32) -> i32);
+	one_func!(EnumPrintersW(u32, PCSTR, u32, PVOID, u32, *mut u32, *mut u32) -> BOOL);
+	one_func!(GetDefaultPrinterW(PSTR, *mut u32) -> BOOL);
+	one_func!(OpenPrinterW(PCSTR, *mut HANDLE, PCVOID) -> BOOL);
+}
+
 extern_sys! { "user32";
+	CopyImage(HANDLE, u32, i32, i32, u32) -> HANDLE // returns GdiObjectGuard, so needs gdi feature
+	CreateIconFromResourceEx(PCVOID, u32, BOOL, u32, i32, i32, u32) -> HANDLE // LR flags need gdi feature
 	LoadImageW(HANDLE, PCSTR, u32, i32, i32, u32) -> HANDLE // returns GdiObjectGuard, so needs gdi feature
 }
@@ -1,9 +1,103 @@
 #![allow(non_snake_case)]
 
+use crate::co;
 use crate::decl::*;
 use crate::gdi::ffi;
 use crate::kernel::privs::*;
 
+/// [`EnumPrinters`](https://learn.microsoft.com/en-us/windows/win32/printdocs/enumprinters)
+/// function.
+///
+/// Internally queries
+/// [`PRINTER_INFO_2`](https://learn.microsoft.com/en-us/windows/win32/printdocs/printer-info-2),
+/// copying the returned data into owned [`PrinterInfo`](crate::PrinterInfo)
+/// objects.
+///
+/// # Examples
+///
+/// Listing the local and connected printers:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let printers = w::EnumPrinters(
+///     co::PRINTER_ENUM::LOCAL | co::PRINTER_ENUM::CONNECTIONS,
+///     None,
+/// )?;
+///
+/// for printer in printers.iter() {
+///     println!("{} on {}{}",
+///         printer.name, printer.port_name,
+///         if printer.is_default { " (default)" } else { "" });
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`GetDefaultPrinter`](crate::GetDefaultPrinter)
+#[must_use]
+pub fn EnumPrinters(
+	flags: co::PRINTER_ENUM,
+	name: Option<&str>,
+) -> SysResult<Vec<PrinterInfo>>
+{
+	let wname = WString::from_opt_str(name);
+	let mut needed = u32::default();
+	let mut returned = u32::default();
+
+	// Buffer of usize, so the PRINTER_INFO_2 array is properly aligned.
+	let mut buf = Vec::<usize>::new();
+
+	loop { // printers may be added between the calls, so retry until it fits
+		match bool_to_sysresult(
+			unsafe {
+				ffi::EnumPrintersW(flags.raw(), wname.as_ptr(), 2,
+					buf.as_mut_ptr() as _,
+					(buf.len() * std::mem::size_of::<usize>()) as _,
+					&mut needed, &mut returned)
+			},
+		) {
+			Ok(_) => break,
+			Err(co::ERROR::INSUFFICIENT_BUFFER) => buf = vec![0usize;
+				(needed as usize).div_ceil(std::mem::size_of::<usize>())],
+			Err(e) => return Err(e),
+		}
+	}
+
+	let default_name = GetDefaultPrinter().ok();
+	let str_opt = |psz: *mut u16| unsafe { psz.as_ref() }
+		.map(|psz| unsafe { WString::from_wchars_nullt(psz) }.to_string())
+		.filter(|s| !s.is_empty());
+
+	let infos = unsafe {
+		std::slice::from_raw_parts(
+			buf.as_ptr() as *const PRINTER_INFO_2,
+			returned as _,
+		)
+	};
+	Ok(
+		infos.iter()
+			.map(|pi| {
+				let name = str_opt(pi.pPrinterName).unwrap_or_default();
+				PrinterInfo {
+					is_default: default_name.as_ref() == Some(&name),
+					name,
+					server_name: str_opt(pi.pServerName),
+					share_name: str_opt(pi.pShareName),
+					port_name: str_opt(pi.pPortName).unwrap_or_default(),
+					driver_name: str_opt(pi.pDriverName).unwrap_or_default(),
+					comment: str_opt(pi.pComment),
+					location: str_opt(pi.pLocation),
+					attributes: unsafe { co::PRINTER_ATTRIBUTE::from_raw(pi.Attributes) },
+					status: unsafe { co::PRINTER_STATUS::from_raw(pi.Status) },
+					jobs: pi.cJobs,
+				}
+			})
+			.collect(),
+	)
+}
+
 /// [`GdiFlush`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-gdiflush)
 /// function.
 pub fn GdiFlush() -> SysResult<()> {
@@ -33,3 +127,57 @@ pub fn GdiSetBatchLimit(limit: u32) -> SysResult<u32> {
 		n => Ok(n),
 	}
 }
+
+/// [`GetDefaultPrinter`](https://learn.microsoft.com/en-us/windows/win32/printdocs/getdefaultprinter)
+/// function.
+///
+/// Fails with [`co::ERROR::FILE_NOT_FOUND`](crate::co::ERROR::FILE_NOT_FOUND)
+/// if there is no default printer.
+///
+/// # Related functions
+///
+/// * [`EnumPrinters`](crate::EnumPrinters)
+#[must_use]
+pub fn GetDefaultPrinter() -> SysResult<String> {
+	let mut name_sz = u32::default();
+
+	unsafe { ffi::GetDefaultPrinterW(std::ptr::null_mut(), &mut name_sz); }
+	let get_size_err = GetLastError();
+	if get_size_err != co::ERROR::INSUFFICIENT_BUFFER {
+		return Err(get_size_err);
+	}
+
+	let mut name_buf = WString::new_alloc_buf(name_sz as _);
+
+	bool_to_sysresult(
+		unsafe { ffi::GetDefaultPrinterW(name_buf.as_mut_ptr(), &mut name_sz) },
+	).map(|_| name_buf.to_string())
+}
+
+/// Native
+/// [`PRINTER_INFO_2`](https://learn.microsoft.com/en-us/windows/win32/printdocs/printer-info-2)
+/// struct, only read by [`EnumPrinters`](crate::EnumPrinters).
+#[repr(C)]
+struct PRINTER_INFO_2 {
+	pServerName: *mut u16,
+	pPrinterName: *mut u16,
+	pShareName: *mut u16,
+	pPortName: *mut u16,
+	pDriverName: *mut u16,
+	pComment: *mut u16,
+	pLocation: *mut u16,
+	pDevMode: *mut DEVMODE,
+	pSepFile: *mut u16,
+	pPrintProcessor: *mut u16,
+	pDatatype: *mut u16,
+	pParameters: *mut u16,
+	pSecurityDescriptor: *mut std::ffi::c_void,
+	Attributes: u32,
+	Priority: u32,
+	DefaultPriority: u32,
+	StartTime: u32,
+	UntilTime: u32,
+	Status: u32,
+	cJobs: u32,
+	AveragePPM: u32,
+}
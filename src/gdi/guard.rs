@@ -6,6 +6,16 @@ use crate::gdi::ffi;
 use crate::guard::*;
 use crate::prelude::*;
 
+handle_guard! { ClosePrinterGuard: HPRINTER;
+	ffi::ClosePrinter;
+	/// RAII implementation for [`HPRINTER`](crate::HPRINTER) which
+	/// automatically calls
+	/// [`ClosePrinter`](https://learn.microsoft.com/en-us/windows/win32/printdocs/closeprinter)
+	/// when the object goes out of scope.
+}
+
+//------------------------------------------------------------------------------
+
 handle_guard! { DeleteDCGuard: HDC;
 	ffi::DeleteDC;
 	/// RAII implementation for [`HDC`](crate::HDC) which automatically calls
@@ -92,6 +102,134 @@ handle_guard! { DeleteObjectPaletteGuard: HPALETTE;
 
 //------------------------------------------------------------------------------
 
+/// RAII implementation for a [`DEVMODE`](crate::DEVMODE) returned by
+/// [`HPRINTER::DocumentProperties`](crate::prelude::gdi_Hprinter::DocumentProperties),
+/// which manages the allocated memory, including the driver-specific private
+/// data which follows the struct.
+pub struct DevmodeGuard {
+	ptr: GlobalFreeGuard,
+}
+
+impl Deref for DevmodeGuard {
+	type Target = DEVMODE;
+
+	fn deref(&self) -> &Self::Target {
+		unsafe { &*(self.ptr.ptr() as *const _) }
+	}
+}
+
+impl DerefMut for DevmodeGuard {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		unsafe { &mut *(self.ptr.ptr() as *mut _) }
+	}
+}
+
+impl DevmodeGuard {
+	/// Allocates zeroed memory for a `DEVMODE` plus its private data; the
+	/// returned size is never smaller than the struct.
+	#[must_use]
+	pub(in crate::gdi) fn new(sz: usize) -> SysResult<Self> {
+		Ok(Self {
+			ptr: HGLOBAL::GlobalAlloc(
+				Some(co::GMEM::FIXED | co::GMEM::ZEROINIT),
+				sz.max(std::mem::size_of::<DEVMODE>()),
+			)?,
+		})
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation for a print job started with
+/// [`HDC::StartDoc`](crate::prelude::gdi_Hdc::StartDoc), which automatically
+/// calls
+/// [`EndDoc`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-enddoc)
+/// when the object goes out of scope.
+///
+/// If the job was cancelled with
+/// [`HDC::AbortDoc`](crate::prelude::gdi_Hdc::AbortDoc), the `EndDoc` call
+/// simply fails, and the error is ignored.
+pub struct EndDocGuard<'a, H>
+	where H: gdi_Hdc,
+{
+	hdc: &'a H,
+	job_id: i32,
+}
+
+impl<'a, H> Drop for EndDocGuard<'a, H>
+	where H: gdi_Hdc,
+{
+	fn drop(&mut self) {
+		if let Some(h) = self.hdc.as_opt() {
+			unsafe { ffi::EndDoc(h.ptr()); } // ignore errors
+		}
+	}
+}
+
+impl<'a, H> EndDocGuard<'a, H>
+	where H: gdi_Hdc,
+{
+	/// Constructs the guard by taking ownership of the print job.
+	///
+	/// # Safety
+	///
+	/// Be sure the print job must be ended with
+	/// [`EndDoc`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-enddoc)
+	/// at the end of scope.
+	#[must_use]
+	pub const unsafe fn new(hdc: &'a H, job_id: i32) -> Self {
+		Self { hdc, job_id }
+	}
+
+	/// Returns the print job identifier returned by
+	/// [`HDC::StartDoc`](crate::prelude::gdi_Hdc::StartDoc).
+	#[must_use]
+	pub const fn job_id(&self) -> i32 {
+		self.job_id
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation for a page started with
+/// [`HDC::StartPage`](crate::prelude::gdi_Hdc::StartPage), which
+/// automatically calls
+/// [`EndPage`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-endpage)
+/// when the object goes out of scope.
+pub struct EndPageGuard<'a, H>
+	where H: gdi_Hdc,
+{
+	hdc: &'a H,
+}
+
+impl<'a, H> Drop for EndPageGuard<'a, H>
+	where H: gdi_Hdc,
+{
+	fn drop(&mut self) {
+		if let Some(h) = self.hdc.as_opt() {
+			unsafe { ffi::EndPage(h.ptr()); } // ignore errors
+		}
+	}
+}
+
+impl<'a, H> EndPageGuard<'a, H>
+	where H: gdi_Hdc,
+{
+	/// Constructs the guard by taking ownership of the page.
+	///
+	/// # Safety
+	///
+	/// Be sure the page must be ended with
+	/// [`EndPage`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-endpage)
+	/// at the end of scope.
+	#[must_use]
+	pub const unsafe fn new(hdc: &'a H) -> Self {
+		Self { hdc }
+	}
+}
+
+//------------------------------------------------------------------------------
+
 /// RAII implementation for [`LOGPALETTE`](crate::LOGPALETTE) which manages the
 /// allocated memory.
 pub struct LogpaletteGuard {
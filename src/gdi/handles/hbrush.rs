@@ -120,6 +120,27 @@ pub trait gdi_Hbrush: Handle {
 
 	/// [`GetSysColorBrush`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getsyscolorbrush)
 	/// function.
+	///
+	/// The returned brush is owned by the system, therefore it must not be
+	/// deleted.
+	///
+	/// # Examples
+	///
+	/// Filling the client area with the window background color, during
+	/// [`WM_PAINT`](https://learn.microsoft.com/en-us/windows/win32/gdi/wm-paint):
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let hdc = hwnd.BeginPaint()?;
+	/// let rc = hwnd.GetClientRect()?;
+	/// let hbr = w::HBRUSH::GetSysColorBrush(co::COLOR::WINDOW)?;
+	/// hdc.FillRect(rc, &hbr)?;
+	/// # w::SysResult::Ok(())
+	/// ```
 	#[must_use]
 	fn GetSysColorBrush(index: co::COLOR) -> SysResult<HBRUSH> {
 		ptr_to_sysresult_handle(unsafe { ffi::GetSysColorBrush(index.raw()) })
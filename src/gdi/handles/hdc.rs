@@ -20,6 +20,19 @@ impl gdi_Hdc for HDC {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait gdi_Hdc: user_Hdc {
+	/// [`AbortDoc`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-abortdoc)
+	/// function.
+	///
+	/// Cancels the print job started with
+	/// [`HDC::StartDoc`](crate::prelude::gdi_Hdc::StartDoc). The
+	/// [`EndDocGuard`](crate::guard::EndDocGuard) can then be safely dropped.
+	fn AbortDoc(&self) -> SysResult<()> {
+		match unsafe { ffi::AbortDoc(self.ptr()) } {
+			r if r <= 0 => Err(GetLastError()),
+			_ => Ok(()),
+		}
+	}
+
 	/// [`AborthPath`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-abortpath)
 	/// function.
 	fn AbortPath(&self) -> SysResult<()> {
@@ -199,6 +212,73 @@ pub trait gdi_Hdc: user_Hdc {
 		}
 	}
 
+	/// [`CreateDC`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createdcw)
+	/// static method.
+	///
+	/// To create a printer DC, pass `"WINSPOOL"` as `driver` and the printer
+	/// name as `device`. The [`DEVMODE`](crate::DEVMODE) can be retrieved
+	/// with
+	/// [`HPRINTER::DocumentProperties`](crate::prelude::gdi_Hprinter::DocumentProperties).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let printer = w::GetDefaultPrinter()?;
+	/// let hdc = w::HDC::CreateDC(Some("WINSPOOL"), Some(&printer), None)?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn CreateDC(
+		driver: Option<&str>,
+		device: Option<&str>,
+		dm: Option<&DEVMODE>,
+	) -> SysResult<DeleteDCGuard>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::CreateDCW(
+					WString::from_opt_str(driver).as_ptr(),
+					WString::from_opt_str(device).as_ptr(),
+					std::ptr::null(),
+					dm.map_or(std::ptr::null(), |dm| dm as *const _ as _),
+				),
+			).map(|h| DeleteDCGuard::new(h))
+		}
+	}
+
+	/// [`CreateDIBSection`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createdibsection)
+	/// function.
+	///
+	/// Returns the bitmap and a pointer to its pixels, which remains valid
+	/// while the bitmap is alive.
+	///
+	/// # Examples
+	///
+	/// See
+	/// [`HWND::update_layered_window`](crate::prelude::user_Hwnd::update_layered_window).
+	#[must_use]
+	fn CreateDIBSection(&self,
+		bmi: &BITMAPINFO,
+		usage: co::DIB,
+	) -> SysResult<(DeleteObjectGuard<HBITMAP>, *mut u8)>
+	{
+		let mut bits = std::ptr::null_mut();
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::CreateDIBSection(
+					self.ptr(),
+					bmi as *const _ as _,
+					usage.raw(),
+					&mut bits,
+					std::ptr::null_mut(),
+					0,
+				),
+			).map(|h| (DeleteObjectGuard::new(h), bits as _))
+		}
+	}
+
 	/// [`CreateHalftonePalette`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-createhalftonepalette)
 	/// function.
 	#[must_use]
@@ -1011,6 +1091,77 @@ pub trait gdi_Hdc: user_Hdc {
 		).map(|_| pt)
 	}
 
+	/// [`StartDoc`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-startdocw)
+	/// function.
+	///
+	/// In the original C implementation, you must call
+	/// [`EndDoc`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-enddoc)
+	/// when the print job is finished.
+	///
+	/// Here, the print job is ended automatically when the returned
+	/// [`EndDocGuard`](crate::guard::EndDocGuard) goes out of scope. To cancel
+	/// the job, call [`HDC::AbortDoc`](crate::prelude::gdi_Hdc::AbortDoc).
+	///
+	/// # Examples
+	///
+	/// Printing a two-page document on the default printer, with 1-inch
+	/// margins:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let printer = w::GetDefaultPrinter()?;
+	/// let hdc = w::HDC::CreateDC(Some("WINSPOOL"), Some(&printer), None)?;
+	///
+	/// let left = hdc.GetDeviceCaps(co::GDC::LOGPIXELSX)
+	///     - hdc.GetDeviceCaps(co::GDC::PHYSICALOFFSETX);
+	/// let top = hdc.GetDeviceCaps(co::GDC::LOGPIXELSY)
+	///     - hdc.GetDeviceCaps(co::GDC::PHYSICALOFFSETY);
+	/// let right = hdc.GetDeviceCaps(co::GDC::HORZRES) - left;
+	///
+	/// let mut doc_name = w::WString::from_str("Two pages");
+	/// let mut di = w::DOCINFO::default();
+	/// di.set_lpszDocName(Some(&mut doc_name));
+	///
+	/// let _job = hdc.StartDoc(&di)?; // EndDoc called when the guard is dropped
+	///
+	/// for page in 1..=2 {
+	///     let _page = hdc.StartPage()?; // EndPage called at the end of each iteration
+	///     hdc.TextOut(left, top, &format!("Page {} of 2", page))?;
+	///
+	///     hdc.SetTextAlign(co::TA::RIGHT)?;
+	///     hdc.TextOut(right, top, "winsafe")?;
+	///     hdc.SetTextAlign(co::TA::LEFT)?;
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn StartDoc(&self, di: &DOCINFO) -> SysResult<EndDocGuard<'_, Self>> {
+		match unsafe { ffi::StartDocW(self.ptr(), di as *const _ as _) } {
+			r if r <= 0 => Err(GetLastError()),
+			job_id => Ok(unsafe { EndDocGuard::new(self, job_id) }),
+		}
+	}
+
+	/// [`StartPage`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-startpage)
+	/// function.
+	///
+	/// In the original C implementation, you must call
+	/// [`EndPage`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-endpage)
+	/// when the page is finished.
+	///
+	/// Here, the page is ended automatically when the returned
+	/// [`EndPageGuard`](crate::guard::EndPageGuard) goes out of scope.
+	///
+	/// For an example, see [`HDC::StartDoc`](crate::prelude::gdi_Hdc::StartDoc).
+	#[must_use]
+	fn StartPage(&self) -> SysResult<EndPageGuard<'_, Self>> {
+		match unsafe { ffi::StartPage(self.ptr()) } {
+			r if r <= 0 => Err(GetLastError()),
+			_ => Ok(unsafe { EndPageGuard::new(self) }),
+		}
+	}
+
 	/// [`StretchBlt`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/nf-wingdi-stretchblt)
 	/// function.
 	fn StretchBlt(&self,
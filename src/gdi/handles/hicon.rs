@@ -0,0 +1,51 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::gdi::ffi;
+use crate::guard::*;
+use crate::kernel::privs::*;
+use crate::prelude::*;
+
+impl gdi_Hicon for HICON {}
+
+/// This trait is enabled with the `gdi` feature, and provides methods for
+/// [`HICON`](crate::HICON).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait gdi_Hicon: user_Hicon {
+	/// [`CreateIconFromResourceEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createiconfromresourceex)
+	/// function.
+	///
+	/// If `cx_desired` or `cy_desired` are zero, the system metrics are used.
+	///
+	/// For an example, see
+	/// [`HINSTANCE::load_icon_best_fit`](crate::prelude::gdi_Hinstance::load_icon_best_fit).
+	#[must_use]
+	fn CreateIconFromResourceEx(
+		res_bits: &[u8],
+		is_icon: bool,
+		cx_desired: u32,
+		cy_desired: u32,
+		flags: co::LR,
+	) -> SysResult<DestroyIconGuard>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::CreateIconFromResourceEx(
+					vec_ptr(res_bits) as _,
+					res_bits.len() as _,
+					is_icon as _,
+					0x0003_0000, // version required by the function
+					cx_desired as _,
+					cy_desired as _,
+					flags.raw(),
+				),
+			).map(|h| DestroyIconGuard::new(h))
+		}
+	}
+}
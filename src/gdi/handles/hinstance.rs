@@ -18,6 +18,160 @@ impl gdi_Hinstance for HINSTANCE {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait gdi_Hinstance: user_Hinstance {
+	/// Parses the directory of an [`RT::GROUP_ICON`](crate::co::RT::GROUP_ICON)
+	/// resource, returning the available images of the icon.
+	///
+	/// # Examples
+	///
+	/// Listing the images of all the icons in an executable:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hexe = w::HINSTANCE::LoadLibrary("hand.exe")?;
+	///
+	/// hexe.EnumResourceNames(
+	///     w::RtStr::Rt(co::RT::GROUP_ICON),
+	///     |group_id: w::IdStr| -> bool {
+	///         for entry in hexe.icon_group_entries(group_id.clone()).unwrap() {
+	///             println!("{}: {}x{}, {} bpp",
+	///                 group_id, entry.width, entry.height, entry.bit_count);
+	///         }
+	///         true
+	///     },
+	/// )?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn icon_group_entries(&self, group_id: IdStr) -> SysResult<Vec<IconDirEntry>> {
+		let hrsrc = self.FindResource(group_id, RtStr::Rt(co::RT::GROUP_ICON))?;
+		let hmem = self.LoadResource(&hrsrc)?;
+		let grp = self.LockResource(&hrsrc, &hmem)?;
+
+		let word = |off: usize| u16::from_le_bytes([grp[off], grp[off + 1]]);
+		if grp.len() < 6 || word(2) != 1 { // GRPICONDIR header, type 1 is icon
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		let count = word(4) as usize;
+		if grp.len() < 6 + count * 14 {
+			return Err(co::ERROR::INVALID_DATA);
+		}
+
+		Ok(
+			(0..count)
+				.map(|i| {
+					let off = 6 + i * 14; // each GRPICONDIRENTRY
+					let dim = |b: u8| if b == 0 { 256 } else { b as u32 };
+					IconDirEntry {
+						width: dim(grp[off]),
+						height: dim(grp[off + 1]),
+						color_count: grp[off + 2],
+						planes: word(off + 4),
+						bit_count: word(off + 6),
+						bytes_in_res: u32::from_le_bytes(
+							[grp[off + 8], grp[off + 9], grp[off + 10], grp[off + 11]]),
+						id: word(off + 12),
+					}
+				})
+				.collect(),
+		)
+	}
+
+	/// Loads all the images of an icon, each one at its native size.
+	///
+	/// The images are listed with
+	/// [`HINSTANCE::icon_group_entries`](crate::prelude::gdi_Hinstance::icon_group_entries),
+	/// and each one is created with
+	/// [`HICON::CreateIconFromResourceEx`](crate::prelude::gdi_Hicon::CreateIconFromResourceEx).
+	/// This allows picking the image for a given DPI, or sending several images
+	/// with [`wm::SetIcon`](crate::msg::wm::SetIcon).
+	///
+	/// # Examples
+	///
+	/// Listing the sizes available in an icon:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// const ID_ICON: u16 = 101;
+	///
+	/// let icons = w::HINSTANCE::GetModuleHandle(None)?
+	///     .load_icon_all_sizes(w::IdStr::Id(ID_ICON))?;
+	///
+	/// for (sz, _hicon) in icons.iter() {
+	///     println!("{}x{}", sz.cx, sz.cy);
+	/// }
+	/// // DestroyIcon() called automatically for each icon
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn load_icon_all_sizes(&self,
+		group_id: IdStr,
+	) -> SysResult<Vec<(SIZE, DestroyIconGuard)>>
+	{
+		self.icon_group_entries(group_id)?
+			.iter()
+			.map(|entry| {
+				let hrsrc = self.FindResource(IdStr::Id(entry.id), RtStr::Rt(co::RT::ICON))?;
+				let hmem = self.LoadResource(&hrsrc)?;
+				let bits = self.LockResource(&hrsrc, &hmem)?;
+				let hicon = HICON::CreateIconFromResourceEx(
+					bits, true, entry.width, entry.height, co::LR::DEFAULTCOLOR)?;
+				Ok((SIZE::new(entry.width as _, entry.height as _), hicon))
+			})
+			.collect()
+	}
+
+	/// Loads the image of an icon which best fits the given size, in pixels.
+	///
+	/// The images are listed with
+	/// [`HINSTANCE::icon_group_entries`](crate::prelude::gdi_Hinstance::icon_group_entries),
+	/// and the smallest one not smaller than `desired_px` is chosen, with the
+	/// highest color depth; if all images are smaller, the largest one is
+	/// chosen. The icon is then created with
+	/// [`HICON::CreateIconFromResourceEx`](crate::prelude::gdi_Hicon::CreateIconFromResourceEx),
+	/// scaled to `desired_px` if needed.
+	///
+	/// This gives better results than loading fixed 16 and 32 pixel sizes on
+	/// high DPI displays.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// const ID_ICON: u16 = 101;
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let dpi = hwnd.GetDpiForWindow();
+	/// let px = w::GetSystemMetricsForDpi(co::SM::CXICON, dpi)?;
+	///
+	/// let hicon = w::HINSTANCE::GetModuleHandle(None)?
+	///     .load_icon_best_fit(w::IdStr::Id(ID_ICON), px as _)?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn load_icon_best_fit(&self,
+		group_id: IdStr,
+		desired_px: u32,
+	) -> SysResult<DestroyIconGuard>
+	{
+		let entries = self.icon_group_entries(group_id)?;
+		let best = entries.iter()
+			.filter(|e| e.width >= desired_px)
+			.min_by_key(|e| (e.width, std::cmp::Reverse(e.bit_count)))
+			.or_else(|| entries.iter().max_by_key(|e| (e.width, e.bit_count)))
+			.ok_or(co::ERROR::RESOURCE_DATA_NOT_FOUND)?;
+
+		let hrsrc = self.FindResource(IdStr::Id(best.id), RtStr::Rt(co::RT::ICON))?;
+		let hmem = self.LoadResource(&hrsrc)?;
+		let bits = self.LockResource(&hrsrc, &hmem)?;
+		HICON::CreateIconFromResourceEx(
+			bits, true, desired_px, desired_px, co::LR::DEFAULTCOLOR)
+	}
+
 	/// [`LoadImage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadimagew)
 	/// method for [`HBITMAP`](crate::HBITMAP).
 	#[must_use]
@@ -54,6 +208,10 @@ pub trait gdi_Hinstance: user_Hinstance {
 
 	/// [`LoadImage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadimagew)
 	/// method for [`HICON`](crate::HICON).
+	///
+	/// If `load` has [`co::LR::SHARED`](crate::co::LR::SHARED), the icon is
+	/// owned by the system and must not be destroyed, so call
+	/// [`leak`](crate::guard::DestroyIconGuard::leak) on the returned guard.
 	#[must_use]
 	fn LoadImageIcon(&self,
 		name: IdOicStr,
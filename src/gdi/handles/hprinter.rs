@@ -0,0 +1,110 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::decl::*;
+use crate::gdi::{ffi, privs::*};
+use crate::guard::*;
+use crate::kernel::privs::*;
+use crate::prelude::*;
+
+impl_handle! { HPRINTER;
+	/// Handle to a
+	/// [printer](https://learn.microsoft.com/en-us/windows/win32/printdocs/openprinter).
+}
+
+impl gdi_Hprinter for HPRINTER {}
+
+/// This trait is enabled with the `gdi` feature, and provides methods for
+/// [`HPRINTER`](crate::HPRINTER).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait gdi_Hprinter: Handle {
+	/// [`DocumentProperties`](https://learn.microsoft.com/en-us/windows/win32/printdocs/documentproperties)
+	/// function.
+	///
+	/// Returns the printer's current [`DEVMODE`](crate::DEVMODE), with the
+	/// values of `dm_in`, if any, merged into it. The returned object can be
+	/// modified and passed again as `dm_in`, or to
+	/// [`HDC::CreateDC`](crate::prelude::gdi_Hdc::CreateDC).
+	///
+	/// The driver-specific private data, whose size is given by
+	/// `dmDriverExtra`, is kept right after the struct.
+	///
+	/// # Examples
+	///
+	/// Switching the default printer to landscape:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let name = w::GetDefaultPrinter()?;
+	/// let hprinter = w::HPRINTER::OpenPrinter(&name)?;
+	///
+	/// let mut dm = hprinter.DocumentProperties(None, &name, None)?;
+	/// dm.set_dmOrientation(co::DMORIENT::LANDSCAPE);
+	/// dm.dmFields |= co::DM::ORIENTATION;
+	/// let dm = hprinter.DocumentProperties(None, &name, Some(&dm))?;
+	///
+	/// let hdc = w::HDC::CreateDC(Some("WINSPOOL"), Some(&name), Some(&dm))?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn DocumentProperties(&self,
+		hwnd: Option<&HWND>,
+		device_name: &str,
+		dm_in: Option<&DEVMODE>,
+	) -> SysResult<DevmodeGuard>
+	{
+		let hwnd_ptr = hwnd.map_or(std::ptr::null_mut(), |h| h.ptr());
+		let wname = WString::from_str(device_name);
+
+		let sz = unsafe {
+			ffi::DocumentPropertiesW(hwnd_ptr, self.ptr(), wname.as_ptr(),
+				std::ptr::null_mut(), std::ptr::null(), 0)
+		};
+		if sz < 0 {
+			return Err(GetLastError());
+		}
+
+		let mut dm_out = DevmodeGuard::new(sz as _)?;
+
+		let mode = if dm_in.is_some() {
+			DM_IN_BUFFER | DM_OUT_BUFFER
+		} else {
+			DM_OUT_BUFFER
+		};
+		if unsafe {
+			ffi::DocumentPropertiesW(hwnd_ptr, self.ptr(), wname.as_ptr(),
+				&mut *dm_out as *mut _ as _,
+				dm_in.map_or(std::ptr::null(), |dm| dm as *const _ as _),
+				mode)
+		} < 0 {
+			return Err(GetLastError());
+		}
+
+		Ok(dm_out)
+	}
+
+	/// [`OpenPrinter`](https://learn.microsoft.com/en-us/windows/win32/printdocs/openprinter)
+	/// static method.
+	///
+	/// Printer names can be retrieved with
+	/// [`EnumPrinters`](crate::EnumPrinters) and
+	/// [`GetDefaultPrinter`](crate::GetDefaultPrinter).
+	#[must_use]
+	fn OpenPrinter(printer_name: &str) -> SysResult<ClosePrinterGuard> {
+		let mut handle = HPRINTER::NULL;
+		unsafe {
+			bool_to_sysresult(
+				ffi::OpenPrinterW(
+					WString::from_str(printer_name).as_ptr(),
+					handle.as_mut(),
+					std::ptr::null(),
+				),
+			).map(|_| ClosePrinterGuard::new(handle))
+		}
+	}
+}
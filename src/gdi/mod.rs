@@ -7,9 +7,11 @@ mod structs;
 
 pub(in crate::gdi) mod ffi;
 pub(crate) mod privs;
+pub mod clipboard;
 pub mod co;
 pub mod guard;
 pub mod messages;
+pub mod spi;
 
 pub mod decl {
 	pub use super::enums::*;
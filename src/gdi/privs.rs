@@ -1,4 +1,6 @@
 pub(crate) const CLR_INVALID: u32 = 0xffff_ffff;
+pub(crate) const DM_IN_BUFFER: u32 = 8;
+pub(crate) const DM_OUT_BUFFER: u32 = 2;
 pub(crate) const GDI_ERROR: u32 = 0xffff_ffff;
 pub(crate) const HIMETRIC_PER_INCH: i32 = 2540;
 pub(crate) const LF_FACESIZE: usize = 32;
@@ -0,0 +1,41 @@
+use crate::co;
+use crate::decl::*;
+
+/// Retrieves the font used for icon titles, with
+/// [`SPI::GETICONTITLELOGFONT`](crate::co::SPI::GETICONTITLELOGFONT).
+#[must_use]
+pub fn icon_title_log_font() -> SysResult<LOGFONT> {
+	let mut lf = LOGFONT::default();
+	unsafe {
+		SystemParametersInfo(co::SPI::GETICONTITLELOGFONT,
+			std::mem::size_of::<LOGFONT>() as _, &mut lf, co::SPIF::NoValue)
+	}.map(|_| lf)
+}
+
+/// Retrieves the metrics of the non-client area of windows, including the
+/// fonts used in captions, menus, status bars and message boxes, with
+/// [`SPI::GETNONCLIENTMETRICS`](crate::co::SPI::GETNONCLIENTMETRICS).
+///
+/// The struct size is adjusted to the running OS: before Windows Vista, the
+/// `iPaddedBorderWidth` field doesn't exist, and passing the full size makes
+/// the call fail.
+///
+/// # Examples
+///
+/// Creating a font which matches the system message box font:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let ncm = w::spi::non_client_metrics()?;
+/// let hfont = w::HFONT::CreateFontIndirect(&ncm.lfMessageFont)?;
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn non_client_metrics() -> SysResult<NONCLIENTMETRICS> {
+	let mut ncm = NONCLIENTMETRICS::default();
+	unsafe {
+		SystemParametersInfo(co::SPI::GETNONCLIENTMETRICS, ncm.cbSize,
+			&mut ncm, co::SPIF::NoValue)
+	}.map(|_| ncm)
+}
@@ -1,5 +1,7 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use std::marker::PhantomData;
+
 use crate::co;
 use crate::decl::*;
 use crate::gdi::privs::*;
@@ -104,6 +106,52 @@ impl BITMAPINFOHEADER {
 	}
 }
 
+/// [`DOCINFO`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-docinfow)
+/// struct.
+#[repr(C)]
+pub struct DOCINFO<'a, 'b, 'c> {
+	cbSize: i32,
+	lpszDocName: *mut u16,
+	lpszOutput: *mut u16,
+	lpszDatatype: *mut u16,
+	pub fwType: co::DI,
+
+	_lpszDocName: PhantomData<&'a mut u16>,
+	_lpszOutput: PhantomData<&'b mut u16>,
+	_lpszDatatype: PhantomData<&'c mut u16>,
+}
+
+impl_default_with_size!(DOCINFO, cbSize, 'a, 'b, 'c);
+
+impl<'a, 'b, 'c> DOCINFO<'a, 'b, 'c> {
+	pub_fn_string_ptr_get_set!('a, lpszDocName, set_lpszDocName);
+	pub_fn_string_ptr_get_set!('b, lpszOutput, set_lpszOutput);
+	pub_fn_string_ptr_get_set!('c, lpszDatatype, set_lpszDatatype);
+}
+
+/// Icon image information returned by
+/// [`HINSTANCE::icon_group_entries`](crate::prelude::gdi_Hinstance::icon_group_entries),
+/// parsed from a `GRPICONDIRENTRY` of an
+/// [`RT::GROUP_ICON`](crate::co::RT::GROUP_ICON) resource.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IconDirEntry {
+	/// Width of the image, in pixels.
+	pub width: u32,
+	/// Height of the image, in pixels.
+	pub height: u32,
+	/// Number of colors in the palette, or zero if there is no palette.
+	pub color_count: u8,
+	/// Number of color planes.
+	pub planes: u16,
+	/// Bits per pixel.
+	pub bit_count: u16,
+	/// Size of the image data, in bytes.
+	pub bytes_in_res: u32,
+	/// ID of the [`RT::ICON`](crate::co::RT::ICON) resource with the image
+	/// data.
+	pub id: u16,
+}
+
 /// [`LOGBRUSH`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-logbrush)
 /// struct.
 #[repr(C)]
@@ -230,7 +278,7 @@ impl_default!(LOGPEN);
 /// struct.
 #[repr(C)]
 pub struct NONCLIENTMETRICS {
-	cbSize: u32,
+	pub(in crate::gdi) cbSize: u32,
 	pub iBorderWidth: i32,
 	pub iScrollWidth: i32,
 	pub iScrollHeight: i32,
@@ -274,6 +322,39 @@ pub struct PALETTEENTRY {
 	pub peFlags: co::PC,
 }
 
+/// Printer information returned by [`EnumPrinters`](crate::EnumPrinters),
+/// copied out of a native
+/// [`PRINTER_INFO_2`](https://learn.microsoft.com/en-us/windows/win32/printdocs/printer-info-2)
+/// struct.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PrinterInfo {
+	/// Name of the printer, to be passed to
+	/// [`HPRINTER::OpenPrinter`](crate::prelude::gdi_Hprinter::OpenPrinter)
+	/// and [`HDC::CreateDC`](crate::prelude::gdi_Hdc::CreateDC).
+	pub name: String,
+	/// Server which controls the printer, if the printer is remote.
+	pub server_name: Option<String>,
+	/// Share point, if the printer is shared.
+	pub share_name: Option<String>,
+	/// Port(s) used to transmit data to the printer.
+	pub port_name: String,
+	/// Name of the printer driver.
+	pub driver_name: String,
+	/// Description of the printer.
+	pub comment: Option<String>,
+	/// Physical location of the printer.
+	pub location: Option<String>,
+	/// Printer attributes.
+	pub attributes: co::PRINTER_ATTRIBUTE,
+	/// Printer status.
+	pub status: co::PRINTER_STATUS,
+	/// Number of print jobs queued for the printer.
+	pub jobs: u32,
+	/// Whether this is the default printer for the current user, as returned
+	/// by [`GetDefaultPrinter`](crate::GetDefaultPrinter).
+	pub is_default: bool,
+}
+
 /// [`RGBQUAD`](https://learn.microsoft.com/en-us/windows/win32/api/wingdi/ns-wingdi-rgbquad)
 /// struct.
 #[repr(C)]
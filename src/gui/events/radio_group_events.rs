@@ -1,9 +1,12 @@
+use std::cell::UnsafeCell;
 use std::ptr::NonNull;
 use std::rc::Rc;
 
 use crate::co;
 use crate::decl::*;
-use crate::gui::{*, events::*, privs::*};
+use crate::gui::{*, privs::*};
+use crate::msg::*;
+use crate::prelude::*;
 
 /// Exposes button control
 /// [notifications](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-button-control-reference-notifications)
@@ -17,7 +20,7 @@ use crate::gui::{*, events::*, privs::*};
 /// control.
 pub struct RadioGroupEvents {
 	parent_ptr: NonNull<Base>,
-	ctrl_ids: Vec<u16>,
+	ctrl_ids: Rc<UnsafeCell<Vec<u16>>>, // shared with the closures, changes when radios are added/removed
 }
 
 impl RadioGroupEvents {
@@ -29,13 +32,35 @@ impl RadioGroupEvents {
 	{
 		Self {
 			parent_ptr: NonNull::from(parent.as_ref()),
-			ctrl_ids,
+			ctrl_ids: Rc::new(UnsafeCell::new(ctrl_ids)),
 		}
 	}
 
-	#[must_use]
-	fn parent_user_events(&self) -> &WindowEvents {
-		unsafe { self.parent_ptr.as_ref().on() }
+	/// Adds the control ID of a radio button added to the group.
+	pub(in crate::gui) fn add_ctrl_id(&self, ctrl_id: u16) {
+		unsafe { &mut *self.ctrl_ids.get() }.push(ctrl_id);
+	}
+
+	/// Removes the control ID of a radio button removed from the group.
+	pub(in crate::gui) fn remove_ctrl_id(&self, ctrl_id: u16) {
+		unsafe { &mut *self.ctrl_ids.get() }.retain(|id| *id != ctrl_id);
+	}
+
+	/// Adds a `WM_COMMAND` event to the parent window, which will be fired for
+	/// all radio buttons currently in the group.
+	fn wm_command_group<F>(&self, code: co::BN, func: F)
+		where F: Fn() -> AnyResult<()> + 'static,
+	{
+		let ctrl_ids = self.ctrl_ids.clone();
+		unsafe { self.parent_ptr.as_ref().on() }.wm(co::WM::COMMAND, move |p| {
+			let (ctrl_id, notif_code) = wm::Command::from_generic_wm(p).event.id_code();
+			if notif_code == code.into() && unsafe { &*ctrl_ids.get() }.contains(&ctrl_id) {
+				func()?;
+				Ok(WmRet::HandledOk)
+			} else {
+				Ok(WmRet::NotHandled) // not from this group
+			}
+		});
 	}
 
 	/// [`BN_CLICKED`](https://learn.microsoft.com/en-us/windows/win32/controls/bn-clicked)
@@ -67,17 +92,7 @@ impl RadioGroupEvents {
 	pub fn bn_clicked<F>(&self, func: F)
 		where F: Fn() -> AnyResult<()> + 'static,
 	{
-		let shared_func = Rc::new(func);
-
-		for ctrl_id in self.ctrl_ids.iter() {
-			self.parent_user_events().wm_command(*ctrl_id, co::BN::CLICKED, {
-				let shared_func = shared_func.clone();
-				move || {
-					shared_func()?;
-					Ok(WmRet::HandledOk)
-				}
-			});
-		}
+		self.wm_command_group(co::BN::CLICKED, func);
 	}
 
 	/// [`BN_DBLCLK`](https://learn.microsoft.com/en-us/windows/win32/controls/bn-dblclk)
@@ -91,17 +106,7 @@ impl RadioGroupEvents {
 	pub fn bn_dbl_clk<F>(&self, func: F)
 		where F: Fn() -> AnyResult<()> + 'static,
 	{
-		let shared_func = Rc::new(func);
-
-		for ctrl_id in self.ctrl_ids.iter() {
-			self.parent_user_events().wm_command(*ctrl_id, co::BN::DBLCLK, {
-				let shared_func = shared_func.clone();
-				move || {
-					shared_func()?;
-					Ok(WmRet::HandledOk)
-				}
-			});
-		}
+		self.wm_command_group(co::BN::DBLCLK, func);
 	}
 
 	/// [`BN_KILLFOCUS`](https://learn.microsoft.com/en-us/windows/win32/controls/bn-killfocus)
@@ -113,17 +118,7 @@ impl RadioGroupEvents {
 	pub fn bn_kill_focus<F>(&self, func: F)
 		where F: Fn() -> AnyResult<()> + 'static,
 	{
-		let shared_func = Rc::new(func);
-
-		for ctrl_id in self.ctrl_ids.iter() {
-			self.parent_user_events().wm_command(*ctrl_id, co::BN::KILLFOCUS, {
-				let shared_func = shared_func.clone();
-				move || {
-					shared_func()?;
-					Ok(WmRet::HandledOk)
-				}
-			});
-		}
+		self.wm_command_group(co::BN::KILLFOCUS, func);
 	}
 
 	/// [`BN_SETFOCUS`](https://learn.microsoft.com/en-us/windows/win32/controls/bn-setfocus)
@@ -135,16 +130,6 @@ impl RadioGroupEvents {
 	pub fn bn_set_focus<F>(&self, func: F)
 		where F: Fn() -> AnyResult<()> + 'static,
 	{
-		let shared_func = Rc::new(func);
-
-		for ctrl_id in self.ctrl_ids.iter() {
-			self.parent_user_events().wm_command(*ctrl_id, co::BN::SETFOCUS, {
-				let shared_func = shared_func.clone();
-				move || {
-					shared_func()?;
-					Ok(WmRet::HandledOk)
-				}
-			});
-		}
+		self.wm_command_group(co::BN::SETFOCUS, func);
 	}
 }
@@ -64,6 +64,29 @@ impl WindowEvents {
 	///     },
 	/// );
 	/// ```
+	///
+	/// Handling a message registered with
+	/// [`RegisterWindowMessage`](crate::RegisterWindowMessage), whose ID is
+	/// unique across the system, so it can be used for cross-process
+	/// communication:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui, msg};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let id = w::RegisterWindowMessage("MyApp.Reload")?;
+	///
+	/// wnd.on().wm(
+	///     unsafe { co::WM::from_raw(id) },
+	///     move |p: msg::WndMsg| -> w::AnyResult<gui::WmRet> {
+	///         println!("Reload requested, wParam: {}", p.wparam);
+	///         Ok(gui::WmRet::HandledOk)
+	///     },
+	/// );
+	/// # w::SysResult::Ok(())
+	/// ```
 	pub fn wm<F>(&self, ident: co::WM, func: F)
 		where F: Fn(WndMsg) -> AnyResult<WmRet> + 'static,
 	{
@@ -144,6 +167,44 @@ impl WindowEvents {
 		self.0.wm_notify(id_from, code, func);
 	}
 
+	/// [`WM_HOTKEY`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-hotkey)
+	/// message, narrowed to a specific hotkey ID, registered with
+	/// [`HWND::RegisterHotKey`](crate::prelude::user_Hwnd::RegisterHotKey).
+	///
+	/// To handle all hotkeys at once, use
+	/// [`wm`](crate::gui::events::WindowEvents::wm) with
+	/// [`WM::HOTKEY`](crate::co::WM::HOTKEY).
+	///
+	/// # Examples
+	///
+	/// A system-wide play/pause key, registered when the window is created:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// const HK_PLAY_PAUSE: i32 = 1;
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let wnd2 = wnd.clone();
+	/// wnd.on().wm_create(move |_| {
+	///     wnd2.hwnd().RegisterHotKey(HK_PLAY_PAUSE,
+	///         co::MOD::CONTROL | co::MOD::ALT | co::MOD::NOREPEAT, co::VK::SPACE)?;
+	///     Ok(0)
+	/// });
+	///
+	/// wnd.on().wm_hot_key(HK_PLAY_PAUSE, move || {
+	///     println!("Play/pause.");
+	///     Ok(())
+	/// });
+	/// ```
+	pub fn wm_hot_key<F>(&self, hotkey_id: i32, func: F)
+		where F: Fn() -> AnyResult<()> + 'static,
+	{
+		self.0.wm_hot_key(hotkey_id, func);
+	}
+
 	/// [`WM_TIMER`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-timer)
 	/// message, narrowed to a specific timer ID.
 	pub fn wm_timer<F>(&self, timer_id: usize, func: F)
@@ -216,6 +277,17 @@ impl WindowEvents {
 		/// message.
 	}
 
+	pub_fn_wm_withparm_i32ret! { wm_char_to_item, co::WM::CHARTOITEM, wm::CharToItem;
+		/// [`WM_CHARTOITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-chartoitem)
+		/// message.
+		///
+		/// Sent by a [`ListBox`](crate::gui::ListBox) with
+		/// [`LBS::WANTKEYBOARDINPUT`](crate::co::LBS::WANTKEYBOARDINPUT) and
+		/// without [`LBS::HASSTRINGS`](crate::co::LBS::HASSTRINGS). Return the
+		/// index of the item to select, `-1` for the default action, or `-2` if
+		/// the message was fully handled.
+	}
+
 	pub_fn_wm_noparm_noret! { wm_child_activate, co::WM::CHILDACTIVATE;
 		/// [`WM_CHILDACTIVATE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-childactivate)
 		/// message.
@@ -334,6 +406,28 @@ impl WindowEvents {
 	pub_fn_wm_withparm_noret! { wm_device_change, co::WM::DEVICECHANGE, wm::DeviceChange;
 		/// [`WM_DEVICECHANGE`](https://learn.microsoft.com/en-us/windows/win32/devio/wm-devicechange)
 		/// message.
+		///
+		/// To be notified of device interface arrival and removal, the window
+		/// must be registered with
+		/// [`HWND::RegisterDeviceNotification`](crate::prelude::user_Hwnd::RegisterDeviceNotification).
+		///
+		/// # Examples
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, co, gui, msg};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// wnd.on().wm_device_change(
+		///     move |p: msg::wm::DeviceChange| -> w::AnyResult<()> {
+		///         if let Some(w::SvcCtlDeviceEvent::Interface(di)) = unsafe { p.device_event() } {
+		///             println!("{} {}", p.event, di.dbcc_name());
+		///         }
+		///         Ok(())
+		///     },
+		/// );
+		/// ```
 	}
 
 	pub_fn_wm_withparm_noret! { wm_display_change, co::WM::DISPLAYCHANGE, wm::DisplayChange;
@@ -341,6 +435,38 @@ impl WindowEvents {
 		/// message.
 	}
 
+	pub_fn_wm_withparm_noret! { wm_dpi_changed, co::WM::DPICHANGED, wm::DpiChanged;
+		/// [`WM_DPICHANGED`](https://learn.microsoft.com/en-us/windows/win32/hidpi/wm-dpichanged)
+		/// message.
+		///
+		/// Sent to per-monitor DPI-aware windows when moved to a monitor with a
+		/// different DPI. The window should be resized to the suggested
+		/// rectangle.
+		///
+		/// # Examples
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, co, gui, msg};
+		///
+		/// let wnd: gui::WindowMain; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		///
+		/// let wnd2 = wnd.clone();
+		/// wnd.on().wm_dpi_changed(
+		///     move |p: msg::wm::DpiChanged| -> w::AnyResult<()> {
+		///         let rc = p.suggested_rect;
+		///         wnd2.hwnd().SetWindowPos(
+		///             w::HwndPlace::None,
+		///             w::POINT::new(rc.left, rc.top),
+		///             w::SIZE::new(rc.right - rc.left, rc.bottom - rc.top),
+		///             co::SWP::NOZORDER | co::SWP::NOACTIVATE,
+		///         )?;
+		///         Ok(())
+		///     },
+		/// );
+		/// ```
+	}
+
 	#[cfg(feature = "shell")]
 	pub_fn_wm_withparm_noret! { wm_drop_files, co::WM::DROPFILES, wm::DropFiles;
 		/// [`WM_DROPFILES`](https://learn.microsoft.com/en-us/windows/win32/shell/wm-dropfiles)
@@ -418,6 +544,31 @@ impl WindowEvents {
 	pub_fn_wm_withparm_coret! { wm_get_dlg_code, co::WM::GETDLGCODE, wm::GetDlgCode, co::DLGC;
 		/// [`WM_GETDLGCODE`](https://learn.microsoft.com/en-us/windows/win32/dlgbox/wm-getdlgcode)
 		/// message.
+		///
+		/// Before performing dialog navigation, the main loop asks the focused
+		/// control which keys it wants to process by itself. By answering this
+		/// message, a control can receive keys like Tab, Enter and arrows,
+		/// instead of losing them to the dialog navigation.
+		///
+		/// # Examples
+		///
+		/// Making a multi-line edit receive the Tab key:
+		///
+		/// ```no_run
+		/// use winsafe::{self as w, prelude::*, co, gui, msg};
+		///
+		/// let txt: gui::Edit; // initialized somewhere
+		/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+		/// # let txt = gui::Edit::new(&wnd, gui::EditOpts::default());
+		///
+		/// let txt2 = txt.clone();
+		/// txt.on_subclass().wm_get_dlg_code(move |p: msg::wm::GetDlgCode| {
+		///     let dlgc_system = unsafe {
+		///         txt2.hwnd().DefSubclassProc::<msg::wm::GetDlgCode>(p.into())
+		///     };
+		///     Ok(dlgc_system | co::DLGC::WANTTAB)
+		/// });
+		/// ```
 	}
 
 	/// [`WM_GETFONT`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-getfont)
@@ -679,7 +830,9 @@ impl WindowEvents {
 		/// * non-dialog [`WindowMain`](crate::gui::WindowMain);
 		/// * dialog [`WindowMain`](crate::gui::WindowMain).
 		///
-		/// In both cases, [`PostQuitMessage`](crate::PostQuitMessage) is called.
+		/// In both cases, [`PostQuitMessage`](crate::PostQuitMessage) is called
+		/// with the code set by
+		/// [`WindowMain::set_exit_code`](crate::gui::WindowMain::set_exit_code).
 	}
 
 	pub_fn_wm_withparm_coret! { wm_nc_hit_test, co::WM::NCHITTEST, wm::NcHitTest, co::HT;
@@ -795,6 +948,11 @@ impl WindowEvents {
 		/// message.
 	}
 
+	pub_fn_wm_withparm_noret! { wm_setting_change, co::WM::SETTINGCHANGE, wm::SettingChange;
+		/// [`WM_SETTINGCHANGE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-settingchange)
+		/// message.
+	}
+
 	pub_fn_wm_withparm_noret! { wm_show_window, co::WM::SHOWWINDOW, wm::ShowWindow;
 		/// [`WM_SHOWWINDOW`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-showwindow)
 		/// message.
@@ -33,6 +33,13 @@ pub(in crate::gui) struct WindowEventsPriv {
 			Box<dyn Fn() -> AnyResult<()>>, // return value is never meaningful
 		>,
 	>,
+	hkeys: UnsafeCell<
+		FuncStore< // WM_HOTKEY messages
+			i32, // hotkey ID
+			Box<dyn Fn() -> AnyResult<()>>, // return value is never meaningful
+		>,
+	>,
+	n_children: UnsafeCell<usize>, // child controls added with wm_create_child()
 }
 
 impl WindowEventsPriv {
@@ -44,6 +51,8 @@ impl WindowEventsPriv {
 			cmds: UnsafeCell::new(FuncStore::new()),
 			nfys: UnsafeCell::new(FuncStore::new()),
 			tmrs: UnsafeCell::new(FuncStore::new()),
+			hkeys: UnsafeCell::new(FuncStore::new()),
+			n_children: UnsafeCell::new(0),
 		}
 	}
 
@@ -53,11 +62,13 @@ impl WindowEventsPriv {
 				&& { &*self.cmds.get() }.is_empty()
 				&& { &*self.nfys.get() }.is_empty()
 				&& { &*self.tmrs.get() }.is_empty()
+				&& { &*self.hkeys.get() }.is_empty()
 		}
 	}
 
 	pub(in crate::gui) fn clear_events(&self) {
 		unsafe {
+			{ &mut *self.hkeys.get() }.clear();
 			{ &mut *self.tmrs.get() }.clear();
 			{ &mut *self.nfys.get() }.clear();
 			{ &mut *self.cmds.get() }.clear();
@@ -105,6 +116,13 @@ impl WindowEventsPriv {
 				func()?;
 				at_least_one = true;
 			}
+		} else if wm_any.msg_id == co::WM::HOTKEY {
+			let wm_hkey = wm::HotKey::from_generic_wm(wm_any);
+			let hkeys = unsafe { &*self.hkeys.get() };
+			for func in hkeys.filter(wm_hkey.id) {
+				func()?;
+				at_least_one = true;
+			}
 		}
 
 		let msgs = unsafe { &*self.msgs.get() };
@@ -152,6 +170,13 @@ impl WindowEventsPriv {
 				func()?;
 				return Ok(WmRet::HandledOk);
 			}
+		} else if wm_any.msg_id == co::WM::HOTKEY {
+			let wm_hkey = wm::HotKey::from_generic_wm(wm_any);
+			let hkeys = unsafe { &*self.hkeys.get() };
+			if let Some(func) = hkeys.filter_rev(wm_hkey.id).next() { // just execute the last, if any
+				func()?;
+				return Ok(WmRet::HandledOk);
+			}
 		}
 
 		let msgs = unsafe { &*self.msgs.get() };
@@ -181,6 +206,24 @@ impl WindowEventsPriv {
 		);
 	}
 
+	/// Adds the creation of a child control, which will run at `WM_CREATE` or
+	/// `WM_INITDIALOG`. Since the functions are run in the order they were
+	/// added, the children are created in declaration order. If the creation
+	/// fails, the error is wrapped into a `CtrlCreateError`, and the remaining
+	/// children are not created.
+	pub(in crate::gui) fn wm_create_child<F>(&self, ctrl_id: u16, func: F)
+		where F: Fn() -> SysResult<()> + 'static,
+	{
+		let n_children = unsafe { &mut *self.n_children.get() };
+		let index = *n_children;
+		*n_children += 1;
+
+		self.wm_create_or_initdialog(move |_, _| {
+			func().map_err(|err| CtrlCreateError::new(index, ctrl_id, err.into()))?;
+			Ok(WmRet::NotHandled)
+		});
+	}
+
 	pub(in crate::gui) fn wm_command<F>(&self,
 		ctrl_id: impl Into<u16>,
 		code: impl Into<co::CMD>,
@@ -214,4 +257,10 @@ impl WindowEventsPriv {
 	{
 		unsafe { &mut *self.tmrs.get() }.push(timer_id, Box::new(func));
 	}
+
+	pub(in crate::gui) fn wm_hot_key<F>(&self, hotkey_id: i32, func: F)
+		where F: Fn() -> AnyResult<()> + 'static,
+	{
+		unsafe { &mut *self.hkeys.get() }.push(hotkey_id, Box::new(func));
+	}
 }
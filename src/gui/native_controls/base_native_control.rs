@@ -52,7 +52,17 @@ impl BaseNativeControl {
 		if *parent.as_ref().hwnd() != HWND::NULL {
 			panic!("Cannot create a child control after the parent window is created.");
 		}
+		Self::new_dynamic(parent, ctrl_id)
+	}
 
+	/// Like [`new`](BaseNativeControl::new), but to be created after the
+	/// parent window is created. The control cannot have its own events.
+	#[must_use]
+	pub(in crate::gui) fn new_dynamic(
+		parent: &impl AsRef<Base>,
+		ctrl_id: u16,
+	) -> Self
+	{
 		Self {
 			ctrl_id,
 			hwnd: UnsafeCell::new(HWND::NULL),
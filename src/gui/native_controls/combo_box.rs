@@ -114,10 +114,8 @@ impl ComboBox {
 		);
 
 		let self2 = new_self.clone();
-		parent.as_ref().before_user_on().wm_create_or_initdialog(move |_, _| {
-			self2.create(OptsResz::Wnd(&opts))?;
-			Ok(WmRet::NotHandled)
-		});
+		parent.as_ref().before_user_on().wm_create_child(
+			new_self.ctrl_id(), move || self2.create(OptsResz::Wnd(&opts)));
 
 		new_self
 	}
@@ -148,10 +146,8 @@ impl ComboBox {
 		);
 
 		let self2 = new_self.clone();
-		parent.as_ref().before_user_on().wm(co::WM::INITDIALOG, move |_, _| {
-			self2.create(OptsResz::Dlg(resize_behavior))?;
-			Ok(WmRet::NotHandled)
-		});
+		parent.as_ref().before_user_on().wm_create_child(
+			new_self.ctrl_id(), move || self2.create(OptsResz::Dlg(resize_behavior)));
 
 		new_self
 	}
@@ -52,6 +52,24 @@ impl<'a> ComboBoxItems<'a> {
 		}.unwrap()
 	}
 
+	/// Retrieves the user-defined value associated with the item at the given
+	/// index by sending a [`cb::GetItemData`](crate::msg::cb::GetItemData)
+	/// message.
+	///
+	/// Returns `None` if the index is invalid.
+	#[must_use]
+	pub fn data(&self, index: u32) -> Option<isize> {
+		if index >= self.count() {
+			return None;
+		}
+		Some(
+			unsafe {
+				self.owner.hwnd()
+					.SendMessage(cb::GetItemData { index })
+			}.unwrap_or(-1), // valid index, so CB_ERR is the actual data
+		)
+	}
+
 	/// Deletes the item at the given index by sending a
 	/// [`cb::DeleteString`](crate::msg::cb::DeleteString) message.
 	///
@@ -71,6 +89,63 @@ impl<'a> ComboBoxItems<'a> {
 		unsafe { self.owner.hwnd().SendMessage(cb::ResetContent {}); }
 	}
 
+	/// Searches for the first item which exactly matches the given text, case
+	/// insensitive, by sending a
+	/// [`cb::FindStringExact`](crate::msg::cb::FindStringExact) message.
+	///
+	/// The search starts after `preceding_index`, wrapping around; if `None`,
+	/// the whole list is searched from the beginning.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let my_combo: gui::ComboBox; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let my_combo = gui::ComboBox::new(&wnd, gui::ComboBoxOpts::default());
+	///
+	/// if let Some(index) = my_combo.items().find_exact(None, "Mary") {
+	///     my_combo.items().select(Some(index));
+	/// }
+	/// ```
+	#[must_use]
+	pub fn find_exact(&self,
+		preceding_index: Option<u32>,
+		text: &str,
+	) -> Option<u32>
+	{
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(cb::FindStringExact {
+					preceding_index,
+					text: WString::from_str(text),
+				})
+		}
+	}
+
+	/// Inserts a new text at the given position, or at the end if `None`, by
+	/// sending a [`cb::InsertString`](crate::msg::cb::InsertString) message.
+	///
+	/// Unlike [`add`](crate::gui::spec::ComboBoxItems::add), the list is not
+	/// sorted, even if the control has the
+	/// [`CBS::SORT`](crate::co::CBS::SORT) style.
+	///
+	/// Returns the index of the new item.
+	///
+	/// # Panics
+	///
+	/// Panics if the index is invalid.
+	pub fn insert(&self, index: Option<u32>, text: &str) -> u32 {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(cb::InsertString {
+					index,
+					text: WString::from_str(text),
+				})
+		}.unwrap()
+	}
+
 	/// Returns an iterator over the texts.
 	///
 	/// # Examples
@@ -100,6 +175,19 @@ impl<'a> ComboBoxItems<'a> {
 		}
 	}
 
+	/// Associates an user-defined value with the item at the given index by
+	/// sending a [`cb::SetItemData`](crate::msg::cb::SetItemData) message.
+	///
+	/// # Panics
+	///
+	/// Panics if the index is invalid.
+	pub fn set_data(&self, index: u32, data: isize) {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(cb::SetItemData { index, data })
+		}.unwrap();
+	}
+
 	/// Retrieves the index of the currently selected item, if any, by sending a
 	/// [`cb::GetCurSel`](crate::msg::cb::GetCurSel) message.
 	#[must_use]
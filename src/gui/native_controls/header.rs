@@ -99,10 +99,8 @@ impl Header {
 		);
 
 		let self2 = new_self.clone();
-		parent.as_ref().before_user_on().wm_create_or_initdialog(move |_, _| {
-			self2.create(OptsReszLv::Wnd(&opts))?;
-			Ok(WmRet::NotHandled)
-		});
+		parent.as_ref().before_user_on().wm_create_child(
+			new_self.ctrl_id(), move || self2.create(OptsReszLv::Wnd(&opts)));
 
 		new_self.default_message_handlers(parent.as_ref());
 		new_self
@@ -134,10 +132,8 @@ impl Header {
 		);
 
 		let self2 = new_self.clone();
-		parent.as_ref().before_user_on().wm(co::WM::INITDIALOG, move |_, _| {
-			self2.create(OptsReszLv::Dlg(resize_behavior))?;
-			Ok(WmRet::NotHandled)
-		});
+		parent.as_ref().before_user_on().wm_create_child(
+			new_self.ctrl_id(), move || self2.create(OptsReszLv::Dlg(resize_behavior)));
 
 		new_self.default_message_handlers(parent.as_ref());
 		new_self
@@ -164,10 +160,8 @@ impl Header {
 
 		let lv_base_ptr = NonNull::from(lv_base_ref);
 		let self2 = new_self.clone();
-		parent_base_ref.before_user_on().wm_create_or_initdialog(move |_, _| {
-			self2.create(OptsReszLv::Lv(lv_base_ptr))?;
-			Ok(WmRet::NotHandled)
-		});
+		parent_base_ref.before_user_on().wm_create_child(
+			new_self.ctrl_id(), move || self2.create(OptsReszLv::Lv(lv_base_ptr)));
 
 		new_self.default_message_handlers(parent_base_ref);
 		new_self
@@ -88,10 +88,8 @@ impl ListBox {
 		);
 
 		let self2 = new_self.clone();
-		parent.as_ref().before_user_on().wm_create_or_initdialog(move |_, _| {
-			self2.create(OptsResz::Wnd(&opts))?;
-			Ok(WmRet::NotHandled)
-		});
+		parent.as_ref().before_user_on().wm_create_child(
+			new_self.ctrl_id(), move || self2.create(OptsResz::Wnd(&opts)));
 
 		new_self
 	}
@@ -121,10 +119,8 @@ impl ListBox {
 		);
 
 		let self2 = new_self.clone();
-		parent.as_ref().before_user_on().wm(co::WM::INITDIALOG, move |_, _| {
-			self2.create(OptsResz::Dlg(resize_behavior))?;
-			Ok(WmRet::NotHandled)
-		});
+		parent.as_ref().before_user_on().wm_create_child(
+			new_self.ctrl_id(), move || self2.create(OptsResz::Dlg(resize_behavior)));
 
 		new_self
 	}
@@ -165,6 +161,20 @@ impl ListBox {
 		ListBoxItems::new(self)
 	}
 
+	/// Retrieves the index of the item nearest to the given point, in client
+	/// coordinates, by sending an
+	/// [`lb::ItemFromPoint`](crate::msg::lb::ItemFromPoint) message.
+	///
+	/// Returns `None` if the point is outside the client area.
+	#[must_use]
+	pub fn item_from_point(&self, pt: POINT) -> Option<u32> {
+		let (index, outside) = unsafe {
+			self.hwnd()
+				.SendMessage(lb::ItemFromPoint { coords: pt })
+		};
+		if outside { None } else { Some(index as _) }
+	}
+
 	/// Sets the scrollable width by sending an
 	/// [`lb::SetHorizontalExtent`](crate::msg::lb::SetHorizontalExtent) message.
 	pub fn set_horizontal_extend(&self, pixels: u32) {
@@ -173,6 +183,18 @@ impl ListBox {
 				.SendMessage(lb::SetHorizontalExtent { width: pixels });
 		}
 	}
+
+	/// Sets the tab stops, in dialog template units, by sending an
+	/// [`lb::SetTabStops`](crate::msg::lb::SetTabStops) message.
+	///
+	/// The control must have the
+	/// [`LBS::USETABSTOPS`](crate::co::LBS::USETABSTOPS) style.
+	pub fn set_tab_stops(&self, stops: &[u32]) -> SysResult<()> {
+		unsafe {
+			self.hwnd()
+				.SendMessage(lb::SetTabStops { tab_stops: stops })
+		}
+	}
 }
 
 //------------------------------------------------------------------------------
@@ -202,6 +224,9 @@ pub struct ListBoxOpts {
 	/// List box styles to be
 	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	///
+	/// Use `LBS::MULTIPLESEL` or `LBS::EXTENDEDSEL` to allow multiple
+	/// selection, and `LBS::SORT` to keep the items sorted.
+	///
 	/// Defaults to `LBS::NOTIFY`.
 	pub list_box_style: co::LBS,
 	/// Window styles to be
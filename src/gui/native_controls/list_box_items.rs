@@ -76,6 +76,42 @@ impl<'a> ListBoxItems<'a> {
 		}.unwrap();
 	}
 
+	/// Inserts a new text at the given position, or at the end if `None`, by
+	/// sending an [`lb::InsertString`](crate::msg::lb::InsertString) message.
+	///
+	/// Unlike [`add`](crate::gui::spec::ListBoxItems::add), the list is not
+	/// sorted, even if the control has the
+	/// [`LBS::SORT`](crate::co::LBS::SORT) style.
+	///
+	/// Returns the index of the new item.
+	///
+	/// # Panics
+	///
+	/// Panics if the index is invalid.
+	pub fn insert(&self, index: Option<u32>, text: &str) -> u32 {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(lb::InsertString {
+					insertion_index: index,
+					text: WString::from_str(text),
+				})
+		}.unwrap()
+	}
+
+	/// Tells whether the item at the given index is selected by sending an
+	/// [`lb::GetSel`](crate::msg::lb::GetSel) message.
+	///
+	/// # Panics
+	///
+	/// Panics if the index is invalid.
+	#[must_use]
+	pub fn is_selected(&self, index: u32) -> bool {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(lb::GetSel { index })
+		}.unwrap()
+	}
+
 	/// Returns an iterator over the texts.
 	///
 	/// # Examples
@@ -118,6 +154,19 @@ impl<'a> ListBoxItems<'a> {
 		ListBoxSelItemIter::new(self.owner)
 	}
 
+	/// Sets the currently selected index, or clears it, by sending an
+	/// [`lb::SetCurSel`](crate::msg::lb::SetCurSel) message.
+	///
+	/// This method works only for single-selection lists; for
+	/// multiple-selection lists, use
+	/// [`set_selected`](crate::gui::spec::ListBoxItems::set_selected).
+	pub fn select(&self, index: Option<u32>) -> SysResult<()> {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(lb::SetCurSel { index })
+		}
+	}
+
 	/// Retrieves the number of selected items by sending an
 	/// [`lb::GetSelCount`](crate::msg::lb::GetSelCount) message.
 	#[must_use]
@@ -128,6 +177,30 @@ impl<'a> ListBoxItems<'a> {
 		}.unwrap()
 	}
 
+	/// Retrieves the index of the currently selected item, if any, by sending
+	/// an [`lb::GetCurSel`](crate::msg::lb::GetCurSel) message.
+	///
+	/// In a multiple-selection list, returns the item with the focus rectangle;
+	/// use [`iter_selected`](crate::gui::spec::ListBoxItems::iter_selected)
+	/// instead.
+	#[must_use]
+	pub fn selected_index(&self) -> Option<u32> {
+		unsafe { self.owner.hwnd().SendMessage(lb::GetCurSel {}) }
+	}
+
+	/// Selects or deselects the item at the given index, or all items if
+	/// `None`, by sending an [`lb::SetSel`](crate::msg::lb::SetSel) message.
+	///
+	/// This method works only for lists with the
+	/// [`LBS::MULTIPLESEL`](crate::co::LBS::MULTIPLESEL) or
+	/// [`LBS::EXTENDEDSEL`](crate::co::LBS::EXTENDEDSEL) styles.
+	pub fn set_selected(&self, select: bool, index: Option<u32>) -> SysResult<()> {
+		unsafe {
+			self.owner.hwnd()
+				.SendMessage(lb::SetSel { select, index })
+		}
+	}
+
 	/// Retrieves the text at the given position, if any, by sending a
 	/// [`lb::GetText`](crate::msg::lb::GetText) message.
 	#[must_use]
@@ -88,6 +88,26 @@ impl RadioButton {
 		)
 	}
 
+	#[must_use]
+	pub(in crate::gui) fn new_dynamic(
+		parent: &impl AsRef<Base>,
+		opts: RadioButtonOpts,
+	) -> Self
+	{
+		let opts = auto_ctrl_id_if_zero(opts);
+		let ctrl_id = opts.ctrl_id;
+
+		Self(
+			Arc::pin(
+				Obj {
+					base: BaseNativeControl::new_dynamic(parent, ctrl_id),
+					events: ButtonEvents::new(parent, ctrl_id),
+					_pin: PhantomPinned,
+				},
+			),
+		)
+	}
+
 	#[must_use]
 	pub(in crate::gui) fn new_dlg(
 		parent: &impl GuiParent,
@@ -1,3 +1,4 @@
+use std::cell::UnsafeCell;
 use std::marker::PhantomPinned;
 use std::ops::Index;
 use std::pin::Pin;
@@ -11,7 +12,8 @@ use crate::prelude::*;
 
 struct Obj { // actual fields of RadioGroup
 	parent_ptr: NonNull<Base>,
-	radios: Vec<RadioButton>,
+	radios: UnsafeCell<Vec<Box<RadioButton>>>, // boxed, so references survive reallocations
+	removed: UnsafeCell<Vec<Box<RadioButton>>>, // kept alive, so references remain valid
 	events: RadioGroupEvents,
 	_pin: PhantomPinned,
 }
@@ -28,7 +30,8 @@ impl Index<usize> for RadioGroup {
 	type Output = RadioButton;
 
 	fn index(&self, i: usize) -> &Self::Output {
-		&self.0.radios[i]
+		let radios = unsafe { &*self.0.radios.get() };
+		&radios[i]
 	}
 }
 
@@ -53,7 +56,8 @@ impl RadioGroup {
 	/// Panics if `opts` is empty.
 	///
 	/// Panics if the parent window was already created – that is, you cannot
-	/// dynamically create a `RadioGroup` in an event closure.
+	/// dynamically create a `RadioGroup` in an event closure. To add radio
+	/// buttons after the creation, use [`add`](crate::gui::RadioGroup::add).
 	#[must_use]
 	pub fn new(
 		parent: &impl GuiParent,
@@ -70,13 +74,14 @@ impl RadioGroup {
 				if i == 0 { // first radio?
 					radio_opt.window_style |= co::WS::TABSTOP | co::WS::GROUP;
 				}
-				RadioButton::new(parent, radio_opt)
+				Box::new(RadioButton::new(parent, radio_opt))
 			})
 			.collect::<Vec<_>>();
 
 		let ctrl_ids = radios.iter()
 			.map(|r| r.ctrl_id()) // when the radio is created, the ctrl ID is defined
 			.collect::<Vec<_>>();
+		let first_ctrl_id = ctrl_ids[0];
 
 		let opts_resz_s = opts.into_iter()
 			.map(|opt| OptsResz::Wnd(opt.clone()))
@@ -86,7 +91,8 @@ impl RadioGroup {
 			Arc::pin(
 				Obj {
 					parent_ptr: NonNull::from(parent.as_ref()),
-					radios,
+					radios: UnsafeCell::new(radios),
+					removed: UnsafeCell::new(Vec::new()),
 					events: RadioGroupEvents::new(parent, ctrl_ids),
 					_pin: PhantomPinned,
 				},
@@ -94,10 +100,8 @@ impl RadioGroup {
 		);
 
 		let self2 = new_self.clone();
-		parent.as_ref().before_user_on().wm_create_or_initdialog(move |_, _| {
-			self2.create(&opts_resz_s)?;
-			Ok(WmRet::NotHandled)
-		});
+		parent.as_ref().before_user_on().wm_create_child(
+			first_ctrl_id, move || self2.create(&opts_resz_s));
 
 		new_self
 	}
@@ -123,12 +127,13 @@ impl RadioGroup {
 		}
 
 		let radios = ctrls.iter()
-			.map(|(ctrl_id, _, _)| RadioButton::new_dlg(parent, *ctrl_id))
+			.map(|(ctrl_id, _, _)| Box::new(RadioButton::new_dlg(parent, *ctrl_id)))
 			.collect::<Vec<_>>();
 
 		let ctrl_ids = ctrls.iter()
 			.map(|(ctrl_id, _, _)| *ctrl_id)
 			.collect::<Vec<_>>();
+		let first_ctrl_id = ctrl_ids[0];
 
 		let opts_resz_s = ctrls.iter()
 			.map(|(_, horz, vert)| OptsResz::Dlg((*horz, *vert)))
@@ -138,7 +143,8 @@ impl RadioGroup {
 			Arc::pin(
 				Obj {
 					parent_ptr: NonNull::from(parent.as_ref()),
-					radios,
+					radios: UnsafeCell::new(radios),
+					removed: UnsafeCell::new(Vec::new()),
 					events: RadioGroupEvents::new(parent, ctrl_ids),
 					_pin: PhantomPinned,
 				},
@@ -146,10 +152,8 @@ impl RadioGroup {
 		);
 
 		let self2 = new_self.clone();
-		parent.as_ref().before_user_on().wm(co::WM::INITDIALOG, move |_, _| {
-			self2.create(&opts_resz_s)?;
-			Ok(WmRet::NotHandled)
-		});
+		parent.as_ref().before_user_on().wm_create_child(
+			first_ctrl_id, move || self2.create(&opts_resz_s));
 
 		new_self
 	}
@@ -158,15 +162,62 @@ impl RadioGroup {
 		opts_resz_s: &Vec<OptsResz<RadioButtonOpts>>,
 	) -> SysResult<()>
 	{
-		self.0.radios.iter()
+		unsafe { &*self.0.radios.get() }.iter()
 			.zip(opts_resz_s.iter())
 			.try_for_each(|(radio, opts_resz)|
 				radio.create(opts_resz) // create each RadioButton sequentially
 			)
 	}
 
-	/// Returns an iterator over the internal
-	/// [`RadioButton`](crate::gui::RadioButton) slice.
+	/// Creates a new [`RadioButton`](crate::gui::RadioButton) with the given
+	/// text, placed right below the last one, at the end of the group.
+	///
+	/// The new radio button can be used right away, and it will fire the events
+	/// of the group. However, it's not handled by the layout arranger, so it
+	/// won't be repositioned when the parent window is resized, regardless of
+	/// the [`Horz`](crate::gui::Horz) and [`Vert`](crate::gui::Vert) values
+	/// given to the other radio buttons.
+	///
+	/// # Panics
+	///
+	/// Panics if the group was not created yet.
+	pub fn add(&mut self, text: &str) -> SysResult<()> {
+		let radios = unsafe { &mut *self.0.radios.get() };
+		let parent = unsafe { self.0.parent_ptr.as_ref() };
+
+		let hlast = radios.last().unwrap().hwnd(); // never empty
+		if *hlast == HWND::NULL {
+			panic!("Cannot add a RadioButton before the RadioGroup creation.");
+		}
+
+		let rc_last = parent.hwnd().ScreenToClientRc(hlast.GetWindowRect()?)?;
+		let pitch = match radios.len() { // vertical distance between radios
+			1 => (rc_last.bottom - rc_last.top) * 3 / 2,
+			n => rc_last.top - parent.hwnd()
+				.ScreenToClientRc(radios[n - 2].hwnd().GetWindowRect()?)?.top,
+		};
+
+		let opts = RadioButtonOpts {
+			text: text.to_owned(),
+			..Default::default()
+		};
+		let radio = RadioButton::new_dynamic(parent, opts.manual_clone());
+		radio.create(&OptsResz::Wnd(opts))?;
+
+		radio.hwnd().SetWindowPos( // right after the last one, to keep the tab order
+			HwndPlace::Hwnd(unsafe { hlast.raw_copy() }),
+			POINT::new(rc_last.left, rc_last.top + pitch),
+			SIZE::default(),
+			co::SWP::NOSIZE,
+		)?;
+
+		self.0.events.add_ctrl_id(radio.ctrl_id());
+		radios.push(Box::new(radio));
+		Ok(())
+	}
+
+	/// Returns an iterator over the [`RadioButton`](crate::gui::RadioButton)
+	/// controls of this group.
 	///
 	/// # Example
 	///
@@ -185,22 +236,29 @@ impl RadioGroup {
 	/// # w::SysResult::Ok(())
 	/// ```
 	#[must_use]
-	pub fn iter(&self) -> std::slice::Iter<'_, RadioButton> {
-		self.0.radios.iter()
+	pub fn iter(&self) -> impl Iterator<Item = &RadioButton> + '_ {
+		let mut idx = 0;
+		std::iter::from_fn(move || { // index at each step, since a clone may add or remove
+			let radios = unsafe { &*self.0.radios.get() };
+			radios.get(idx).map(|radio| {
+				idx += 1;
+				&**radio
+			})
+		})
 	}
 
 	/// Returns the currently checked [`RadioButton`](crate::gui::RadioButton)
 	/// of this group, if any.
 	#[must_use]
 	pub fn checked(&self) -> Option<&RadioButton> {
-		self.checked_index().map(|idx| &self.0.radios[idx])
+		self.checked_index().map(|idx| self.index(idx))
 	}
 
 	/// Returns the index of the currently selected
 	/// [`RadioButton`](crate::gui::RadioButton) of this group, if any.
 	#[must_use]
 	pub fn checked_index(&self) -> Option<usize> {
-		self.0.radios.iter()
+		self.iter()
 			.position(|radio| radio.is_selected())
 	}
 
@@ -208,6 +266,55 @@ impl RadioGroup {
 	/// in this group.
 	#[must_use]
 	pub fn count(&self) -> usize {
-		self.0.radios.len()
+		unsafe { &*self.0.radios.get() }.len()
+	}
+
+	/// Destroys the [`RadioButton`](crate::gui::RadioButton) at the given
+	/// index, shifting down the indexes of the following ones.
+	///
+	/// If the removed radio button was selected, the previous one is selected
+	/// – or the next one, if the first was removed. If the first one is
+	/// removed, the next one receives the `WS::TABSTOP | WS::GROUP` styles.
+	///
+	/// The removed [`RadioButton`](crate::gui::RadioButton) object itself is
+	/// kept in memory until the group is dropped, so references to it remain
+	/// valid, although its window no longer exists.
+	///
+	/// # Panics
+	///
+	/// Panics if the index is out of bounds, or if it's the only radio button
+	/// of the group.
+	///
+	/// Panics if the group was not created yet.
+	pub fn remove_at(&mut self, index: usize) -> SysResult<()> {
+		let radios = unsafe { &mut *self.0.radios.get() };
+		if radios.len() == 1 {
+			panic!("RadioGroup needs at least one RadioButton.");
+		}
+
+		let radio = &radios[index];
+		if *radio.hwnd() == HWND::NULL {
+			panic!("Cannot remove a RadioButton before the RadioGroup creation.");
+		}
+
+		let was_selected = radio.is_selected();
+		radio.hwnd().DestroyWindow()?;
+		unsafe { self.0.parent_ptr.as_ref() }
+			.remove_from_layout_arranger(radio.hwnd());
+		self.0.events.remove_ctrl_id(radio.ctrl_id());
+		unsafe { &mut *self.0.removed.get() }.push(radios.remove(index));
+
+		if index == 0 { // the new first radio must start the group
+			let hfirst = radios[0].hwnd();
+			let style = unsafe {
+				co::WS::from_raw(hfirst.GetWindowLongPtr(co::GWLP::STYLE) as _)
+			} | co::WS::TABSTOP | co::WS::GROUP;
+			unsafe { hfirst.SetWindowLongPtr(co::GWLP::STYLE, style.raw() as _); }
+		}
+
+		if was_selected {
+			radios[index.saturating_sub(1)].select(true); // previous one, or the new first
+		}
+		Ok(())
 	}
 }
@@ -107,10 +107,8 @@ impl StatusBar {
 		);
 
 		let self2 = new_self.clone();
-		parent.as_ref().before_user_on().wm_create_or_initdialog(move |_, _| {
-			self2.create()?;
-			Ok(WmRet::NotHandled)
-		});
+		parent.as_ref().before_user_on().wm_create_child(
+			new_self.ctrl_id(), move || self2.create());
 
 		let self2 = new_self.clone();
 		parent.as_ref().before_user_on().wm(co::WM::SIZE, move |_, p| {
@@ -27,16 +27,8 @@ static mut UI_HFONT: Option<DeleteObjectGuard<HFONT>> = None;
 
 /// Creates the global UI font object.
 pub(in crate::gui) fn create_ui_font() -> SysResult<()> {
-	let mut ncm = NONCLIENTMETRICS::default();
-	unsafe {
-		SystemParametersInfo(
-			co::SPI::GETNONCLIENTMETRICS,
-			std::mem::size_of::<NONCLIENTMETRICS>() as _,
-			&mut ncm,
-			co::SPIF::NoValue,
-		)?;
-		UI_HFONT = Some(HFONT::CreateFontIndirect(&ncm.lfMenuFont)?);
-	}
+	let ncm = crate::spi::non_client_metrics()?;
+	unsafe { UI_HFONT = Some(HFONT::CreateFontIndirect(&ncm.lfMenuFont)?); }
 	Ok(())
 }
 
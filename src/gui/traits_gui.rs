@@ -25,6 +25,37 @@ pub trait GuiWindow: Send {
 	#[must_use]
 	fn hwnd(&self) -> &HWND;
 
+	/// Tells whether the window has already been physically created, that is,
+	/// its [`hwnd`](crate::prelude::GuiWindow::hwnd) is not null.
+	///
+	/// Events can only be added before creation, so this method can be used to
+	/// avoid the panics of [`GuiParent::on`](crate::prelude::GuiParent::on),
+	/// [`GuiNativeControl::on_subclass`](crate::prelude::GuiNativeControl::on_subclass)
+	/// and
+	/// [`GuiNativeControlEvents::on`](crate::prelude::GuiNativeControlEvents::on).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// let btn: gui::Button;
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let btn = gui::Button::new(&wnd, gui::ButtonOpts::default());
+	///
+	/// if !wnd.is_created() && !btn.is_created() {
+	///     btn.on().bn_clicked(|| {
+	///         println!("Clicked.");
+	///         Ok(())
+	///     });
+	/// }
+	/// ```
+	#[must_use]
+	fn is_created(&self) -> bool {
+		*self.hwnd() != HWND::NULL
+	}
+
 	/// Converts a reference to the [`Any`] trait. This is useful when storing a
 	/// collection of polymorphic controls, because `Any` allows downcasting.
 	///
@@ -89,7 +120,8 @@ pub trait GuiParent: GuiWindow + AsRef<Base> {
 	/// # Panics
 	///
 	/// Panics if the window is already created. Events must be set before
-	/// window creation.
+	/// window creation, which can be checked with
+	/// [`is_created`](crate::prelude::GuiWindow::is_created).
 	#[must_use]
 	fn on(&self) -> &WindowEvents {
 		self.as_ref().on()
@@ -307,11 +339,40 @@ pub trait GuiNativeControl: GuiChild + AsRef<BaseNativeControl> {
 	/// # Panics
 	///
 	/// Panics if the control or the parent window are already created. Events
-	/// must be set before control and parent window creation.
+	/// must be set before control and parent window creation, which can be
+	/// checked with [`is_created`](crate::prelude::GuiWindow::is_created) on
+	/// both.
 	#[must_use]
 	fn on_subclass(&self) -> &WindowEvents {
 		self.as_ref().on_subclass()
 	}
+
+	/// Enables or disables the control by calling
+	/// [`HWND::EnableWindow`](crate::prelude::user_Hwnd::EnableWindow).
+	///
+	/// Returns the previous enabled state. To query the current state, call
+	/// [`HWND::IsWindowEnabled`](crate::prelude::user_Hwnd::IsWindowEnabled).
+	///
+	/// If the control being disabled has the keyboard focus, the focus is
+	/// first moved to the next control in the tab order, otherwise it would be
+	/// lost.
+	fn set_enabled(&self, enabled: bool) -> bool {
+		let hwnd = self.hwnd();
+		if !enabled && HWND::GetFocus().as_ref() == Some(hwnd) {
+			match hwnd.GetParent() {
+				Ok(hparent) if hparent.is_dialog() => unsafe {
+					hparent.SendMessage(wm::NextDlgCtl {
+						hwnd_focus: HwndFocus::FocusNext(true),
+					});
+				},
+				Ok(hparent) => if let Ok(hnext) = hparent.GetNextDlgTabItem(Some(hwnd), false) {
+					hnext.SetFocus();
+				},
+				Err(_) => {}, // no parent, nowhere to move the focus
+			}
+		}
+		!hwnd.EnableWindow(enabled)
+	}
 }
 
 /// Events of a native control.
@@ -326,8 +387,10 @@ pub trait GuiNativeControlEvents<E> {
 	///
 	/// # Panics
 	///
-	/// Panics if the control is already created. Events must be set before
-	/// control creation.
+	/// Panics if the control or the parent window are already created. Events
+	/// must be set before control and parent window creation, which can be
+	/// checked with [`is_created`](crate::prelude::GuiWindow::is_created) on
+	/// both.
 	#[must_use]
 	fn on(&self) -> &E;
 }
@@ -0,0 +1,51 @@
+use std::cell::UnsafeCell;
+
+use crate::decl::*;
+use crate::guard::*;
+use crate::prelude::*;
+
+/// Keeps the accelerator table of a main window, which can be replaced at any
+/// time – even within an event closure dispatched by
+/// [`TranslateAccelerator`](crate::prelude::user_Hwnd::TranslateAccelerator).
+pub(in crate::gui) struct AccelTable {
+	cur: UnsafeCell<Option<DestroyAcceleratorTableGuard>>,
+	retired: UnsafeCell<Vec<DestroyAcceleratorTableGuard>>, // replaced tables, which may still be in use by the main loop
+}
+
+impl AccelTable {
+	#[must_use]
+	pub(in crate::gui) const fn new(
+		haccel: Option<DestroyAcceleratorTableGuard>,
+	) -> Self
+	{
+		Self {
+			cur: UnsafeCell::new(haccel),
+			retired: UnsafeCell::new(Vec::new()),
+		}
+	}
+
+	/// Returns a copy of the current accelerator table handle, if any, so it
+	/// can be safely replaced while in use.
+	#[must_use]
+	pub(in crate::gui) fn get(&self) -> Option<HACCEL> {
+		unsafe { &*self.cur.get() }.as_ref()
+			.map(|haccel| unsafe { haccel.raw_copy() })
+	}
+
+	/// Replaces the current accelerator table. The previous one is destroyed
+	/// only when the main loop calls `free_retired`.
+	pub(in crate::gui) fn set(&self,
+		haccel: Option<DestroyAcceleratorTableGuard>,
+	) {
+		let prev = std::mem::replace(unsafe { &mut *self.cur.get() }, haccel);
+		if let Some(prev) = prev {
+			unsafe { &mut *self.retired.get() }.push(prev);
+		}
+	}
+
+	/// Destroys the tables replaced by `set`. Must be called by the main loop
+	/// when no table is being used.
+	pub(in crate::gui) fn free_retired(&self) {
+		unsafe { &mut *self.retired.get() }.clear();
+	}
+}
@@ -152,6 +152,10 @@ impl Base {
 		self.layout_arranger.add_child(&self.hwnd, hchild, resize_behavior)
 	}
 
+	pub(in crate::gui) fn remove_from_layout_arranger(&self, hchild: &HWND) {
+		self.layout_arranger.remove_child(hchild)
+	}
+
 	pub(in crate::gui) fn spawn_new_thread<F>(&self, func: F)
 		where F: FnOnce() -> AnyResult<()> + Send + 'static,
 	{
@@ -227,13 +231,16 @@ impl Base {
 	}
 
 	pub(in crate::gui) fn run_main_loop(
-		haccel: Option<&HACCEL>,
+		hwnd_main: &HWND,
+		accel_table: &AccelTable,
 		process_dlg_msgs: bool,
 	) -> AnyResult<i32>
 	{
 		let mut msg = MSG::default();
 
 		loop {
+			accel_table.free_retired(); // tables replaced while processing the previous message
+
 			if !GetMessage(&mut msg, None, 0, 0)? {
 				// WM_QUIT was sent, gracefully terminate the program.
 				// wParam has the program exit code.
@@ -245,14 +252,24 @@ impl Base {
 				};
 			}
 
+			// Application thread messages, posted with PostThreadMessage(), have
+			// no target window; deliver them to the main window, so they can be
+			// handled as ordinary events. Other thread messages are left as
+			// they are.
+			if msg.hwnd == HWND::NULL
+				&& (co::WM::APP.raw()..=0xbfff).contains(&msg.message.raw())
+			{
+				msg.hwnd = unsafe { hwnd_main.raw_copy() };
+			}
+
 			// If a child window, will retrieve its top-level parent.
 			// If a top-level, use itself.
 			let hwnd_top_level = msg.hwnd.GetAncestor(co::GA::ROOT)
 				.unwrap_or(unsafe { msg.hwnd.raw_copy() });
 
 			// If we have an accelerator table, try to translate the message.
-			if let Some(haccel) = haccel {
-				if hwnd_top_level.TranslateAccelerator(haccel, &mut msg).is_ok() {
+			if let Some(haccel) = accel_table.get() {
+				if hwnd_top_level.TranslateAccelerator(&haccel, &mut msg).is_ok() {
 					continue; // message translated
 				}
 			}
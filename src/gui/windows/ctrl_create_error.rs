@@ -0,0 +1,56 @@
+/// An error that occurred while creating a child control, when its parent
+/// window processed `WM_CREATE` or `WM_INITDIALOG`.
+///
+/// The children of a window are created in the order they were declared, and
+/// the creation stops at the first failure, so the controls after the failed
+/// one are not created. This error is then wrapped into a
+/// [`MsgError`](crate::gui::MsgError), and returned by the main loop.
+pub struct CtrlCreateError {
+	index: usize,
+	ctrl_id: u16,
+	source: Box<dyn std::error::Error + Send + Sync>,
+}
+
+impl std::error::Error for CtrlCreateError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		Some(self.source.as_ref())
+	}
+}
+
+impl std::fmt::Display for CtrlCreateError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Child #{} (ID {}) - {}",
+			self.index, self.ctrl_id, self.source.to_string())
+	}
+}
+impl std::fmt::Debug for CtrlCreateError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		std::fmt::Display::fmt(self, f)
+	}
+}
+
+impl CtrlCreateError {
+	/// Constructs a new `CtrlCreateError` by wrapping the given error.
+	#[must_use]
+	pub const fn new(
+		index: usize,
+		ctrl_id: u16,
+		source: Box<dyn std::error::Error + Send + Sync>,
+	) -> CtrlCreateError
+	{
+		Self { index, ctrl_id, source }
+	}
+
+	/// The zero-based position of the failed control among the children of
+	/// its parent window, in declaration order.
+	#[must_use]
+	pub const fn index(&self) -> usize {
+		self.index
+	}
+
+	/// The ID of the failed control.
+	#[must_use]
+	pub const fn ctrl_id(&self) -> u16 {
+		self.ctrl_id
+	}
+}
@@ -1,9 +1,11 @@
+use std::cell::UnsafeCell;
 use std::marker::PhantomPinned;
 use std::pin::Pin;
 use std::sync::Arc;
 
 use crate::co;
 use crate::decl::*;
+use crate::guard::*;
 use crate::gui::{*, privs::*};
 use crate::msg::*;
 use crate::prelude::*;
@@ -11,7 +13,10 @@ use crate::prelude::*;
 struct Obj { // actual fields of DlgMain
 	dlg_base: DlgBase,
 	icon_id: Option<u16>,
-	accel_table_id: Option<u16>,
+	accel_table_id: UnsafeCell<Option<u16>>, // cleared if replaced by the user before run
+	accel_table: AccelTable,
+	thread_id: u32,
+	exit_code: UnsafeCell<i32>,
 	_pin: PhantomPinned,
 }
 
@@ -29,12 +34,16 @@ impl DlgMain {
 		accel_table_id: Option<u16>,
 	) -> Self
 	{
+		PeekMessage(&mut MSG::default(), None, 0, 0, co::PM::NOREMOVE); // make sure the thread has a message queue, for PostThreadMessage()
 		let new_self = Self(
 			Arc::pin(
 				Obj {
 					dlg_base: DlgBase::new(None::<&WindowMain>, dialog_id),
 					icon_id,
-					accel_table_id,
+					accel_table_id: UnsafeCell::new(accel_table_id),
+					accel_table: AccelTable::new(None),
+					thread_id: GetCurrentThreadId(),
+					exit_code: UnsafeCell::new(0),
 					_pin: PhantomPinned,
 				},
 			),
@@ -48,21 +57,37 @@ impl DlgMain {
 		self.0.dlg_base.base()
 	}
 
+	#[must_use]
+	pub(in crate::gui) fn thread_id(&self) -> u32 {
+		self.0.thread_id
+	}
+
 	pub(in crate::gui) fn run_main(&self,
 		cmd_show: Option<co::SW>,
 	) -> AnyResult<i32>
 	{
 		self.0.dlg_base.create_dialog_param().unwrap();
 		let hinst = HINSTANCE::GetModuleHandle(None).unwrap();
-		let haccel = self.0.accel_table_id
-			.map(|id| hinst.LoadAccelerators(IdStr::Id(id))) // resources are automatically freed
-			.transpose()
-			.unwrap();
+		if let Some(id) = *unsafe { &*self.0.accel_table_id.get() } { // not replaced by the user before run
+			let haccel = hinst.LoadAccelerators(IdStr::Id(id)).unwrap(); // resources are automatically freed
+			self.0.accel_table.set(Some(haccel));
+		}
 
 		self.set_icon_if_any(&hinst).unwrap();
 		self.base().hwnd().ShowWindow(cmd_show.unwrap_or(co::SW::SHOW));
 
-		Base::run_main_loop(haccel.as_deref(), true) // blocks until window is closed
+		Base::run_main_loop(self.base().hwnd(), &self.0.accel_table, true) // blocks until window is closed
+	}
+
+	pub(in crate::gui) fn set_accel_table(&self,
+		accel_table: Option<DestroyAcceleratorTableGuard>,
+	) {
+		*unsafe { &mut *self.0.accel_table_id.get() } = None; // resource table won't be loaded anymore
+		self.0.accel_table.set(accel_table);
+	}
+
+	pub(in crate::gui) fn set_exit_code(&self, exit_code: i32) {
+		*unsafe { &mut *self.0.exit_code.get() } = exit_code;
 	}
 
 	fn default_message_handlers(&self) {
@@ -72,28 +97,30 @@ impl DlgMain {
 			Ok(())
 		});
 
-		self.base().on().wm_nc_destroy(|| {
-			PostQuitMessage(0);
+		let self2 = self.clone();
+		self.base().on().wm_nc_destroy(move || {
+			PostQuitMessage(*unsafe { &*self2.0.exit_code.get() });
 			Ok(())
 		});
 	}
 
 	fn set_icon_if_any(&self, hinst: &HINSTANCE) -> SysResult<()> {
-		// If an icon ID was specified, load it from the resources.
-		// Resource icons are automatically released by the system.
+		// If an icon ID was specified, load it from the resources, choosing the
+		// image sizes according to the window DPI.
+		// The icons live as long as the process, so they're never destroyed.
 		if let Some(id) = self.0.icon_id {
-			unsafe {
-				self.base().hwnd().SendMessage(wm::SetIcon {
-					hicon: hinst.LoadImageIcon(
-						IdOicStr::Id(id), SIZE::new(16, 16), co::LR::DEFAULTCOLOR)?.leak(),
-					size: co::ICON_SZ::SMALL,
-				});
-
-				self.base().hwnd().SendMessage(wm::SetIcon {
-					hicon: hinst.LoadImageIcon(
-						IdOicStr::Id(id), SIZE::new(32, 32), co::LR::DEFAULTCOLOR)?.leak(),
-					size: co::ICON_SZ::BIG,
-				});
+			let hwnd = self.base().hwnd();
+			let dpi = hwnd.GetDpiForWindow();
+
+			for (metric, size) in [
+				(co::SM::CXSMICON, co::ICON_SZ::SMALL),
+				(co::SM::CXICON, co::ICON_SZ::BIG),
+			] {
+				let px = GetSystemMetricsForDpi(metric, dpi)?;
+				let mut hicon = hinst.load_icon_best_fit(IdStr::Id(id), px as _)?;
+				unsafe {
+					hwnd.SendMessage(wm::SetIcon { hicon: hicon.leak(), size });
+				}
 			}
 		}
 		Ok(())
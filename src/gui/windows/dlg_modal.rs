@@ -47,6 +47,10 @@ impl DlgModal {
 			.map_err(|err| err.into())
 	}
 
+	pub(in crate::gui) fn end_modal(&self, result: i32) -> SysResult<()> {
+		self.base().hwnd().EndDialog(result as _)
+	}
+
 	fn default_message_handlers(&self) {
 		self.base().before_user_on().wm(co::WM::INITDIALOG, move |hwnd, _| {
 			let rc = hwnd.GetWindowRect()?;
@@ -69,6 +69,12 @@ impl LayoutArranger {
 		Ok(())
 	}
 
+	/// Removes a child control from the internal list, if present.
+	pub(in crate::gui) fn remove_child(&self, hchild: &HWND) {
+		unsafe { &mut *self.0.ctrls.get() }
+			.retain(|ctrl| ctrl.hchild != *hchild);
+	}
+
 	/// Saves the original client area of the parent window.
 	pub(in crate::gui) fn save_original_client_area(&self,
 		hparent: &HWND,
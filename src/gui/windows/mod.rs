@@ -1,6 +1,8 @@
 //! Windows and dialogs.
 
+mod accel_table;
 mod base;
+mod ctrl_create_error;
 mod dlg_base;
 mod dlg_control;
 mod dlg_main;
@@ -20,6 +22,7 @@ mod window_modal;
 mod window_modeless;
 
 pub(in crate::gui) mod privs {
+	pub(in crate::gui) use super::accel_table::AccelTable;
 	pub(in crate::gui) use super::base::Base;
 	pub(in crate::gui) use super::dlg_base::DlgBase;
 	pub(in crate::gui) use super::dlg_control::DlgControl;
@@ -35,6 +38,7 @@ pub(in crate::gui) mod privs {
 }
 
 pub mod decl {
+	pub use super::ctrl_create_error::CtrlCreateError;
 	pub use super::msg_error::MsgError;
 	pub use super::raw_control::WindowControlOpts;
 	pub use super::raw_main::WindowMainOpts;
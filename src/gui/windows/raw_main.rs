@@ -13,7 +13,10 @@ use crate::user::guard::*;
 struct Obj { // actual fields of RawMain
 	raw_base: RawBase,
 	opts: WindowMainOpts,
+	accel_table: AccelTable,
+	thread_id: u32,
 	hchild_prev_focus: UnsafeCell<HWND>, // WM_ACTIVATE woes
+	exit_code: UnsafeCell<i32>,
 	_pin: PhantomPinned,
 }
 
@@ -25,13 +28,18 @@ pub(in crate::gui) struct RawMain(Pin<Arc<Obj>>);
 
 impl RawMain {
 	#[must_use]
-	pub(in crate::gui) fn new(opts: WindowMainOpts) -> Self {
+	pub(in crate::gui) fn new(mut opts: WindowMainOpts) -> Self {
+		let accel_table = AccelTable::new(opts.accel_table.take());
+		PeekMessage(&mut MSG::default(), None, 0, 0, co::PM::NOREMOVE); // make sure the thread has a message queue, for PostThreadMessage()
 		let new_self = Self(
 			Arc::pin(
 				Obj {
 					raw_base: RawBase::new(None::<&WindowMain>),
 					opts,
+					accel_table,
+					thread_id: GetCurrentThreadId(),
 					hchild_prev_focus: UnsafeCell::new(HWND::NULL),
+					exit_code: UnsafeCell::new(0),
 					_pin: PhantomPinned,
 				},
 			),
@@ -45,6 +53,11 @@ impl RawMain {
 		self.0.raw_base.base()
 	}
 
+	#[must_use]
+	pub(in crate::gui) fn thread_id(&self) -> u32 {
+		self.0.thread_id
+	}
+
 	pub(in crate::gui) fn run_main(&self,
 		cmd_show: Option<co::SW>,
 	) -> AnyResult<i32>
@@ -81,8 +94,13 @@ impl RawMain {
 			right: wnd_pos.x + wnd_sz.cx,
 			bottom: wnd_pos.y + wnd_sz.cy,
 		};
+		let ex_style = match opts.opacity {
+			Some(_) => opts.ex_style | co::WS_EX::LAYERED,
+			None => opts.ex_style,
+		};
+
 		wnd_rc = AdjustWindowRectEx(wnd_rc, opts.style,
-			opts.menu != HMENU::NULL, opts.ex_style)?;
+			opts.menu != HMENU::NULL, ex_style)?;
 		wnd_sz.cx = wnd_rc.right - wnd_rc.left;
 		wnd_sz.cy = wnd_rc.bottom - wnd_rc.top;
 
@@ -96,13 +114,28 @@ impl RawMain {
 				IdMenu::Menu(&opts.menu)
 			},
 			POINT::new(wnd_rc.left, wnd_rc.top), wnd_sz,
-			opts.ex_style, opts.style,
+			ex_style, opts.style,
 		)?;
 
+		if let Some(opacity) = opts.opacity {
+			self.base().hwnd().SetLayeredWindowAttributes(
+				COLORREF::default(), opacity, co::LWA::ALPHA)?;
+		}
+
 		self.base().hwnd().ShowWindow(cmd_show.unwrap_or(co::SW::SHOW));
 		self.base().hwnd().UpdateWindow()?;
 
-		Base::run_main_loop(opts.accel_table.as_deref(), opts.process_dlg_msgs) // blocks until window is closed
+		Base::run_main_loop(self.base().hwnd(), &self.0.accel_table, opts.process_dlg_msgs) // blocks until window is closed
+	}
+
+	pub(in crate::gui) fn set_accel_table(&self,
+		accel_table: Option<DestroyAcceleratorTableGuard>,
+	) {
+		self.0.accel_table.set(accel_table);
+	}
+
+	pub(in crate::gui) fn set_exit_code(&self, exit_code: i32) {
+		*unsafe { &mut *self.0.exit_code.get() } = exit_code;
 	}
 
 	fn default_message_handlers(&self) {
@@ -130,8 +163,9 @@ impl RawMain {
 			Ok(WmRet::HandledOk)
 		});
 
+		let self2 = self.clone();
 		self.base().on().wm_nc_destroy(move || {
-			PostQuitMessage(0);
+			PostQuitMessage(*unsafe { &*self2.0.exit_code.get() });
 			Ok(())
 		});
 	}
@@ -196,6 +230,15 @@ pub struct WindowMainOpts {
 	///
 	/// Defaults to `WS_EX::LEFT`.
 	pub ex_style: co::WS_EX,
+	/// Opacity of the whole window, from `0` (transparent) to `255` (opaque),
+	/// set with
+	/// [`HWND::SetLayeredWindowAttributes`](crate::prelude::user_Hwnd::SetLayeredWindowAttributes)
+	/// after the window is created. If set, the
+	/// [`WS_EX::LAYERED`](crate::co::WS_EX::LAYERED) style is added
+	/// automatically.
+	///
+	/// Defaults to `None`.
+	pub opacity: Option<u8>,
 	/// Main menu of the window to be
 	/// [created](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createwindowexw).
 	///
@@ -210,6 +253,9 @@ pub struct WindowMainOpts {
 	/// [`HACCEL::CreateAcceleratorTable`](crate::prelude::user_Haccel::CreateAcceleratorTable)
 	/// to create one.
 	///
+	/// The table can be replaced later with
+	/// [`WindowMain::set_accel_table`](crate::gui::WindowMain::set_accel_table).
+	///
 	/// Defaults to `None`.
 	pub accel_table: Option<DestroyAcceleratorTableGuard>,
 	/// In most applications, the window loop calls
@@ -240,6 +286,7 @@ impl Default for WindowMainOpts {
 			size: (600, 500),
 			style: co::WS::CAPTION | co::WS::SYSMENU | co::WS::CLIPCHILDREN | co::WS::BORDER | co::WS::VISIBLE,
 			ex_style: co::WS_EX::LEFT,
+			opacity: None,
 			menu: HMENU::NULL,
 			accel_table: None,
 			process_dlg_msgs: true,
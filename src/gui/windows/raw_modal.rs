@@ -12,6 +12,7 @@ struct Obj { // actual fields of RawModal
 	raw_base: RawBase,
 	opts: WindowModalOpts,
 	hchild_prev_focus_parent: UnsafeCell<HWND>,
+	modal_result: UnsafeCell<i32>,
 	_pin: PhantomPinned,
 }
 
@@ -34,6 +35,7 @@ impl RawModal {
 					raw_base: RawBase::new(Some(parent)),
 					opts,
 					hchild_prev_focus_parent: UnsafeCell::new(HWND::NULL),
+					modal_result: UnsafeCell::new(0),
 					_pin: PhantomPinned,
 				},
 			),
@@ -75,7 +77,12 @@ impl RawModal {
 			right: wnd_sz.cx,
 			bottom: wnd_sz.cy,
 		};
-		wnd_rc = AdjustWindowRectEx(wnd_rc, opts.style, false, opts.ex_style)?;
+		let ex_style = match opts.opacity {
+			Some(_) => opts.ex_style | co::WS_EX::LAYERED,
+			None => opts.ex_style,
+		};
+
+		wnd_rc = AdjustWindowRectEx(wnd_rc, opts.style, false, ex_style)?;
 		wnd_sz.cx = wnd_rc.right - wnd_rc.left;
 		wnd_sz.cy = wnd_rc.bottom - wnd_rc.top;
 
@@ -91,12 +98,22 @@ impl RawModal {
 			Some(&opts.title),
 			IdMenu::None,
 			wnd_pos, wnd_sz,
-			opts.ex_style, opts.style,
+			ex_style, opts.style,
 		)?;
 
+		if let Some(opacity) = opts.opacity {
+			self.base().hwnd().SetLayeredWindowAttributes(
+				COLORREF::default(), opacity, co::LWA::ALPHA)?;
+		}
+
 		self.run_modal_loop()
 	}
 
+	pub(in crate::gui) fn end_modal(&self, result: i32) -> SysResult<()> {
+		*unsafe { &mut *self.0.modal_result.get() } = result;
+		self.close()
+	}
+
 	fn run_modal_loop(&self) -> AnyResult<i32> {
 		loop {
 			let mut msg = MSG::default();
@@ -107,11 +124,11 @@ impl RawModal {
 				// https://devblogs.microsoft.com/oldnewthing/20050222-00/?p=36393
 				// https://stackoverflow.com/a/29359913/6923555
 				PostQuitMessage(msg.wParam as _);
-				return Ok(0); // raw modals will always return 0
+				return Ok(0);
 			}
 
 			if *self.base().hwnd() == HWND::NULL || !self.base().hwnd().IsWindow() {
-				return Ok(0); // our modal was destroyed, terminate loop
+				return Ok(self.modal_result()); // our modal was destroyed, terminate loop
 			}
 
 			// If a child window, will retrieve its top-level parent.
@@ -123,7 +140,7 @@ impl RawModal {
 			if hwnd_top_level.IsDialogMessage(&mut msg) {
 				// Processed all keyboard actions for child controls.
 				if *self.base().hwnd() == HWND::NULL {
-					return Ok(0); // our modal was destroyed, terminate loop
+					return Ok(self.modal_result()); // our modal was destroyed, terminate loop
 				} else {
 					continue;
 				}
@@ -133,9 +150,26 @@ impl RawModal {
 			unsafe { DispatchMessage(&msg); }
 
 			if *self.base().hwnd() == HWND::NULL || !self.base().hwnd().IsWindow() {
-				return Ok(0); // our modal was destroyed, terminate loop
+				return Ok(self.modal_result()); // our modal was destroyed, terminate loop
+			}
+		}
+	}
+
+	#[must_use]
+	fn modal_result(&self) -> i32 {
+		*unsafe { &*self.0.modal_result.get() }
+	}
+
+	fn close(&self) -> SysResult<()> {
+		if let Ok(hparent) = self.base().hwnd().GetWindow(co::GW::OWNER) {
+			hparent.EnableWindow(true); // re-enable parent
+			self.base().hwnd().DestroyWindow()?; // then destroy modal
+			let hchild_prev_focus_parent = unsafe { &mut *self.0.hchild_prev_focus_parent.get() };
+			if *hchild_prev_focus_parent != HWND::NULL {
+				hchild_prev_focus_parent.SetFocus(); // this focus could be set on WM_DESTROY as well
 			}
 		}
+		Ok(())
 	}
 
 	fn default_message_handlers(&self) {
@@ -147,14 +181,7 @@ impl RawModal {
 
 		let self2 = self.clone();
 		self.base().on().wm_close(move || {
-			if let Ok(hparent) = self2.base().hwnd().GetWindow(co::GW::OWNER) {
-				hparent.EnableWindow(true); // re-enable parent
-				self2.base().hwnd().DestroyWindow()?; // then destroy modal
-				let hchild_prev_focus_parent = unsafe { &mut *self2.0.hchild_prev_focus_parent.get() };
-				if *hchild_prev_focus_parent != HWND::NULL {
-					hchild_prev_focus_parent.SetFocus(); // this focus could be set on WM_DESTROY as well
-				}
-			}
+			self2.close()?;
 			Ok(())
 		});
 	}
@@ -218,6 +245,15 @@ pub struct WindowModalOpts {
 	///
 	/// Defaults to `WS_EX::LEFT | WS_EX::DLGMODALFRAME`.
 	pub ex_style: co::WS_EX,
+	/// Opacity of the whole window, from `0` (transparent) to `255` (opaque),
+	/// set with
+	/// [`HWND::SetLayeredWindowAttributes`](crate::prelude::user_Hwnd::SetLayeredWindowAttributes)
+	/// after the window is created. If set, the
+	/// [`WS_EX::LAYERED`](crate::co::WS_EX::LAYERED) style is added
+	/// automatically.
+	///
+	/// Defaults to `None`.
+	pub opacity: Option<u8>,
 }
 
 impl Default for WindowModalOpts {
@@ -232,6 +268,7 @@ impl Default for WindowModalOpts {
 			size: (500, 400),
 			style: co::WS::CAPTION | co::WS::SYSMENU | co::WS::CLIPCHILDREN | co::WS::BORDER | co::WS::VISIBLE,
 			ex_style: co::WS_EX::LEFT | co::WS_EX::DLGMODALFRAME,
+			opacity: None,
 		}
 	}
 }
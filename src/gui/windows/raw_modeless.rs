@@ -67,14 +67,24 @@ impl RawModeless {
 			let mut wnd_sz = SIZE::new(opts.size.0 as _, opts.size.1 as _);
 			multiply_dpi_or_dtu(parent_base_ref, None, Some(&mut wnd_sz))?;
 
+			let ex_style = match opts.opacity {
+				Some(_) => opts.ex_style | co::WS_EX::LAYERED,
+				None => opts.ex_style,
+			};
+
 			self2.0.raw_base.create_window(
 				Some(parent_base_ref.hwnd()),
 				atom,
 				Some(&opts.title),
 				IdMenu::Menu(&HMENU::NULL),
 				wnd_pos, wnd_sz,
-				opts.ex_style, opts.style,
+				ex_style, opts.style,
 			)?;
+
+			if let Some(opacity) = opts.opacity {
+				self2.base().hwnd().SetLayeredWindowAttributes(
+					COLORREF::default(), opacity, co::LWA::ALPHA)?;
+			}
 			Ok(WmRet::NotHandled)
 		});
 	}
@@ -148,6 +158,15 @@ pub struct WindowModelessOpts {
 	///
 	/// Defaults to `WS_EX::LEFT | WS_EX::TOOLWINDOW`.
 	pub ex_style: co::WS_EX,
+	/// Opacity of the whole window, from `0` (transparent) to `255` (opaque),
+	/// set with
+	/// [`HWND::SetLayeredWindowAttributes`](crate::prelude::user_Hwnd::SetLayeredWindowAttributes)
+	/// after the window is created. If set, the
+	/// [`WS_EX::LAYERED`](crate::co::WS_EX::LAYERED) style is added
+	/// automatically.
+	///
+	/// Defaults to `None`.
+	pub opacity: Option<u8>,
 }
 
 impl Default for WindowModelessOpts {
@@ -163,6 +182,7 @@ impl Default for WindowModelessOpts {
 			size: (220, 150),
 			style: co::WS::CAPTION | co::WS::SYSMENU | co::WS::CLIPCHILDREN | co::WS::BORDER | co::WS::VISIBLE,
 			ex_style: co::WS_EX::LEFT | co::WS_EX::TOOLWINDOW,
+			opacity: None,
 		}
 	}
 }
@@ -2,6 +2,7 @@ use std::any::Any;
 
 use crate::co;
 use crate::decl::*;
+use crate::guard::*;
 use crate::gui::{*, privs::*};
 use crate::kernel::ffi_types::*;
 use crate::prelude::*;
@@ -74,6 +75,126 @@ impl WindowMain {
 		)
 	}
 
+	/// Replaces the accelerator table used by the main application loop,
+	/// destroying the previous one. Passing `None` removes it.
+	///
+	/// This method can be called at any time, including within an event
+	/// closure – e.g., after the user edits the keyboard shortcuts. For a
+	/// dialog window, if called before
+	/// [`run_main`](crate::gui::WindowMain::run_main), the table loaded from
+	/// the resource is ignored.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// const ID_SAVE: u16 = 1001;
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let haccel = w::HACCEL::CreateAcceleratorTable(&[
+	///     w::ACCEL::parse("Ctrl+S", ID_SAVE).unwrap(),
+	/// ])?;
+	/// wnd.set_accel_table(Some(haccel));
+	/// # w::SysResult::Ok(())
+	/// ```
+	pub fn set_accel_table(&self,
+		accel_table: Option<DestroyAcceleratorTableGuard>,
+	) {
+		match &self.0 {
+			RawDlg::Raw(r) => r.set_accel_table(accel_table),
+			RawDlg::Dlg(d) => d.set_accel_table(accel_table),
+		}
+	}
+
+	/// Sets the exit code to be passed to
+	/// [`PostQuitMessage`](crate::PostQuitMessage) when the window is
+	/// destroyed, which is then returned by
+	/// [`run_main`](crate::gui::WindowMain::run_main). Defaults to zero.
+	///
+	/// This method can be called at any time before the window is destroyed,
+	/// including within an event closure.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let wnd2 = wnd.clone();
+	/// wnd.on().wm_close(move || {
+	///     wnd2.set_exit_code(3);
+	///     wnd2.hwnd().DestroyWindow()?;
+	///     Ok(())
+	/// });
+	///
+	/// let exit_code = wnd.run_main(None)?;
+	/// std::process::exit(exit_code);
+	/// # w::AnyResult::Ok(())
+	/// ```
+	pub fn set_exit_code(&self, exit_code: i32) {
+		match &self.0 {
+			RawDlg::Raw(r) => r.set_exit_code(exit_code),
+			RawDlg::Dlg(d) => d.set_exit_code(exit_code),
+		}
+	}
+
+	/// Returns the identifier of the thread which created this window, which
+	/// is the thread running the main application loop.
+	///
+	/// Application messages – from [`WM::APP`](crate::co::WM::APP) to `0xbfff`
+	/// – posted to this thread with
+	/// [`PostThreadMessage`](crate::PostThreadMessage) are delivered to this
+	/// window, so they can be handled with
+	/// [`wm`](crate::gui::events::WindowEvents::wm) events. Other thread
+	/// messages are dispatched untouched.
+	///
+	/// The thread message queue is created along with the `WindowMain` object,
+	/// so a worker thread can post messages even before the window is
+	/// created; they are kept in the queue until the main loop starts. Note,
+	/// however, that thread messages are lost while a modal loop – like a
+	/// message box or a menu – is running.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui, msg};
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	///
+	/// let WM_WORKER_DONE = unsafe { co::WM::from_raw(co::WM::APP.raw() + 1) };
+	///
+	/// wnd.on().wm(
+	///     WM_WORKER_DONE,
+	///     move |p: msg::WndMsg| -> w::AnyResult<gui::WmRet> {
+	///         println!("Worker finished with {}", p.wparam);
+	///         Ok(gui::WmRet::HandledOk)
+	///     },
+	/// );
+	///
+	/// let ui_thread_id = wnd.thread_id();
+	/// std::thread::spawn(move || {
+	///     unsafe {
+	///         w::PostThreadMessage(ui_thread_id, msg::WndMsg {
+	///             msg_id: WM_WORKER_DONE,
+	///             wparam: 42,
+	///             lparam: 0,
+	///         })
+	///     }.unwrap();
+	/// });
+	/// ```
+	#[must_use]
+	pub fn thread_id(&self) -> u32 {
+		match &self.0 {
+			RawDlg::Raw(r) => r.thread_id(),
+			RawDlg::Dlg(d) => d.thread_id(),
+		}
+	}
+
 	/// Physically creates the window, then runs the main application loop. This
 	/// method will block until the window is closed.
 	///
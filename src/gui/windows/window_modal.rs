@@ -66,29 +66,120 @@ impl WindowModal {
 		)
 	}
 
+	/// Closes the modal, making
+	/// [`show_modal`](crate::gui::WindowModal::show_modal) return `result`.
+	///
+	/// For a modal created with
+	/// [`WindowModal::new_dlg`](crate::gui::WindowModal::new_dlg), calls
+	/// [`HWND::EndDialog`](crate::prelude::user_Hwnd::EndDialog).
+	///
+	/// # Examples
+	///
+	/// An OK button which closes the modal with a user-defined result:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, gui};
+	///
+	/// #[derive(Clone, Copy)]
+	/// enum Choice { Cancel, Ok }
+	///
+	/// impl From<Choice> for i32 {
+	///     fn from(v: Choice) -> Self { v as _ }
+	/// }
+	///
+	/// impl From<i32> for Choice {
+	///     fn from(v: i32) -> Self {
+	///         if v == Choice::Ok as _ { Choice::Ok } else { Choice::Cancel }
+	///     }
+	/// }
+	///
+	/// let wnd_modal: gui::WindowModal; // initialized somewhere
+	/// let btn_ok: gui::Button;
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let wnd_modal = gui::WindowModal::new_dlg(&wnd, 200);
+	/// # let btn_ok = gui::Button::new(&wnd_modal, gui::ButtonOpts::default());
+	///
+	/// let wnd_modal2 = wnd_modal.clone();
+	/// btn_ok.on().bn_clicked(move || {
+	///     wnd_modal2.end_modal(Choice::Ok)?;
+	///     Ok(())
+	/// });
+	///
+	/// let choice: Choice = wnd_modal.show_modal_as()?;
+	/// # w::AnyResult::Ok(())
+	/// ```
+	pub fn end_modal(&self, result: impl Into<i32>) -> SysResult<()> {
+		match &self.0 {
+			RawDlg::Raw(r) => r.end_modal(result.into()),
+			RawDlg::Dlg(d) => d.end_modal(result.into()),
+		}
+	}
+
 	/// Physically creates the window, then runs the modal loop. This method
 	/// will block until the window is closed.
 	///
-	/// For a modal created with
-	/// [`WindowModal::new`](crate::gui::WindowModal::new), the returned `i32`
-	/// is always zero.
+	/// The returned `i32` is the value passed to
+	/// [`WindowModal::end_modal`](crate::gui::WindowModal::end_modal), or
+	/// zero if the modal was closed otherwise.
 	///
 	/// For a modal created with
-	/// [`WindowModal::new_dlg`](crate::gui::WindowModal::new_dlg), the returned
-	/// `i32` is the value passed to
+	/// [`WindowModal::new_dlg`](crate::gui::WindowModal::new_dlg), it can also
+	/// be the value passed to
 	/// [`HWND::EndDialog`](crate::prelude::user_Hwnd::EndDialog). Note that, if
 	/// the user clicks the "X" to close the modal, the default behavior is to
 	/// call `EndDialog(0)`. To override this behavior, handle the
 	/// modal's [`wm_close`](crate::gui::events::WindowEvents::wm_close)
 	/// yourself.
 	///
+	/// While the modal is shown, the parent window is disabled, so this is the
+	/// window type for settings and about dialogs.
+	///
 	/// # Panics
 	///
 	/// Panics if the window is already created.
+	///
+	/// # Examples
+	///
+	/// A dialog resource shown as a modal of the main window, when a button is
+	/// clicked:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, gui};
+	///
+	/// const DLG_SETTINGS: u16 = 200;
+	///
+	/// let wnd: gui::WindowMain; // initialized somewhere
+	/// let btn: gui::Button;
+	/// # let wnd = gui::WindowMain::new(gui::WindowMainOpts::default());
+	/// # let btn = gui::Button::new(&wnd, gui::ButtonOpts::default());
+	///
+	/// let wnd2 = wnd.clone();
+	/// btn.on().bn_clicked(move || {
+	///     let settings = gui::WindowModal::new_dlg(&wnd2, DLG_SETTINGS);
+	///     let ret = settings.show_modal()?; // blocks until EndDialog()
+	///     if ret == co::DLGID::OK.raw() as i32 {
+	///         println!("Settings saved.");
+	///     }
+	///     Ok(())
+	/// });
+	/// ```
 	pub fn show_modal(&self) -> AnyResult<i32> {
 		match &self.0 {
 			RawDlg::Raw(r) => r.show_modal(),
 			RawDlg::Dlg(d) => d.show_modal(),
 		}
 	}
+
+	/// Calls [`WindowModal::show_modal`](crate::gui::WindowModal::show_modal),
+	/// converting the returned value into a user-defined type, usually an
+	/// enum.
+	///
+	/// # Panics
+	///
+	/// Panics if the window is already created.
+	pub fn show_modal_as<T>(&self) -> AnyResult<T>
+		where T: From<i32>,
+	{
+		self.show_modal().map(|ret| T::from(ret))
+	}
 }
@@ -79,21 +79,10 @@ pub trait NativeStrConst: PartialEq + Eq
 pub trait FormattedError: Into<u32> {
 	/// Returns the textual description of the system error, by calling
 	/// [`FormatMessage`](crate::FormatMessage).
-	/// function.
 	#[must_use]
 	fn FormatMessage(self) -> String {
 		let err_code: u32 = self.into();
-		match unsafe {
-			FormatMessage(
-				co::FORMAT_MESSAGE::ALLOCATE_BUFFER
-					| co::FORMAT_MESSAGE::FROM_SYSTEM
-					| co::FORMAT_MESSAGE::IGNORE_INSERTS,
-				None,
-				err_code,
-				LANGID::USER_DEFAULT,
-				None,
-			)
-		} {
+		match unsafe { FormatMessage(FormatMsgSrc::System, err_code, None, None) } {
 			Err(err_fmt) => format!( // never fails, returns a message instead
 				"FormatMessage failed to format error {:#06x}: error {:#06x}.",
 				err_code, err_fmt,
@@ -102,3 +91,53 @@ pub trait FormattedError: Into<u32> {
 		}
 	}
 }
+
+/// Adds a message to the error of a [`Result`](std::result::Result), like a
+/// [`SysResult`](crate::SysResult) or an `HrResult`, turning it into a
+/// [`ContextError`](crate::ContextError).
+///
+/// This is useful to tell which one of several calls failed, since the error
+/// codes alone don't carry this information.
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// fn read_config(path: &str) -> w::AnyResult<String> {
+///     let f = w::File::open(path, w::FileAccess::ExistingReadOnly)
+///         .with_context(|| format!("Opening {}", path))?;
+///     let bytes = f.read_all()
+///         .context("Reading config")?;
+///     Ok(w::WString::parse(&bytes)?.to_string())
+/// }
+/// ```
+pub trait ResultContext<T, E: FormattedError> {
+	/// Wraps the error with the given message.
+	fn context(self, context: &str) -> Result<T, ContextError<E>>;
+
+	/// Wraps the error with the message returned by the closure, which is
+	/// called only if there is an error.
+	fn with_context<F, S>(self, func: F) -> Result<T, ContextError<E>>
+		where F: FnOnce() -> S,
+			S: Into<String>;
+}
+
+impl<T, E: FormattedError> ResultContext<T, E> for Result<T, E> {
+	fn context(self, context: &str) -> Result<T, ContextError<E>> {
+		self.map_err(|err| ContextError::new(err, context))
+	}
+
+	fn with_context<F, S>(self, func: F) -> Result<T, ContextError<E>>
+		where F: FnOnce() -> S,
+			S: Into<String>,
+	{
+		self.map_err(|err| ContextError::new(err, func()))
+	}
+}
@@ -42,6 +42,23 @@ const_ordinary! { CLAIM_SECURITY_ATTRIBUTE_TYPE: u16;
 	OCTET_STRING 0x0010
 }
 
+const_ordinary! { COMPUTER_NAME_FORMAT: u32;
+	/// [`COMPUTER_NAME_FORMAT`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/ne-sysinfoapi-computer_name_format)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `ComputerName` prefix.
+	=>
+	=>
+	NetBIOS 0
+	DnsHostname 1
+	DnsDomain 2
+	DnsFullyQualified 3
+	PhysicalNetBIOS 4
+	PhysicalDnsHostname 5
+	PhysicalDnsDomain 6
+	PhysicalDnsFullyQualified 7
+}
+
 const_bitflag! { CONSOLE: u32;
 	/// [`HSTD::SetConsoleMode`](crate::prelude::kernel_Hstd::SetConsoleMode)
 	/// `mode` (`u32`).
@@ -197,7 +214,9 @@ const_ordinary! { DBT_DEVTYP: u32;
 	=>
 	=>
 	DEVICEINTERFACE 0x0000_0005
+	DEVNODE 0x0000_0001
 	HANDLE 0x0000_0006
+	NET 0x0000_0004
 	OEM 0x0000_0000
 	PORT 0x0000_0003
 	VOLUME 0x0000_0002
@@ -295,6 +314,19 @@ const_bitflag! { EVENT_RIGHTS: u32;
 	MODIFY_STATE 0x0002
 }
 
+const_bitflag! { EXECUTION_STATE: u32;
+	/// [`SetThreadExecutionState`](crate::SetThreadExecutionState) `flags`
+	/// (`u32`).
+	///
+	/// Originally has `ES` prefix.
+	=>
+	=>
+	AWAYMODE_REQUIRED 0x0000_0040
+	CONTINUOUS 0x8000_0000
+	DISPLAY_REQUIRED 0x0000_0002
+	SYSTEM_REQUIRED 0x0000_0001
+}
+
 const_bitflag! { FILE_ATTRIBUTE: u32;
 	/// File
 	/// [attributes](https://learn.microsoft.com/en-us/windows/win32/fileio/file-attribute-constants)
@@ -379,6 +411,21 @@ const_bitflag! { FILE_SHARE: u32;
 	DELETE 0x0000_0004
 }
 
+const_bitflag! { FILE_NOTIFY_CHANGE: u32;
+	/// [`DirectoryWatcher::new`](crate::DirectoryWatcher::new) `filter`
+	/// (`u32`).
+	=>
+	=>
+	FILE_NAME 0x0000_0001
+	DIR_NAME 0x0000_0002
+	ATTRIBUTES 0x0000_0004
+	SIZE 0x0000_0008
+	LAST_WRITE 0x0000_0010
+	LAST_ACCESS 0x0000_0020
+	CREATION 0x0000_0040
+	SECURITY 0x0000_0100
+}
+
 const_bitflag! { FILE_SECURITY: u32;
 	/// [`HFILE::CreateFile`](crate::prelude::kernel_Hfile::CreateFile)
 	/// `security` (`u32`).
@@ -459,6 +506,41 @@ const_bitflag! { FILE_VOL: u32;
 	SUPPORTS_BLOCK_REFCOUNTING 0x0800_0000
 }
 
+const_ordinary! { FINDEX_INFO_LEVELS: u32;
+	/// [`FINDEX_INFO_LEVELS`](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ne-minwinbase-findex_info_levels)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `FindExInfo` prefix.
+	=>
+	=>
+	Standard 0
+	Basic 1
+}
+
+const_ordinary! { FINDEX_SEARCH_OPS: u32;
+	/// [`FINDEX_SEARCH_OPS`](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ne-minwinbase-findex_search_ops)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `FindExSearch` prefix.
+	=>
+	=>
+	NameMatch 0
+	LimitToDirectories 1
+	LimitToDevices 2
+}
+
+const_bitflag! { FIND_FIRST_EX: u32;
+	/// [`HFINDFILE::FindFirstFileEx`](crate::prelude::kernel_Hfindfile::FindFirstFileEx)
+	/// `additional_flags` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	CASE_SENSITIVE 1
+	LARGE_FETCH 2
+	ON_DISK_ENTRIES_ONLY 4
+}
+
 const_ordinary! { FIRMWARE_TYPE: u32;
 	/// [`FIRMWARE_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ne-winnt-firmware_type)
 	/// enumeration (`u32`).
@@ -603,6 +685,89 @@ const_ordinary! { HF32: u32;
 	DEFAULT 1
 }
 
+const_ordinary! { IMAGE_FILE_MACHINE: u16;
+	/// [`IMAGE_FILE_MACHINE`](https://learn.microsoft.com/en-us/windows/win32/sysinfo/image-file-machine-constants)
+	/// constants (`u16`).
+	=>
+	=>
+	UNKNOWN 0
+	TARGET_HOST 0x0001
+	I386 0x014c
+	ARM 0x01c0
+	ARMNT 0x01c4
+	IA64 0x0200
+	AMD64 0x8664
+	ARM64 0xaa64
+}
+
+const_ordinary! { JOBOBJECTINFOCLASS: u32;
+	/// [`HJOB::QueryInformationJobObject`](crate::prelude::kernel_Hjob::QueryInformationJobObject)
+	/// `information_class` (`u32`).
+	///
+	/// Originally has `JobObject` prefix.
+	=>
+	=>
+	BasicAccountingInformation 1
+	BasicLimitInformation 2
+	BasicUIRestrictions 4
+	ExtendedLimitInformation 9
+	CpuRateControlInformation 15
+}
+
+const_bitflag! { JOB_OBJECT_CPU_RATE_CONTROL: u32;
+	/// [`JOBOBJECT_CPU_RATE_CONTROL_INFORMATION`](crate::JOBOBJECT_CPU_RATE_CONTROL_INFORMATION)
+	/// `ControlFlags` (`u32`).
+	=>
+	=>
+	NoValue 0
+	ENABLE 0x1
+	WEIGHT_BASED 0x2
+	HARD_CAP 0x4
+	NOTIFY 0x8
+	MIN_MAX_RATE 0x10
+}
+
+const_bitflag! { JOB_OBJECT_LIMIT: u32;
+	/// [`JOBOBJECT_BASIC_LIMIT_INFORMATION`](crate::JOBOBJECT_BASIC_LIMIT_INFORMATION)
+	/// `LimitFlags` (`u32`).
+	=>
+	=>
+	NoValue 0
+	ACTIVE_PROCESS 0x0000_0008
+	AFFINITY 0x0000_0010
+	BREAKAWAY_OK 0x0000_0800
+	DIE_ON_UNHANDLED_EXCEPTION 0x0000_0400
+	JOB_MEMORY 0x0000_0200
+	JOB_MEMORY_HIGH 0x0000_0200
+	JOB_MEMORY_LOW 0x0000_8000
+	JOB_TIME 0x0000_0004
+	KILL_ON_JOB_CLOSE 0x0000_2000
+	PRESERVE_JOB_TIME 0x0000_0040
+	PRIORITY_CLASS 0x0000_0020
+	PROCESS_MEMORY 0x0000_0100
+	PROCESS_TIME 0x0000_0002
+	SCHEDULING_CLASS 0x0000_0080
+	SILENT_BREAKAWAY_OK 0x0000_1000
+	SUBSET_AFFINITY 0x0000_4000
+	WORKINGSET 0x0000_0001
+}
+
+const_bitflag! { JOB_OBJECT_UILIMIT: u32;
+	/// [`JOBOBJECT_BASIC_UI_RESTRICTIONS`](crate::JOBOBJECT_BASIC_UI_RESTRICTIONS)
+	/// `UIRestrictionsClass` (`u32`).
+	=>
+	=>
+	NoValue 0
+	DESKTOP 0x0000_0040
+	DISPLAYSETTINGS 0x0000_0010
+	EXITWINDOWS 0x0000_0080
+	GLOBALATOMS 0x0000_0020
+	HANDLES 0x0000_0001
+	READCLIPBOARD 0x0000_0002
+	SYSTEMPARAMETERS 0x0000_0008
+	WRITECLIPBOARD 0x0000_0004
+}
+
 const_ordinary! { LANG: u16;
 	/// Language
 	/// [identifier](https://learn.microsoft.com/en-us/windows/win32/intl/language-identifier-constants-and-strings)
@@ -799,6 +964,17 @@ const_ordinary! { MONITOR_DISPLAY_STATE: u32;
 	Dim 2
 }
 
+const_ordinary! { NORM_FORM: u32;
+	/// [`NORM_FORM`](https://learn.microsoft.com/en-us/windows/win32/api/winnls/ne-winnls-norm_form)
+	/// enumeration (`u32`).
+	=>
+	=>
+	NormalizationC 0x1
+	NormalizationD 0x2
+	NormalizationKC 0x5
+	NormalizationKD 0x6
+}
+
 const_ordinary! { PAGE: u32;
 	/// [`HFILE::CreateFileMapping`](crate::prelude::kernel_Hfile::CreateFileMapping)
 	/// `protect` (`u32`).
@@ -867,6 +1043,19 @@ const_ordinary! { PBT: u32;
 	POWERSETTINGCHANGE 0x8013
 }
 
+const_ordinary! { POWER_REQUEST_TYPE: u32;
+	/// [`POWER_REQUEST_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ne-winnt-power_request_type)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `PowerRequest` prefix.
+	=>
+	=>
+	DisplayRequired 0
+	SystemRequired 1
+	AwayModeRequired 2
+	ExecutionRequired 3
+}
+
 const_bitflag! { PRIORITY_CLASS: u32;
 	/// [`GetPriorityClass`](crate::prelude::kernel_Hprocess::GetPriorityClass)
 	/// and
@@ -1179,6 +1368,57 @@ const_bitflag! { SHTDN_REASON: u32; // used by InitiateSystemShutdownEx (advapi)
 	FLAG_PLANNED 0x8000_0000
 }
 
+const_bitflag! { SND: u32;
+	/// [`PlaySound`](crate::PlaySound) `flags` (`u32`).
+	///
+	/// The flags which identify the sound source, like `SND_ALIAS` and
+	/// `SND_FILENAME`, are set automatically according to the
+	/// [`PlaySoundSrc`](crate::PlaySoundSrc) variant.
+	=>
+	=>
+	/// The sound is played synchronously, and the function returns after the
+	/// sound event completes. This is the default behavior.
+	SYNC 0x0000
+	/// The sound is played asynchronously, and the function returns
+	/// immediately after beginning the sound.
+	ASYNC 0x0001
+	/// No default sound event is used. If the sound cannot be found, the
+	/// function returns silently without playing the default sound.
+	NODEFAULT 0x0002
+	/// The sound plays repeatedly until the function is called again with
+	/// [`PlaySoundSrc::Stop`](crate::PlaySoundSrc::Stop). Must be combined with
+	/// [`SND::ASYNC`](crate::co::SND::ASYNC).
+	LOOP 0x0008
+	/// The specified sound event will yield to another sound event that is
+	/// already playing in the same process, and the function immediately
+	/// returns `false` without playing the requested sound.
+	NOSTOP 0x0010
+	/// If the driver is busy, return immediately without playing the sound.
+	NOWAIT 0x0000_2000
+	/// The sound is played using an application-specific association.
+	APPLICATION 0x0080
+	/// Triggers a SoundSentry event when the sound is played.
+	SENTRY 0x0008_0000
+	/// The sound is played as a system notification sound, respecting the
+	/// system volume setting.
+	SYSTEM 0x0020_0000
+}
+
+const_ordinary! { SND_ALIAS: u32;
+	/// Predefined system sound identifiers for
+	/// [`PlaySoundSrc::AliasId`](crate::PlaySoundSrc::AliasId) (`u32`).
+	=>
+	=>
+	SYSTEMASTERISK 0x2a53
+	SYSTEMDEFAULT 0x4453
+	SYSTEMEXCLAMATION 0x2153
+	SYSTEMEXIT 0x4553
+	SYSTEMHAND 0x4853
+	SYSTEMQUESTION 0x3f53
+	SYSTEMSTART 0x5353
+	SYSTEMWELCOME 0x5753
+}
+
 const_ordinary! { SORT: u16;
 	/// Sort order
 	/// [identifiers](https://learn.microsoft.com/en-us/windows/win32/intl/sort-order-identifiers)
@@ -1595,6 +1835,16 @@ const_bitflag! { THREAD_CREATE: u32;
 	STACK_SIZE_PARAM_IS_A_RESERVATION 0x0001_0000
 }
 
+const_ordinary! { TIME: u32;
+	/// [`timeSetEvent`](crate::timeSetEvent) `event_type` (`u32`).
+	=>
+	=>
+	/// Event occurs once, after `delay` milliseconds.
+	ONESHOT 0x0000
+	/// Event occurs every `delay` milliseconds.
+	PERIODIC 0x0001
+}
+
 const_bitflag! { TOKEN: u32;
 	/// [Token access rights](https://learn.microsoft.com/en-us/windows/win32/secauthz/access-rights-for-access-token-objects).
 	=>
@@ -1721,11 +1971,24 @@ const_ordinary! { WAIT: u32;
 	=>
 	=>
 	ABANDONED 0x0000_0080
+	IO_COMPLETION 0x0000_00c0
 	OBJECT_0 0x0000_0000
 	TIMEOUT 0x0000_0102
 	FAILED 0xffff_ffff
 }
 
+const_ordinary! { WAVE_FORMAT: u16;
+	/// [`WAVEFORMATEX`](crate::WAVEFORMATEX) `wFormatTag` (`u16`).
+	=>
+	=>
+	PCM 0x0001
+	ADPCM 0x0002
+	IEEE_FLOAT 0x0003
+	ALAW 0x0006
+	MULAW 0x0007
+	EXTENSIBLE 0xfffe
+}
+
 const_bitflag! { WC: u32;
 	/// [`WideCharToMultiByte`](crate::WideCharToMultiByte) `flags` (`u32`).
 	=>
@@ -28,11 +28,26 @@ const_no_debug_display! { ERROR: u32;
 	/// Will print:
 	///
 	/// ```text
-	/// [0x001c 28] The printer is out of paper.
+	/// [28] The printer is out of paper.
 	/// ```
 	///
-	/// The numbers inside the brackets are the system error code, in
-	/// hexadecimal and decimal formats for convenience.
+	/// The number inside the brackets is the system error code. The `Debug`
+	/// output shows it in both hexadecimal and decimal formats:
+	/// `ERROR(0x001c 28)`.
+	///
+	/// Since it implements `Error`, it can be propagated with the `?` operator
+	/// into a boxed error, like [`AnyResult`](crate::AnyResult), or the error
+	/// types of crates like `anyhow`:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// fn load_text() -> w::AnyResult<String> {
+	///     let f = w::File::open("C:\\Temp\\foo.txt", w::FileAccess::ExistingReadOnly)?;
+	///     let text = w::WString::parse(&f.read_all()?)?.to_string();
+	///     Ok(text)
+	/// }
+	/// ```
 }
 
 impl std::error::Error for ERROR {
@@ -250,6 +265,7 @@ const_values! { ERROR;
 	VC_DISCONNECTED 240
 	INVALID_EA_NAME 254
 	EA_LIST_INCONSISTENT 255
+	WAIT_TIMEOUT 258
 	NO_MORE_ITEMS 259
 	CANNOT_COPY 266
 	DIRECTORY 267
@@ -1,5 +1,23 @@
 #![allow(non_camel_case_types)]
 
+const_guid! { DEVINTERFACE;
+	/// Device interface class
+	/// [identifiers](https://learn.microsoft.com/en-us/windows-hardware/drivers/install/overview-of-device-interface-classes)
+	/// (`GUID`).
+	///
+	/// Originally has `GUID_DEVINTERFACE` prefix.
+	=>
+	COMPORT "86e0d1e0-8089-11d0-9ce4-08003e301f73"
+	DISK "53f56307-b6bf-11d0-94f2-00a0c91efb8b"
+	HID "4d1e55b2-f16f-11cf-88cb-001111000030"
+	KEYBOARD "884b96c3-56ef-11d1-bc8c-00a0c91405dd"
+	MONITOR "e6f07b5f-ee97-4a90-b076-33f57bf4eaa7"
+	MOUSE "378de44c-56ef-11d1-bc8c-00a0c91405dd"
+	USB_DEVICE "a5dcbf10-6530-11d2-901f-00c04fb951ed"
+	USB_HUB "f18a0e88-c30c-11d0-8815-00a0c906bed8"
+	VOLUME "53f5630d-b6bf-11d0-94f2-00a0c91efb8b"
+}
+
 const_guid! { POWER_SAVINGS;
 	/// Power scheme
 	/// [identifiers](https://learn.microsoft.com/en-us/windows/win32/power/power-setting-guids)
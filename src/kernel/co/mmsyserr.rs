@@ -0,0 +1,84 @@
+#![allow(non_upper_case_globals)]
+
+use crate::kernel::ffi;
+
+const_no_debug_display! { MMSYSERR: u32;
+	/// Multimedia
+	/// [error codes](https://learn.microsoft.com/en-us/windows/win32/multimedia/multimedia-error-codes)
+	/// (`u32`), returned by the `waveOut` functions.
+	///
+	/// Also includes `WAVERR` and `TIMERR` prefixes.
+	///
+	/// Implements the standard [`Error`](std::error::Error) trait.
+	///
+	/// Does not implement [`FormattedError`](crate::prelude::FormattedError)
+	/// because [`FormatMessage`](crate::FormatMessage) function does not offer
+	/// support for it. The textual description is taken from
+	/// [`waveOutGetErrorText`](https://learn.microsoft.com/en-us/windows/win32/api/mmeapi/nf-mmeapi-waveoutgeterrortextw)
+	/// instead.
+}
+
+impl std::error::Error for MMSYSERR {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		None
+	}
+}
+
+impl std::fmt::Display for MMSYSERR {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "[{}] {}", self.0, self.error_text())
+	}
+}
+impl std::fmt::Debug for MMSYSERR {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "MMSYSERR({:#06x} {}) {}", self.0, self.0, self.error_text())
+	}
+}
+
+impl MMSYSERR {
+	fn error_text(&self) -> String {
+		let mut buf = [0u16; 256]; // MAXERRORLENGTH
+		match unsafe {
+			ffi::waveOutGetErrorTextW(self.0, buf.as_mut_ptr(), buf.len() as _)
+		} {
+			0 => {
+				let len = buf.iter().position(|ch| *ch == 0).unwrap_or(buf.len());
+				String::from_utf16_lossy(&buf[..len])
+			},
+			_ => "Multimedia error.".to_owned(),
+		}
+	}
+}
+
+const_values! { MMSYSERR;
+	=>
+	/// No error.
+	NOERROR 0
+	ERROR 1
+	BADDEVICEID 2
+	NOTENABLED 3
+	ALLOCATED 4
+	INVALHANDLE 5
+	NODRIVER 6
+	NOMEM 7
+	NOTSUPPORTED 8
+	BADERRNUM 9
+	INVALFLAG 10
+	INVALPARAM 11
+	HANDLEBUSY 12
+	INVALIDALIAS 13
+	BADDB 14
+	KEYNOTFOUND 15
+	READERROR 16
+	WRITEERROR 17
+	DELETEERROR 18
+	VALNOTFOUND 19
+	NODRIVERCB 20
+	MOREDATA 21
+	WAVERR_BADFORMAT 32
+	WAVERR_STILLPLAYING 33
+	WAVERR_UNPREPARED 34
+	WAVERR_SYNC 35
+	TIMERR_NOCANDO 97
+	TIMERR_STRUCT 129
+}
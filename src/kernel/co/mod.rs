@@ -1,7 +1,9 @@
 mod consts;
 mod error;
 mod guids;
+mod mmsyserr;
 
 pub use consts::*;
 pub use error::*;
 pub use guids::*;
+pub use mmsyserr::*;
@@ -13,6 +13,56 @@ pub enum ClaimSecurityAttr<'a> {
 	OctetString(&'a [CLAIM_SECURITY_ATTRIBUTE_OCTET_STRING_VALUE<'a>]),
 }
 
+/// A change reported by [`DirectoryWatcher`](crate::DirectoryWatcher). Each
+/// variant contains the full path of the affected file or directory.
+#[derive(Clone, PartialEq, Eq)]
+pub enum DirChange {
+	/// The file was added to the directory.
+	Added(String),
+	/// The file was removed from the directory.
+	Removed(String),
+	/// The file was modified: its contents, attributes or timestamps,
+	/// according to the filter.
+	Modified(String),
+	/// The file was renamed, and this is the old name. Usually followed by
+	/// `RenamedNew`.
+	RenamedOld(String),
+	/// The file was renamed, and this is the new name.
+	RenamedNew(String),
+	/// Too many changes happened at once, and they could not be recorded. The
+	/// directory must be fully rescanned.
+	Overflow,
+}
+
+/// An insert argument for:
+///
+/// * [`FormatMessage`](crate::FormatMessage).
+///
+/// Each variant must match the format specifier of its insert in the message:
+/// `%n` or `%n!s!` for strings, `%n!u!` or `%n!d!` for `u32`, and `%n!I64u!`
+/// for `u64`.
+pub enum FormatMsgArg<'a> {
+	/// A string, for `%n` or `%n!s!` inserts.
+	Str(&'a str),
+	/// A `u32`, for `%n!u!` or `%n!d!` inserts.
+	U32(u32),
+	/// A `u64`, for `%n!I64u!` inserts.
+	U64(u64),
+}
+
+/// The message source for:
+///
+/// * [`FormatMessage`](crate::FormatMessage).
+pub enum FormatMsgSrc<'a> {
+	/// The system message table, used to format error codes.
+	System,
+	/// The message table resource of a loaded module, like a driver or service
+	/// message DLL.
+	Module(&'a HINSTANCE),
+	/// A message definition string, with `%1`, `%2!d!`, etc. inserts.
+	Str(&'a str),
+}
+
 /// A resource identifier.
 ///
 /// Variable parameter for:
@@ -77,6 +127,43 @@ impl IdStr {
 	}
 }
 
+/// Information class for:
+///
+/// * [`HJOB::QueryInformationJobObject`](crate::prelude::kernel_Hjob::QueryInformationJobObject);
+/// * [`HJOB::SetInformationJobObject`](crate::prelude::kernel_Hjob::SetInformationJobObject).
+pub enum JobObjectInfo {
+	/// Can only be queried.
+	BasicAccounting(JOBOBJECT_BASIC_ACCOUNTING_INFORMATION),
+	/// Basic limits, like the active process count and the processor time.
+	BasicLimit(JOBOBJECT_BASIC_LIMIT_INFORMATION),
+	/// Restrictions on the user interface, like clipboard and desktop access.
+	BasicUiRestrictions(JOBOBJECT_BASIC_UI_RESTRICTIONS),
+	/// Processor usage limit, as a rate or a relative weight.
+	CpuRateControl(JOBOBJECT_CPU_RATE_CONTROL_INFORMATION),
+	/// Basic limits plus memory limits and I/O accounting; can be used to kill
+	/// all processes when the job handle is closed.
+	ExtendedLimit(JOBOBJECT_EXTENDED_LIMIT_INFORMATION),
+}
+
+/// The sound source for:
+///
+/// * [`PlaySound`](crate::PlaySound).
+pub enum PlaySoundSrc<'a> {
+	/// A system event alias, as registered in the Sounds control panel, like
+	/// `"SystemAsterisk"`, `"SystemDefault"`, `"SystemExclamation"`,
+	/// `"SystemExit"`, `"SystemHand"`, `"SystemNotification"`,
+	/// `"SystemQuestion"` or `"SystemStart"`.
+	Alias(&'a str),
+	/// A predefined system sound identifier.
+	AliasId(co::SND_ALIAS),
+	/// Path to a `.wav` file.
+	File(&'a str),
+	/// A `WAVE` resource in the given module.
+	Resource(&'a HINSTANCE, IdStr),
+	/// Stops any waveform sound currently playing.
+	Stop,
+}
+
 /// Variant parameter for:
 ///
 /// * [`POWERBROADCAST_SETTING`](crate::POWERBROADCAST_SETTING).
@@ -171,3 +258,86 @@ impl RtStr {
 		}
 	}
 }
+
+/// Major Windows releases, ordered from the oldest to the newest, so they can
+/// be compared to gate features which require a minimum version.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe as w;
+///
+/// if w::WindowsVersion::current()? >= w::WindowsVersion::Win10 {
+///     // use Windows 10 APIs
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WindowsVersion {
+	/// Versions prior to Windows Vista.
+	Older,
+	/// Windows Vista, 6.0.
+	Vista,
+	/// Windows 7, 6.1.
+	Win7,
+	/// Windows 8, 6.2.
+	Win8,
+	/// Windows 8.1, 6.3.
+	Win8_1,
+	/// Windows 10, 10.0 with build number prior to 22000.
+	Win10,
+	/// Windows 11, 10.0 with build number 22000 or greater.
+	Win11,
+}
+
+impl WindowsVersion {
+	/// Returns the release of the running operating system, retrieved with
+	/// [`RtlGetVersion`](crate::RtlGetVersion), therefore not affected by the
+	/// application manifest.
+	#[must_use]
+	pub fn current() -> SysResult<Self> {
+		RtlGetVersion().map(|osvi| Self::from_version(
+			osvi.dwMajorVersion, osvi.dwMinorVersion, osvi.dwBuildNumber))
+	}
+
+	/// Returns the release which corresponds to the given version numbers.
+	#[must_use]
+	pub const fn from_version(major: u32, minor: u32, build: u32) -> Self {
+		match (major, minor) {
+			(0..=5, _) => Self::Older,
+			(6, 0) => Self::Vista,
+			(6, 1) => Self::Win7,
+			(6, 2) => Self::Win8,
+			(6, _) => Self::Win8_1,
+			_ => if build >= 22000 { Self::Win11 } else { Self::Win10 },
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn windows_version_is_monotonic() {
+		let versions = [
+			(5, 1, 2600),
+			(6, 0, 6000),
+			(6, 1, 7600),
+			(6, 2, 9200),
+			(6, 3, 9600),
+			(10, 0, 10240),
+			(10, 0, 19045),
+			(10, 0, 22000),
+			(10, 0, 26100),
+		];
+		let releases = versions.iter()
+			.map(|&(major, minor, build)|
+				WindowsVersion::from_version(major, minor, build))
+			.collect::<Vec<_>>();
+		assert!(releases.windows(2).all(|w| w[0] <= w[1]));
+		assert_eq!(releases[0], WindowsVersion::Older);
+		assert_eq!(releases[5], WindowsVersion::Win10);
+		assert_eq!(releases[7], WindowsVersion::Win11);
+	}
+}
@@ -1,7 +1,9 @@
 use crate::kernel::ffi_types::*;
 
 extern_sys! { "kernel32";
+	AssignProcessToJobObject(HANDLE, HANDLE) -> BOOL
 	BeginUpdateResourceW(PCSTR, BOOL) -> HANDLE
+	CancelIoEx(HANDLE, PVOID) -> BOOL
 	CheckRemoteDebuggerPresent(HANDLE, *mut BOOL) -> BOOL
 	CloseHandle(HANDLE) -> BOOL
 	CopyFileW(PCSTR, PCSTR, BOOL) -> BOOL
@@ -10,10 +12,12 @@ extern_sys! { "kernel32";
 	CreateEventW(PCVOID, BOOL, BOOL, PCSTR) -> HANDLE
 	CreateFileMappingFromApp(HANDLE, PVOID, u32, u64, PCSTR) -> HANDLE
 	CreateFileW(PCSTR, u32, u32, PVOID, u32, u32, HANDLE) -> HANDLE
+	CreateJobObjectW(PCVOID, PCSTR) -> HANDLE
 	CreatePipe(*mut HANDLE, *mut HANDLE, PVOID, u32) -> BOOL
 	CreateProcessW(PCSTR, PSTR, PVOID, PVOID, BOOL, u32, PVOID, PCSTR, PVOID, PVOID) -> BOOL
 	CreateThread(PVOID, usize, PVOID, PVOID, u32, *mut u32) -> HANDLE
 	CreateToolhelp32Snapshot(u32, u32) -> HANDLE
+	DebugBreak()
 	DeleteFileW(PCSTR) -> BOOL
 	EndUpdateResourceW(HANDLE, BOOL) -> BOOL
 	EnumResourceLanguagesW(HANDLE, PCSTR, PCSTR, PFUNC, isize) -> BOOL
@@ -24,10 +28,14 @@ extern_sys! { "kernel32";
 	ExpandEnvironmentStringsW(PCSTR, PSTR, u32) -> u32
 	FileTimeToSystemTime(PCVOID, PVOID) -> BOOL
 	FindClose(HANDLE) -> BOOL
+	FindFirstFileExW(PCSTR, u32, PVOID, u32, PVOID, u32) -> HANDLE
 	FindFirstFileW(PCSTR, PVOID) -> HANDLE
+	FindFirstVolumeW(PSTR, u32) -> HANDLE
 	FindNextFileW(HANDLE, PVOID) -> BOOL
+	FindNextVolumeW(HANDLE, PSTR, u32) -> BOOL
 	FindResourceExW(HANDLE, PCSTR, PCSTR, u16) -> HANDLE
 	FindResourceW(HANDLE, PCSTR, PCSTR) -> HANDLE
+	FindVolumeClose(HANDLE) -> BOOL
 	FlushConsoleInputBuffer(HANDLE) -> BOOL
 	FlushInstructionCache(HANDLE, PCVOID, usize) -> BOOL
 	FlushProcessWriteBuffers()
@@ -37,6 +45,7 @@ extern_sys! { "kernel32";
 	FreeLibrary(HANDLE) -> BOOL
 	GetBinaryTypeW(PCSTR, *mut u32) -> BOOL
 	GetCommandLineW() -> PCSTR
+	GetComputerNameExW(u32, PSTR, *mut u32) -> BOOL
 	GetComputerNameW(PSTR, *mut u32) -> BOOL
 	GetConsoleMode(HANDLE, *mut u32) -> BOOL
 	GetCurrentDirectoryW(u32, PSTR) -> u32
@@ -48,6 +57,7 @@ extern_sys! { "kernel32";
 	GetDiskSpaceInformationW(PCSTR, PVOID) -> u32
 	GetDriveTypeW(PCSTR) -> u32
 	GetEnvironmentStringsW() -> *mut u16
+	GetEnvironmentVariableW(PCSTR, PSTR, u32) -> u32
 	GetExitCodeProcess(HANDLE, *mut u32) -> BOOL
 	GetExitCodeThread(HANDLE, *mut u32) -> BOOL
 	GetFileAttributesW(PCSTR) -> u32
@@ -56,15 +66,19 @@ extern_sys! { "kernel32";
 	GetFileTime(HANDLE, PVOID, PVOID, PVOID) -> BOOL
 	GetFileType(HANDLE) -> u32
 	GetFirmwareType(*mut u32) -> BOOL
-	GetGuiResources(HANDLE, u32) -> u32
+	GetFullPathNameW(PCSTR, u32, PSTR, *mut PSTR) -> u32
 	GetLargePageMinimum() -> usize
 	GetLastError() -> u32
 	GetLocalTime(PVOID)
 	GetLogicalDrives() -> u32
 	GetLogicalDriveStringsW(u32, PSTR) -> u32
+	GetLongPathNameW(PCSTR, PSTR, u32) -> u32
 	GetModuleFileNameW(HANDLE, PSTR, u32) -> u32
 	GetModuleHandleW(PCSTR) -> HANDLE
 	GetNativeSystemInfo(PVOID)
+	GetOverlappedResult(HANDLE, PVOID, *mut u32, BOOL) -> BOOL
+	GetOverlappedResultEx(HANDLE, PVOID, *mut u32, u32, BOOL) -> BOOL
+	GetPhysicallyInstalledSystemMemory(*mut u64) -> BOOL
 	GetPriorityClass(HANDLE) -> u32
 	GetPrivateProfileSectionNamesW(PSTR, u32, PCSTR) -> u32
 	GetPrivateProfileSectionW(PCSTR, PSTR, u32, PCSTR) -> u32
@@ -76,11 +90,13 @@ extern_sys! { "kernel32";
 	GetProcessId(HANDLE) -> u32
 	GetProcessIdOfThread(HANDLE) -> u32
 	GetProcessTimes(HANDLE, PVOID, PVOID, PVOID, PVOID) -> BOOL
+	GetShortPathNameW(PCSTR, PSTR, u32) -> u32
 	GetStartupInfoW(PVOID)
 	GetStdHandle(u32) -> HANDLE
 	GetSystemDirectoryW(PSTR, u32) -> u32
 	GetSystemFileCacheSize(*mut usize, *mut usize, *mut u32) -> BOOL
 	GetSystemInfo(PVOID)
+	GetSystemPowerStatus(PVOID) -> BOOL
 	GetSystemTime(PVOID)
 	GetSystemTimeAsFileTime(PVOID)
 	GetSystemTimePreciseAsFileTime(PVOID)
@@ -92,6 +108,7 @@ extern_sys! { "kernel32";
 	GetTickCount64() -> u64
 	GetVolumeInformationW(PCSTR, PSTR, u32, *mut u32, *mut u32, *mut u32, PSTR, u32) -> BOOL
 	GetVolumePathNameW(PCSTR, PSTR, u32) -> BOOL
+	GetVolumePathNamesForVolumeNameW(PCSTR, PSTR, u32, *mut u32) -> BOOL
 	GlobalAlloc(u32, usize) -> HANDLE
 	GlobalFlags(HANDLE) -> u32
 	GlobalFree(HANDLE) -> HANDLE
@@ -116,7 +133,9 @@ extern_sys! { "kernel32";
 	HeapWalk(HANDLE, PVOID) -> BOOL
 	IsDebuggerPresent() -> BOOL
 	IsNativeVhdBoot(*mut BOOL) -> BOOL
+	IsNormalizedString(u32, PCSTR, i32) -> BOOL
 	IsProcessCritical(HANDLE, *mut BOOL) -> BOOL
+	IsProcessInJob(HANDLE, HANDLE, *mut BOOL) -> BOOL
 	IsWow64Process(HANDLE, *mut BOOL) -> BOOL
 	LoadLibraryW(PCSTR) -> HANDLE
 	LoadResource(HANDLE, HANDLE) -> HANDLE
@@ -137,13 +156,19 @@ extern_sys! { "kernel32";
 	MoveFileW(PCSTR, PCSTR) -> BOOL
 	MulDiv(i32, i32, i32) -> i32
 	MultiByteToWideChar(u32, u32, *const u8, i32, PSTR, i32) -> i32
+	NormalizeString(u32, PCSTR, i32, PSTR, i32) -> i32
 	OpenEventW(u32, BOOL, PCSTR) -> HANDLE
 	OpenProcess(u32, BOOL, u32) -> HANDLE
 	OutputDebugStringW(PCSTR)
+	PowerClearRequest(HANDLE, u32) -> BOOL
+	PowerCreateRequest(PCVOID) -> HANDLE
+	PowerSetRequest(HANDLE, u32) -> BOOL
 	Process32FirstW(HANDLE, PVOID) -> BOOL
 	Process32NextW(HANDLE, PVOID) -> BOOL
 	PulseEvent(HANDLE) -> BOOL
+	QueryDosDeviceW(PCSTR, PSTR, u32) -> u32
 	QueryFullProcessImageNameW(HANDLE, u32, PSTR, *mut u32) -> BOOL
+	QueryInformationJobObject(HANDLE, u32, PVOID, u32, *mut u32) -> BOOL
 	QueryPerformanceCounter(*mut i64) -> BOOL
 	QueryPerformanceFrequency(*mut i64) -> BOOL
 	QueryProcessAffinityUpdateMode(HANDLE, *mut u32) -> BOOL
@@ -151,27 +176,35 @@ extern_sys! { "kernel32";
 	QueryThreadCycleTime(HANDLE, &mut u64) -> BOOL
 	QueryUnbiasedInterruptTime(&mut u64) -> BOOL
 	ReadConsoleW(HANDLE, PVOID, u32, *mut u32, PVOID) -> BOOL
+	ReadDirectoryChangesW(HANDLE, PVOID, u32, BOOL, u32, *mut u32, PVOID, PVOID) -> BOOL
 	ReadFile(HANDLE, PVOID, u32, *mut u32, PVOID) -> BOOL
+	ReadFileEx(HANDLE, PVOID, u32, PVOID, PFUNC) -> BOOL
+	RemoveDirectoryW(PCSTR) -> BOOL
 	ReplaceFileW(PCSTR, PCSTR, PCSTR, u32, PVOID, PVOID) -> BOOL
 	ResetEvent(HANDLE) -> BOOL
 	ResumeThread(HANDLE) -> u32
+	SearchPathW(PCSTR, PCSTR, PCSTR, u32, PSTR, *mut PSTR) -> u32
 	SetConsoleMode(HANDLE, u32) -> BOOL
 	SetCurrentDirectoryW(PCSTR) -> BOOL
 	SetEndOfFile(HANDLE) -> BOOL
+	SetEnvironmentVariableW(PCSTR, PCSTR) -> BOOL
 	SetEvent(HANDLE) -> BOOL
 	SetFileAttributesW(PCSTR, u32) -> BOOL
 	SetFilePointerEx(HANDLE, i64, *mut i64, u32) -> BOOL
 	SetFileTime(HANDLE, PCVOID, PCVOID, PCVOID) -> BOOL
+	SetInformationJobObject(HANDLE, u32, PCVOID, u32) -> BOOL
 	SetLastError(u32)
 	SetPriorityClass(HANDLE, u32) -> BOOL
 	SetProcessAffinityUpdateMode(HANDLE, u32) -> BOOL
 	SetProcessPriorityBoost(HANDLE, BOOL) -> BOOL
+	SetThreadExecutionState(u32) -> u32
 	SetThreadIdealProcessor(HANDLE, u32) -> u32
 	SetThreadIdealProcessorEx(HANDLE, PCVOID, PVOID) -> BOOL
 	SetThreadPriorityBoost(HANDLE, BOOL) -> BOOL
 	SetThreadStackGuarantee(*mut u32) -> BOOL
 	SizeofResource(HANDLE, HANDLE) -> u32
 	Sleep(u32)
+	SleepEx(u32, BOOL) -> u32
 	SuspendThread(HANDLE) -> u32
 	SwitchToThread() -> BOOL
 	SystemTimeToFileTime(PCVOID, PVOID) -> BOOL
@@ -185,14 +218,44 @@ extern_sys! { "kernel32";
 	UpdateResourceW(HANDLE, PCSTR, PCSTR, u16, PVOID, u32) -> BOOL
 	VerifyVersionInfoW(PVOID, u32, u64) -> BOOL
 	VerSetConditionMask(u64, u32, u8) -> u64
+	WaitForMultipleObjects(u32, *const HANDLE, BOOL, u32) -> u32
 	WaitForSingleObject(HANDLE, u32) -> u32
+	WaitForSingleObjectEx(HANDLE, u32, BOOL) -> u32
 	WideCharToMultiByte(u32, u32, PCSTR, i32, PSTR, i32, *const u8, *mut BOOL) -> i32
 	WriteConsoleW(HANDLE, PCVOID, u32, *mut u32, PVOID) -> BOOL
 	WriteFile(HANDLE, PCVOID, u32, *mut u32, PVOID) -> BOOL
+	WriteFileEx(HANDLE, PCVOID, u32, PVOID, PFUNC) -> BOOL
 	WritePrivateProfileStringW(PCSTR, PCSTR, PCSTR, PCSTR) -> BOOL
+	WTSGetActiveConsoleSessionId() -> u32
+}
+
+extern_sys! { "psapi";
+	GetProcessMemoryInfo(HANDLE, PVOID, u32) -> BOOL
 }
 
 extern_sys! { "user32"; // these functions should belong to kernel
 	CharLowerW(PSTR) -> PSTR
 	CharUpperW(PSTR) -> PSTR
+	GetGuiResources(HANDLE, u32) -> u32
+}
+
+extern_sys! { "winmm"; // always present, and only imported if a function is actually used
+	PlaySoundW(PCSTR, HANDLE, u32) -> BOOL
+	timeBeginPeriod(u32) -> u32
+	timeEndPeriod(u32) -> u32
+	timeGetDevCaps(PVOID, u32) -> u32
+	timeKillEvent(u32) -> u32
+	timeSetEvent(u32, u32, PFUNC, usize, u32) -> u32
+	waveOutClose(HANDLE) -> u32
+	waveOutGetErrorTextW(u32, PSTR, u32) -> u32
+	waveOutGetNumDevs() -> u32
+	waveOutGetVolume(HANDLE, *mut u32) -> u32
+	waveOutOpen(*mut HANDLE, u32, PCVOID, usize, usize, u32) -> u32
+	waveOutPause(HANDLE) -> u32
+	waveOutPrepareHeader(HANDLE, PVOID, u32) -> u32
+	waveOutReset(HANDLE) -> u32
+	waveOutRestart(HANDLE) -> u32
+	waveOutSetVolume(HANDLE, u32) -> u32
+	waveOutUnprepareHeader(HANDLE, PVOID, u32) -> u32
+	waveOutWrite(HANDLE, PVOID, u32) -> u32
 }
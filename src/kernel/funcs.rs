@@ -1,9 +1,11 @@
 #![allow(non_snake_case)]
 
+use std::sync::atomic::AtomicU8;
+
 use crate::co;
 use crate::decl::*;
 use crate::guard::*;
-use crate::kernel::{ffi, ffi_types::*, privs::*};
+use crate::kernel::{ffi, ffi_types::*, privs::*, proc};
 use crate::prelude::*;
 
 /// [`CopyFile`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-copyfilew)
@@ -33,6 +35,10 @@ pub fn CopyFile(
 
 /// [`CreateDirectory`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-createdirectoryw)
 /// function.
+///
+/// # Related functions
+///
+/// * [`RemoveDirectory`](crate::RemoveDirectory)
 pub fn CreateDirectory(
 	path_name: &str,
 	security_attributes: Option<&SECURITY_ATTRIBUTES>,
@@ -48,6 +54,15 @@ pub fn CreateDirectory(
 	)
 }
 
+/// [`DebugBreak`](https://learn.microsoft.com/en-us/windows/win32/api/debugapi/nf-debugapi-debugbreak)
+/// function.
+///
+/// If no debugger is attached, the process will crash. Consider checking
+/// [`IsDebuggerPresent`](crate::IsDebuggerPresent) first.
+pub fn DebugBreak() {
+	unsafe { ffi::DebugBreak() }
+}
+
 /// [`DeleteFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-deletefilew)
 /// function.
 ///
@@ -92,24 +107,21 @@ pub fn ExitThread(exit_code: u32) {
 #[must_use]
 pub fn ExpandEnvironmentStrings(src: &str) -> SysResult<String> {
 	let wsrc = WString::from_str(src);
-	let len = unsafe {
-		ffi::ExpandEnvironmentStringsW(
-			wsrc.as_ptr(),
-			std::ptr::null_mut(),
-			0,
-		)
-	};
-
-	let mut buf = WString::new_alloc_buf(len as _);
-	bool_to_sysresult(
-		unsafe {
+	let mut len = u32::default();
+	loop { // variables may change between the calls
+		let mut buf = WString::new_alloc_buf(len as _);
+		match unsafe {
 			ffi::ExpandEnvironmentStringsW(
 				wsrc.as_ptr(),
-				buf.as_mut_ptr(),
+				if len == 0 { std::ptr::null_mut() } else { buf.as_mut_ptr() },
 				len,
 			)
-		} as _,
-	).map(|_| buf.to_string())
+		} {
+			0 => return Err(GetLastError()),
+			n if n <= len => return Ok(buf.to_string()), // n includes terminating null
+			n => len = n,
+		}
+	}
 }
 
 /// [`FileTimeToSystemTime`](https://learn.microsoft.com/en-us/windows/win32/api/timezoneapi/nf-timezoneapi-filetimetosystemtime)
@@ -140,37 +152,145 @@ pub fn FlushProcessWriteBuffers() {
 /// [`FormatMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-formatmessagew)
 /// function.
 ///
-/// You don't need to call this function: all error types implement the
-/// [`FormattedError`](crate::prelude::FormattedError) trait which will
-/// automatically call `FormatMessage`.
+/// If `lang_id` is `None`, the user default language is tried first, then
+/// the system default, then US English, since the message may not be
+/// available in all languages. With [`FormatMsgSrc::Str`](crate::FormatMsgSrc::Str),
+/// `message_id` and `lang_id` are ignored.
+///
+/// If `args` is `None`, the inserts are kept as they are in the message.
+///
+/// You don't need to call this function for system errors: all error types
+/// implement the [`FormattedError`](crate::prelude::FormattedError) trait
+/// which will automatically call `FormatMessage`.
+///
+/// # Safety
+///
+/// If `args` is `Some`, the inserts referenced by the message must match the
+/// given arguments, in number and type – the system reads them blindly, so a
+/// missing argument or a `%1` formatted as a string from a
+/// [`FormatMsgArg::U32`](crate::FormatMsgArg::U32) will read invalid memory.
+///
+/// # Examples
+///
+/// Formatting a message from a driver message DLL:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hmsgs = w::HINSTANCE::LoadLibrary("C:\\Drivers\\mydrvmsg.dll")?;
+///
+/// let text = unsafe {
+///     w::FormatMessage(
+///         w::FormatMsgSrc::Module(&hmsgs),
+///         0xc004_0001,
+///         None,
+///         Some(&[w::FormatMsgArg::Str("COM3"), w::FormatMsgArg::U32(5)]),
+///     )?
+/// };
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// Formatting a message definition string:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let text = unsafe {
+///     w::FormatMessage(
+///         w::FormatMsgSrc::Str("%1 has %2!u! items."),
+///         0,
+///         None,
+///         Some(&[w::FormatMsgArg::Str("The list"), w::FormatMsgArg::U32(3)]),
+///     )?
+/// };
+/// assert_eq!(text, "The list has 3 items.");
+/// # w::SysResult::Ok(())
+/// ```
 #[must_use]
 pub unsafe fn FormatMessage(
-	flags: co::FORMAT_MESSAGE,
-	source: Option<*mut std::ffi::c_void>,
+	source: FormatMsgSrc,
 	message_id: u32,
-	lang_id: LANGID,
-	args: Option<&[*mut std::ffi::c_void]>,
+	lang_id: Option<LANGID>,
+	args: Option<&[FormatMsgArg]>,
 ) -> SysResult<String>
 {
-	let mut ptr_buf = std::ptr::null_mut() as *mut u16;
-
-	let nchars = match ffi::FormatMessageW(
-		flags.raw(),
-		source.unwrap_or(std::ptr::null_mut()),
-		message_id,
-		u16::from(lang_id) as _,
-		&mut ptr_buf as *mut *mut _ as _, // pass pointer to pointer
-		0,
-		args.map_or(std::ptr::null_mut(), |arr| arr.as_ptr() as _),
-	) as _ {
-		0 => Err(GetLastError()),
-		nchars => Ok(nchars),
-	}?;
+	let wsource = match source {
+		FormatMsgSrc::Str(s) => WString::from_str(s),
+		_ => WString::new(),
+	};
+	let (mut flags, ptr_source) = match source {
+		FormatMsgSrc::System => (co::FORMAT_MESSAGE::FROM_SYSTEM, std::ptr::null()),
+		FormatMsgSrc::Module(hmod) => (co::FORMAT_MESSAGE::FROM_HMODULE, hmod.ptr() as _),
+		FormatMsgSrc::Str(_) => (co::FORMAT_MESSAGE::FROM_STRING, wsource.as_ptr() as _),
+	};
+	flags |= co::FORMAT_MESSAGE::ALLOCATE_BUFFER;
+
+	let wargs = args.map_or(Vec::new(), |args| { // keep the strings alive
+		args.iter()
+			.map(|arg| match arg {
+				FormatMsgArg::Str(s) => WString::from_str(s),
+				_ => WString::new(),
+			})
+			.collect::<Vec<_>>()
+	});
+	let ptr_args = args.map(|args| {
+		let mut ptrs = Vec::<usize>::with_capacity(args.len());
+		for (arg, warg) in args.iter().zip(wargs.iter()) {
+			match arg {
+				FormatMsgArg::Str(_) => ptrs.push(warg.as_ptr() as _),
+				FormatMsgArg::U32(n) => ptrs.push(*n as _),
+				#[cfg(target_pointer_width = "64")]
+				FormatMsgArg::U64(n) => ptrs.push(*n as _),
+				#[cfg(target_pointer_width = "32")]
+				FormatMsgArg::U64(n) => { // takes two slots
+					ptrs.push(LODWORD(*n) as _);
+					ptrs.push(HIDWORD(*n) as _);
+				},
+			}
+		}
+		ptrs
+	});
+	match ptr_args {
+		Some(_) => flags |= co::FORMAT_MESSAGE::ARGUMENT_ARRAY,
+		None => flags |= co::FORMAT_MESSAGE::IGNORE_INSERTS,
+	}
+
+	let lang_ids = match lang_id {
+		Some(lang_id) => vec![lang_id],
+		None => vec![
+			LANGID::USER_DEFAULT,
+			LANGID::SYSTEM_DEFAULT,
+			LANGID::new(co::LANG::ENGLISH, co::SUBLANG::ENGLISH_US),
+		],
+	};
 
-	let final_wstr = WString::from_wchars_count(ptr_buf, nchars as _);
-	let _ = LocalFreeGuard::new(HLOCAL::from_ptr(ptr_buf as _)); // free returned pointer
-	let final_str = final_wstr.to_string();
-	Ok(final_str)
+	let mut last_err = co::ERROR::SUCCESS;
+	for lang_id in lang_ids.iter() {
+		let mut ptr_buf = std::ptr::null_mut::<u16>();
+		match unsafe {
+			ffi::FormatMessageW(
+				flags.raw(),
+				ptr_source,
+				message_id,
+				u16::from(*lang_id) as _,
+				&mut ptr_buf as *mut *mut _ as _, // pass pointer to pointer
+				0,
+				ptr_args.as_ref().map_or(std::ptr::null_mut(), |ptrs| ptrs.as_ptr() as _),
+			)
+		} {
+			0 => match GetLastError() {
+				err @ (co::ERROR::RESOURCE_LANG_NOT_FOUND
+					| co::ERROR::MUI_FILE_NOT_FOUND) => last_err = err, // try next language
+				err => return Err(err),
+			},
+			nchars => {
+				let _buf_guard = unsafe { LocalFreeGuard::new(HLOCAL::from_ptr(ptr_buf as _)) }; // free returned pointer
+				let wstr = WString::from_wchars_count(ptr_buf, nchars as _);
+				return Ok(wstr.to_string());
+			},
+		}
+	}
+	Err(last_err)
 }
 
 /// [`GetBinaryType`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getbinarytypew)
@@ -210,21 +330,46 @@ pub fn GetComputerName() -> SysResult<String> {
 	).map(|_| buf.to_string())
 }
 
-/// [`GetCurrentDirectory`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getcurrentdirectory)
+/// [`GetComputerNameEx`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getcomputernameexw)
 /// function.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let fqdn = w::GetComputerNameEx(co::COMPUTER_NAME_FORMAT::DnsFullyQualified)?;
+/// println!("{}", fqdn);
+/// # w::SysResult::Ok(())
+/// ```
 #[must_use]
-pub fn GetCurrentDirectory() -> SysResult<String> {
-	let mut buf = WString::new_alloc_buf(MAX_PATH + 1);
+pub fn GetComputerNameEx(format: co::COMPUTER_NAME_FORMAT) -> SysResult<String> {
+	let mut sz = u32::default();
+
+	unsafe { ffi::GetComputerNameExW(format.raw(), std::ptr::null_mut(), &mut sz); }
+	let get_size_err = GetLastError();
+	if get_size_err != co::ERROR::MORE_DATA {
+		return Err(get_size_err);
+	}
+
+	let mut buf = WString::new_alloc_buf(sz as _);
+
 	bool_to_sysresult(
-		unsafe {
-			ffi::GetCurrentDirectoryW(
-				buf.buf_len() as _,
-				buf.as_mut_ptr(),
-			)
-		} as _,
+		unsafe { ffi::GetComputerNameExW(format.raw(), buf.as_mut_ptr(), &mut sz) },
 	).map(|_| buf.to_string())
 }
 
+/// [`GetCurrentDirectory`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getcurrentdirectory)
+/// function.
+///
+/// # Related functions
+///
+/// * [`SetCurrentDirectory`](crate::SetCurrentDirectory)
+#[must_use]
+pub fn GetCurrentDirectory() -> SysResult<String> {
+	sized_string_call(|buf, sz| unsafe { ffi::GetCurrentDirectoryW(sz, buf) })
+}
+
 /// [`GetCurrentProcessId`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getcurrentprocessid)
 /// function.
 #[must_use]
@@ -317,19 +462,38 @@ pub fn GetDiskSpaceInformation(
 pub fn GetEnvironmentStrings() -> SysResult<Vec<(String, String)>> {
 	ptr_to_sysresult(unsafe { ffi::GetEnvironmentStringsW() } as _)
 		.map(|ptr| {
-			let vec_entries = parse_multi_z_str(ptr as *mut _ as _);
+			let vec_entries = parse_env_block(ptr as *mut _ as _);
 			unsafe { ffi::FreeEnvironmentStringsW(ptr); }
-			vec_entries.iter()
-				.map(|env_str| {
-					let mut pair = env_str.split("="); // assumes correctly formatted pairs
-					let key = pair.next().unwrap();
-					let val = pair.next().unwrap();
-					(key.to_owned(), val.to_owned())
-				})
-				.collect()
+			vec_entries
 		})
 }
 
+/// [`GetEnvironmentVariable`](https://learn.microsoft.com/en-us/windows/win32/api/processenv/nf-processenv-getenvironmentvariablew)
+/// function.
+///
+/// If the variable doesn't exist, returns
+/// [`ERROR::ENVVAR_NOT_FOUND`](crate::co::ERROR::ENVVAR_NOT_FOUND).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// match w::GetEnvironmentVariable("MY_APP_HOME") {
+///     Ok(val) => println!("Home: {}", val),
+///     Err(co::ERROR::ENVVAR_NOT_FOUND) => println!("Not set"),
+///     Err(e) => return Err(e),
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn GetEnvironmentVariable(name: &str) -> SysResult<String> {
+	let wname = WString::from_str(name);
+	sized_string_call(|buf, sz| unsafe {
+		ffi::GetEnvironmentVariableW(wname.as_ptr(), buf, sz)
+	})
+}
+
 /// [`GetFirmwareType`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getfirmwaretype)
 /// function.
 #[must_use]
@@ -339,6 +503,19 @@ pub fn GetFirmwareType() -> SysResult<co::FIRMWARE_TYPE> {
 		.map(|_| unsafe { co::FIRMWARE_TYPE::from_raw(ft) })
 }
 
+/// [`GetFullPathName`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getfullpathnamew)
+/// function.
+///
+/// Relative paths are resolved against the current directory. The file
+/// doesn't need to exist.
+#[must_use]
+pub fn GetFullPathName(file_name: &str) -> SysResult<String> {
+	let wname = WString::from_str(file_name);
+	sized_string_call(|buf, sz| unsafe {
+		ffi::GetFullPathNameW(wname.as_ptr(), sz, buf, std::ptr::null_mut())
+	})
+}
+
 /// [`GetLargePageMinimum`](https://learn.microsoft.com/en-us/windows/win32/api/memoryapi/nf-memoryapi-getlargepageminimum)
 /// function.
 #[must_use]
@@ -439,6 +616,18 @@ pub fn GetFileAttributes(file_name: &str) -> SysResult<co::FILE_ATTRIBUTE> {
 	}
 }
 
+/// [`GetLongPathName`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getlongpathnamew)
+/// function.
+///
+/// The file must exist.
+#[must_use]
+pub fn GetLongPathName(short_path: &str) -> SysResult<String> {
+	let wpath = WString::from_str(short_path);
+	sized_string_call(|buf, sz| unsafe {
+		ffi::GetLongPathNameW(wpath.as_ptr(), buf, sz)
+	})
+}
+
 /// [`GetNativeSystemInfo`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getnativesysteminfo)
 /// function.
 #[must_use]
@@ -448,6 +637,18 @@ pub fn GetNativeSystemInfo() -> SYSTEM_INFO {
 	si
 }
 
+/// [`GetPhysicallyInstalledSystemMemory`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getphysicallyinstalledsystemmemory)
+/// function.
+///
+/// Returns the amount of RAM physically installed, in kilobytes.
+#[must_use]
+pub fn GetPhysicallyInstalledSystemMemory() -> SysResult<u64> {
+	let mut kbytes = u64::default();
+	bool_to_sysresult(
+		unsafe { ffi::GetPhysicallyInstalledSystemMemory(&mut kbytes) },
+	).map(|_| kbytes)
+}
+
 /// [`GetPrivateProfileSection`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getprivateprofilesectionw)
 /// function.
 ///
@@ -624,6 +825,19 @@ pub fn GetPrivateProfileString(
 	}
 }
 
+/// [`GetShortPathName`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getshortpathnamew)
+/// function.
+///
+/// The file must exist. If 8.3 name creation is disabled on the volume, the
+/// long path is returned.
+#[must_use]
+pub fn GetShortPathName(long_path: &str) -> SysResult<String> {
+	let wpath = WString::from_str(long_path);
+	sized_string_call(|buf, sz| unsafe {
+		ffi::GetShortPathNameW(wpath.as_ptr(), buf, sz)
+	})
+}
+
 /// [`GetStartupInfo`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getstartupinfow)
 /// function.
 #[must_use]
@@ -670,6 +884,16 @@ pub fn GetSystemInfo() -> SYSTEM_INFO {
 	si
 }
 
+/// [`GetSystemPowerStatus`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-getsystempowerstatus)
+/// function.
+#[must_use]
+pub fn GetSystemPowerStatus() -> SysResult<SYSTEM_POWER_STATUS> {
+	let mut sps = SYSTEM_POWER_STATUS::default();
+	bool_to_sysresult(
+		unsafe { ffi::GetSystemPowerStatus(&mut sps as *mut _ as _) },
+	).map(|_| sps)
+}
+
 /// [`GetSystemTime`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-getsystemtime)
 /// function.
 ///
@@ -741,34 +965,62 @@ pub fn GetSystemTimes() -> SysResult<(FILETIME, FILETIME, FILETIME)> {
 
 /// [`GetTempFileName`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-gettempfilenamew)
 /// function.
+///
+/// Returns the file name and the unique number used to build it.
+///
+/// If `unique` is zero, the system generates a unique number and creates an
+/// empty file with the resulting name, which is then guaranteed not to be
+/// taken by another process – you are responsible for deleting it. Otherwise,
+/// no file is created, and the name is not checked for uniqueness.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let (tmp_file, _) = w::GetTempFileName(&w::GetTempPath()?, "abc", 0)?;
+///
+/// // use the file...
+///
+/// w::DeleteFile(&tmp_file)?;
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`GetTempPath`](crate::GetTempPath)
 #[must_use]
 pub fn GetTempFileName(
 	path_name: &str,
 	prefix: &str,
 	unique: u32,
-) -> SysResult<String>
+) -> SysResult<(String, u32)>
 {
 	let mut buf = WString::new_alloc_buf(MAX_PATH + 1);
-	bool_to_sysresult(
-		unsafe {
-			ffi::GetTempFileNameW(
-				WString::from_str(path_name).as_ptr(),
-				WString::from_str(prefix).as_ptr(),
-				unique,
-				buf.as_mut_ptr(),
-			)
-		} as _,
-	).map(|_| buf.to_string())
+	match unsafe {
+		ffi::GetTempFileNameW(
+			WString::from_str(path_name).as_ptr(),
+			WString::from_str(prefix).as_ptr(),
+			unique,
+			buf.as_mut_ptr(),
+		)
+	} {
+		0 => Err(GetLastError()),
+		n => Ok((buf.to_string(), n)),
+	}
 }
 
 /// [`GetTempPath`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-gettemppathw)
 /// function.
+///
+/// The returned path ends with a backslash.
+///
+/// # Related functions
+///
+/// * [`GetTempFileName`](crate::GetTempFileName)
 #[must_use]
 pub fn GetTempPath() -> SysResult<String> {
-	let mut buf = WString::new_alloc_buf(MAX_PATH + 1);
-	bool_to_sysresult(
-		unsafe { ffi::GetTempPathW(buf.buf_len() as _, buf.as_mut_ptr()) } as _,
-	).map(|_| buf.to_string())
+	sized_string_call(|buf, sz| unsafe { ffi::GetTempPathW(sz, buf) })
 }
 
 /// [`GetTickCount64`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-gettickcount64)
@@ -871,6 +1123,53 @@ pub fn GetVolumePathName(file_name: &str) -> SysResult<String> {
 	).map(|_| buf.to_string())
 }
 
+/// [`GetVolumePathNamesForVolumeName`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-getvolumepathnamesforvolumenamew)
+/// function.
+///
+/// Returns the drive letters and mounted folders of the given volume GUID
+/// path, which can be retrieved with
+/// [`HFINDVOLUME::iter_volumes`](crate::prelude::kernel_Hfindvolume::iter_volumes).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let mount_points = w::GetVolumePathNamesForVolumeName(
+///     "\\\\?\\Volume{26a21bda-a627-11d7-9931-806e6f6e6963}\\")?;
+///
+/// for mount_point in mount_points.iter() {
+///     println!("{}", mount_point);
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn GetVolumePathNamesForVolumeName(
+	volume_name: &str,
+) -> SysResult<Vec<String>>
+{
+	let wvolume_name = WString::from_str(volume_name);
+	let mut buf_sz = MAX_PATH + 1;
+	loop {
+		let mut buf = WString::new_alloc_buf(buf_sz);
+		let mut needed = u32::default();
+		match unsafe {
+			ffi::GetVolumePathNamesForVolumeNameW(
+				wvolume_name.as_ptr(),
+				buf.as_mut_ptr(),
+				buf.buf_len() as _,
+				&mut needed,
+			)
+		} {
+			0 => match GetLastError() {
+				co::ERROR::MORE_DATA => buf_sz = needed as _, // try again with the needed size
+				err => return Err(err),
+			},
+			_ => return Ok(parse_multi_z_str(buf.as_ptr())),
+		}
+	}
+}
+
 /// [`GlobalMemoryStatusEx`](https://learn.microsoft.com/en-us/windows/win32/api/sysinfoapi/nf-sysinfoapi-globalmemorystatusex)
 /// function.
 #[must_use]
@@ -950,6 +1249,31 @@ pub fn IsNativeVhdBoot() -> SysResult<bool> {
 		.map(|_| is_native != 0)
 }
 
+/// [`IsNormalizedString`](https://learn.microsoft.com/en-us/windows/win32/api/winnls/nf-winnls-isnormalizedstring)
+/// function.
+///
+/// # Related functions
+///
+/// * [`NormalizeString`](crate::NormalizeString)
+#[must_use]
+pub fn IsNormalizedString(form: co::NORM_FORM, s: &str) -> SysResult<bool> {
+	let wsrc = s.encode_utf16().collect::<Vec<_>>();
+	if wsrc.is_empty() {
+		return Ok(true);
+	}
+
+	SetLastError(co::ERROR::SUCCESS);
+	match unsafe {
+		ffi::IsNormalizedString(form.raw(), wsrc.as_ptr(), wsrc.len() as _)
+	} {
+		0 => match GetLastError() {
+			co::ERROR::SUCCESS => Ok(false),
+			err => Err(err),
+		},
+		_ => Ok(true),
+	}
+}
+
 /// [`IsWindows10OrGreater`](https://learn.microsoft.com/en-us/windows/win32/api/versionhelpers/nf-versionhelpers-iswindows10orgreater)
 /// function.
 #[must_use]
@@ -961,6 +1285,34 @@ pub fn IsWindows10OrGreater() -> SysResult<bool> {
 	)
 }
 
+/// Returns `true` if the operating system is Windows 11 or greater, which is
+/// Windows 10 with build number 22000 or greater.
+///
+/// Note that, like the other version helpers, this function is based on
+/// [`VerifyVersionInfo`](crate::VerifyVersionInfo), which requires the
+/// application to be manifested for Windows 10.
+#[must_use]
+pub fn IsWindows11OrGreater() -> SysResult<bool> {
+	let mut osvi = OSVERSIONINFOEX::default();
+	osvi.dwMajorVersion = HIBYTE(co::WIN32::WINNT_WINTHRESHOLD.raw()) as _;
+	osvi.dwMinorVersion = LOBYTE(co::WIN32::WINNT_WINTHRESHOLD.raw()) as _;
+	osvi.dwBuildNumber = 22000;
+
+	let cond_mask = VerSetConditionMask(
+		VerSetConditionMask(
+			VerSetConditionMask(0, co::VER_MASK::MAJORVERSION, co::VER_COND::GREATER_EQUAL),
+			co::VER_MASK::MINORVERSION, co::VER_COND::GREATER_EQUAL,
+		),
+		co::VER_MASK::BUILDNUMBER, co::VER_COND::GREATER_EQUAL,
+	);
+
+	VerifyVersionInfo(
+		&mut osvi,
+		co::VER_MASK::MAJORVERSION | co::VER_MASK::MINORVERSION | co::VER_MASK::BUILDNUMBER,
+		cond_mask,
+	)
+}
+
 /// [`IsWindows7OrGreater`](https://learn.microsoft.com/en-us/windows/win32/api/versionhelpers/nf-versionhelpers-iswindows7orgreater)
 /// function.
 #[must_use]
@@ -1228,14 +1580,161 @@ pub fn MultiByteToWideChar(
 	).map(|_| buf)
 }
 
+/// [`NormalizeString`](https://learn.microsoft.com/en-us/windows/win32/api/winnls/nf-winnls-normalizestring)
+/// function.
+///
+/// Strings which look the same may have different code points – like `é`,
+/// which can be a single precomposed character or an `e` followed by a
+/// combining accent –, so they must be normalized to the same form before
+/// being compared.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let composed = "caf\u{e9}";
+/// let decomposed = "cafe\u{301}";
+///
+/// assert_eq!(
+///     w::NormalizeString(co::NORM_FORM::NormalizationC, composed)?,
+///     w::NormalizeString(co::NORM_FORM::NormalizationC, decomposed)?,
+/// );
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`IsNormalizedString`](crate::IsNormalizedString)
+#[must_use]
+pub fn NormalizeString(form: co::NORM_FORM, src: &str) -> SysResult<String> {
+	let wsrc = src.encode_utf16().collect::<Vec<_>>();
+	if wsrc.is_empty() {
+		return Ok(String::new());
+	}
+
+	let mut buf_sz = match unsafe {
+		ffi::NormalizeString(form.raw(), wsrc.as_ptr(), wsrc.len() as _,
+			std::ptr::null_mut(), 0)
+	} {
+		n if n <= 0 => return Err(GetLastError()),
+		n => n, // estimated length
+	};
+
+	loop {
+		let mut buf = vec![0u16; buf_sz as _];
+		match unsafe {
+			ffi::NormalizeString(form.raw(), wsrc.as_ptr(), wsrc.len() as _,
+				buf.as_mut_ptr(), buf_sz)
+		} {
+			n if n > 0 => return Ok(WString::from_wchars_slice(&buf[..n as _]).to_string()),
+			n => match GetLastError() {
+				co::ERROR::INSUFFICIENT_BUFFER => buf_sz = (-n).max(buf_sz * 2), // new estimate, retry
+				err => return Err(err),
+			},
+		}
+	}
+}
+
 /// [`OutputDebugString`](https://learn.microsoft.com/en-us/windows/win32/api/debugapi/nf-debugapi-outputdebugstringw)
 /// function.
+///
+/// To write formatted messages only in debug builds, see the
+/// [`debug_output!`](crate::debug_output) macro.
 pub fn OutputDebugString(output_string: &str) {
 	unsafe {
 		ffi::OutputDebugStringW(WString::from_str(output_string).as_ptr())
 	}
 }
 
+/// [`PlaySound`](https://learn.microsoft.com/en-us/previous-versions/dd743680(v=vs.85))
+/// function.
+///
+/// Returns `false` if the sound could not be played, which includes the case
+/// when [`SND::NOSTOP`](crate::co::SND::NOSTOP) is given and another sound is
+/// already playing.
+///
+/// # Examples
+///
+/// Playing a system sound, and then a file in a loop:
+///
+/// ```no_run
+/// use winsafe::{self as w, co};
+///
+/// w::PlaySound(
+///     w::PlaySoundSrc::Alias("SystemExclamation"),
+///     co::SND::SYNC | co::SND::NODEFAULT,
+/// );
+///
+/// w::PlaySound(
+///     w::PlaySoundSrc::File("C:\\Temp\\ding.wav"),
+///     co::SND::ASYNC | co::SND::LOOP,
+/// );
+///
+/// w::Sleep(5000);
+/// w::PlaySound(w::PlaySoundSrc::Stop, co::SND::SYNC);
+/// ```
+pub fn PlaySound(src: PlaySoundSrc, flags: co::SND) -> bool {
+	let wbuf: WString;
+	let (psz, hmod, src_flags) = match &src {
+		PlaySoundSrc::Alias(alias) => {
+			wbuf = WString::from_str(alias);
+			(wbuf.as_ptr(), std::ptr::null_mut(), 0x0001_0000) // SND_ALIAS
+		},
+		PlaySoundSrc::AliasId(id) => {
+			(id.raw() as usize as _, std::ptr::null_mut(), 0x0011_0000) // SND_ALIAS_ID
+		},
+		PlaySoundSrc::File(path) => {
+			wbuf = WString::from_str(path);
+			(wbuf.as_ptr(), std::ptr::null_mut(), 0x0002_0000) // SND_FILENAME
+		},
+		PlaySoundSrc::Resource(hinst, res_id) => {
+			(res_id.as_ptr(), hinst.ptr(), 0x0004_0004) // SND_RESOURCE
+		},
+		PlaySoundSrc::Stop => (std::ptr::null(), std::ptr::null_mut(), 0),
+	};
+
+	unsafe { ffi::PlaySoundW(psz, hmod, flags.raw() | src_flags) != 0 }
+}
+
+/// [`QueryDosDevice`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-querydosdevicew)
+/// function.
+///
+/// If `device_name` is `None`, returns all existing MS-DOS device names.
+/// Otherwise, returns the NT device paths the name is mapped to, the first one
+/// being the current mapping.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let targets = w::QueryDosDevice(Some("C:"))?;
+/// println!("{}", targets[0]); // \Device\HarddiskVolume3
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn QueryDosDevice(device_name: Option<&str>) -> SysResult<Vec<String>> {
+	let wdevice_name = WString::from_opt_str(device_name);
+	let mut buf_sz = MAX_PATH + 1;
+	loop {
+		let mut buf = WString::new_alloc_buf(buf_sz);
+		match unsafe {
+			ffi::QueryDosDeviceW(
+				wdevice_name.as_ptr(),
+				buf.as_mut_ptr(),
+				buf.buf_len() as _,
+			)
+		} {
+			0 => match GetLastError() {
+				co::ERROR::INSUFFICIENT_BUFFER => buf_sz *= 2, // try again with a bigger buffer
+				err => return Err(err),
+			},
+			_ => return Ok(parse_multi_z_str(buf.as_ptr())),
+		}
+	}
+}
+
 /// [`QueryPerformanceCounter`](https://learn.microsoft.com/en-us/windows/win32/api/profileapi/nf-profileapi-queryperformancecounter)
 /// function.
 ///
@@ -1288,6 +1787,20 @@ pub fn QueryUnbiasedInterruptTime() -> SysResult<u64> {
 		.map(|_| t)
 }
 
+/// [`RemoveDirectory`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-removedirectoryw)
+/// function.
+///
+/// The directory must be empty.
+///
+/// # Related functions
+///
+/// * [`CreateDirectory`](crate::CreateDirectory)
+pub fn RemoveDirectory(path_name: &str) -> SysResult<()> {
+	bool_to_sysresult(
+		unsafe { ffi::RemoveDirectoryW(WString::from_str(path_name).as_ptr()) },
+	)
+}
+
 /// [`ReplaceFile`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-replacefilew)
 /// function.
 ///
@@ -1317,8 +1830,81 @@ pub fn ReplaceFile(
 	)
 }
 
+/// [`RtlGetVersion`](https://learn.microsoft.com/en-us/windows/win32/devnotes/rtlgetversion)
+/// function.
+///
+/// Unlike `GetVersionEx`, which reports Windows 8 unless the application is
+/// manifested for newer versions, this function always returns the actual
+/// version of the operating system. Since it's a driver function exported by
+/// ntdll, it's loaded at runtime.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe as w;
+///
+/// let osvi = w::RtlGetVersion()?;
+/// println!("Windows {}.{}, build {}",
+///     osvi.dwMajorVersion, osvi.dwMinorVersion, osvi.dwBuildNumber);
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// # Related functions
+///
+/// * [`WindowsVersion::current`](crate::WindowsVersion::current)
+#[must_use]
+pub fn RtlGetVersion() -> SysResult<OSVERSIONINFOEX> {
+	let mut osvi = OSVERSIONINFOEX::default();
+	match unsafe {
+		call_dyn("ntdll", "RtlGetVersion",
+			|f: extern "system" fn(PVOID) -> i32| f(&mut osvi as *mut _ as _))
+	} {
+		Some(0) => Ok(osvi), // STATUS_SUCCESS
+		Some(_) => Err(co::ERROR::INVALID_PARAMETER), // only possible NTSTATUS error
+		None => Err(co::ERROR::PROC_NOT_FOUND),
+	}
+}
+
+/// [`SearchPath`](https://learn.microsoft.com/en-us/windows/win32/api/processenv/nf-processenv-searchpathw)
+/// function.
+///
+/// If `path` is `None`, the system search path is used.
+///
+/// # Examples
+///
+/// Finding where an executable is:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let full_path = w::SearchPath(None, "notepad", Some(".exe"))?;
+/// println!("{}", full_path);
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn SearchPath(
+	path: Option<&str>,
+	file_name: &str,
+	extension: Option<&str>,
+) -> SysResult<String>
+{
+	let (wpath, wname, wext) = (
+		WString::from_opt_str(path),
+		WString::from_str(file_name),
+		WString::from_opt_str(extension),
+	);
+	sized_string_call(|buf, sz| unsafe {
+		ffi::SearchPathW(wpath.as_ptr(), wname.as_ptr(), wext.as_ptr(),
+			sz, buf, std::ptr::null_mut())
+	})
+}
+
 /// [`SetCurrentDirectory`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-setcurrentdirectory)
 /// function.
+///
+/// # Related functions
+///
+/// * [`GetCurrentDirectory`](crate::GetCurrentDirectory)
 pub fn SetCurrentDirectory(path_name: &str) -> SysResult<()> {
 	bool_to_sysresult(
 		unsafe {
@@ -1327,6 +1913,26 @@ pub fn SetCurrentDirectory(path_name: &str) -> SysResult<()> {
 	)
 }
 
+/// [`SetEnvironmentVariable`](https://learn.microsoft.com/en-us/windows/win32/api/processenv/nf-processenv-setenvironmentvariablew)
+/// function.
+///
+/// If `value` is `None`, the variable is deleted from the environment of the
+/// current process.
+pub fn SetEnvironmentVariable(name: &str, value: Option<&str>) -> SysResult<()> {
+	let wvalue = match value {
+		Some("") => WString::new_alloc_buf(1), // empty string, but not null
+		value => WString::from_opt_str(value),
+	};
+	bool_to_sysresult(
+		unsafe {
+			ffi::SetEnvironmentVariableW(
+				WString::from_str(name).as_ptr(),
+				wvalue.as_ptr(),
+			)
+		},
+	)
+}
+
 /// [`SetFileAttributes`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-setfileattributesw)
 /// function.
 pub fn SetFileAttributes(
@@ -1350,6 +1956,41 @@ pub fn SetLastError(err_code: co::ERROR) {
 	unsafe { ffi::SetLastError(err_code.raw()) }
 }
 
+/// [`SetThreadExecutionState`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-setthreadexecutionstate)
+/// function.
+///
+/// The returned guard restores the previous execution state when dropped,
+/// which is relevant when `flags` has
+/// [`EXECUTION_STATE::CONTINUOUS`](crate::co::EXECUTION_STATE::CONTINUOUS).
+///
+/// # Examples
+///
+/// Keeping the display on while a video is playing:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let _awake_guard = w::SetThreadExecutionState(
+///     co::EXECUTION_STATE::CONTINUOUS
+///         | co::EXECUTION_STATE::DISPLAY_REQUIRED
+///         | co::EXECUTION_STATE::SYSTEM_REQUIRED,
+/// )?; // keep guard alive
+///
+/// // previous state restored automatically
+/// # w::SysResult::Ok(())
+/// ```
+pub fn SetThreadExecutionState(
+	flags: co::EXECUTION_STATE,
+) -> SysResult<SetThreadExecutionStateGuard>
+{
+	match unsafe { ffi::SetThreadExecutionState(flags.raw()) } {
+		0 => Err(co::ERROR::INVALID_PARAMETER),
+		prev => Ok(unsafe {
+			SetThreadExecutionStateGuard::new(co::EXECUTION_STATE::from_raw(prev))
+		}),
+	}
+}
+
 /// [`SetThreadStackGuarantee`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-setthreadstackguarantee)
 /// function.
 ///
@@ -1366,6 +2007,22 @@ pub fn Sleep(milliseconds: u32) {
 	unsafe { ffi::Sleep(milliseconds) }
 }
 
+/// [`SleepEx`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-sleepex)
+/// function.
+///
+/// If `alertable` is `true`, returns
+/// [`WAIT::IO_COMPLETION`](crate::co::WAIT::IO_COMPLETION) when the sleep was
+/// interrupted to run completion routines, like those of
+/// [`HFILE::ReadFileEx`](crate::prelude::kernel_Hfile::ReadFileEx). If
+/// `milliseconds` is `None`, sleeps until that happens.
+pub fn SleepEx(milliseconds: Option<u32>, alertable: bool) -> co::WAIT {
+	unsafe {
+		co::WAIT::from_raw(
+			ffi::SleepEx(milliseconds.unwrap_or(INFINITE), alertable as _),
+		)
+	}
+}
+
 /// [`SwitchToThread`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-switchtothread)
 /// function.
 pub fn SwitchToThread() -> SysResult<()> {
@@ -1418,6 +2075,126 @@ pub fn SystemTimeToTzSpecificLocalTime(
 	).map(|_| local_time)
 }
 
+/// [`timeBeginPeriod`](https://learn.microsoft.com/en-us/windows/win32/api/timeapi/nf-timeapi-timebeginperiod)
+/// function.
+///
+/// Sets the minimum resolution of the system timers, in milliseconds, which
+/// also affects the granularity of [`Sleep`](crate::Sleep). Since a higher
+/// resolution increases power consumption, keep the returned guard alive only
+/// while it's needed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe as w;
+///
+/// let _period_guard = w::timeBeginPeriod(1)?; // keep guard alive
+/// w::Sleep(1); // now sleeps about 1 ms, instead of up to 15.6 ms
+///
+/// // timeEndPeriod() called automatically
+/// # w::SysResult::Ok(())
+/// ```
+pub fn timeBeginPeriod(period: u32) -> SysResult<TimeEndPeriodGuard> {
+	match unsafe { ffi::timeBeginPeriod(period) } {
+		0 => Ok(unsafe { TimeEndPeriodGuard::new(period) }), // TIMERR_NOERROR
+		_ => Err(co::ERROR::INVALID_PARAMETER), // TIMERR_NOCANDO, period out of range
+	}
+}
+
+/// [`timeGetDevCaps`](https://learn.microsoft.com/en-us/windows/win32/api/timeapi/nf-timeapi-timegetdevcaps)
+/// function.
+///
+/// Returns the minimum and maximum timer resolutions, in milliseconds,
+/// accepted by [`timeBeginPeriod`](crate::timeBeginPeriod) and
+/// [`timeSetEvent`](crate::timeSetEvent).
+#[must_use]
+pub fn timeGetDevCaps() -> Result<TIMECAPS, co::MMSYSERR> {
+	let mut tc = TIMECAPS::default();
+	mmresult(
+		unsafe {
+			ffi::timeGetDevCaps(&mut tc as *mut _ as _, std::mem::size_of::<TIMECAPS>() as _)
+		},
+	).map(|_| tc)
+}
+
+/// [`timeSetEvent`](https://learn.microsoft.com/en-us/windows/win32/api/mmsystem/nf-mmsystem-timesetevent)
+/// function.
+///
+/// Starts a multimedia timer, which calls the closure after `delay`
+/// milliseconds – once, or repeatedly, according to `event_type`. The
+/// `resolution` is the accuracy of the event, in milliseconds; `0` means the
+/// greatest possible accuracy, at the cost of system overhead.
+///
+/// The closure runs in a separate thread owned by the system, hence the
+/// [`Send`](std::marker::Send) bound. It must return quickly and never block,
+/// since all multimedia timers share this thread.
+///
+/// The timer is stopped with
+/// [`timeKillEvent`](https://learn.microsoft.com/en-us/windows/win32/api/mmsystem/nf-mmsystem-timekillevent)
+/// when the returned guard goes out of scope; after that, the closure is
+/// guaranteed not to be called anymore. A
+/// [`TIME::ONESHOT`](crate::co::TIME::ONESHOT) timer is never killed, because
+/// its ID may be reused by another timer once it fires; if it's still pending,
+/// it will fire without calling the closure.
+///
+/// The function itself reports no error code; if the timer cannot be created –
+/// usually because `delay` or `resolution` is out of range –
+/// [`MMSYSERR::TIMERR_NOCANDO`](crate::co::MMSYSERR::TIMERR_NOCANDO) is
+/// returned.
+///
+/// # Examples
+///
+/// A 1 millisecond periodic timer:
+///
+/// ```no_run
+/// use std::sync::atomic::{AtomicU32, Ordering};
+/// use std::sync::Arc;
+/// use winsafe::{self as w, co};
+///
+/// let ticks = Arc::new(AtomicU32::new(0));
+/// let ticks2 = ticks.clone();
+///
+/// let _period_guard = w::timeBeginPeriod(1)?;
+/// let timer_guard = w::timeSetEvent(1, 1, co::TIME::PERIODIC, move || {
+///     ticks2.fetch_add(1, Ordering::Relaxed);
+/// })?;
+///
+/// w::Sleep(1000);
+/// drop(timer_guard); // timeKillEvent() called here
+/// println!("Ticks: {}", ticks.load(Ordering::Relaxed)); // about 1000
+/// # w::AnyResult::Ok(())
+/// ```
+#[must_use]
+pub fn timeSetEvent<F>(
+	delay: u32,
+	resolution: u32,
+	event_type: co::TIME,
+	func: F,
+) -> Result<TimeKillEventGuard, co::MMSYSERR>
+	where F: FnMut() + Send + 'static,
+{
+	let pctx = Box::into_raw(Box::new(TimeEventCtx {
+		func: Box::new(func),
+		one_shot: (event_type == co::TIME::ONESHOT)
+			.then(|| AtomicU8::new(TimeEventCtx::PENDING)),
+	}));
+	match unsafe {
+		ffi::timeSetEvent(
+			delay,
+			resolution,
+			proc::time_set_event_proc as _,
+			pctx as _,
+			event_type.raw() | TIME_CALLBACK_FUNCTION | TIME_KILL_SYNCHRONOUS,
+		)
+	} {
+		0 => {
+			let _ = unsafe { Box::from_raw(pctx) }; // closure won't be called
+			Err(co::MMSYSERR::TIMERR_NOCANDO) // NULL is returned, no error code
+		},
+		timer_id => Ok(unsafe { TimeKillEventGuard::new_with_ctx(timer_id, pctx) }),
+	}
+}
+
 /// [`VerifyVersionInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-verifyversioninfow)
 /// function.
 #[must_use]
@@ -1558,3 +2335,78 @@ pub fn WritePrivateProfileString(
 		},
 	)
 }
+
+/// [`WTSGetActiveConsoleSessionId`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-wtsgetactiveconsolesessionid)
+/// function.
+///
+/// Returns `None` if there is no session attached to the physical console,
+/// which may happen while a session is being attached or detached.
+#[must_use]
+pub fn WTSGetActiveConsoleSessionId() -> Option<u32> {
+	match unsafe { ffi::WTSGetActiveConsoleSessionId() } {
+		0xffff_ffff => None,
+		session_id => Some(session_id),
+	}
+}
+
+#[cfg(all(test, windows))]
+mod tests {
+	use std::sync::atomic::{AtomicU32, Ordering};
+	use std::sync::{Arc, Mutex};
+	use super::*;
+
+	#[test]
+	fn time_set_event_periodic_cadence() {
+		let _period_guard = timeBeginPeriod(1).unwrap();
+		let ticks = Arc::new(Mutex::new(Vec::<u64>::with_capacity(200)));
+		let ticks2 = ticks.clone();
+
+		let timer_guard = timeSetEvent(10, 1, co::TIME::PERIODIC, move || {
+			ticks2.lock().unwrap().push(GetTickCount64());
+		}).unwrap();
+		Sleep(1000);
+		drop(timer_guard);
+
+		let ticks = ticks.lock().unwrap().clone(); // about 100, but machines may be loaded
+		assert!(ticks.len() >= 10, "{} ticks", ticks.len());
+		let avg_interval = (ticks[ticks.len() - 1] - ticks[0]) as f64
+			/ (ticks.len() - 1) as f64;
+		assert!((5.0..=100.0).contains(&avg_interval), "{} ms", avg_interval);
+	}
+
+	#[test]
+	fn time_set_event_stops_on_drop() {
+		let count = Arc::new(AtomicU32::new(0));
+		let count2 = count.clone();
+
+		let timer_guard = timeSetEvent(5, 1, co::TIME::PERIODIC, move || {
+			count2.fetch_add(1, Ordering::SeqCst);
+		}).unwrap();
+		Sleep(100);
+		drop(timer_guard);
+
+		let after_drop = count.load(Ordering::SeqCst);
+		Sleep(100);
+		assert_eq!(count.load(Ordering::SeqCst), after_drop);
+	}
+
+	#[test]
+	fn time_set_event_one_shot() {
+		let count = Arc::new(AtomicU32::new(0));
+		let count2 = count.clone();
+		let fired = timeSetEvent(10, 1, co::TIME::ONESHOT, move || {
+			count2.fetch_add(1, Ordering::SeqCst);
+		}).unwrap();
+		Sleep(500);
+		assert_eq!(count.load(Ordering::SeqCst), 1);
+		drop(fired);
+
+		let count3 = count.clone();
+		let pending = timeSetEvent(100, 1, co::TIME::ONESHOT, move || {
+			count3.fetch_add(1, Ordering::SeqCst);
+		}).unwrap();
+		drop(pending); // cancelled before firing
+		Sleep(300);
+		assert_eq!(count.load(Ordering::SeqCst), 1);
+	}
+}
@@ -1,7 +1,10 @@
+use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
+use std::sync::atomic::Ordering;
 
+use crate::co;
 use crate::decl::*;
-use crate::kernel::ffi;
+use crate::kernel::{ffi, privs::*};
 use crate::prelude::*;
 
 /// RAII implementation for a [`Handle`](crate::prelude::Handle) which
@@ -135,7 +138,10 @@ impl CloseHandlePiGuard {
 /// RAII implementation [`HUPDATERSRC`](crate::HUPDATERSRC) which automatically
 /// calls
 /// [`EndUpdateResource`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-endupdateresourcew)
-/// when the object goes out of scope.
+/// when the object goes out of scope, discarding all the changes.
+///
+/// To write the changes to the file, call
+/// [`commit`](crate::guard::EndUpdateResourceGuard::commit).
 pub struct EndUpdateResourceGuard {
 	hupsrc: HUPDATERSRC,
 }
@@ -143,7 +149,7 @@ pub struct EndUpdateResourceGuard {
 impl Drop for EndUpdateResourceGuard {
 	fn drop(&mut self) {
 		if let Some(h) = self.hupsrc.as_opt() {
-			unsafe { ffi::EndUpdateResourceW(h.ptr(), false as _); } // ignore errors
+			unsafe { ffi::EndUpdateResourceW(h.ptr(), true as _); } // discard, ignore errors
 		}
 	}
 }
@@ -185,6 +191,16 @@ impl EndUpdateResourceGuard {
 	pub fn leak(&mut self) -> HUPDATERSRC {
 		std::mem::replace(&mut self.hupsrc, HUPDATERSRC::INVALID)
 	}
+
+	/// Calls
+	/// [`EndUpdateResource`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-endupdateresourcew)
+	/// writing all the changes to the file, and consumes the guard.
+	pub fn commit(mut self) -> SysResult<()> {
+		let h = self.leak();
+		bool_to_sysresult(
+			unsafe { ffi::EndUpdateResourceW(h.ptr(), false as _) },
+		)
+	}
 }
 
 //------------------------------------------------------------------------------
@@ -197,6 +213,14 @@ handle_guard! { FindCloseGuard: HFINDFILE;
 	/// when the object goes out of scope.
 }
 
+handle_guard! { FindVolumeCloseGuard: HFINDVOLUME;
+	ffi::FindVolumeClose;
+	/// RAII implementation for [`HFINDVOLUME`](crate::HFINDVOLUME) which
+	/// automatically calls
+	/// [`FindVolumeClose`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findvolumeclose)
+	/// when the object goes out of scope.
+}
+
 handle_guard! { FreeLibraryGuard: HINSTANCE;
 	ffi::FreeLibrary;
 	/// RAII implementation for [`HINSTANCE`](crate::HINSTANCE) which
@@ -432,6 +456,207 @@ impl<'a, H> LocalUnlockGuard<'a, H>
 
 //------------------------------------------------------------------------------
 
+/// RAII implementation for the [`HPOWERREQUEST`](crate::HPOWERREQUEST)
+/// request which automatically calls
+/// [`PowerClearRequest`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-powerclearrequest)
+/// when the object goes out of scope.
+pub struct PowerClearRequestGuard<'a, H>
+	where H: kernel_Hpowerrequest,
+{
+	hpowerrequest: &'a H,
+	request_type: co::POWER_REQUEST_TYPE,
+}
+
+impl<'a, H> Drop for PowerClearRequestGuard<'a, H>
+	where H: kernel_Hpowerrequest,
+{
+	fn drop(&mut self) {
+		if let Some(h) = self.hpowerrequest.as_opt() {
+			unsafe { ffi::PowerClearRequest(h.ptr(), self.request_type.raw()); } // ignore errors
+		}
+	}
+}
+
+impl<'a, H> PowerClearRequestGuard<'a, H>
+	where H: kernel_Hpowerrequest,
+{
+	/// Constructs the guard by taking ownership of the objects.
+	///
+	/// # Safety
+	///
+	/// Be sure the request must be cleared with
+	/// [`PowerClearRequest`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-powerclearrequest)
+	/// at the end of scope.
+	#[must_use]
+	pub const unsafe fn new(
+		hpowerrequest: &'a H,
+		request_type: co::POWER_REQUEST_TYPE,
+	) -> Self
+	{
+		Self { hpowerrequest, request_type }
+	}
+
+	/// Returns the type of the request.
+	#[must_use]
+	pub const fn request_type(&self) -> co::POWER_REQUEST_TYPE {
+		self.request_type
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation for the execution state set by
+/// [`SetThreadExecutionState`](crate::SetThreadExecutionState), which
+/// automatically calls
+/// [`SetThreadExecutionState`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-setthreadexecutionstate)
+/// with the previous state when the object goes out of scope.
+///
+/// The state belongs to the thread which set it, so the guard cannot be sent
+/// to another thread.
+pub struct SetThreadExecutionStateGuard {
+	prev_state: co::EXECUTION_STATE,
+	_not_send: PhantomData<*const ()>,
+}
+
+impl Drop for SetThreadExecutionStateGuard {
+	fn drop(&mut self) {
+		unsafe { // without CONTINUOUS, the previous state would not persist
+			ffi::SetThreadExecutionState(
+				(self.prev_state | co::EXECUTION_STATE::CONTINUOUS).raw(),
+			); // ignore errors
+		}
+	}
+}
+
+impl SetThreadExecutionStateGuard {
+	/// Constructs the guard by taking ownership of the state.
+	///
+	/// # Safety
+	///
+	/// Be sure the previous state must be restored with
+	/// [`SetThreadExecutionState`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-setthreadexecutionstate)
+	/// at the end of scope.
+	#[must_use]
+	pub const unsafe fn new(prev_state: co::EXECUTION_STATE) -> Self {
+		Self { prev_state, _not_send: PhantomData }
+	}
+
+	/// Returns the execution state before the call.
+	#[must_use]
+	pub const fn prev_state(&self) -> co::EXECUTION_STATE {
+		self.prev_state
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation for the timer resolution set by
+/// [`timeBeginPeriod`](crate::timeBeginPeriod), which automatically calls
+/// [`timeEndPeriod`](https://learn.microsoft.com/en-us/windows/win32/api/timeapi/nf-timeapi-timeendperiod)
+/// when the object goes out of scope.
+pub struct TimeEndPeriodGuard {
+	period: u32,
+}
+
+impl Drop for TimeEndPeriodGuard {
+	fn drop(&mut self) {
+		unsafe { ffi::timeEndPeriod(self.period); } // ignore errors
+	}
+}
+
+impl TimeEndPeriodGuard {
+	/// Constructs the guard by taking ownership of the period.
+	///
+	/// # Safety
+	///
+	/// Be sure the period must be ended with
+	/// [`timeEndPeriod`](https://learn.microsoft.com/en-us/windows/win32/api/timeapi/nf-timeapi-timeendperiod)
+	/// at the end of scope.
+	#[must_use]
+	pub const unsafe fn new(period: u32) -> Self {
+		Self { period }
+	}
+
+	/// Returns the timer resolution, in milliseconds.
+	#[must_use]
+	pub const fn period(&self) -> u32 {
+		self.period
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation for the multimedia timer started by
+/// [`timeSetEvent`](crate::timeSetEvent), which automatically calls
+/// [`timeKillEvent`](https://learn.microsoft.com/en-us/windows/win32/api/mmsystem/nf-mmsystem-timekillevent)
+/// when the object goes out of scope.
+///
+/// A [`TIME::ONESHOT`](crate::co::TIME::ONESHOT) timer is never killed, since
+/// its ID is released by the system once it fires, and may be reused by
+/// another timer. Instead, the closure is simply not called anymore.
+pub struct TimeKillEventGuard {
+	timer_id: u32,
+	ctx: *mut TimeEventCtx,
+}
+
+unsafe impl Send for TimeKillEventGuard {}
+
+impl Drop for TimeKillEventGuard {
+	fn drop(&mut self) {
+		if self.ctx.is_null() {
+			unsafe { ffi::timeKillEvent(self.timer_id); } // ignore errors
+			return;
+		}
+
+		match unsafe { &(*self.ctx).one_shot } {
+			None => {
+				unsafe { ffi::timeKillEvent(self.timer_id); } // ignore errors
+			},
+			Some(state) => loop {
+				match state.compare_exchange(TimeEventCtx::PENDING,
+					TimeEventCtx::CANCELLED, Ordering::AcqRel, Ordering::Acquire)
+				{
+					Ok(_) => return, // callback will free the closure when it fires
+					Err(TimeEventCtx::RUNNING) => std::thread::yield_now(),
+					Err(_) => break, // already fired
+				}
+			},
+		}
+		let _ = unsafe { Box::from_raw(self.ctx) }; // timer is done, closure can be freed
+	}
+}
+
+impl TimeKillEventGuard {
+	/// Constructs the guard by taking ownership of the timer.
+	///
+	/// # Safety
+	///
+	/// Be sure the timer must be stopped with
+	/// [`timeKillEvent`](https://learn.microsoft.com/en-us/windows/win32/api/mmsystem/nf-mmsystem-timekillevent)
+	/// at the end of scope.
+	#[must_use]
+	pub const unsafe fn new(timer_id: u32) -> Self {
+		Self { timer_id, ctx: std::ptr::null_mut() }
+	}
+
+	#[must_use]
+	pub(in crate::kernel) const unsafe fn new_with_ctx(
+		timer_id: u32,
+		ctx: *mut TimeEventCtx,
+	) -> Self
+	{
+		Self { timer_id, ctx }
+	}
+
+	/// Returns the timer identifier.
+	#[must_use]
+	pub const fn timer_id(&self) -> u32 {
+		self.timer_id
+	}
+}
+
+//------------------------------------------------------------------------------
+
 /// RAII implementation for the [`HFILE`](crate::HFILE) lock which automatically
 /// calls
 /// [`UnlockFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-lockfile)
@@ -504,3 +729,11 @@ handle_guard! { UnmapViewOfFileGuard: HFILEMAPVIEW;
 	/// [`UnmapViewOfFile`](https://learn.microsoft.com/en-us/windows/win32/api/memoryapi/nf-memoryapi-unmapviewoffile)
 	/// when the object goes out of scope.
 }
+
+handle_guard! { WaveOutCloseGuard: HWAVEOUT;
+	ffi::waveOutClose;
+	/// RAII implementation for [`HWAVEOUT`](crate::HWAVEOUT) which
+	/// automatically calls
+	/// [`waveOutClose`](https://learn.microsoft.com/en-us/windows/win32/api/mmeapi/nf-mmeapi-waveoutclose)
+	/// when the object goes out of scope.
+}
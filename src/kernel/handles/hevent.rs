@@ -123,4 +123,30 @@ pub trait kernel_Hevent: Handle {
 			wait => Ok(wait),
 		}
 	}
+
+	/// [`WaitForSingleObjectEx`](https://learn.microsoft.com/en-us/windows/win32/api/synchapi/nf-synchapi-waitforsingleobjectex)
+	/// function.
+	///
+	/// If `alertable` is `true`, returns
+	/// [`WAIT::IO_COMPLETION`](crate::co::WAIT::IO_COMPLETION) when the wait
+	/// was interrupted to run completion routines, like those of
+	/// [`HFILE::ReadFileEx`](crate::prelude::kernel_Hfile::ReadFileEx).
+	fn WaitForSingleObjectEx(&self,
+		milliseconds: Option<u32>,
+		alertable: bool,
+	) -> SysResult<co::WAIT>
+	{
+		match unsafe {
+			co::WAIT::from_raw(
+				ffi::WaitForSingleObjectEx(
+					self.ptr(),
+					milliseconds.unwrap_or(INFINITE),
+					alertable as _,
+				),
+			)
+		} {
+			co::WAIT::FAILED => Err(GetLastError()),
+			wait => Ok(wait),
+		}
+	}
 }
@@ -26,6 +26,113 @@ impl kernel_Hfile for HFILE {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Hfile: Handle {
+	/// Starts an asynchronous read with
+	/// [`ReadFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-readfile),
+	/// reading at most `buffer.len()` bytes starting at `offset`.
+	///
+	/// The file must have been open with
+	/// [`FILE_FLAG::OVERLAPPED`](crate::co::FILE_FLAG::OVERLAPPED). The buffer
+	/// and the [`Overlapped`](crate::Overlapped) remain borrowed until the
+	/// returned [`PendingIo`](crate::PendingIo) goes out of scope.
+	///
+	/// # Safety
+	///
+	/// The returned [`PendingIo`](crate::PendingIo) must not be leaked (e.g.
+	/// with [`std::mem::forget`]), because its destructor is what keeps the
+	/// system from writing to the buffer after the borrow ends.
+	///
+	/// # Examples
+	///
+	/// Reading two files concurrently:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let open = |path: &str| w::HFILE::CreateFile(
+	///     path,
+	///     co::GENERIC::READ,
+	///     Some(co::FILE_SHARE::READ),
+	///     None,
+	///     co::DISPOSITION::OPEN_EXISTING,
+	///     co::FILE_ATTRIBUTE::NORMAL,
+	///     Some(co::FILE_FLAG::OVERLAPPED),
+	///     None,
+	///     None,
+	/// ).map(|(hfile, _)| hfile);
+	///
+	/// let (hfile1, hfile2) = (open("C:\\Temp\\a.log")?, open("C:\\Temp\\b.log")?);
+	/// let (mut ov1, mut ov2) = (w::Overlapped::new()?, w::Overlapped::new()?);
+	/// let (mut buf1, mut buf2) = (vec![0u8; 65536], vec![0u8; 65536]);
+	///
+	/// let pending1 = unsafe { hfile1.read_overlapped(&mut buf1, 0, &mut ov1)? };
+	/// let pending2 = unsafe { hfile2.read_overlapped(&mut buf2, 0, &mut ov2)? };
+	///
+	/// let read1 = pending1.wait(None)?;
+	/// let read2 = pending2.wait(None)?;
+	/// drop((pending1, pending2)); // release the buffers
+	///
+	/// println!("{:?}", &buf1[..read1 as usize]);
+	/// println!("{:?}", &buf2[..read2 as usize]);
+	/// # w::SysResult::Ok(())
+	/// ```
+	unsafe fn read_overlapped<'a>(&'a self,
+		buffer: &'a mut [u8],
+		offset: u64,
+		overlapped: &'a mut Overlapped,
+	) -> SysResult<PendingIo<'a>>
+	{
+		PendingIo::start(
+			unsafe { HFILE::from_ptr(self.ptr()) },
+			overlapped,
+			offset,
+			|pov| unsafe {
+				ffi::ReadFile(
+					self.ptr(),
+					buffer.as_mut_ptr() as _,
+					buffer.len() as _,
+					std::ptr::null_mut(),
+					pov as _,
+				)
+			},
+		)
+	}
+
+	/// Starts an asynchronous write with
+	/// [`WriteFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-writefile),
+	/// writing `data` starting at `offset`.
+	///
+	/// The file must have been open with
+	/// [`FILE_FLAG::OVERLAPPED`](crate::co::FILE_FLAG::OVERLAPPED). The data
+	/// and the [`Overlapped`](crate::Overlapped) remain borrowed until the
+	/// returned [`PendingIo`](crate::PendingIo) goes out of scope.
+	///
+	/// # Safety
+	///
+	/// The returned [`PendingIo`](crate::PendingIo) must not be leaked (e.g.
+	/// with [`std::mem::forget`]), because its destructor is what keeps the
+	/// system from reading the data after the borrow ends.
+	unsafe fn write_overlapped<'a>(&'a self,
+		data: &'a [u8],
+		offset: u64,
+		overlapped: &'a mut Overlapped,
+	) -> SysResult<PendingIo<'a>>
+	{
+		PendingIo::start(
+			unsafe { HFILE::from_ptr(self.ptr()) },
+			overlapped,
+			offset,
+			|pov| unsafe {
+				ffi::WriteFile(
+					self.ptr(),
+					vec_ptr(data) as _,
+					data.len() as _,
+					std::ptr::null_mut(),
+					pov as _,
+				)
+			},
+		)
+	}
+
 	/// [`CreateFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-createfilew)
 	/// function.
 	///
@@ -259,10 +366,9 @@ pub trait kernel_Hfile: Handle {
 	/// current file pointer offset. Returns how many bytes were actually read.
 	/// The file pointer is then incremented by the number of bytes read.
 	///
-	/// Note that asynchronous reading – which use the
-	/// [`OVERLAPPED`](crate::OVERLAPPED) struct – is not currently supported by
-	/// this method, because the buffer must remain untouched until the async
-	/// operation is complete, thus making the method unsound.
+	/// For asynchronous reading, see
+	/// [`HFILE::read_overlapped`](crate::prelude::kernel_Hfile::read_overlapped)
+	/// and [`HFILE::ReadFileEx`](crate::prelude::kernel_Hfile::ReadFileEx).
 	fn ReadFile(&self, buffer: &mut [u8]) -> SysResult<u32> {
 		let mut bytes_read = u32::default();
 		bool_to_sysresult(
@@ -278,6 +384,71 @@ pub trait kernel_Hfile: Handle {
 		).map(|_| bytes_read)
 	}
 
+	/// [`ReadFileEx`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-readfileex)
+	/// function.
+	///
+	/// Starts an asynchronous read of at most `buffer.len()` bytes, starting at
+	/// `offset`. The file must have been open with
+	/// [`FILE_FLAG::OVERLAPPED`](crate::co::FILE_FLAG::OVERLAPPED).
+	///
+	/// The closure receives the number of bytes read, and it runs on the
+	/// calling thread, when it enters an alertable wait with
+	/// [`SleepEx`](crate::SleepEx) or
+	/// [`HEVENT::WaitForSingleObjectEx`](crate::prelude::kernel_Hevent::WaitForSingleObjectEx).
+	///
+	/// # Safety
+	///
+	/// The returned [`PendingIoEx`](crate::PendingIoEx) must not be leaked
+	/// (e.g. with [`std::mem::forget`]), because its destructor is what keeps
+	/// the system from writing to the buffer and calling the closure after the
+	/// borrow ends.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hfile: w::HFILE; // initialized somewhere
+	/// # let hfile = w::HFILE::NULL;
+	///
+	/// let mut buf = vec![0u8; 4096];
+	/// let pending = unsafe {
+	///     hfile.ReadFileEx(&mut buf, 0, |res| {
+	///         match res {
+	///             Ok(n) => println!("Read {} bytes.", n),
+	///             Err(e) => eprintln!("Failed: {}", e),
+	///         }
+	///     })?
+	/// };
+	///
+	/// while !pending.is_complete() {
+	///     w::SleepEx(None, true); // the closure runs here
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	unsafe fn ReadFileEx<'a, F>(&'a self,
+		buffer: &'a mut [u8],
+		offset: u64,
+		func: F,
+	) -> SysResult<PendingIoEx<'a>>
+		where F: FnOnce(SysResult<u32>) + 'a,
+	{
+		PendingIoEx::start(
+			unsafe { HFILE::from_ptr(self.ptr()) },
+			offset,
+			func,
+			|pov, routine| unsafe {
+				ffi::ReadFileEx(
+					self.ptr(),
+					buffer.as_mut_ptr() as _,
+					buffer.len() as _,
+					pov as _,
+					routine,
+				)
+			},
+		)
+	}
+
 	/// [`SetEndOfFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-setendoffile)
 	/// function.
 	fn SetEndOfFile(&self) -> SysResult<()> {
@@ -330,10 +501,9 @@ pub trait kernel_Hfile: Handle {
 	///
 	/// Returns the number of bytes written.
 	///
-	/// Note that asynchronous writing – which use the
-	/// [`OVERLAPPED`](crate::OVERLAPPED) struct – is not currently supported by
-	/// this method, because the buffer must remain untouched until the async
-	/// operation is complete, thus making the method unsound.
+	/// For asynchronous writing, see
+	/// [`HFILE::write_overlapped`](crate::prelude::kernel_Hfile::write_overlapped)
+	/// and [`HFILE::WriteFileEx`](crate::prelude::kernel_Hfile::WriteFileEx).
 	fn WriteFile(&self, data: &[u8]) -> SysResult<u32> {
 		let mut bytes_written = u32::default();
 
@@ -349,4 +519,45 @@ pub trait kernel_Hfile: Handle {
 			},
 		).map(|_| bytes_written)
 	}
+
+	/// [`WriteFileEx`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-writefileex)
+	/// function.
+	///
+	/// Starts an asynchronous write of `data`, starting at `offset`. The file
+	/// must have been open with
+	/// [`FILE_FLAG::OVERLAPPED`](crate::co::FILE_FLAG::OVERLAPPED).
+	///
+	/// The closure receives the number of bytes written, and it runs on the
+	/// calling thread, when it enters an alertable wait with
+	/// [`SleepEx`](crate::SleepEx) or
+	/// [`HEVENT::WaitForSingleObjectEx`](crate::prelude::kernel_Hevent::WaitForSingleObjectEx).
+	///
+	/// # Safety
+	///
+	/// The returned [`PendingIoEx`](crate::PendingIoEx) must not be leaked
+	/// (e.g. with [`std::mem::forget`]), because its destructor is what keeps
+	/// the system from reading the data and calling the closure after the
+	/// borrow ends.
+	unsafe fn WriteFileEx<'a, F>(&'a self,
+		data: &'a [u8],
+		offset: u64,
+		func: F,
+	) -> SysResult<PendingIoEx<'a>>
+		where F: FnOnce(SysResult<u32>) + 'a,
+	{
+		PendingIoEx::start(
+			unsafe { HFILE::from_ptr(self.ptr()) },
+			offset,
+			func,
+			|pov, routine| unsafe {
+				ffi::WriteFileEx(
+					self.ptr(),
+					vec_ptr(data) as _,
+					data.len() as _,
+					pov as _,
+					routine,
+				)
+			},
+		)
+	}
 }
@@ -60,6 +60,45 @@ pub trait kernel_Hfindfile: Handle {
 		}
 	}
 
+	/// [`FindFirstFileEx`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findfirstfileexw)
+	/// function.
+	///
+	/// This method is rather tricky, consider using
+	/// [`path::dir_entries`](crate::path::dir_entries).
+	#[must_use]
+	fn FindFirstFileEx(
+		file_name: &str,
+		info_level: co::FINDEX_INFO_LEVELS,
+		wfd: &mut WIN32_FIND_DATA,
+		search_op: co::FINDEX_SEARCH_OPS,
+		additional_flags: co::FIND_FIRST_EX,
+	) -> SysResult<(FindCloseGuard, bool)>
+	{
+		unsafe {
+			let h = HFINDFILE::from_ptr(
+				ffi::FindFirstFileExW(
+					WString::from_str(file_name).as_ptr(),
+					info_level.raw(),
+					wfd as *mut _ as _,
+					search_op.raw(),
+					std::ptr::null_mut(),
+					additional_flags.raw(),
+				),
+			);
+			if h == HFINDFILE::INVALID { // the function never returns null
+				match GetLastError() {
+					co::ERROR::FILE_NOT_FOUND => Ok((
+						FindCloseGuard::new(HFINDFILE::NULL), // not an error, first file not found
+						false,
+					)),
+					err => Err(err),
+				}
+			} else {
+				Ok((FindCloseGuard::new(h), true))
+			}
+		}
+	}
+
 	/// [`FindNextFile`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findnextfilew)
 	/// function.
 	///
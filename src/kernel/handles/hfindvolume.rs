@@ -0,0 +1,94 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::{ffi, iterators::*, privs::*};
+use crate::prelude::*;
+
+impl_handle! { HFINDVOLUME;
+	/// Handle to a
+	/// [volume search](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findfirstvolumew).
+	/// Originally just a `HANDLE`.
+}
+
+impl kernel_Hfindvolume for HFINDVOLUME {}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HFINDVOLUME`](crate::HFINDVOLUME).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hfindvolume: Handle {
+	/// Returns an iterator over the volume GUID paths of the system, like
+	/// `\\?\Volume{...}\`. Calls
+	/// [`HFINDVOLUME::FindFirstVolume`](crate::prelude::kernel_Hfindvolume::FindFirstVolume)
+	/// and then
+	/// [`HFINDVOLUME::FindNextVolume`](crate::prelude::kernel_Hfindvolume::FindNextVolume)
+	/// consecutively.
+	///
+	/// # Examples
+	///
+	/// Listing the mount points of each volume:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// for vol_name in w::HFINDVOLUME::iter_volumes() {
+	///     let vol_name = vol_name?;
+	///     let mount_points = w::GetVolumePathNamesForVolumeName(&vol_name)?;
+	///     println!("{} {:?}", vol_name, mount_points);
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn iter_volumes() -> impl Iterator<Item = SysResult<String>> {
+		HfindvolumeIter::new()
+	}
+
+	/// [`FindFirstVolume`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findfirstvolumew)
+	/// function.
+	///
+	/// Returns the search handle and the first volume GUID path.
+	///
+	/// This method is rather tricky, consider using
+	/// [`HFINDVOLUME::iter_volumes`](crate::prelude::kernel_Hfindvolume::iter_volumes).
+	#[must_use]
+	fn FindFirstVolume() -> SysResult<(FindVolumeCloseGuard, String)> {
+		let mut buf = WString::new_alloc_buf(MAX_PATH + 1);
+		let h = unsafe {
+			HFINDVOLUME::from_ptr(
+				ffi::FindFirstVolumeW(buf.as_mut_ptr(), buf.buf_len() as _),
+			)
+		};
+		if h == HFINDVOLUME::INVALID {
+			Err(GetLastError())
+		} else {
+			Ok((unsafe { FindVolumeCloseGuard::new(h) }, buf.to_string()))
+		}
+	}
+
+	/// [`FindNextVolume`](https://learn.microsoft.com/en-us/windows/win32/api/fileapi/nf-fileapi-findnextvolumew)
+	/// function.
+	///
+	/// Returns `None` if there are no further volumes.
+	///
+	/// This method is rather tricky, consider using
+	/// [`HFINDVOLUME::iter_volumes`](crate::prelude::kernel_Hfindvolume::iter_volumes).
+	#[must_use]
+	fn FindNextVolume(&self) -> SysResult<Option<String>> {
+		let mut buf = WString::new_alloc_buf(MAX_PATH + 1);
+		match unsafe {
+			ffi::FindNextVolumeW(self.ptr(), buf.as_mut_ptr(), buf.buf_len() as _)
+		} {
+			0 => match GetLastError() {
+				co::ERROR::NO_MORE_FILES => Ok(None), // not an error, no further volumes found
+				err => Err(err),
+			},
+			_ => Ok(Some(buf.to_string())),
+		}
+	}
+}
@@ -23,6 +23,60 @@ impl kernel_Hinstance for HINSTANCE {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Hinstance: Handle {
+	/// Returns the raw bytes of a resource, by calling
+	/// [`HINSTANCE::FindResource`](crate::prelude::kernel_Hinstance::FindResource),
+	/// [`HINSTANCE::LoadResource`](crate::prelude::kernel_Hinstance::LoadResource)
+	/// and
+	/// [`HINSTANCE::LockResource`](crate::prelude::kernel_Hinstance::LockResource).
+	///
+	/// The resource memory is never freed, it's valid as long as the module is
+	/// loaded – that's why the slice is bound to the `HINSTANCE`. If the module
+	/// may be unloaded, copy the data with
+	/// [`to_vec`](https://doc.rust-lang.org/std/primitive.slice.html#method.to_vec).
+	///
+	/// # Examples
+	///
+	/// Loading a JSON configuration embedded as `RCDATA`:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// const ID_CONFIG: u16 = 101;
+	///
+	/// let hinst = w::HINSTANCE::GetModuleHandle(None)?;
+	/// let bytes = hinst.resource_bytes(
+	///     w::IdStr::Id(ID_CONFIG),
+	///     w::RtStr::Rt(co::RT::RCDATA),
+	/// )?;
+	/// let json = std::str::from_utf8(bytes).unwrap();
+	/// # w::SysResult::Ok(())
+	/// ```
+	///
+	/// Loading the application manifest, which has ID 1:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hinst = w::HINSTANCE::GetModuleHandle(None)?;
+	/// let manifest = String::from_utf8_lossy(
+	///     hinst.resource_bytes(
+	///         w::IdStr::Id(1),
+	///         w::RtStr::Rt(co::RT::MANIFEST),
+	///     )?,
+	/// );
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn resource_bytes(&self,
+		resource_id: IdStr,
+		resource_type: RtStr,
+	) -> SysResult<&[u8]>
+	{
+		let hres = self.FindResource(resource_id, resource_type)?;
+		let hres_load = self.LoadResource(&hres)?;
+		self.LockResource(&hres, &hres_load)
+	}
+
 	/// [`EnumResourceLanguages`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-enumresourcelanguagesw)
 	/// function.
 	fn EnumResourceLanguages<F>(&self,
@@ -298,10 +352,10 @@ pub trait kernel_Hinstance: Handle {
 	///     w::RtStr::Rt(co::RT::DIALOG),
 	///     w::IdStr::Id(IDD_FOOT_ABOUTBOX),
 	///     w::LANGID::new(co::LANG::NEUTRAL, co::SUBLANG::NEUTRAL),
-	///     hres_slice_lock,
+	///     Some(hres_slice_lock),
 	/// )?;
 	///
-	/// // EndUpdateResource() called automatically
+	/// hres_update.commit()?; // EndUpdateResource()
 	///
 	/// // FreeLibrary() called automatically
 	/// # w::SysResult::Ok(())
@@ -0,0 +1,213 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::{ffi, privs::*};
+use crate::prelude::*;
+
+impl_handle! { HJOB;
+	/// Handle to a
+	/// [job object](https://learn.microsoft.com/en-us/windows/win32/procthread/job-objects).
+	/// Originally just a `HANDLE`.
+	///
+	/// Starting with Windows 8, a process can belong to several nested jobs;
+	/// the limits of each job in the chain apply, and the most restrictive one
+	/// prevails. On earlier versions, assigning a process which already belongs
+	/// to a job fails with
+	/// [`ERROR::ACCESS_DENIED`](crate::co::ERROR::ACCESS_DENIED).
+}
+
+impl kernel_Hjob for HJOB {}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HJOB`](crate::HJOB).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hjob: Handle {
+	/// [`AssignProcessToJobObject`](https://learn.microsoft.com/en-us/windows/win32/api/jobapi2/nf-jobapi2-assignprocesstojobobject)
+	/// function.
+	///
+	/// Child processes created afterwards by the assigned process are
+	/// automatically associated with the job, unless they break away from it.
+	fn AssignProcessToJobObject(&self, hprocess: &HPROCESS) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe { ffi::AssignProcessToJobObject(self.ptr(), hprocess.ptr()) },
+		)
+	}
+
+	/// [`CreateJobObject`](https://learn.microsoft.com/en-us/windows/win32/api/jobapi2/nf-jobapi2-createjobobjectw)
+	/// function.
+	///
+	/// # Examples
+	///
+	/// Guaranteeing that a child process is terminated when the current process
+	/// exits, even if it crashes:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hjob = w::HJOB::CreateJobObject(None, None)?;
+	///
+	/// let mut limits = w::JOBOBJECT_EXTENDED_LIMIT_INFORMATION::default();
+	/// limits.BasicLimitInformation.LimitFlags = co::JOB_OBJECT_LIMIT::KILL_ON_JOB_CLOSE;
+	/// hjob.SetInformationJobObject(&w::JobObjectInfo::ExtendedLimit(limits))?;
+	///
+	/// let pi: w::PROCESS_INFORMATION; // initialized somewhere
+	/// # let pi = w::PROCESS_INFORMATION::default();
+	/// hjob.AssignProcessToJobObject(&pi.hProcess)?;
+	///
+	/// // CloseHandle() called automatically, terminating the child process
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn CreateJobObject(
+		security_attributes: Option<&mut SECURITY_ATTRIBUTES>,
+		name: Option<&str>,
+	) -> SysResult<CloseHandleGuard<HJOB>>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::CreateJobObjectW(
+					security_attributes.map_or(std::ptr::null_mut(), |sa| sa as *const _ as _),
+					WString::from_opt_str(name).as_ptr(),
+				),
+			).map(|h| CloseHandleGuard::new(h))
+		}
+	}
+
+	/// [`QueryInformationJobObject`](https://learn.microsoft.com/en-us/windows/win32/api/jobapi2/nf-jobapi2-queryinformationjobobject)
+	/// function.
+	///
+	/// The returned [`JobObjectInfo`](crate::JobObjectInfo) variant matches
+	/// the given `information_class`.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hjob: w::HJOB; // initialized somewhere
+	/// # let hjob = w::HJOB::NULL;
+	///
+	/// if let w::JobObjectInfo::BasicAccounting(acc) = hjob.QueryInformationJobObject(
+	///     co::JOBOBJECTINFOCLASS::BasicAccountingInformation)?
+	/// {
+	///     println!("Active: {}, total: {}, user time: {} ms",
+	///         acc.ActiveProcesses, acc.TotalProcesses,
+	///         acc.TotalUserTime / 10_000);
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn QueryInformationJobObject(&self,
+		information_class: co::JOBOBJECTINFOCLASS,
+	) -> SysResult<JobObjectInfo>
+	{
+		fn query<T: Default>(
+			hjob: *mut std::ffi::c_void,
+			information_class: co::JOBOBJECTINFOCLASS,
+		) -> SysResult<T>
+		{
+			let mut info = T::default();
+			bool_to_sysresult(
+				unsafe {
+					ffi::QueryInformationJobObject(
+						hjob,
+						information_class.raw(),
+						&mut info as *mut _ as _,
+						std::mem::size_of::<T>() as _,
+						std::ptr::null_mut(),
+					)
+				},
+			).map(|_| info)
+		}
+
+		let hjob = self.ptr();
+		Ok(match information_class {
+			co::JOBOBJECTINFOCLASS::BasicAccountingInformation =>
+				JobObjectInfo::BasicAccounting(query(hjob, information_class)?),
+			co::JOBOBJECTINFOCLASS::BasicLimitInformation =>
+				JobObjectInfo::BasicLimit(query(hjob, information_class)?),
+			co::JOBOBJECTINFOCLASS::BasicUIRestrictions =>
+				JobObjectInfo::BasicUiRestrictions(query(hjob, information_class)?),
+			co::JOBOBJECTINFOCLASS::CpuRateControlInformation =>
+				JobObjectInfo::CpuRateControl(query(hjob, information_class)?),
+			co::JOBOBJECTINFOCLASS::ExtendedLimitInformation =>
+				JobObjectInfo::ExtendedLimit(query(hjob, information_class)?),
+			_ => return Err(co::ERROR::INVALID_PARAMETER),
+		})
+	}
+
+	/// [`SetInformationJobObject`](https://learn.microsoft.com/en-us/windows/win32/api/jobapi2/nf-jobapi2-setinformationjobobject)
+	/// function.
+	///
+	/// Passing [`JobObjectInfo::BasicAccounting`](crate::JobObjectInfo::BasicAccounting)
+	/// fails with [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER).
+	///
+	/// # Examples
+	///
+	/// Limiting the job to 4 processes of 512 MiB each, using at most 25% of
+	/// the processor cycles:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hjob: w::HJOB; // initialized somewhere
+	/// # let hjob = w::HJOB::NULL;
+	///
+	/// let mut limits = w::JOBOBJECT_EXTENDED_LIMIT_INFORMATION::default();
+	/// limits.BasicLimitInformation.LimitFlags = co::JOB_OBJECT_LIMIT::ACTIVE_PROCESS
+	///     | co::JOB_OBJECT_LIMIT::PROCESS_MEMORY;
+	/// limits.BasicLimitInformation.ActiveProcessLimit = 4;
+	/// limits.ProcessMemoryLimit = 512 * 1024 * 1024;
+	/// hjob.SetInformationJobObject(&w::JobObjectInfo::ExtendedLimit(limits))?;
+	///
+	/// let mut cpu = w::JOBOBJECT_CPU_RATE_CONTROL_INFORMATION::default();
+	/// cpu.ControlFlags = co::JOB_OBJECT_CPU_RATE_CONTROL::ENABLE
+	///     | co::JOB_OBJECT_CPU_RATE_CONTROL::HARD_CAP;
+	/// cpu.set_CpuRate(2500);
+	/// hjob.SetInformationJobObject(&w::JobObjectInfo::CpuRateControl(cpu))?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn SetInformationJobObject(&self, info: &JobObjectInfo) -> SysResult<()> {
+		let (information_class, ptr, len) = match info {
+			JobObjectInfo::BasicAccounting(_) => return Err(co::ERROR::INVALID_PARAMETER),
+			JobObjectInfo::BasicLimit(i) => (
+				co::JOBOBJECTINFOCLASS::BasicLimitInformation,
+				i as *const _ as *const std::ffi::c_void,
+				std::mem::size_of_val(i),
+			),
+			JobObjectInfo::BasicUiRestrictions(i) => (
+				co::JOBOBJECTINFOCLASS::BasicUIRestrictions,
+				i as *const _ as _,
+				std::mem::size_of_val(i),
+			),
+			JobObjectInfo::CpuRateControl(i) => (
+				co::JOBOBJECTINFOCLASS::CpuRateControlInformation,
+				i as *const _ as _,
+				std::mem::size_of_val(i),
+			),
+			JobObjectInfo::ExtendedLimit(i) => (
+				co::JOBOBJECTINFOCLASS::ExtendedLimitInformation,
+				i as *const _ as _,
+				std::mem::size_of_val(i),
+			),
+		};
+
+		bool_to_sysresult(
+			unsafe {
+				ffi::SetInformationJobObject(
+					self.ptr(),
+					information_class.raw(),
+					ptr,
+					len as _,
+				)
+			},
+		)
+	}
+}
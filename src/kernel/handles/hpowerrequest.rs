@@ -0,0 +1,97 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::{ffi, privs::*};
+use crate::prelude::*;
+
+impl_handle! { HPOWERREQUEST;
+	/// Handle to a
+	/// [power request](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-powercreaterequest)
+	/// object. Originally just a `HANDLE`.
+}
+
+impl kernel_Hpowerrequest for HPOWERREQUEST {}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HPOWERREQUEST`](crate::HPOWERREQUEST).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hpowerrequest: Handle {
+	/// [`PowerCreateRequest`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-powercreaterequest)
+	/// function.
+	///
+	/// The `reason` is a simple string, which is shown by `powercfg
+	/// /requests`.
+	///
+	/// # Examples
+	///
+	/// Keeping the display on while a video is playing:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hreq = w::HPOWERREQUEST::PowerCreateRequest("Playing video")?;
+	/// let _display_guard = hreq.PowerSetRequest(
+	///     co::POWER_REQUEST_TYPE::DisplayRequired)?; // keep guard alive
+	///
+	/// // PowerClearRequest() called automatically
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn PowerCreateRequest(
+		reason: &str,
+	) -> SysResult<CloseHandleGuard<HPOWERREQUEST>>
+	{
+		#[repr(C)]
+		struct REASON_CONTEXT {
+			Version: u32,
+			Flags: u32,
+			SimpleReasonString: *const u16, // union with Detailed, whose remaining fields follow
+			_LocalizedReasonId: u32,
+			_ReasonStringCount: u32,
+			_ReasonStrings: *const u16,
+		}
+
+		let wreason = WString::from_str(reason);
+		let ctx = REASON_CONTEXT {
+			Version: 0, // POWER_REQUEST_CONTEXT_VERSION
+			Flags: 0x1, // POWER_REQUEST_CONTEXT_SIMPLE_STRING
+			SimpleReasonString: wreason.as_ptr(),
+			_LocalizedReasonId: 0,
+			_ReasonStringCount: 0,
+			_ReasonStrings: std::ptr::null(),
+		};
+
+		unsafe {
+			match HPOWERREQUEST(
+				ffi::PowerCreateRequest(&ctx as *const _ as _) as _,
+			) {
+				HPOWERREQUEST::NULL | HPOWERREQUEST::INVALID => Err(GetLastError()),
+				handle => Ok(CloseHandleGuard::new(handle)),
+			}
+		}
+	}
+
+	/// [`PowerSetRequest`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-powersetrequest)
+	/// function.
+	///
+	/// Each request type can be set once per object, and it will be cleared
+	/// when the returned guard is dropped.
+	#[must_use]
+	fn PowerSetRequest(&self,
+		request_type: co::POWER_REQUEST_TYPE,
+	) -> SysResult<PowerClearRequestGuard<'_, Self>>
+	{
+		unsafe {
+			bool_to_sysresult(
+				ffi::PowerSetRequest(self.ptr(), request_type.raw()),
+			).map(|_| PowerClearRequestGuard::new(self, request_type))
+		}
+	}
+}
@@ -50,6 +50,18 @@ pub trait kernel_Hprocess: Handle {
 	{
 		let mut buf_cmd_line = WString::from_opt_str(command_line);
 		let mut pi = PROCESS_INFORMATION::default();
+		let buf_env = environment.map(|environment| {
+			WString::from_str_vec(
+				&environment.iter()
+					.map(|(name, val)| format!("{}={}", name, val))
+					.collect::<Vec<_>>()
+			)
+		});
+		let creation_flags = if buf_env.is_some() {
+			creation_flags | co::CREATE::UNICODE_ENVIRONMENT // our block is UTF-16
+		} else {
+			creation_flags
+		};
 
 		unsafe {
 			bool_to_sysresult(
@@ -60,13 +72,7 @@ pub trait kernel_Hprocess: Handle {
 					thread_attrs.map_or(std::ptr::null_mut(), |lp| lp as *mut _ as _),
 					inherit_handles as _,
 					creation_flags.raw(),
-					environment.map_or(std::ptr::null_mut(), |environment| {
-						WString::from_str_vec(
-							&environment.iter()
-								.map(|(name, val)| format!("{}={}", name, val))
-								.collect::<Vec<_>>()
-						).as_ptr() as _
-					}),
+					buf_env.as_ref().map_or(std::ptr::null_mut(), |buf| buf.as_ptr() as _),
 					WString::from_opt_str(current_dir).as_ptr(),
 					si as *mut _ as _,
 					&mut pi as *mut _ as _,
@@ -144,6 +150,37 @@ pub trait kernel_Hprocess: Handle {
 		}
 	}
 
+	/// [`GetProcessMemoryInfo`](https://learn.microsoft.com/en-us/windows/win32/api/psapi/nf-psapi-getprocessmemoryinfo)
+	/// function.
+	///
+	/// The process must have been opened with
+	/// [`co::PROCESS::QUERY_LIMITED_INFORMATION`](crate::co::PROCESS::QUERY_LIMITED_INFORMATION)
+	/// and [`co::PROCESS::VM_READ`](crate::co::PROCESS::VM_READ) access rights.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let pmc = w::HPROCESS::GetCurrentProcess().GetProcessMemoryInfo()?;
+	/// println!("Working set: {} bytes", pmc.WorkingSetSize);
+	/// println!("Private: {} bytes", pmc.PrivateUsage);
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn GetProcessMemoryInfo(&self) -> SysResult<PROCESS_MEMORY_COUNTERS_EX> {
+		let mut pmc = PROCESS_MEMORY_COUNTERS_EX::default();
+		bool_to_sysresult(
+			unsafe {
+				ffi::GetProcessMemoryInfo(
+					self.ptr(),
+					&mut pmc as *mut _ as _,
+					std::mem::size_of::<PROCESS_MEMORY_COUNTERS_EX>() as _,
+				)
+			},
+		).map(|_| pmc)
+	}
+
 	/// [`GetProcessTimes`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-getprocesstimes)
 	/// function.
 	///
@@ -163,6 +200,7 @@ pub trait kernel_Hprocess: Handle {
 	/// # let hprocess = w::HPROCESS::NULL;
 	///
 	/// let (creation, exit, kernel, user) = hprocess.GetProcessTimes()?;
+	/// println!("CPU time: {:?}", kernel.as_duration() + user.as_duration());
 	/// # w::SysResult::Ok(())
 	/// ```
 	fn GetProcessTimes(&self,
@@ -195,6 +233,24 @@ pub trait kernel_Hprocess: Handle {
 		).map(|_| critical != 0)
 	}
 
+	/// [`IsProcessInJob`](https://learn.microsoft.com/en-us/windows/win32/api/jobapi/nf-jobapi-isprocessinjob)
+	/// function.
+	///
+	/// If `job` is `None`, checks whether the process runs in any job.
+	#[must_use]
+	fn IsProcessInJob(&self, job: Option<&HJOB>) -> SysResult<bool> {
+		let mut in_job: BOOL = 0;
+		bool_to_sysresult(
+			unsafe {
+				ffi::IsProcessInJob(
+					self.ptr(),
+					job.map_or(std::ptr::null_mut(), |h| h.ptr()),
+					&mut in_job,
+				)
+			},
+		).map(|_| in_job != 0)
+	}
+
 	/// [`IsWow64Process`](https://learn.microsoft.com/en-us/windows/win32/api/wow64apiset/nf-wow64apiset-iswow64process)
 	/// function.
 	#[must_use]
@@ -206,6 +262,52 @@ pub trait kernel_Hprocess: Handle {
 		}
 	}
 
+	/// [`IsWow64Process2`](https://learn.microsoft.com/en-us/windows/win32/api/wow64apiset/nf-wow64apiset-iswow64process2)
+	/// function.
+	///
+	/// Returns the architecture of the process, which is
+	/// [`IMAGE_FILE_MACHINE::UNKNOWN`](crate::co::IMAGE_FILE_MACHINE::UNKNOWN)
+	/// if it's not running under WOW64, and the native architecture of the
+	/// machine.
+	///
+	/// The function is loaded at runtime. On Windows versions prior to 10 1511,
+	/// where it doesn't exist, returns
+	/// [`ERROR::PROC_NOT_FOUND`](crate::co::ERROR::PROC_NOT_FOUND).
+	///
+	/// # Examples
+	///
+	/// Checking whether the current process is emulated on an ARM64 machine:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let (process_machine, native_machine) =
+	///     w::HPROCESS::GetCurrentProcess().IsWow64Process2()?;
+	///
+	/// let emulated_on_arm64 = native_machine == co::IMAGE_FILE_MACHINE::ARM64
+	///     && process_machine != co::IMAGE_FILE_MACHINE::UNKNOWN;
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn IsWow64Process2(&self,
+	) -> SysResult<(co::IMAGE_FILE_MACHINE, co::IMAGE_FILE_MACHINE)>
+	{
+		let (mut process_machine, mut native_machine) = (0u16, 0u16);
+		match unsafe {
+			call_dyn("kernel32", "IsWow64Process2",
+				|f: extern "system" fn(HANDLE, *mut u16, *mut u16) -> BOOL| {
+					f(self.ptr(), &mut process_machine, &mut native_machine)
+				})
+		} {
+			Some(0) => Err(GetLastError()),
+			Some(_) => Ok(unsafe {(
+				co::IMAGE_FILE_MACHINE::from_raw(process_machine),
+				co::IMAGE_FILE_MACHINE::from_raw(native_machine),
+			)}),
+			None => Err(co::ERROR::PROC_NOT_FOUND),
+		}
+	}
+
 	/// [`OpenProcess`](https://learn.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-openprocess)
 	/// function.
 	///
@@ -107,6 +107,7 @@ pub trait kernel_Hthread: Handle {
 	/// # let hthread = w::HTHREAD::NULL;
 	///
 	/// let (creation, exit, kernel, user) = hthread.GetThreadTimes()?;
+	/// println!("CPU time: {:?}", kernel.as_duration() + user.as_duration());
 	/// # w::SysResult::Ok(())
 	/// ```
 	fn GetThreadTimes(&self,
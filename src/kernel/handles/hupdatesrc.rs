@@ -1,5 +1,6 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use crate::co;
 use crate::decl::*;
 use crate::guard::*;
 use crate::kernel::{ffi, privs::*};
@@ -22,8 +23,104 @@ impl kernel_Hupdatersrc for HUPDATERSRC {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait kernel_Hupdatersrc: Handle {
+	/// Reads an `.ico` file and writes its images as
+	/// [`RT::ICON`](crate::co::RT::ICON) resources, plus the
+	/// [`RT::GROUP_ICON`](crate::co::RT::GROUP_ICON) resource which binds them
+	/// together, with
+	/// [`HUPDATERSRC::UpdateResource`](crate::prelude::kernel_Hupdatersrc::UpdateResource).
+	///
+	/// Each image receives a sequential ID starting at `first_icon_id`. If the
+	/// existing resources were not deleted in
+	/// [`HUPDATERSRC::BeginUpdateResource`](crate::prelude::kernel_Hupdatersrc::BeginUpdateResource),
+	/// choose an ID above the existing icon IDs, otherwise they'll be
+	/// overwritten. Returns the next free icon ID.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hupd = w::HUPDATERSRC::BeginUpdateResource("app.exe", true)?;
+	///
+	/// hupd.set_icon_from_ico_file(
+	///     w::IdStr::Id(1),
+	///     w::LANGID::USER_DEFAULT,
+	///     "C:\\Temp\\app.ico",
+	///     1,
+	/// )?;
+	///
+	/// hupd.commit()?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn set_icon_from_ico_file(&self,
+		group_id: IdStr,
+		language: LANGID,
+		ico_file: &str,
+		first_icon_id: u16,
+	) -> SysResult<u16>
+	{
+		let fin = FileMapped::open(ico_file, FileAccess::ExistingReadOnly)?;
+		let ico = fin.as_slice();
+
+		let word = |off: usize| -> SysResult<u16> {
+			ico.get(off..off + 2)
+				.map(|b| u16::from_le_bytes([b[0], b[1]]))
+				.ok_or(co::ERROR::INVALID_DATA)
+		};
+		let dword = |off: usize| -> SysResult<u32> {
+			ico.get(off..off + 4)
+				.map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+				.ok_or(co::ERROR::INVALID_DATA)
+		};
+
+		if word(0)? != 0 || word(2)? != 1 { // ICONDIR idReserved and idType
+			return Err(co::ERROR::INVALID_DATA);
+		}
+		let count = word(4)?;
+
+		let mut grp = Vec::<u8>::with_capacity(6 + count as usize * 14);
+		grp.extend_from_slice(&ico[0..6]); // GRPICONDIR header is identical
+
+		for i in 0..count {
+			let entry = 6 + i as usize * 16; // ICONDIRENTRY
+			let bytes_in_res = dword(entry + 8)? as usize;
+			let image_offset = dword(entry + 12)? as usize;
+			let image = ico.get(image_offset..image_offset + bytes_in_res)
+				.ok_or(co::ERROR::INVALID_DATA)?;
+
+			let icon_id = first_icon_id.checked_add(i)
+				.ok_or(co::ERROR::INVALID_DATA)?;
+			self.UpdateResource(RtStr::Rt(co::RT::ICON),
+				IdStr::Id(icon_id), language, Some(image))?;
+
+			grp.extend_from_slice(&ico[entry..entry + 12]); // GRPICONDIRENTRY
+			grp.extend_from_slice(&icon_id.to_le_bytes());
+		}
+
+		self.UpdateResource(RtStr::Rt(co::RT::GROUP_ICON),
+			group_id, language, Some(&grp))?;
+		first_icon_id.checked_add(count).ok_or(co::ERROR::INVALID_DATA)
+	}
+
+	/// Writes an [`RT::RCDATA`](crate::co::RT::RCDATA) resource with
+	/// [`HUPDATERSRC::UpdateResource`](crate::prelude::kernel_Hupdatersrc::UpdateResource).
+	fn set_rcdata(&self,
+		resource_id: IdStr,
+		language: LANGID,
+		data: &[u8],
+	) -> SysResult<()>
+	{
+		self.UpdateResource(RtStr::Rt(co::RT::RCDATA),
+			resource_id, language, Some(data))
+	}
+
 	/// [`BeginUpdateResource`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-beginupdateresourcew)
 	/// function.
+	///
+	/// The changes are written to the file only when
+	/// [`EndUpdateResourceGuard::commit`](crate::guard::EndUpdateResourceGuard::commit)
+	/// is called; if the guard simply goes out of scope – like when an error
+	/// is returned halfway – all the changes are discarded.
 	#[must_use]
 	fn BeginUpdateResource(
 		file_name: &str,
@@ -42,11 +139,13 @@ pub trait kernel_Hupdatersrc: Handle {
 
 	/// [`UpdateResource`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-updateresourcew)
 	/// function.
+	///
+	/// If `data` is `None`, the resource is deleted.
 	fn UpdateResource(&self,
 		resource_type: RtStr,
 		resource_id: IdStr,
 		language: LANGID,
-		data: &[u8],
+		data: Option<&[u8]>,
 	) -> SysResult<()>
 	{
 		bool_to_sysresult(
@@ -56,8 +155,8 @@ pub trait kernel_Hupdatersrc: Handle {
 					resource_type.as_ptr(),
 					resource_id.as_ptr(),
 					language.into(),
-					vec_ptr(data) as _,
-					data.len() as _,
+					data.map_or(std::ptr::null(), |d| vec_ptr(d)) as _,
+					data.map_or(0, |d| d.len()) as _,
 				)
 			},
 		)
@@ -0,0 +1,151 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::{ffi, privs::*};
+use crate::prelude::*;
+
+impl_handle! { HWAVEOUT;
+	/// Handle to a
+	/// [waveform-audio output device](https://learn.microsoft.com/en-us/windows/win32/api/mmeapi/nf-mmeapi-waveoutopen).
+}
+
+impl kernel_Hwaveout for HWAVEOUT {}
+
+/// This trait is enabled with the `kernel` feature, and provides methods for
+/// [`HWAVEOUT`](crate::HWAVEOUT).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait kernel_Hwaveout: Handle {
+	/// [`waveOutGetNumDevs`](https://learn.microsoft.com/en-us/windows/win32/api/mmeapi/nf-mmeapi-waveoutgetnumdevs)
+	/// function.
+	#[must_use]
+	fn waveOutGetNumDevs() -> u32 {
+		unsafe { ffi::waveOutGetNumDevs() }
+	}
+
+	/// [`waveOutGetVolume`](https://learn.microsoft.com/en-us/windows/win32/api/mmeapi/nf-mmeapi-waveoutgetvolume)
+	/// function.
+	///
+	/// Returns the left and right channel volumes, from `0` to `0xffff`.
+	#[must_use]
+	fn waveOutGetVolume(&self) -> Result<(u16, u16), co::MMSYSERR> {
+		let mut vol = u32::default();
+		mmresult(unsafe { ffi::waveOutGetVolume(self.ptr(), &mut vol) })
+			.map(|_| (LOWORD(vol), HIWORD(vol)))
+	}
+
+	/// [`waveOutOpen`](https://learn.microsoft.com/en-us/windows/win32/api/mmeapi/nf-mmeapi-waveoutopen)
+	/// function.
+	///
+	/// If `device_id` is `None`, `WAVE_MAPPER` is used, which selects a device
+	/// capable of playing the given format.
+	///
+	/// If `hevent` is given, it will be signaled whenever a buffer written with
+	/// [`HWAVEOUT::waveOutWrite`](crate::prelude::kernel_Hwaveout::waveOutWrite)
+	/// finishes playing, so it should be an auto-reset event.
+	///
+	/// # Examples
+	///
+	/// Playing a 440 Hz tone for one second:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let fmt = w::WAVEFORMATEX::new_pcm(1, 44100, 16);
+	/// let samples = (0..44100)
+	///     .flat_map(|i| {
+	///         let t = i as f64 / 44100.0;
+	///         let s = (t * 440.0 * 2.0 * std::f64::consts::PI).sin();
+	///         ((s * 8000.0) as i16).to_le_bytes()
+	///     })
+	///     .collect::<Vec<_>>();
+	///
+	/// let hevent = w::HEVENT::CreateEvent(None, false, false, None)?;
+	/// let hwo = w::HWAVEOUT::waveOutOpen(None, &fmt, Some(&hevent))?;
+	///
+	/// let pending = hwo.waveOutWrite(samples)?;
+	/// while !pending.is_done() {
+	///     hevent.WaitForSingleObject(None)?;
+	/// }
+	///
+	/// // waveOutUnprepareHeader() and waveOutClose() called automatically
+	/// # w::AnyResult::Ok(())
+	/// ```
+	#[must_use]
+	fn waveOutOpen(
+		device_id: Option<u32>,
+		format: &WAVEFORMATEX,
+		hevent: Option<&HEVENT>,
+	) -> Result<WaveOutCloseGuard, co::MMSYSERR>
+	{
+		let mut handle = HWAVEOUT::NULL;
+		unsafe {
+			mmresult(
+				ffi::waveOutOpen(
+					handle.as_mut(),
+					device_id.unwrap_or(WAVE_MAPPER),
+					format as *const _ as _,
+					hevent.map_or(0, |h| h.ptr() as _),
+					0,
+					if hevent.is_some() { CALLBACK_EVENT } else { CALLBACK_NULL },
+				),
+			).map(|_| WaveOutCloseGuard::new(handle))
+		}
+	}
+
+	/// [`waveOutPause`](https://learn.microsoft.com/en-us/windows/win32/api/mmeapi/nf-mmeapi-waveoutpause)
+	/// function.
+	fn waveOutPause(&self) -> Result<(), co::MMSYSERR> {
+		mmresult(unsafe { ffi::waveOutPause(self.ptr()) })
+	}
+
+	/// [`waveOutReset`](https://learn.microsoft.com/en-us/windows/win32/api/mmeapi/nf-mmeapi-waveoutreset)
+	/// function.
+	///
+	/// Stops the playback, marking all pending buffers as done.
+	fn waveOutReset(&self) -> Result<(), co::MMSYSERR> {
+		mmresult(unsafe { ffi::waveOutReset(self.ptr()) })
+	}
+
+	/// [`waveOutRestart`](https://learn.microsoft.com/en-us/windows/win32/api/mmeapi/nf-mmeapi-waveoutrestart)
+	/// function.
+	fn waveOutRestart(&self) -> Result<(), co::MMSYSERR> {
+		mmresult(unsafe { ffi::waveOutRestart(self.ptr()) })
+	}
+
+	/// [`waveOutSetVolume`](https://learn.microsoft.com/en-us/windows/win32/api/mmeapi/nf-mmeapi-waveoutsetvolume)
+	/// function.
+	///
+	/// The volumes range from `0` (silence) to `0xffff` (full volume). If the
+	/// device doesn't support separate channel volumes, only `left` is used.
+	fn waveOutSetVolume(&self, left: u16, right: u16) -> Result<(), co::MMSYSERR> {
+		mmresult(
+			unsafe { ffi::waveOutSetVolume(self.ptr(), MAKEDWORD(left, right)) },
+		)
+	}
+
+	/// [`waveOutWrite`](https://learn.microsoft.com/en-us/windows/win32/api/mmeapi/nf-mmeapi-waveoutwrite)
+	/// function.
+	///
+	/// The buffer is prepared with
+	/// [`waveOutPrepareHeader`](https://learn.microsoft.com/en-us/windows/win32/api/mmeapi/nf-mmeapi-waveoutprepareheader)
+	/// and queued for playback. The data must match the format given to
+	/// [`HWAVEOUT::waveOutOpen`](crate::prelude::kernel_Hwaveout::waveOutOpen),
+	/// and it's kept by the returned [`PendingWaveOut`](crate::PendingWaveOut)
+	/// until it goes out of scope.
+	///
+	/// Successive calls queue the buffers, which are played in order.
+	#[must_use]
+	fn waveOutWrite<'a>(&'a self,
+		data: Vec<u8>,
+	) -> Result<PendingWaveOut<'a>, co::MMSYSERR>
+	{
+		PendingWaveOut::start(unsafe { HWAVEOUT::from_ptr(self.ptr()) }, data)
+	}
+}
@@ -4,16 +4,20 @@ mod hfile;
 mod hfilemap;
 mod hfilemapview;
 mod hfindfile;
+mod hfindvolume;
 mod hglobal;
 mod hheap;
 mod hinstance;
+mod hjob;
 mod hlocal;
 mod hpipe;
+mod hpowerrequest;
 mod hprocess;
 mod hprocesslist;
 mod hstd;
 mod hthread;
 mod hupdatesrc;
+mod hwaveout;
 
 pub mod decl {
 	pub use super::hevent::HEVENT;
@@ -21,16 +25,20 @@ pub mod decl {
 	pub use super::hfilemap::HFILEMAP;
 	pub use super::hfilemapview::HFILEMAPVIEW;
 	pub use super::hfindfile::HFINDFILE;
+	pub use super::hfindvolume::HFINDVOLUME;
 	pub use super::hglobal::HGLOBAL;
 	pub use super::hheap::HHEAP;
 	pub use super::hinstance::HINSTANCE;
+	pub use super::hjob::HJOB;
 	pub use super::hlocal::HLOCAL;
 	pub use super::hpipe::HPIPE;
+	pub use super::hpowerrequest::HPOWERREQUEST;
 	pub use super::hprocess::HPROCESS;
 	pub use super::hprocesslist::HPROCESSLIST;
 	pub use super::hstd::HSTD;
 	pub use super::hthread::HTHREAD;
 	pub use super::hupdatesrc::HUPDATERSRC;
+	pub use super::hwaveout::HWAVEOUT;
 
 	impl_handle! { HRSRC;
 		/// Handle to a
@@ -58,14 +66,18 @@ pub mod traits {
 	pub use super::hfilemap::kernel_Hfilemap;
 	pub use super::hfilemapview::kernel_Hfilemapview;
 	pub use super::hfindfile::kernel_Hfindfile;
+	pub use super::hfindvolume::kernel_Hfindvolume;
 	pub use super::hglobal::kernel_Hglobal;
 	pub use super::hheap::kernel_Hheap;
 	pub use super::hinstance::kernel_Hinstance;
+	pub use super::hjob::kernel_Hjob;
 	pub use super::hlocal::kernel_Hlocal;
 	pub use super::hpipe::kernel_Hpipe;
+	pub use super::hpowerrequest::kernel_Hpowerrequest;
 	pub use super::hprocess::kernel_Hprocess;
 	pub use super::hprocesslist::kernel_Hprocesslist;
 	pub use super::hstd::kernel_Hstd;
 	pub use super::hthread::kernel_Hthread;
 	pub use super::hupdatesrc::kernel_Hupdatersrc;
+	pub use super::hwaveout::kernel_Hwaveout;
 }
@@ -147,6 +147,140 @@ impl<'a> DirWalkIter<'a> {
 
 //------------------------------------------------------------------------------
 
+pub(in crate::kernel) struct DirEntriesIter {
+	dir_path: String,
+	large_fetch: bool,
+	hfind: Option<FindCloseGuard>,
+	wfd: WIN32_FIND_DATA,
+	no_more: bool,
+}
+
+impl Iterator for DirEntriesIter {
+	type Item = SysResult<DirEntry>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.no_more {
+			return None;
+		}
+
+		let res = match &self.hfind {
+			None => { // first pass
+				let dir_final = format!("{}\\*", self.long_path());
+				HFINDFILE::FindFirstFileEx(
+					&dir_final,
+					co::FINDEX_INFO_LEVELS::Basic,
+					&mut self.wfd,
+					co::FINDEX_SEARCH_OPS::NameMatch,
+					if self.large_fetch {
+						co::FIND_FIRST_EX::LARGE_FETCH
+					} else {
+						co::FIND_FIRST_EX::NoValue
+					},
+				).map(|(hfind, found)| {
+					self.hfind = Some(hfind); // store our find handle
+					found
+				})
+			},
+			Some(hfind) => hfind.FindNextFile(&mut self.wfd), // subsequent passes
+		};
+
+		match res {
+			Err(e) => {
+				self.no_more = true; // prevent further iterations
+				Some(Err(e))
+			},
+			Ok(false) => { // no file found, halt
+				self.no_more = true;
+				None
+			},
+			Ok(true) => {
+				let file_name = self.wfd.cFileName();
+				if file_name == "." || file_name == ".." { // skip these
+					self.next()
+				} else {
+					let path = format!("{}\\{}", self.dir_path, file_name);
+					Some(Ok(DirEntry::new(path, std::mem::take(&mut self.wfd))))
+				}
+			},
+		}
+	}
+}
+
+impl DirEntriesIter {
+	#[must_use]
+	pub(in crate::kernel) fn new(dir_path: String, large_fetch: bool) -> Self {
+		Self {
+			dir_path: path::rtrim_backslash(&dir_path).to_owned(),
+			large_fetch,
+			hfind: None,
+			wfd: WIN32_FIND_DATA::default(),
+			no_more: false,
+		}
+	}
+
+	/// Prefixes the path with `\\?\`, so it's not limited to `MAX_PATH`.
+	#[must_use]
+	fn long_path(&self) -> String {
+		if self.dir_path.starts_with("\\\\?\\") || self.dir_path.starts_with("\\\\.\\") {
+			return self.dir_path.clone();
+		}
+		match GetFullPathName(&self.dir_path) {
+			Ok(full) => match full.strip_prefix("\\\\") {
+				Some(unc) => format!("\\\\?\\UNC\\{}", unc),
+				None => format!("\\\\?\\{}", full),
+			},
+			Err(_) => self.dir_path.clone(), // let FindFirstFileEx report the error
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+pub(in crate::kernel) struct DirWalkEntriesIter<'a> {
+	stack: Vec<DirEntriesIter>,
+	opts: DirWalkOpts<'a>,
+}
+
+impl<'a> Iterator for DirWalkEntriesIter<'a> {
+	type Item = SysResult<DirEntry>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			let runner = self.stack.last_mut()?; // stack empty, halt
+			match runner.next() {
+				None => { self.stack.pop(); }, // directory finished, resume parent
+				Some(Err(e)) => {
+					self.stack.pop(); // the directory can't be read, but the walk goes on
+					return Some(Err(e));
+				},
+				Some(Ok(entry)) => {
+					if entry.is_directory()
+						&& (entry.reparse_tag().is_none() || self.opts.follow_reparse_points)
+						&& self.opts.filter.as_mut().map_or(true, |f| f(&entry))
+					{
+						self.stack.push(
+							DirEntriesIter::new(entry.path().to_owned(), self.opts.large_fetch),
+						);
+					}
+					return Some(Ok(entry));
+				},
+			}
+		}
+	}
+}
+
+impl<'a> DirWalkEntriesIter<'a> {
+	#[must_use]
+	pub(in crate::kernel) fn new(dir_path: String, opts: DirWalkOpts<'a>) -> Self {
+		Self {
+			stack: vec![DirEntriesIter::new(dir_path, opts.large_fetch)],
+			opts,
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
 pub(in crate::kernel) struct HheapHeapwalkIter<'a, H>
 	where H: kernel_Hheap,
 {
@@ -445,3 +579,51 @@ impl<'a, H> HprocesslistThreadIter<'a, H>
 		}
 	}
 }
+
+//------------------------------------------------------------------------------
+
+pub(in crate::kernel) struct HfindvolumeIter {
+	hfind: Option<FindVolumeCloseGuard>,
+	no_more: bool,
+}
+
+impl Iterator for HfindvolumeIter {
+	type Item = SysResult<String>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.no_more {
+			return None;
+		}
+
+		let found = match &self.hfind {
+			None => HFINDVOLUME::FindFirstVolume() // first pass
+				.map(|(hfind, vol_name)| {
+					self.hfind = Some(hfind); // store our find handle
+					Some(vol_name)
+				}),
+			Some(hfind) => hfind.FindNextVolume(), // subsequent passes
+		};
+
+		match found {
+			Err(e) => {
+				self.no_more = true; // prevent further iterations
+				Some(Err(e))
+			},
+			Ok(Some(vol_name)) => Some(Ok(vol_name)),
+			Ok(None) => {
+				self.no_more = true; // no volume found, halt
+				None
+			},
+		}
+	}
+}
+
+impl HfindvolumeIter {
+	#[must_use]
+	pub(in crate::kernel) const fn new() -> Self {
+		Self {
+			hfind: None,
+			no_more: false,
+		}
+	}
+}
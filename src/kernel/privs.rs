@@ -1,10 +1,21 @@
 #![allow(dead_code, non_snake_case)]
 
+use std::collections::HashMap;
+use std::sync::atomic::AtomicU8;
+use std::sync::{Mutex, OnceLock};
+
 use crate::co;
 use crate::decl::*;
 use crate::kernel::ffi_types::*;
 use crate::prelude::*;
 
+pub(crate) const CALLBACK_EVENT: u32 = 0x0005_0000;
+pub(crate) const CALLBACK_NULL: u32 = 0x0000_0000;
+pub(crate) const FILE_ACTION_ADDED: u32 = 0x0000_0001;
+pub(crate) const FILE_ACTION_REMOVED: u32 = 0x0000_0002;
+pub(crate) const FILE_ACTION_MODIFIED: u32 = 0x0000_0003;
+pub(crate) const FILE_ACTION_RENAMED_OLD_NAME: u32 = 0x0000_0004;
+pub(crate) const FILE_ACTION_RENAMED_NEW_NAME: u32 = 0x0000_0005;
 pub(crate) const GMEM_INVALID_HANDLE: u32 = 0x8000;
 pub(crate) const INFINITE: u32 = 0xffff_ffff;
 pub(crate) const INVALID_FILE_ATTRIBUTES: i32 = -1;
@@ -14,6 +25,57 @@ pub(crate) const MAX_MODULE_NAME32: usize = 255;
 pub(crate) const MAX_PATH: usize = 260;
 pub(crate) const SECURITY_SQOS_PRESENT: u32 = 0x0010_0000;
 pub(crate) const SSO_LEN: usize = 20; // defines WString SSO stack buffer size
+pub(crate) const TIME_CALLBACK_FUNCTION: u32 = 0x0000;
+pub(crate) const TIME_KILL_SYNCHRONOUS: u32 = 0x0100;
+pub(crate) const WAIT_OBJECT_0: u32 = 0x0000_0000;
+pub(crate) const WAVE_MAPPER: u32 = 0xffff_ffff;
+pub(crate) const WHDR_DONE: u32 = 0x0000_0001;
+
+/// Closure passed to [`timeSetEvent`](crate::timeSetEvent), shared between
+/// [`TimeKillEventGuard`](crate::guard::TimeKillEventGuard) and the timer
+/// callback.
+pub(in crate::kernel) struct TimeEventCtx {
+	pub(in crate::kernel) func: Box<dyn FnMut() + Send>,
+	/// State of a one-shot timer, `None` if periodic.
+	pub(in crate::kernel) one_shot: Option<AtomicU8>,
+}
+
+impl TimeEventCtx {
+	pub(in crate::kernel) const PENDING: u8 = 0;
+	pub(in crate::kernel) const RUNNING: u8 = 1;
+	pub(in crate::kernel) const DONE: u8 = 2;
+	pub(in crate::kernel) const CANCELLED: u8 = 3;
+}
+
+/// Loads a function from the DLL at runtime, and calls it, if present.
+///
+/// Used with functions which don't exist in older Windows versions, because a
+/// link-time import would prevent the executable from loading there.
+///
+/// The function address – or its absence – is cached after the first lookup,
+/// and the DLL is kept loaded, so the address remains valid.
+pub(crate) unsafe fn call_dyn<F: Copy, R>(
+	dll: &'static str,
+	proc_name: &'static str,
+	call: impl FnOnce(F) -> R,
+) -> Option<R>
+{
+	static PROCS: OnceLock<Mutex<HashMap<(&str, &str), usize>>> = OnceLock::new();
+
+	let proc = *PROCS.get_or_init(|| Mutex::new(HashMap::new()))
+		.lock().unwrap()
+		.entry((dll, proc_name))
+		.or_insert_with(|| {
+			HINSTANCE::LoadLibrary(dll).ok()
+				.and_then(|mut hlib| hlib.leak().GetProcAddress(proc_name).ok())
+				.map_or(0, |proc| proc as usize)
+		});
+
+	match proc {
+		0 => None,
+		proc => Some(call(std::mem::transmute_copy(&proc))),
+	}
+}
 
 /// [`IS_INTRESOURCE`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-is_intresource)
 /// macro.
@@ -78,6 +140,15 @@ pub(crate) const fn error_to_sysresult(lstatus: i32) -> SysResult<()> {
 	}
 }
 
+/// If value is `MMSYSERR::NOERROR`, yields `Ok(())`, otherwise `Err(err)`.
+#[must_use]
+pub(crate) const fn mmresult(mmresult: u32) -> Result<(), co::MMSYSERR> {
+	match unsafe { co::MMSYSERR::from_raw(mmresult) } {
+		co::MMSYSERR::NOERROR => Ok(()),
+		err => Err(err),
+	}
+}
+
 /// If value is -1, yields `Err(GetLastError())`, otherwise `Ok(dword)`.
 #[must_use]
 pub(crate) fn minus1_as_error(dword: u32) -> SysResult<u32> {
@@ -119,6 +190,56 @@ pub(crate) fn parse_multi_z_str(src: *const u16) -> Vec<String> {
 	strings
 }
 
+/// Parses a null-delimited multi-string within the given slice, stopping at
+/// two nulls or at the end of the slice.
+#[must_use]
+pub(crate) fn parse_multi_z_slice(src: &[u16]) -> Vec<String> {
+	src.split(|ch| *ch == 0)
+		.take_while(|s| !s.is_empty())
+		.map(|s| WString::from_wchars_slice(s).to_string())
+		.collect()
+}
+
+/// Parses an environment block – a null-delimited multi-string of `key=value`
+/// pairs. Keys may start with `=`, like the per-drive current directories.
+#[must_use]
+pub(crate) fn parse_env_block(src: *const u16) -> Vec<(String, String)> {
+	parse_multi_z_str(src)
+		.iter()
+		.map(|env_str| {
+			match env_str.char_indices().skip(1).find(|(_, ch)| *ch == '=') {
+				Some((idx, _)) => (env_str[..idx].to_owned(), env_str[idx + 1..].to_owned()),
+				None => (env_str.clone(), String::new()),
+			}
+		})
+		.collect()
+}
+
+/// Calls a function which fills a string buffer and returns the number of
+/// chars copied, without the terminating null, or the needed buffer size if
+/// the buffer is too small. The buffer is grown until the string fits.
+///
+/// A zero return is an error, unless the last error is
+/// [`ERROR::SUCCESS`](crate::co::ERROR::SUCCESS), which means an empty string.
+pub(crate) fn sized_string_call(
+	mut func: impl FnMut(*mut u16, u32) -> u32,
+) -> SysResult<String>
+{
+	let mut buf_sz = MAX_PATH as u32 + 1;
+	loop {
+		let mut buf = WString::new_alloc_buf(buf_sz as _);
+		SetLastError(co::ERROR::SUCCESS);
+		match func(unsafe { buf.as_mut_ptr() }, buf_sz) {
+			0 => return match GetLastError() {
+				co::ERROR::SUCCESS => Ok(String::new()),
+				err => Err(err),
+			},
+			n if n < buf_sz => return Ok(buf.to_string()),
+			n => buf_sz = n, // buffer too small, try again with the needed size
+		}
+	}
+}
+
 /// If the vector is empty, returns null, otherwise calls `as_ptr`.
 ///
 /// This is necessary because an empty vector returns garbage as its underlying
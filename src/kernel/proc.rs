@@ -1,5 +1,7 @@
+use std::sync::atomic::Ordering;
+
 use crate::decl::*;
-use crate::kernel::ffi_types::*;
+use crate::kernel::{ffi_types::*, privs::*};
 
 pub(in crate::kernel) extern "system" fn hinstance_enum_resource_languages<F>(
 	_: HINSTANCE,
@@ -36,3 +38,26 @@ pub(in crate::kernel) extern "system" fn hinstance_enum_resource_types<F>(
 	let func = unsafe { &mut *(lparam as *mut F) };
 	func(unsafe { RtStr::from_ptr(resource_type) }) as _
 }
+
+pub(in crate::kernel) extern "system" fn time_set_event_proc(
+	_: u32,
+	_: u32,
+	dw_user: usize,
+	_: usize,
+	_: usize,
+) {
+	let ctx = dw_user as *mut TimeEventCtx;
+	match unsafe { &(*ctx).one_shot } {
+		None => unsafe { ((*ctx).func)() },
+		Some(state) => {
+			if state.compare_exchange(TimeEventCtx::PENDING, TimeEventCtx::RUNNING,
+				Ordering::AcqRel, Ordering::Acquire).is_ok()
+			{
+				unsafe { ((*ctx).func)() };
+				state.store(TimeEventCtx::DONE, Ordering::Release); // guard may free ctx now
+			} else {
+				let _ = unsafe { Box::from_raw(ctx) }; // guard is gone, free the closure
+			}
+		},
+	}
+}
@@ -204,6 +204,27 @@ pub struct FILETIME {
 	pub dwHighDateTime: u32,
 }
 
+impl FILETIME {
+	/// Returns the value as a number of 100-nanosecond intervals.
+	#[must_use]
+	pub const fn as_u64(&self) -> u64 {
+		MAKEQWORD(self.dwLowDateTime, self.dwHighDateTime)
+	}
+
+	/// Converts the value to a [`Duration`](std::time::Duration), which is
+	/// meaningful for time intervals, like the kernel and user times returned
+	/// by [`HPROCESS::GetProcessTimes`](crate::prelude::kernel_Hprocess::GetProcessTimes)
+	/// and [`HTHREAD::GetThreadTimes`](crate::prelude::kernel_Hthread::GetThreadTimes).
+	#[must_use]
+	pub const fn as_duration(&self) -> std::time::Duration {
+		let intervals = self.as_u64();
+		std::time::Duration::new(
+			intervals / 10_000_000,
+			(intervals % 10_000_000) as u32 * 100,
+		)
+	}
+}
+
 /// [`HEAPLIST32`](https://learn.microsoft.com/en-us/windows/win32/api/tlhelp32/ns-tlhelp32-heaplist32)
 /// struct.
 #[repr(C)]
@@ -216,6 +237,127 @@ pub struct HEAPLIST32 {
 
 impl_default_with_size!(HEAPLIST32, dwSize);
 
+/// [`IO_COUNTERS`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-io_counters)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+pub struct IO_COUNTERS {
+	pub ReadOperationCount: u64,
+	pub WriteOperationCount: u64,
+	pub OtherOperationCount: u64,
+	pub ReadTransferCount: u64,
+	pub WriteTransferCount: u64,
+	pub OtherTransferCount: u64,
+}
+
+/// [`JOBOBJECT_BASIC_ACCOUNTING_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-jobobject_basic_accounting_information)
+/// struct.
+///
+/// The times are counted in 100-nanosecond intervals.
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+pub struct JOBOBJECT_BASIC_ACCOUNTING_INFORMATION {
+	pub TotalUserTime: i64,
+	pub TotalKernelTime: i64,
+	pub ThisPeriodTotalUserTime: i64,
+	pub ThisPeriodTotalKernelTime: i64,
+	pub TotalPageFaultCount: u32,
+	pub TotalProcesses: u32,
+	pub ActiveProcesses: u32,
+	pub TotalTerminatedProcesses: u32,
+}
+
+/// [`JOBOBJECT_BASIC_LIMIT_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-jobobject_basic_limit_information)
+/// struct.
+///
+/// Only the limits whose flags are set in `LimitFlags` are taken into
+/// account.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct JOBOBJECT_BASIC_LIMIT_INFORMATION {
+	pub PerProcessUserTimeLimit: i64,
+	pub PerJobUserTimeLimit: i64,
+	pub LimitFlags: co::JOB_OBJECT_LIMIT,
+	pub MinimumWorkingSetSize: usize,
+	pub MaximumWorkingSetSize: usize,
+	pub ActiveProcessLimit: u32,
+	pub Affinity: usize,
+	pub PriorityClass: co::PRIORITY_CLASS,
+	pub SchedulingClass: u32,
+}
+
+impl_default!(JOBOBJECT_BASIC_LIMIT_INFORMATION);
+
+/// [`JOBOBJECT_BASIC_UI_RESTRICTIONS`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-jobobject_basic_ui_restrictions)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+pub struct JOBOBJECT_BASIC_UI_RESTRICTIONS {
+	pub UIRestrictionsClass: co::JOB_OBJECT_UILIMIT,
+}
+
+/// [`JOBOBJECT_CPU_RATE_CONTROL_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-jobobject_cpu_rate_control_information)
+/// struct.
+///
+/// Which union field is meaningful depends on `ControlFlags`.
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+pub struct JOBOBJECT_CPU_RATE_CONTROL_INFORMATION {
+	pub ControlFlags: co::JOB_OBJECT_CPU_RATE_CONTROL,
+	union0: u32,
+}
+
+impl JOBOBJECT_CPU_RATE_CONTROL_INFORMATION {
+	/// Returns the `CpuRate` union field, the portion of processor cycles in
+	/// hundredths of a percent – `10000` means 100%.
+	#[must_use]
+	pub const fn CpuRate(&self) -> u32 {
+		self.union0
+	}
+
+	/// Sets the `CpuRate` union field.
+	pub fn set_CpuRate(&mut self, val: u32) {
+		self.union0 = val;
+	}
+
+	/// Returns the `Weight` union field, from `1` to `9`, used with
+	/// [`JOB_OBJECT_CPU_RATE_CONTROL::WEIGHT_BASED`](crate::co::JOB_OBJECT_CPU_RATE_CONTROL::WEIGHT_BASED).
+	#[must_use]
+	pub const fn Weight(&self) -> u32 {
+		self.union0
+	}
+
+	/// Sets the `Weight` union field.
+	pub fn set_Weight(&mut self, val: u32) {
+		self.union0 = val;
+	}
+
+	/// Returns the `MinRate` and `MaxRate` union fields, used with
+	/// [`JOB_OBJECT_CPU_RATE_CONTROL::MIN_MAX_RATE`](crate::co::JOB_OBJECT_CPU_RATE_CONTROL::MIN_MAX_RATE).
+	#[must_use]
+	pub const fn MinMaxRate(&self) -> (u16, u16) {
+		(LOWORD(self.union0), HIWORD(self.union0))
+	}
+
+	/// Sets the `MinRate` and `MaxRate` union fields.
+	pub fn set_MinMaxRate(&mut self, min_rate: u16, max_rate: u16) {
+		self.union0 = MAKEDWORD(min_rate, max_rate);
+	}
+}
+
+/// [`JOBOBJECT_EXTENDED_LIMIT_INFORMATION`](https://learn.microsoft.com/en-us/windows/win32/api/winnt/ns-winnt-jobobject_extended_limit_information)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy)]
+pub struct JOBOBJECT_EXTENDED_LIMIT_INFORMATION {
+	pub BasicLimitInformation: JOBOBJECT_BASIC_LIMIT_INFORMATION,
+	pub IoInfo: IO_COUNTERS,
+	pub ProcessMemoryLimit: usize,
+	pub JobMemoryLimit: usize,
+	pub PeakProcessMemoryUsed: usize,
+	pub PeakJobMemoryUsed: usize,
+}
+
 /// [`LANGID`](https://learn.microsoft.com/en-us/windows/win32/intl/language-identifiers)
 /// language identifier.
 #[repr(transparent)]
@@ -421,12 +563,29 @@ impl OSVERSIONINFOEX {
 pub struct OVERLAPPED {
 	pub Internal: usize,
 	pub InternalHigh: usize,
-	pub Pointer: usize,
+	pub Offset: u32,
+	pub OffsetHigh: u32,
 	pub hEvent: HEVENT,
 }
 
 impl_default!(OVERLAPPED);
 
+impl OVERLAPPED {
+	/// Returns the `Pointer` field, which is part of an union with `Offset`
+	/// and `OffsetHigh`.
+	#[must_use]
+	pub const fn Pointer(&self) -> usize {
+		(self.Offset as u64 | (self.OffsetHigh as u64) << 32) as _
+	}
+
+	/// Sets the `Pointer` field, which is part of an union with `Offset` and
+	/// `OffsetHigh`.
+	pub fn set_Pointer(&mut self, val: usize) {
+		self.Offset = val as u32;
+		self.OffsetHigh = (val as u64 >> 32) as u32;
+	}
+}
+
 /// [`POWERBROADCAST_SETTING`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-powerbroadcast_setting)
 /// struct.
 #[allow(dead_code)] // used by wm::PowerBroadcast in user
@@ -586,6 +745,25 @@ pub struct PROCESS_INFORMATION {
 
 impl_default!(PROCESS_INFORMATION);
 
+/// [`PROCESS_MEMORY_COUNTERS_EX`](https://learn.microsoft.com/en-us/windows/win32/api/psapi/ns-psapi-process_memory_counters_ex)
+/// struct.
+#[repr(C)]
+pub struct PROCESS_MEMORY_COUNTERS_EX {
+	cb: u32,
+	pub PageFaultCount: u32,
+	pub PeakWorkingSetSize: usize,
+	pub WorkingSetSize: usize,
+	pub QuotaPeakPagedPoolUsage: usize,
+	pub QuotaPagedPoolUsage: usize,
+	pub QuotaPeakNonPagedPoolUsage: usize,
+	pub QuotaNonPagedPoolUsage: usize,
+	pub PagefileUsage: usize,
+	pub PeakPagefileUsage: usize,
+	pub PrivateUsage: usize,
+}
+
+impl_default_with_size!(PROCESS_MEMORY_COUNTERS_EX, cb);
+
 /// [`PROCESSENTRY32`](https://learn.microsoft.com/en-us/windows/win32/api/tlhelp32/ns-tlhelp32-processentry32w)
 /// struct.
 #[repr(C)]
@@ -717,6 +895,19 @@ pub struct SYSTEM_INFO {
 
 impl_default!(SYSTEM_INFO);
 
+/// [`SYSTEM_POWER_STATUS`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/ns-winbase-system_power_status)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, PartialEq, Eq)]
+pub struct SYSTEM_POWER_STATUS {
+	pub ACLineStatus: u8,
+	pub BatteryFlag: u8,
+	pub BatteryLifePercent: u8,
+	pub SystemStatusFlag: u8,
+	pub BatteryLifeTime: u32,
+	pub BatteryFullLifeTime: u32,
+}
+
 /// [`SYSTEMTIME`](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-systemtime)
 /// struct.
 ///
@@ -769,6 +960,55 @@ impl TIME_ZONE_INFORMATION {
 	pub_fn_string_arr_get_set!(daylightName, set_daylightName);
 }
 
+/// [`TIMECAPS`](https://learn.microsoft.com/en-us/windows/win32/api/timeapi/ns-timeapi-timecaps)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct TIMECAPS {
+	pub wPeriodMin: u32,
+	pub wPeriodMax: u32,
+}
+
+/// [`WAVEFORMATEX`](https://learn.microsoft.com/en-us/windows/win32/api/mmeapi/ns-mmeapi-waveformatex)
+/// struct.
+#[repr(C, packed(2))]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct WAVEFORMATEX {
+	pub wFormatTag: co::WAVE_FORMAT,
+	pub nChannels: u16,
+	pub nSamplesPerSec: u32,
+	pub nAvgBytesPerSec: u32,
+	pub nBlockAlign: u16,
+	pub wBitsPerSample: u16,
+	pub cbSize: u16,
+}
+
+impl WAVEFORMATEX {
+	/// Creates a `WAVEFORMATEX` for uncompressed
+	/// [`WAVE_FORMAT::PCM`](crate::co::WAVE_FORMAT::PCM) audio, calculating
+	/// `nBlockAlign` and `nAvgBytesPerSec` from the given values.
+	///
+	/// `bits_per_sample` is usually `8` or `16`.
+	#[must_use]
+	pub const fn new_pcm(
+		channels: u16,
+		samples_per_sec: u32,
+		bits_per_sample: u16,
+	) -> Self
+	{
+		let block_align = channels * bits_per_sample / 8;
+		Self {
+			wFormatTag: co::WAVE_FORMAT::PCM,
+			nChannels: channels,
+			nSamplesPerSec: samples_per_sec,
+			nAvgBytesPerSec: samples_per_sec * block_align as u32,
+			nBlockAlign: block_align,
+			wBitsPerSample: bits_per_sample,
+			cbSize: 0,
+		}
+	}
+}
+
 /// [`WIN32_FIND_DATA`](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-win32_find_dataw)
 /// struct.
 #[repr(C)]
@@ -796,4 +1036,12 @@ impl WIN32_FIND_DATA {
 	pub const fn nFileSize(&self) -> u64 {
 		MAKEQWORD(self.nFileSizeLow, self.nFileSizeHigh)
 	}
+
+	/// Returns the dwReserved0 field, which holds the reparse point tag if
+	/// `dwFileAttributes` has
+	/// [`FILE_ATTRIBUTE::REPARSE_POINT`](crate::co::FILE_ATTRIBUTE::REPARSE_POINT).
+	#[must_use]
+	pub const fn dwReserved0(&self) -> u32 {
+		self.dwReserved0
+	}
 }
@@ -0,0 +1,58 @@
+/// An error code along with a message describing the operation which failed,
+/// returned by the methods of
+/// [`ResultContext`](crate::prelude::ResultContext).
+///
+/// The `Display` output shows the message followed by the error itself, like
+/// `Sending LVM_GETITEM: [1400] Invalid window handle.`. The original error is
+/// also exposed through [`Error::source`](std::error::Error::source).
+#[derive(Clone, PartialEq, Eq)]
+pub struct ContextError<E> {
+	err: E,
+	context: String,
+}
+
+impl<E: std::fmt::Display> std::fmt::Display for ContextError<E> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}: {}", self.context, self.err)
+	}
+}
+impl<E: std::fmt::Debug> std::fmt::Debug for ContextError<E> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}: {:?}", self.context, self.err)
+	}
+}
+
+impl<E> std::error::Error for ContextError<E>
+	where E: std::error::Error + 'static,
+{
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		Some(&self.err)
+	}
+}
+
+impl<E> ContextError<E> {
+	/// Creates a new `ContextError`.
+	#[must_use]
+	pub fn new(err: E, context: impl Into<String>) -> Self {
+		Self { err, context: context.into() }
+	}
+
+	/// Returns the message describing the failed operation.
+	#[must_use]
+	pub fn context(&self) -> &str {
+		&self.context
+	}
+
+	/// Returns the original error, like an [`ERROR`](crate::co::ERROR) or an
+	/// `HRESULT`.
+	#[must_use]
+	pub const fn error(&self) -> &E {
+		&self.err
+	}
+
+	/// Discards the message, returning the original error.
+	#[must_use]
+	pub fn into_error(self) -> E {
+		self.err
+	}
+}
@@ -0,0 +1,117 @@
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// A file or directory found by
+/// [`path::dir_entries`](crate::path::dir_entries) or
+/// [`path::dir_walk_entries`](crate::path::dir_walk_entries).
+///
+/// All the information comes from the
+/// [`WIN32_FIND_DATA`](crate::WIN32_FIND_DATA) returned by the search itself,
+/// so no further system calls are made.
+pub struct DirEntry {
+	path: String,
+	wfd: WIN32_FIND_DATA,
+}
+
+impl DirEntry {
+	pub(in crate::kernel) const fn new(path: String, wfd: WIN32_FIND_DATA) -> Self {
+		Self { path, wfd }
+	}
+
+	/// Returns the attributes.
+	#[must_use]
+	pub const fn attributes(&self) -> co::FILE_ATTRIBUTE {
+		self.wfd.dwFileAttributes
+	}
+
+	/// Returns the creation time.
+	#[must_use]
+	pub const fn creation_time(&self) -> FILETIME {
+		self.wfd.ftCreationTime
+	}
+
+	/// Returns the underlying [`WIN32_FIND_DATA`](crate::WIN32_FIND_DATA).
+	#[must_use]
+	pub const fn find_data(&self) -> &WIN32_FIND_DATA {
+		&self.wfd
+	}
+
+	/// Returns `true` if the entry is a directory.
+	#[must_use]
+	pub fn is_directory(&self) -> bool {
+		self.wfd.dwFileAttributes.has(co::FILE_ATTRIBUTE::DIRECTORY)
+	}
+
+	/// Returns the last access time.
+	#[must_use]
+	pub const fn last_access_time(&self) -> FILETIME {
+		self.wfd.ftLastAccessTime
+	}
+
+	/// Returns the last write time.
+	#[must_use]
+	pub const fn last_write_time(&self) -> FILETIME {
+		self.wfd.tLastWriteTime
+	}
+
+	/// Returns the file name, without the path.
+	#[must_use]
+	pub fn name(&self) -> String {
+		self.wfd.cFileName()
+	}
+
+	/// Returns the full path, starting with the path passed to the search
+	/// function.
+	#[must_use]
+	pub fn path(&self) -> &str {
+		&self.path
+	}
+
+	/// Returns the reparse point tag, or `None` if the entry is not a reparse
+	/// point – like a symbolic link or a junction.
+	#[must_use]
+	pub fn reparse_tag(&self) -> Option<u32> {
+		if self.wfd.dwFileAttributes.has(co::FILE_ATTRIBUTE::REPARSE_POINT) {
+			Some(self.wfd.dwReserved0())
+		} else {
+			None
+		}
+	}
+
+	/// Returns the file size, in bytes.
+	#[must_use]
+	pub const fn size(&self) -> u64 {
+		self.wfd.nFileSize()
+	}
+}
+
+/// Options for [`path::dir_walk_entries`](crate::path::dir_walk_entries).
+pub struct DirWalkOpts<'a> {
+	/// Whether to descend into directories which are reparse points, like
+	/// symbolic links and junctions. Enabling it may cause infinite cycles.
+	///
+	/// Defaults to `false`.
+	pub follow_reparse_points: bool,
+	/// Whether to use
+	/// [`FIND_FIRST_EX::LARGE_FETCH`](crate::co::FIND_FIRST_EX::LARGE_FETCH),
+	/// which uses a larger buffer for the searches, improving performance.
+	///
+	/// Defaults to `true`.
+	pub large_fetch: bool,
+	/// Called for each directory found; if it returns `false`, the directory
+	/// is still yielded, but its contents are not.
+	///
+	/// Defaults to `None`.
+	pub filter: Option<Box<dyn FnMut(&DirEntry) -> bool + 'a>>,
+}
+
+impl<'a> Default for DirWalkOpts<'a> {
+	fn default() -> Self {
+		Self {
+			follow_reparse_points: false,
+			large_fetch: true,
+			filter: None,
+		}
+	}
+}
@@ -0,0 +1,321 @@
+use std::collections::VecDeque;
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::{ffi, privs::*};
+use crate::prelude::*;
+
+/// Watches a directory for changes with
+/// [`ReadDirectoryChangesW`](https://learn.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-readdirectorychangesw),
+/// delivering them as [`DirChange`](crate::DirChange) events.
+///
+/// The changes are recorded by the system since the watcher is created, even
+/// while no event is being waited for. If they come faster than they can be
+/// read, a [`DirChange::Overflow`](crate::DirChange::Overflow) is delivered,
+/// and the directory must be rescanned.
+///
+/// The events can be read in three ways:
+///
+/// * blocking, with [`next_event`](crate::DirectoryWatcher::next_event);
+/// * blocking, as an endless [`Iterator`](std::iter::Iterator);
+/// * in a background thread, with [`spawn`](crate::DirectoryWatcher::spawn).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut watcher = w::DirectoryWatcher::new(
+///     "C:\\Temp",
+///     true,
+///     co::FILE_NOTIFY_CHANGE::FILE_NAME | co::FILE_NOTIFY_CHANGE::LAST_WRITE,
+///     None,
+/// )?;
+///
+/// for change in &mut watcher {
+///     match change? {
+///         w::DirChange::Modified(path) => println!("Modified: {}", path),
+///         w::DirChange::Overflow => println!("Too many changes, rescan."),
+///         _ => {},
+///     }
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+pub struct DirectoryWatcher {
+	dir_path: String,
+	hdir: CloseHandleGuard<HFILE>,
+	hevent: CloseHandleGuard<HEVENT>,
+	ov: Box<OVERLAPPED>, // must have a stable address while the read is pending
+	buf: Vec<u32>, // records are DWORD-aligned
+	watch_subtree: bool,
+	filter: co::FILE_NOTIFY_CHANGE,
+	armed: bool,
+	queue: VecDeque<DirChange>,
+}
+
+impl Drop for DirectoryWatcher {
+	fn drop(&mut self) {
+		if self.armed {
+			unsafe {
+				let pov = &mut *self.ov as *mut _ as _;
+				ffi::CancelIoEx(self.hdir.ptr(), pov); // ignore errors
+				let mut bytes = u32::default();
+				ffi::GetOverlappedResult(self.hdir.ptr(), pov, &mut bytes, 1);
+			}
+		}
+	}
+}
+
+impl Iterator for DirectoryWatcher {
+	type Item = SysResult<DirChange>;
+
+	/// Blocks until the next event, never returning `None`.
+	fn next(&mut self) -> Option<Self::Item> {
+		Some(self.next_event(None))
+	}
+}
+
+impl DirectoryWatcher {
+	/// Opens the directory and starts recording the changes.
+	///
+	/// If `watch_subtree`, changes in all subdirectories are also reported.
+	/// The `buffer_size`, in bytes, defaults to 64 KiB, which is also the
+	/// maximum for directories on network shares.
+	#[must_use]
+	pub fn new(
+		dir_path: &str,
+		watch_subtree: bool,
+		filter: co::FILE_NOTIFY_CHANGE,
+		buffer_size: Option<usize>,
+	) -> SysResult<Self>
+	{
+		let (hdir, _) = HFILE::CreateFile(
+			dir_path,
+			co::GENERIC::READ,
+			Some(co::FILE_SHARE::READ | co::FILE_SHARE::WRITE | co::FILE_SHARE::DELETE),
+			None,
+			co::DISPOSITION::OPEN_EXISTING,
+			co::FILE_ATTRIBUTE::NORMAL,
+			Some(co::FILE_FLAG::BACKUP_SEMANTICS | co::FILE_FLAG::OVERLAPPED),
+			None,
+			None,
+		)?;
+		let hevent = HEVENT::CreateEvent(None, true, false, None)?;
+		let mut ov = Box::new(OVERLAPPED::default());
+		ov.hEvent = unsafe { hevent.raw_copy() };
+
+		let mut new_self = Self {
+			dir_path: path::rtrim_backslash(dir_path).to_owned(),
+			hdir,
+			hevent,
+			ov,
+			buf: vec![0; (buffer_size.unwrap_or(64 * 1024) + 3) / 4],
+			watch_subtree,
+			filter,
+			armed: false,
+			queue: VecDeque::new(),
+		};
+		new_self.arm()?; // changes are recorded only after the first call
+		Ok(new_self)
+	}
+
+	/// Returns the next change, blocking until it happens.
+	///
+	/// If the timeout elapses, returns
+	/// [`ERROR::WAIT_TIMEOUT`](crate::co::ERROR::WAIT_TIMEOUT); no change is
+	/// lost, and the method can be called again.
+	pub fn next_event(&mut self,
+		milliseconds: Option<u32>,
+	) -> SysResult<DirChange>
+	{
+		loop {
+			if let Some(change) = self.queue.pop_front() {
+				return Ok(change);
+			}
+			if !self.armed {
+				self.arm()?;
+			}
+			self.collect(milliseconds.unwrap_or(INFINITE))?;
+		}
+	}
+
+	/// Moves the watcher into a new thread, which calls the closure for each
+	/// change, until the returned
+	/// [`DirectoryWatcherThread`](crate::DirectoryWatcherThread) goes out of
+	/// scope.
+	///
+	/// To handle the changes in a window created with the `gui` module, call
+	/// `run_ui_thread` from within the closure.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let watcher = w::DirectoryWatcher::new(
+	///     "C:\\Temp",
+	///     false,
+	///     co::FILE_NOTIFY_CHANGE::LAST_WRITE,
+	///     None,
+	/// )?;
+	///
+	/// let _thread = watcher.spawn(|change| {
+	///     if let Ok(w::DirChange::Modified(path)) = change {
+	///         println!("Reload {}", path);
+	///     }
+	/// })?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	pub fn spawn<F>(mut self, func: F) -> SysResult<DirectoryWatcherThread>
+		where F: FnMut(SysResult<DirChange>) + Send + 'static,
+	{
+		let hstop = HEVENT::CreateEvent(None, true, false, None)?;
+		let hstop_thread = unsafe { hstop.raw_copy() };
+		let mut func = func;
+
+		let thread = std::thread::spawn(move || {
+			while let Some(res) = self.next_event_or_stop(&hstop_thread) {
+				func(res);
+			}
+		});
+		Ok(DirectoryWatcherThread { hstop, thread: Some(thread) })
+	}
+
+	fn next_event_or_stop(&mut self,
+		hstop: &HEVENT,
+	) -> Option<SysResult<DirChange>>
+	{
+		loop {
+			if let Some(change) = self.queue.pop_front() {
+				return Some(Ok(change));
+			}
+			if !self.armed {
+				if let Err(e) = self.arm() {
+					return Some(Err(e));
+				}
+			}
+
+			let handles = [self.hevent.ptr(), hstop.ptr()];
+			match unsafe {
+				ffi::WaitForMultipleObjects(2, handles.as_ptr(), 0, INFINITE)
+			} {
+				WAIT_OBJECT_0 => if let Err(e) = self.collect(0) {
+					return Some(Err(e));
+				},
+				_ => return None, // stop requested, or the wait failed
+			}
+		}
+	}
+
+	fn arm(&mut self) -> SysResult<()> {
+		self.ov.Internal = 0;
+		self.ov.InternalHigh = 0;
+		bool_to_sysresult(
+			unsafe {
+				ffi::ReadDirectoryChangesW(
+					self.hdir.ptr(),
+					self.buf.as_mut_ptr() as _,
+					(self.buf.len() * std::mem::size_of::<u32>()) as _,
+					self.watch_subtree as _,
+					self.filter.raw(),
+					std::ptr::null_mut(),
+					&mut *self.ov as *mut _ as _,
+					std::ptr::null_mut(),
+				)
+			},
+		).map(|_| self.armed = true)
+	}
+
+	/// Waits for the pending read, then decodes the records into the queue
+	/// and starts the next read.
+	fn collect(&mut self, milliseconds: u32) -> SysResult<()> {
+		let mut bytes = u32::default();
+		if unsafe {
+			ffi::GetOverlappedResultEx(self.hdir.ptr(),
+				&mut *self.ov as *mut _ as _, &mut bytes, milliseconds, 0)
+		} == 0 {
+			match GetLastError() {
+				co::ERROR::IO_INCOMPLETE
+					| co::ERROR::WAIT_TIMEOUT => return Err(co::ERROR::WAIT_TIMEOUT), // still pending
+				co::ERROR::NOTIFY_ENUM_DIR => {
+					self.armed = false;
+					self.queue.push_back(DirChange::Overflow);
+					return Ok(());
+				},
+				err => {
+					self.armed = false;
+					return Err(err);
+				},
+			}
+		}
+
+		self.armed = false;
+		if bytes == 0 {
+			self.queue.push_back(DirChange::Overflow); // buffer too small
+		} else {
+			self.decode(bytes as _);
+		}
+		self.arm()
+	}
+
+	/// Parses the chained FILE_NOTIFY_INFORMATION records.
+	fn decode(&mut self, num_bytes: usize) {
+		let data = unsafe {
+			std::slice::from_raw_parts(self.buf.as_ptr() as *const u8, num_bytes)
+		};
+		let read_u32 = |off: usize| u32::from_ne_bytes(data[off..off + 4].try_into().unwrap());
+
+		let mut off = 0;
+		loop {
+			let next_entry_offset = read_u32(off) as usize;
+			let action = read_u32(off + 4);
+			let name_len = read_u32(off + 8) as usize; // in bytes
+			let name = data[off + 12..off + 12 + name_len]
+				.chunks_exact(2)
+				.map(|ch| u16::from_ne_bytes([ch[0], ch[1]]))
+				.collect::<Vec<_>>();
+			let path = format!("{}\\{}",
+				self.dir_path, WString::from_wchars_slice(&name).to_string());
+
+			if let Some(change) = match action {
+				FILE_ACTION_ADDED => Some(DirChange::Added(path)),
+				FILE_ACTION_REMOVED => Some(DirChange::Removed(path)),
+				FILE_ACTION_MODIFIED => Some(DirChange::Modified(path)),
+				FILE_ACTION_RENAMED_OLD_NAME => Some(DirChange::RenamedOld(path)),
+				FILE_ACTION_RENAMED_NEW_NAME => Some(DirChange::RenamedNew(path)),
+				_ => None, // unknown action, ignore
+			} {
+				self.queue.push_back(change);
+			}
+
+			if next_entry_offset == 0 {
+				break;
+			}
+			off += next_entry_offset;
+		}
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// A [`DirectoryWatcher`](crate::DirectoryWatcher) running in a background
+/// thread, returned by
+/// [`DirectoryWatcher::spawn`](crate::DirectoryWatcher::spawn).
+///
+/// When the object goes out of scope, the thread is stopped, and the
+/// destructor blocks until it finishes – including any closure call in
+/// progress.
+pub struct DirectoryWatcherThread {
+	hstop: CloseHandleGuard<HEVENT>,
+	thread: Option<std::thread::JoinHandle<()>>,
+}
+
+impl Drop for DirectoryWatcherThread {
+	fn drop(&mut self) {
+		let _ = self.hstop.SetEvent(); // ignore errors
+		if let Some(thread) = self.thread.take() {
+			let _ = thread.join(); // a panic in the closure is not propagated
+		}
+	}
+}
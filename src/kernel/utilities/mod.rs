@@ -1,11 +1,23 @@
+mod context_error;
+mod dir_entry;
+mod directory_watcher;
 mod encoding;
 mod file_mapped;
 mod file;
+mod overlapped;
+mod pending_wave_out;
+mod stopwatch;
 mod w_string;
 
 pub mod path;
 
+pub use context_error::ContextError;
+pub use dir_entry::{DirEntry, DirWalkOpts};
+pub use directory_watcher::{DirectoryWatcher, DirectoryWatcherThread};
 pub use encoding::Encoding;
 pub use file_mapped::FileMapped;
 pub use file::{File, FileAccess};
+pub use overlapped::{Overlapped, PendingIo, PendingIoEx};
+pub use pending_wave_out::PendingWaveOut;
+pub use stopwatch::Stopwatch;
 pub use w_string::WString;
@@ -0,0 +1,234 @@
+use std::marker::PhantomData;
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::{ffi, ffi_types::*, privs::*};
+use crate::prelude::*;
+
+/// Owns an [`OVERLAPPED`](crate::OVERLAPPED) struct and a manual-reset
+/// [`HEVENT`](crate::HEVENT), which are used in the asynchronous operations
+/// started by
+/// [`HFILE::read_overlapped`](crate::prelude::kernel_Hfile::read_overlapped)
+/// and
+/// [`HFILE::write_overlapped`](crate::prelude::kernel_Hfile::write_overlapped).
+///
+/// The object can be reused by successive operations, one at a time.
+pub struct Overlapped {
+	ov: OVERLAPPED,
+	hevent: CloseHandleGuard<HEVENT>,
+}
+
+impl Overlapped {
+	/// Creates the internal event with
+	/// [`HEVENT::CreateEvent`](crate::prelude::kernel_Hevent::CreateEvent).
+	#[must_use]
+	pub fn new() -> SysResult<Self> {
+		let hevent = HEVENT::CreateEvent(None, true, false, None)?;
+		let mut ov = OVERLAPPED::default();
+		ov.hEvent = unsafe { hevent.raw_copy() };
+		Ok(Self { ov, hevent })
+	}
+
+	/// Returns the event which is signaled when the operation is complete. It
+	/// can be used to wait on several operations at once.
+	#[must_use]
+	pub fn hevent(&self) -> &HEVENT {
+		&self.hevent
+	}
+
+	fn prepare(&mut self, offset: u64) -> *mut OVERLAPPED {
+		self.ov.Internal = 0;
+		self.ov.InternalHigh = 0;
+		self.ov.Offset = offset as u32;
+		self.ov.OffsetHigh = (offset >> 32) as u32;
+		&mut self.ov
+	}
+}
+
+//------------------------------------------------------------------------------
+
+/// An asynchronous operation started by
+/// [`HFILE::read_overlapped`](crate::prelude::kernel_Hfile::read_overlapped)
+/// or
+/// [`HFILE::write_overlapped`](crate::prelude::kernel_Hfile::write_overlapped).
+///
+/// The file, the buffer and the [`Overlapped`](crate::Overlapped) remain
+/// borrowed while this object is alive. If it goes out of scope while the
+/// operation is still pending, the operation is cancelled with
+/// [`CancelIoEx`](https://learn.microsoft.com/en-us/windows/win32/fileio/cancelioex-func),
+/// and the destructor blocks until the cancellation is complete.
+pub struct PendingIo<'a> {
+	hfile: HFILE,
+	ov: &'a mut Overlapped,
+	_data: PhantomData<&'a mut [u8]>,
+}
+
+impl<'a> Drop for PendingIo<'a> {
+	fn drop(&mut self) {
+		if let Ok(None) = self.get_result() {
+			unsafe {
+				ffi::CancelIoEx(self.hfile.ptr(), &mut self.ov.ov as *mut _ as _); // ignore errors
+				let mut bytes = u32::default();
+				ffi::GetOverlappedResult(self.hfile.ptr(),
+					&mut self.ov.ov as *mut _ as _, &mut bytes, 1);
+			}
+		}
+	}
+}
+
+impl<'a> PendingIo<'a> {
+	pub(in crate::kernel) fn start(
+		hfile: HFILE,
+		ov: &'a mut Overlapped,
+		offset: u64,
+		op: impl FnOnce(*mut OVERLAPPED) -> BOOL,
+	) -> SysResult<Self>
+	{
+		if op(ov.prepare(offset)) == 0 {
+			match GetLastError() {
+				co::ERROR::IO_PENDING => {},
+				err => return Err(err), // the operation was not started
+			}
+		}
+		Ok(Self { hfile, ov, _data: PhantomData })
+	}
+
+	/// Cancels the operation with
+	/// [`CancelIoEx`](https://learn.microsoft.com/en-us/windows/win32/fileio/cancelioex-func).
+	///
+	/// The cancellation is asynchronous; call
+	/// [`wait`](crate::PendingIo::wait) to wait for it, which then returns
+	/// [`ERROR::OPERATION_ABORTED`](crate::co::ERROR::OPERATION_ABORTED).
+	pub fn cancel(&mut self) -> SysResult<()> {
+		bool_to_sysresult(
+			unsafe {
+				ffi::CancelIoEx(self.hfile.ptr(), &mut self.ov.ov as *mut _ as _)
+			},
+		)
+	}
+
+	/// Checks the operation with
+	/// [`GetOverlappedResultEx`](https://learn.microsoft.com/en-us/windows/win32/api/ioapiset/nf-ioapiset-getoverlappedresultex),
+	/// without blocking.
+	///
+	/// Returns the number of bytes transferred, or `None` if the operation is
+	/// still pending.
+	#[must_use]
+	pub fn get_result(&self) -> SysResult<Option<u32>> {
+		match self.overlapped_result(0) {
+			Ok(bytes) => Ok(Some(bytes)),
+			Err(co::ERROR::IO_INCOMPLETE) => Ok(None),
+			Err(err) => Err(err),
+		}
+	}
+
+	/// Waits for the operation with
+	/// [`GetOverlappedResultEx`](https://learn.microsoft.com/en-us/windows/win32/api/ioapiset/nf-ioapiset-getoverlappedresultex),
+	/// returning the number of bytes transferred.
+	///
+	/// If the timeout elapses, returns
+	/// [`ERROR::WAIT_TIMEOUT`](crate::co::ERROR::WAIT_TIMEOUT). When reading
+	/// past the end of the file, returns
+	/// [`ERROR::HANDLE_EOF`](crate::co::ERROR::HANDLE_EOF).
+	pub fn wait(&self, milliseconds: Option<u32>) -> SysResult<u32> {
+		self.overlapped_result(milliseconds.unwrap_or(INFINITE))
+	}
+
+	fn overlapped_result(&self, milliseconds: u32) -> SysResult<u32> {
+		let mut bytes = u32::default();
+		bool_to_sysresult(
+			unsafe {
+				ffi::GetOverlappedResultEx(
+					self.hfile.ptr(),
+					&self.ov.ov as *const _ as _,
+					&mut bytes,
+					milliseconds,
+					0,
+				)
+			},
+		).map(|_| bytes)
+	}
+}
+
+//------------------------------------------------------------------------------
+
+#[repr(C)]
+struct IoExCtx<'a> {
+	ov: OVERLAPPED, // must be the first field, so the pointers are the same
+	func: Option<Box<dyn FnOnce(SysResult<u32>) + 'a>>,
+}
+
+/// An asynchronous operation started by
+/// [`HFILE::ReadFileEx`](crate::prelude::kernel_Hfile::ReadFileEx) or
+/// [`HFILE::WriteFileEx`](crate::prelude::kernel_Hfile::WriteFileEx), whose
+/// completion routine runs when the calling thread enters an alertable wait,
+/// like [`SleepEx`](crate::SleepEx) or
+/// [`HEVENT::WaitForSingleObjectEx`](crate::prelude::kernel_Hevent::WaitForSingleObjectEx).
+///
+/// The file and the buffer remain borrowed while this object is alive. If it
+/// goes out of scope before the completion routine runs, the operation is
+/// cancelled with
+/// [`CancelIoEx`](https://learn.microsoft.com/en-us/windows/win32/fileio/cancelioex-func),
+/// and the destructor performs alertable waits until the routine runs –
+/// which will receive
+/// [`ERROR::OPERATION_ABORTED`](crate::co::ERROR::OPERATION_ABORTED).
+pub struct PendingIoEx<'a> {
+	hfile: HFILE,
+	ctx: *mut IoExCtx<'a>,
+	_data: PhantomData<&'a mut [u8]>,
+}
+
+impl<'a> Drop for PendingIoEx<'a> {
+	fn drop(&mut self) {
+		if !self.is_complete() {
+			unsafe { ffi::CancelIoEx(self.hfile.ptr(), self.ctx as _); } // ignore errors
+			while !self.is_complete() {
+				unsafe { ffi::SleepEx(INFINITE, 1); }
+			}
+		}
+		let _ = unsafe { Box::from_raw(self.ctx) };
+	}
+}
+
+impl<'a> PendingIoEx<'a> {
+	pub(in crate::kernel) fn start<F>(
+		hfile: HFILE,
+		offset: u64,
+		func: F,
+		op: impl FnOnce(*mut OVERLAPPED, PFUNC) -> BOOL,
+	) -> SysResult<Self>
+		where F: FnOnce(SysResult<u32>) + 'a,
+	{
+		let mut ov = OVERLAPPED::default();
+		ov.Offset = offset as u32;
+		ov.OffsetHigh = (offset >> 32) as u32;
+
+		let ctx = Box::into_raw(Box::new(IoExCtx { ov, func: Some(Box::new(func)) }));
+		if op(ctx as _, io_completion_routine as _) == 0 {
+			let _ = unsafe { Box::from_raw(ctx) }; // routine won't be called
+			return Err(GetLastError());
+		}
+		Ok(Self { hfile, ctx, _data: PhantomData })
+	}
+
+	/// Returns `true` if the completion routine has already run.
+	#[must_use]
+	pub fn is_complete(&self) -> bool {
+		unsafe { (*self.ctx).func.is_none() }
+	}
+}
+
+extern "system" fn io_completion_routine(
+	error_code: u32,
+	bytes_transferred: u32,
+	overlapped: *mut OVERLAPPED,
+) {
+	let ctx = unsafe { &mut *(overlapped as *mut IoExCtx) };
+	if let Some(func) = ctx.func.take() {
+		func(match error_code {
+			0 => Ok(bytes_transferred),
+			err => Err(unsafe { co::ERROR::from_raw(err) }),
+		});
+	}
+}
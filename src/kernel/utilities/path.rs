@@ -8,6 +8,34 @@ use crate::decl::*;
 use crate::kernel::iterators::*;
 use crate::prelude::*;
 
+/// Returns an iterator over the files and folders within a directory, with
+/// all the information returned by the search.
+///
+/// This is a high-level abstraction over
+/// [`HFINDFILE::FindFirstFileEx`](crate::prelude::kernel_Hfindfile::FindFirstFileEx),
+/// using [`FIND_FIRST_EX::LARGE_FETCH`](crate::co::FIND_FIRST_EX::LARGE_FETCH).
+/// Long paths are handled transparently, by internally prefixing them with
+/// `\\?\`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// for entry in w::path::dir_entries("C:\\Temp") {
+///     let entry = entry?;
+///     println!("{} - {} bytes", entry.name(), entry.size());
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn dir_entries(
+	dir_path: &str,
+) -> impl Iterator<Item = SysResult<DirEntry>>
+{
+	DirEntriesIter::new(dir_path.to_owned(), true)
+}
+
 /// Returns an iterator over the files and folders within a directory.
 /// Optionally, a wildcard can be specified to filter files by name.
 ///
@@ -82,6 +110,51 @@ pub fn dir_walk<'a>(
 	DirWalkIter::new(dir_path.to_owned())
 }
 
+/// Returns an iterator over the files and folders within a directory, and all
+/// its subdirectories, recursively, with all the information returned by the
+/// search. Each directory is yielded before its contents.
+///
+/// Unlike [`dir_walk`](crate::path::dir_walk), if a subdirectory cannot be
+/// read, the error is yielded and the walk goes on.
+///
+/// This is a high-level abstraction over
+/// [`HFINDFILE::FindFirstFileEx`](crate::prelude::kernel_Hfindfile::FindFirstFileEx).
+/// Long paths are handled transparently, by internally prefixing them with
+/// `\\?\`.
+///
+/// # Examples
+///
+/// Summing the sizes of all files, skipping `.git` directories:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let mut total = 0u64;
+///
+/// for entry in w::path::dir_walk_entries(
+///     "C:\\Projects",
+///     w::DirWalkOpts {
+///         filter: Some(Box::new(|dir| dir.name() != ".git")),
+///         ..Default::default()
+///     },
+/// ) {
+///     match entry {
+///         Ok(entry) => total += entry.size(),
+///         Err(e) => println!("Error: {}", e),
+///     }
+/// }
+///
+/// println!("Total: {} bytes", total);
+/// ```
+#[must_use]
+pub fn dir_walk_entries<'a>(
+	dir_path: &str,
+	opts: DirWalkOpts<'a>,
+) -> impl Iterator<Item = SysResult<DirEntry>> + 'a
+{
+	DirWalkEntriesIter::new(dir_path.to_owned(), opts)
+}
+
 /// Returns a new string with the path of the current EXE file, without the EXE
 /// filename, and without a trailing backslash.
 ///
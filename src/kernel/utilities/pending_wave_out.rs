@@ -0,0 +1,90 @@
+#![allow(non_snake_case)]
+
+use std::marker::PhantomData;
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::{ffi, privs::*};
+use crate::prelude::*;
+
+#[repr(C)]
+struct WAVEHDR {
+	lpData: *mut u8,
+	dwBufferLength: u32,
+	dwBytesRecorded: u32,
+	dwUser: usize,
+	dwFlags: u32,
+	dwLoops: u32,
+	lpNext: *mut WAVEHDR,
+	reserved: usize,
+}
+
+/// A buffer queued for playback by
+/// [`HWAVEOUT::waveOutWrite`](crate::prelude::kernel_Hwaveout::waveOutWrite).
+///
+/// The audio data is owned by this object, and the device remains borrowed
+/// while it's alive. When it goes out of scope, the buffer is released with
+/// [`waveOutUnprepareHeader`](https://learn.microsoft.com/en-us/windows/win32/api/mmeapi/nf-mmeapi-waveoutunprepareheader).
+/// If it's still playing at that moment, the device is stopped with
+/// [`waveOutReset`](crate::prelude::kernel_Hwaveout::waveOutReset) – which
+/// also marks all other pending buffers as done.
+pub struct PendingWaveOut<'a> {
+	hwo: HWAVEOUT,
+	hdr: Box<WAVEHDR>, // the driver keeps the pointer, so it must not move
+	_data: Box<[u8]>, // pointed by the header, must not move either
+	_hwo: PhantomData<&'a HWAVEOUT>,
+}
+
+impl<'a> Drop for PendingWaveOut<'a> {
+	fn drop(&mut self) {
+		unsafe {
+			if !self.is_done() {
+				ffi::waveOutReset(self.hwo.ptr()); // ignore errors
+			}
+			ffi::waveOutUnprepareHeader( // ignore errors
+				self.hwo.ptr(),
+				self.hdr.as_mut() as *mut _ as _,
+				std::mem::size_of::<WAVEHDR>() as _,
+			);
+		}
+	}
+}
+
+impl<'a> PendingWaveOut<'a> {
+	pub(in crate::kernel) fn start(
+		hwo: HWAVEOUT,
+		data: Vec<u8>,
+	) -> Result<Self, co::MMSYSERR>
+	{
+		let mut data = data.into_boxed_slice();
+		let mut hdr = Box::new(WAVEHDR {
+			lpData: data.as_mut_ptr(),
+			dwBufferLength: data.len() as _,
+			dwBytesRecorded: 0,
+			dwUser: 0,
+			dwFlags: 0,
+			dwLoops: 0,
+			lpNext: std::ptr::null_mut(),
+			reserved: 0,
+		});
+		let phdr = hdr.as_mut() as *mut WAVEHDR;
+		let hdr_size = std::mem::size_of::<WAVEHDR>() as u32;
+
+		unsafe {
+			mmresult(ffi::waveOutPrepareHeader(hwo.ptr(), phdr as _, hdr_size))?;
+			if let Err(e) = mmresult(ffi::waveOutWrite(hwo.ptr(), phdr as _, hdr_size)) {
+				ffi::waveOutUnprepareHeader(hwo.ptr(), phdr as _, hdr_size); // ignore errors
+				return Err(e);
+			}
+		}
+		Ok(Self { hwo, hdr, _data: data, _hwo: PhantomData })
+	}
+
+	/// Returns `true` if the buffer has finished playing, or if the device was
+	/// reset.
+	#[must_use]
+	pub fn is_done(&self) -> bool {
+		let flags = unsafe { std::ptr::read_volatile(&self.hdr.dwFlags) }; // written by the driver
+		flags & WHDR_DONE != 0
+	}
+}
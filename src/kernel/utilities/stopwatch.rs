@@ -0,0 +1,79 @@
+use std::time::Duration;
+
+use crate::kernel::ffi;
+
+/// Measures elapsed time with
+/// [`QueryPerformanceCounter`](crate::QueryPerformanceCounter), the highest
+/// resolution timer available.
+///
+/// Durations are computed from the integer counter values, so there is no
+/// floating point drift, no matter how long the stopwatch runs.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe as w;
+///
+/// let mut sw = w::Stopwatch::start();
+/// // load the data...
+/// println!("Loading: {:?}", sw.lap());
+/// // render the UI...
+/// println!("Rendering: {:?}", sw.lap());
+/// println!("Total: {:?}", sw.elapsed());
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Stopwatch {
+	freq: i64,
+	t0: i64,
+	last_lap: i64,
+}
+
+impl Stopwatch {
+	/// Creates a new stopwatch, already running.
+	#[must_use]
+	pub fn start() -> Self {
+		let mut freq = i64::default();
+		unsafe { ffi::QueryPerformanceFrequency(&mut freq); } // never fails since Windows XP
+		let t0 = Self::now();
+		Self { freq, t0, last_lap: t0 }
+	}
+
+	/// Returns the time elapsed since the stopwatch was started or restarted.
+	#[must_use]
+	pub fn elapsed(&self) -> Duration {
+		self.as_duration(Self::now() - self.t0)
+	}
+
+	/// Returns the time elapsed since the previous call to `lap`, or since the
+	/// stopwatch was started or restarted.
+	pub fn lap(&mut self) -> Duration {
+		let now = Self::now();
+		let lap = self.as_duration(now - self.last_lap);
+		self.last_lap = now;
+		lap
+	}
+
+	/// Restarts the stopwatch, returning the time elapsed until now.
+	pub fn restart(&mut self) -> Duration {
+		let now = Self::now();
+		let elapsed = self.as_duration(now - self.t0);
+		self.t0 = now;
+		self.last_lap = now;
+		elapsed
+	}
+
+	fn now() -> i64 {
+		let mut count = i64::default();
+		unsafe { ffi::QueryPerformanceCounter(&mut count); } // never fails since Windows XP
+		count
+	}
+
+	fn as_duration(&self, ticks: i64) -> Duration {
+		let ticks = ticks.max(0) as u64;
+		let freq = self.freq as u64;
+		Duration::new(
+			ticks / freq,
+			((ticks % freq) as u128 * 1_000_000_000 / freq as u128) as u32,
+		)
+	}
+}
@@ -57,6 +57,33 @@ mod decl {
 }
 pub use decl::*;
 
+#[cfg(feature = "user")]
+pub mod clipboard {
+	//! High-level clipboard utilities.
+	//!
+	//! These functions open the clipboard, perform the operation and close it
+	//! right away, taking care of the ownership of the memory blocks handed to
+	//! the system. For finer control, use
+	//! [`HWND::OpenClipboard`](crate::prelude::user_Hwnd::OpenClipboard) and
+	//! the raw clipboard functions.
+
+	pub use super::user::clipboard::*;
+	#[cfg(feature = "gdi")] pub use super::gdi::clipboard::*;
+}
+
+#[cfg(feature = "user")]
+pub mod spi {
+	//! Typed wrappers to
+	//! [`SystemParametersInfo`](crate::SystemParametersInfo), for the most
+	//! common system settings.
+	//!
+	//! Useful to match custom controls to the system look and behavior, without
+	//! dealing with the raw data of each action.
+
+	pub use super::user::spi::*;
+	#[cfg(feature = "gdi")] pub use super::gdi::spi::*;
+}
+
 #[cfg(feature = "kernel")]
 pub mod co {
 	//! Native constants.
@@ -96,6 +123,7 @@ pub mod guard {
 
 	#[cfg(feature = "advapi")] pub use super::advapi::guard::*;
 	#[cfg(feature = "comctl")] pub use super::comctl::guard::*;
+	#[cfg(feature = "dshow")] pub use super::dshow::guard::*;
 	#[cfg(feature = "gdi")] pub use super::gdi::guard::*;
 	#[cfg(feature = "kernel")] pub use super::kernel::guard::*;
 	#[cfg(feature = "mf")] pub use super::mf::guard::*;
@@ -106,6 +134,28 @@ pub mod guard {
 	#[cfg(feature = "version")] pub use super::version::guard::*;
 }
 
+#[cfg(feature = "user")]
+pub mod hook {
+	//! Hooks which run closures, installed with
+	//! [`HHOOK::SetWindowsHookEx`](crate::prelude::user_Hhook::SetWindowsHookEx)
+	//! and
+	//! [`HWINEVENTHOOK::SetWinEventHook`](crate::prelude::user_Hwineventhook::SetWinEventHook),
+	//! and removed automatically.
+
+	pub use super::user::hook::*;
+}
+
+#[cfg(feature = "user")]
+pub mod input {
+	//! Keyboard and mouse input synthesis, built upon
+	//! [`SendInput`](crate::SendInput).
+	//!
+	//! The events are described with [`InputEvent`](crate::InputEvent), which
+	//! takes care of the flags, scan codes and coordinate normalization.
+
+	pub use super::user::input::*;
+}
+
 #[cfg(feature = "user")]
 pub mod msg {
 	#![doc = include_str!("msg.md")]
@@ -0,0 +1,26 @@
+/// Writes a formatted message to the debugger output window with
+/// [`OutputDebugString`](crate::OutputDebugString), prefixed with the source
+/// file and line. In release builds, nothing is written.
+///
+/// This macro is enabled with the `kernel` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::debug_output;
+///
+/// let count = 42;
+/// debug_output!("Loaded {} items.", count);
+/// // src\main.rs(4): Loaded 42 items.
+/// ```
+#[cfg(feature = "kernel")]
+#[macro_export]
+macro_rules! debug_output {
+	($($arg:tt)*) => {
+		if cfg!(debug_assertions) {
+			$crate::OutputDebugString(
+				&format!("{}({}): {}\n", file!(), line!(), format_args!($($arg)*)),
+			);
+		}
+	};
+}
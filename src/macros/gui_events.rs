@@ -90,6 +90,24 @@ macro_rules! pub_fn_wm_withparm_coret {
 	};
 }
 
+/// Ordinary window message, with parameters, returns i32.
+macro_rules! pub_fn_wm_withparm_i32ret {
+	(
+		$name:ident, $wmconst:expr, $parm:ty;
+		$( #[$doc:meta] )*
+	) => {
+		$( #[$doc] )*
+		pub fn $name<F>(&self, func: F)
+			where F: Fn($parm) -> AnyResult<i32> + 'static,
+		{
+			self.wm($wmconst, move |p| {
+				let ret_val = func(<$parm>::from_generic_wm(p))? as isize;
+				Ok(crate::gui::WmRet::HandledWithRet(ret_val))
+			});
+		}
+	};
+}
+
 /// WM_CTLCOLOR* message.
 macro_rules! pub_fn_wm_ctlcolor {
 	(
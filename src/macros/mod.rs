@@ -4,6 +4,7 @@
 
 #[macro_use] mod com;
 #[macro_use] mod consts;
+#[macro_use] mod debug_output;
 #[macro_use] mod ffis;
 #[macro_use] mod gui_events;
 #[macro_use] mod handles;
@@ -27,11 +27,12 @@ const_no_debug_display! { HRESULT: u32;
 	/// Will print:
 	///
 	/// ```text
-	/// [0x80070057 2147942487] The parameter is incorrect.
+	/// [0x80070057] The parameter is incorrect.
 	/// ```
 	///
-	/// The numbers inside the brackets are the system error code, in
-	/// hexadecimal and decimal formats for convenience.
+	/// The number inside the brackets is the COM error code, in hexadecimal.
+	/// The `Debug` output shows it in decimal format too:
+	/// `HRESULT(0x80070057 2147942487)`.
 	///
 	/// An [`ERROR`](crate::co::ERROR) can be seamlessly converted into an
 	/// `HRESULT`. This operation is equivalent to the
@@ -306,6 +307,10 @@ const_values! { HRESULT;
 	CO_E_OBJNOTCONNECTED 0x8004_01fd
 	CO_E_APPDIDNTREG 0x8004_01fe
 	CO_E_RELEASED 0x8004_01ff
+	CONNECT_E_NOCONNECTION 0x8004_0200
+	CONNECT_E_ADVISELIMIT 0x8004_0201
+	CONNECT_E_CANNOTCONNECT 0x8004_0202
+	CONNECT_E_OVERRIDDEN 0x8004_0203
 	EVENT_S_SOME_SUBSCRIBERS_FAILED 0x0004_0200
 	EVENT_E_ALL_SUBSCRIBERS_FAILED 0x8004_0201
 	EVENT_S_NOSUBSCRIBERS 0x0004_0202
@@ -0,0 +1,110 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::decl::*;
+use crate::guard::*;
+use crate::ole::{privs::*, vts::*};
+use crate::prelude::*;
+
+com_interface! { IConnectionPoint: "b196b286-bab4-101a-b69c-00aa00341d07";
+	/// [`IConnectionPoint`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nn-ocidl-iconnectionpoint)
+	/// COM interface.
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl ole_IConnectionPoint for IConnectionPoint {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IConnectionPoint`](crate::IConnectionPoint).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IConnectionPoint: ole_IUnknown {
+	/// [`IConnectionPoint::Advise`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-advise)
+	/// method.
+	///
+	/// Returns a guard which keeps the connection point alive, and calls
+	/// [`IConnectionPoint::Unadvise`](crate::prelude::ole_IConnectionPoint::Unadvise)
+	/// when it goes out of scope, so the sink is always disconnected.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let container: w::IConnectionPointContainer; // initialized somewhere
+	/// # let container = unsafe { w::IConnectionPointContainer::null() };
+	/// let sink: w::IUnknown; // initialized somewhere
+	/// # let sink = unsafe { w::IUnknown::null() };
+	/// let events_iid: w::GUID; // initialized somewhere
+	/// # let events_iid = w::GUID::default();
+	///
+	/// let cp = container.FindConnectionPoint(&events_iid)?;
+	/// let _connection = cp.Advise(&sink)?;
+	///
+	/// // events are received while _connection is alive
+	/// # w::HrResult::Ok(())
+	/// ```
+	#[must_use]
+	fn Advise(&self, sink: &impl ole_IUnknown) -> HrResult<UnadviseGuard<Self>>
+		where Self: Sized,
+	{
+		let mut cookie = u32::default();
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IConnectionPointVT>(self).Advise)(
+					self.ptr(),
+					sink.ptr(),
+					&mut cookie,
+				)
+			},
+		).map(|_| unsafe { UnadviseGuard::new(self.clone(), cookie) })
+	}
+
+	/// [`IConnectionPoint::GetConnectionInterface`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-getconnectioninterface)
+	/// method.
+	#[must_use]
+	fn GetConnectionInterface(&self) -> HrResult<GUID> {
+		let mut iid = GUID::default();
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IConnectionPointVT>(self).GetConnectionInterface)(
+					self.ptr(),
+					&mut iid as *mut _ as _,
+				)
+			},
+		).map(|_| iid)
+	}
+
+	/// [`IConnectionPoint::GetConnectionPointContainer`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-getconnectionpointcontainer)
+	/// method.
+	#[must_use]
+	fn GetConnectionPointContainer(&self) -> HrResult<IConnectionPointContainer> {
+		let mut queried = unsafe { IConnectionPointContainer::null() };
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IConnectionPointVT>(self).GetConnectionPointContainer)(
+					self.ptr(),
+					queried.as_mut(),
+				)
+			},
+		).map(|_| queried)
+	}
+
+	/// [`IConnectionPoint::Unadvise`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpoint-unadvise)
+	/// method.
+	///
+	/// Paired with
+	/// [`IConnectionPoint::Advise`](crate::prelude::ole_IConnectionPoint::Advise),
+	/// whose returned guard calls this method automatically.
+	fn Unadvise(&self, cookie: u32) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe { (vt::<IConnectionPointVT>(self).Unadvise)(self.ptr(), cookie) },
+		)
+	}
+}
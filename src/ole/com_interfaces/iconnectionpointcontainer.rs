@@ -0,0 +1,74 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::decl::*;
+use crate::ole::{privs::*, vts::*};
+use crate::prelude::*;
+
+com_interface! { IConnectionPointContainer: "b196b284-bab4-101a-b69c-00aa00341d07";
+	/// [`IConnectionPointContainer`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nn-ocidl-iconnectionpointcontainer)
+	/// COM interface.
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// Querying the container from an automation object:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let obj: w::IUnknown; // initialized somewhere
+	/// # let obj = unsafe { w::IUnknown::null() };
+	///
+	/// let container = obj.QueryInterface::<w::IConnectionPointContainer>()?;
+	/// # w::HrResult::Ok(())
+	/// ```
+}
+
+impl ole_IConnectionPointContainer for IConnectionPointContainer {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IConnectionPointContainer`](crate::IConnectionPointContainer).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IConnectionPointContainer: ole_IUnknown {
+	/// [`IConnectionPointContainer::EnumConnectionPoints`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpointcontainer-enumconnectionpoints)
+	/// method.
+	#[must_use]
+	fn EnumConnectionPoints(&self) -> HrResult<IEnumConnectionPoints> {
+		let mut queried = unsafe { IEnumConnectionPoints::null() };
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IConnectionPointContainerVT>(self).EnumConnectionPoints)(
+					self.ptr(),
+					queried.as_mut(),
+				)
+			},
+		).map(|_| queried)
+	}
+
+	/// [`IConnectionPointContainer::FindConnectionPoint`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-iconnectionpointcontainer-findconnectionpoint)
+	/// method.
+	///
+	/// If the object doesn't support the given outgoing interface, returns
+	/// [`HRESULT::CONNECT_E_NOCONNECTION`](crate::co::HRESULT::CONNECT_E_NOCONNECTION).
+	#[must_use]
+	fn FindConnectionPoint(&self, riid: &GUID) -> HrResult<IConnectionPoint> {
+		let mut queried = unsafe { IConnectionPoint::null() };
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IConnectionPointContainerVT>(self).FindConnectionPoint)(
+					self.ptr(),
+					riid as *const _ as _,
+					queried.as_mut(),
+				)
+			},
+		).map(|_| queried)
+	}
+}
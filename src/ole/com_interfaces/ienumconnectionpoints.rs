@@ -0,0 +1,93 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::ole::{privs::*, vts::*};
+use crate::prelude::*;
+
+com_interface! { IEnumConnectionPoints: "b196b285-bab4-101a-b69c-00aa00341d07";
+	/// [`IEnumConnectionPoints`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nn-ocidl-ienumconnectionpoints)
+	/// COM interface.
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+}
+
+impl ole_IEnumConnectionPoints for IEnumConnectionPoints {}
+
+/// This trait is enabled with the `ole` feature, and provides methods for
+/// [`IEnumConnectionPoints`](crate::IEnumConnectionPoints).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait ole_IEnumConnectionPoints: ole_IUnknown {
+	/// Returns an iterator over the [`IConnectionPoint`](crate::IConnectionPoint)
+	/// elements which calls
+	/// [`IEnumConnectionPoints::Next`](crate::prelude::ole_IEnumConnectionPoints::Next)
+	/// internally.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let container: w::IConnectionPointContainer; // initialized somewhere
+	/// # let container = unsafe { w::IConnectionPointContainer::null() };
+	///
+	/// for cp in container.EnumConnectionPoints()?.iter() {
+	///     let cp = cp?;
+	///     println!("{}", cp.GetConnectionInterface()?);
+	/// }
+	/// # w::HrResult::Ok(())
+	/// ```
+	#[must_use]
+	fn iter(&self) -> impl Iterator<Item = HrResult<IConnectionPoint>> + '_ {
+		std::iter::from_fn(move || self.Next().transpose())
+	}
+
+	/// [`IEnumConnectionPoints::Next`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-ienumconnectionpoints-next)
+	/// method.
+	///
+	/// Prefer using
+	/// [`IEnumConnectionPoints::iter`](crate::prelude::ole_IEnumConnectionPoints::iter),
+	/// which is simpler.
+	#[must_use]
+	fn Next(&self) -> HrResult<Option<IConnectionPoint>> {
+		let mut queried = unsafe { IConnectionPoint::null() };
+		let mut fetched = u32::default();
+
+		match ok_to_hrresult(
+			unsafe {
+				(vt::<IEnumConnectionPointsVT>(self).Next)(
+					self.ptr(),
+					1, // retrieve only 1
+					queried.as_mut(),
+					&mut fetched,
+				)
+			},
+		) {
+			Ok(_) => Ok(Some(queried)),
+			Err(hr) => match hr {
+				co::HRESULT::S_FALSE => Ok(None), // no item found
+				hr => Err(hr), // actual error
+			},
+		}
+	}
+
+	fn_com_noparm! { Reset: IEnumConnectionPointsVT;
+		/// [`IEnumConnectionPoints::Reset`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-ienumconnectionpoints-reset)
+		/// method.
+	}
+
+	/// [`IEnumConnectionPoints::Skip`](https://learn.microsoft.com/en-us/windows/win32/api/ocidl/nf-ocidl-ienumconnectionpoints-skip)
+	/// method.
+	fn Skip(&self, count: u32) -> HrResult<bool> {
+		okfalse_to_hrresult(
+			unsafe { (vt::<IEnumConnectionPointsVT>(self).Skip)(self.ptr(), count) },
+		)
+	}
+}
@@ -10,7 +10,26 @@ com_interface! { IUnknown: "00000000-0000-0000-c000-000000000046";
 	///
 	/// The `clone` method calls
 	/// [`AddRef`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-addref)
-	/// internally.
+	/// internally. This is true for all COM interfaces, so each clone owns its
+	/// own reference, and can be stored and dropped independently:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// struct Player {
+	///     control: w::IMediaControl,
+	/// }
+	///
+	/// struct SeekBar {
+	///     control: w::IMediaControl,
+	/// }
+	///
+	/// let control: w::IMediaControl; // initialized somewhere
+	/// # let control = unsafe { w::IMediaControl::null() };
+	///
+	/// let player = Player { control: control.clone() };
+	/// let seek_bar = SeekBar { control };
+	/// ```
 	///
 	/// Automatically calls
 	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
@@ -102,8 +121,55 @@ pub trait ole_IUnknown: Clone {
 		p
 	}
 
+	/// Returns the current reference count of the object, by calling
+	/// [`AddRef`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-addref)
+	/// immediately followed by
+	/// [`Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release).
+	///
+	/// This method is meant only to diagnose reference leaks during
+	/// development, thus it's available only in debug builds. The value is
+	/// racy, since other threads may change it at any time, and some objects
+	/// don't return meaningful counts at all.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let obj: w::IUnknown; // initialized somewhere
+	/// # let obj = unsafe { w::IUnknown::null() };
+	///
+	/// let _copy = obj.clone();
+	/// println!("References: {}", obj.debug_refcount()); // at least 2
+	/// ```
+	#[cfg(debug_assertions)]
+	#[must_use]
+	fn debug_refcount(&self) -> u32 {
+		let vt = unsafe { vt::<IUnknownVT>(self) };
+		(vt.AddRef)(self.ptr());
+		(vt.Release)(self.ptr())
+	}
+
 	/// [`IUnknown::QueryInterface`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-queryinterface(refiid_void))
 	/// method.
+	///
+	/// Navigates to another interface implemented by the same object. If the
+	/// object doesn't implement it, returns
+	/// [`HRESULT::E_NOINTERFACE`](crate::co::HRESULT::E_NOINTERFACE).
+	///
+	/// # Examples
+	///
+	/// Retrieving the connection points of an object:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let obj: w::IUnknown; // initialized somewhere
+	/// # let obj = unsafe { w::IUnknown::null() };
+	///
+	/// let cpc = obj.QueryInterface::<w::IConnectionPointContainer>()?;
+	/// # w::HrResult::Ok(())
+	/// ```
 	#[must_use]
 	fn QueryInterface<T>(&self) -> HrResult<T>
 		where T: ole_IUnknown,
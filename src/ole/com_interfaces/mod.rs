@@ -1,7 +1,10 @@
 mod iadvisesink;
 mod ibindctx;
+mod iconnectionpoint;
+mod iconnectionpointcontainer;
 mod idataobject;
 mod idroptarget;
+mod ienumconnectionpoints;
 mod imoniker;
 mod ipersist;
 mod ipersistfile;
@@ -15,8 +18,11 @@ mod iunknown;
 pub mod decl {
 	pub use super::iadvisesink::IAdviseSink;
 	pub use super::ibindctx::IBindCtx;
+	pub use super::iconnectionpoint::IConnectionPoint;
+	pub use super::iconnectionpointcontainer::IConnectionPointContainer;
 	pub use super::idataobject::IDataObject;
 	pub use super::idroptarget::IDropTarget;
+	pub use super::ienumconnectionpoints::IEnumConnectionPoints;
 	pub use super::imoniker::IMoniker;
 	pub use super::ipersist::IPersist;
 	pub use super::ipersistfile::IPersistFile;
@@ -31,7 +37,10 @@ pub mod decl {
 pub mod traits {
 	pub use super::iadvisesink::ole_IAdviseSink;
 	pub use super::ibindctx::ole_IBindCtx;
+	pub use super::iconnectionpoint::ole_IConnectionPoint;
+	pub use super::iconnectionpointcontainer::ole_IConnectionPointContainer;
 	pub use super::idataobject::ole_IDataObject;
+	pub use super::ienumconnectionpoints::ole_IEnumConnectionPoints;
 	pub use super::imoniker::ole_IMoniker;
 	pub use super::ipersist::ole_IPersist;
 	pub use super::ipersistfile::ole_IPersistFile;
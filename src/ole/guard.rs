@@ -119,3 +119,58 @@ impl CoUninitializeGuard {
 		self.hr
 	}
 }
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation which automatically calls
+/// [`IConnectionPoint::Unadvise`](crate::prelude::ole_IConnectionPoint::Unadvise)
+/// when the object goes out of scope.
+pub struct UnadviseGuard<T>
+	where T: ole_IConnectionPoint,
+{
+	cp: T,
+	cookie: u32,
+}
+
+impl<T> Drop for UnadviseGuard<T>
+	where T: ole_IConnectionPoint,
+{
+	fn drop(&mut self) {
+		if self.cookie != 0 {
+			let _ = self.cp.Unadvise(self.cookie); // ignore errors
+		}
+	}
+}
+
+impl<T> UnadviseGuard<T>
+	where T: ole_IConnectionPoint,
+{
+	/// Constructs the guard by taking ownership of the connection point and the
+	/// cookie.
+	///
+	/// # Safety
+	///
+	/// Be sure the cookie must be released with
+	/// [`IConnectionPoint::Unadvise`](crate::prelude::ole_IConnectionPoint::Unadvise)
+	/// at the end of scope.
+	#[must_use]
+	pub const unsafe fn new(cp: T, cookie: u32) -> Self {
+		Self { cp, cookie }
+	}
+
+	/// Returns the cookie which identifies the connection.
+	#[must_use]
+	pub const fn cookie(&self) -> u32 {
+		self.cookie
+	}
+
+	/// Ejects the underlying cookie, leaving zero in its place.
+	///
+	/// Since the internal cookie will be invalidated, the destructor will not
+	/// run. It's your responsibility to run it, otherwise the event sink will
+	/// remain connected.
+	#[must_use]
+	pub fn leak(&mut self) -> u32 {
+		std::mem::replace(&mut self.cookie, 0)
+	}
+}
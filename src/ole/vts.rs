@@ -27,6 +27,23 @@ pub struct IBindCtxVT {
 	pub RevokeObjectParam: fn(COMPTR, PCSTR) -> HRES,
 }
 
+#[repr(C)]
+pub struct IConnectionPointVT {
+	pub IUnknownVT: IUnknownVT,
+	pub GetConnectionInterface: fn(COMPTR, PVOID) -> HRES,
+	pub GetConnectionPointContainer: fn(COMPTR, *mut COMPTR) -> HRES,
+	pub Advise: fn(COMPTR, COMPTR, *mut u32) -> HRES,
+	pub Unadvise: fn(COMPTR, u32) -> HRES,
+	pub EnumConnections: fn(COMPTR, *mut COMPTR) -> HRES,
+}
+
+#[repr(C)]
+pub struct IConnectionPointContainerVT {
+	pub IUnknownVT: IUnknownVT,
+	pub EnumConnectionPoints: fn(COMPTR, *mut COMPTR) -> HRES,
+	pub FindConnectionPoint: fn(COMPTR, PCVOID, *mut COMPTR) -> HRES,
+}
+
 #[repr(C)]
 pub struct IDataObjectVT {
 	pub IUnknownVT: IUnknownVT,
@@ -50,6 +67,15 @@ pub struct IDropTargetVT {
 	pub Drop: fn(COMPTR, COMPTR, u32, u64, *mut u32) -> HRES,
 }
 
+#[repr(C)]
+pub struct IEnumConnectionPointsVT {
+	pub IUnknownVT: IUnknownVT,
+	pub Next: fn(COMPTR, u32, *mut COMPTR, *mut u32) -> HRES,
+	pub Skip: fn(COMPTR, u32) -> HRES,
+	pub Reset: fn(COMPTR) -> HRES,
+	pub Clone: fn(COMPTR, *mut COMPTR) -> HRES,
+}
+
 #[repr(C)]
 pub struct IMonikerVT {
 	pub IPersistStreamVT: IPersistStreamVT,
@@ -1,3 +1,14 @@
+const_bitflag! { DISPATCH: u16;
+	/// [`IDispatch::Invoke`](crate::prelude::oleaut_IDispatch::Invoke) `flags`
+	/// (`u16`).
+	=>
+	=>
+	METHOD 0x1
+	PROPERTYGET 0x2
+	PROPERTYPUT 0x4
+	PROPERTYPUTREF 0x8
+}
+
 const_bitflag! { VT: u16;
 	/// [`VARENUM`](https://learn.microsoft.com/en-us/windows/win32/api/wtypes/ne-wtypes-varenum)
 	/// enumeration (`u16`).
@@ -80,4 +80,62 @@ pub trait oleaut_IDispatch: ole_IUnknown {
 			},
 		).map(|_| queried)
 	}
+
+	/// [`IDispatch::Invoke`](https://learn.microsoft.com/en-us/windows/win32/api/oaidl/nf-oaidl-idispatch-invoke)
+	/// method.
+	///
+	/// When setting a property with
+	/// [`DISPATCH::PROPERTYPUT`](crate::co::DISPATCH::PROPERTYPUT), the value
+	/// must be a named argument whose ID is `DISPID_PROPERTYPUT` (-3).
+	///
+	/// # Examples
+	///
+	/// Calling a method with one argument:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let obj: w::IDispatch; // initialized somewhere
+	/// # let obj = unsafe { w::IDispatch::null() };
+	///
+	/// let ids = obj.GetIDsOfNames(&["Navigate"], w::LCID::USER_DEFAULT)?;
+	///
+	/// let mut args = [w::VARIANT::new_bstr("https://example.com")?];
+	/// let mut params = w::DISPPARAMS::default();
+	/// params.set_rgvarg(Some(&mut args));
+	///
+	/// let mut result = w::VARIANT::default();
+	/// obj.Invoke(
+	///     ids[0],
+	///     w::LCID::USER_DEFAULT,
+	///     co::DISPATCH::METHOD,
+	///     &params,
+	///     Some(&mut result),
+	/// )?;
+	/// # w::HrResult::Ok(())
+	/// ```
+	fn Invoke(&self,
+		disp_id: i32,
+		lcid: LCID,
+		flags: co::DISPATCH,
+		params: &DISPPARAMS,
+		result: Option<&mut VARIANT>,
+	) -> HrResult<()>
+	{
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IDispatchVT>(self).Invoke)(
+					self.ptr(),
+					disp_id,
+					&co::IID::default() as *const _ as _,
+					lcid.into(),
+					flags.raw(),
+					params as *const _ as _,
+					result.map_or(std::ptr::null_mut(), |r| r as *mut _ as _),
+					std::ptr::null_mut(),
+					std::ptr::null_mut(),
+				)
+			},
+		)
+	}
 }
@@ -0,0 +1,139 @@
+use std::mem::ManuallyDrop;
+
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+/// High-level representation of the values held by a
+/// [`VARIANT`](crate::VARIANT).
+///
+/// Converted with [`Variant::from_raw`](crate::Variant::from_raw) and
+/// [`Variant::into_raw`](crate::Variant::into_raw).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let v = unsafe { w::Variant::I4(42).into_raw() };
+/// let back = w::Variant::from_raw(v)?;
+///
+/// if let w::Variant::I4(n) = back {
+///     println!("{}", n);
+/// }
+/// # w::HrResult::Ok(())
+/// ```
+pub enum Variant<'a> {
+	/// [`co::VT::EMPTY`](crate::co::VT::EMPTY).
+	Empty,
+	/// [`co::VT::NULL`](crate::co::VT::NULL), an SQL style null.
+	Null,
+	/// [`co::VT::BOOL`](crate::co::VT::BOOL).
+	Bool(bool),
+	/// [`co::VT::I2`](crate::co::VT::I2).
+	I2(i16),
+	/// [`co::VT::I4`](crate::co::VT::I4).
+	I4(i32),
+	/// [`co::VT::I8`](crate::co::VT::I8).
+	I8(i64),
+	/// [`co::VT::R4`](crate::co::VT::R4).
+	R4(f32),
+	/// [`co::VT::R8`](crate::co::VT::R8).
+	R8(f64),
+	/// [`co::VT::BSTR`](crate::co::VT::BSTR).
+	Bstr(String),
+	/// [`co::VT::DATE`](crate::co::VT::DATE), an OLE automation date, which
+	/// can be converted with
+	/// [`VariantTimeToSystemTime`](https://learn.microsoft.com/en-us/windows/win32/api/oleauto/nf-oleauto-varianttimetosystemtime).
+	Date(f64),
+	/// [`co::VT::DISPATCH`](crate::co::VT::DISPATCH).
+	Dispatch(IDispatch),
+	/// [`co::VT::UNKNOWN`](crate::co::VT::UNKNOWN).
+	Unknown(IUnknown),
+	/// [`co::VT::ERROR`](crate::co::VT::ERROR), an `SCODE` value.
+	Error(u32),
+	/// [`co::VT::I4`](crate::co::VT::I4) |
+	/// [`co::VT::BYREF`](crate::co::VT::BYREF). The pointed value is not owned
+	/// by the `VARIANT`, which must not outlive it – see
+	/// [`into_raw`](crate::Variant::into_raw).
+	ByRefI4(&'a mut i32),
+}
+
+impl<'a> Default for Variant<'a> {
+	fn default() -> Self {
+		Self::Empty
+	}
+}
+
+impl<'a> Variant<'a> {
+	/// Converts a [`VARIANT`](crate::VARIANT) into the corresponding
+	/// `Variant`. The `VARIANT` is consumed, and strings and COM objects are
+	/// copied out of it before it's cleared.
+	///
+	/// Returns
+	/// [`co::HRESULT::DISP_E_BADVARTYPE`](crate::co::HRESULT::DISP_E_BADVARTYPE)
+	/// if the variant type has no representation here, which includes
+	/// [`ByRefI4`](crate::Variant::ByRefI4), since the lifetime of the pointed
+	/// value is unknown.
+	pub fn from_raw(v: VARIANT) -> HrResult<Variant<'static>> {
+		let raw = v.raw();
+
+		Ok(match v.vt() {
+			co::VT::EMPTY => Variant::Empty,
+			co::VT::NULL => Variant::Null,
+			co::VT::BOOL => Variant::Bool(v.bool().unwrap()),
+			co::VT::I2 => Variant::I2(v.i16().unwrap()),
+			co::VT::I4 => Variant::I4(v.i32().unwrap()),
+			co::VT::I8 => Variant::I8(i64::from_ne_bytes(raw[..8].try_into().unwrap())),
+			co::VT::R4 => Variant::R4(v.f32().unwrap()),
+			co::VT::R8 => Variant::R8(v.f64().unwrap()),
+			co::VT::BSTR => Variant::Bstr(v.bstr().unwrap()),
+			co::VT::DATE => Variant::Date(f64::from_ne_bytes(raw[..8].try_into().unwrap())),
+			co::VT::DISPATCH => Variant::Dispatch(v.idispatch().unwrap()),
+			co::VT::UNKNOWN => {
+				const PTR_SZ: usize = std::mem::size_of::<usize>();
+				let ptr = usize::from_ne_bytes(raw[..PTR_SZ].try_into().unwrap());
+				let obj = ManuallyDrop::new(unsafe { IUnknown::from_ptr(ptr as _) }); // won't release the stored pointer
+				Variant::Unknown(IUnknown::clone(&obj)) // call AddRef
+			},
+			co::VT::ERROR => Variant::Error(u32::from_ne_bytes(raw[..4].try_into().unwrap())),
+			_ => return Err(co::HRESULT::DISP_E_BADVARTYPE),
+		})
+	}
+
+	/// Converts the `Variant` into a [`VARIANT`](crate::VARIANT), which will
+	/// own any strings and COM objects.
+	///
+	/// # Panics
+	///
+	/// Panics if the system is out of memory to allocate a
+	/// [`BSTR`](crate::BSTR).
+	///
+	/// # Safety
+	///
+	/// If the `Variant` is a [`ByRefI4`](crate::Variant::ByRefI4), the
+	/// returned `VARIANT` holds a raw pointer to the borrowed value, so it
+	/// must not be used after the value goes out of scope.
+	#[must_use]
+	pub unsafe fn into_raw(self) -> VARIANT {
+		unsafe {
+			match self {
+				Self::Empty => VARIANT::default(),
+				Self::Null => VARIANT::from_raw(co::VT::NULL, &[]),
+				Self::Bool(b) => VARIANT::new_bool(b),
+				Self::I2(n) => VARIANT::new_i16(n),
+				Self::I4(n) => VARIANT::new_i32(n),
+				Self::I8(n) => VARIANT::from_raw(co::VT::I8, &n.to_ne_bytes()),
+				Self::R4(n) => VARIANT::new_f32(n),
+				Self::R8(n) => VARIANT::new_f64(n),
+				Self::Bstr(s) => VARIANT::new_bstr(&s).unwrap(),
+				Self::Date(d) => VARIANT::from_raw(co::VT::DATE, &d.to_ne_bytes()),
+				Self::Dispatch(obj) => VARIANT::new_idispatch(&obj),
+				Self::Unknown(obj) => VARIANT::new_iunknown::<IUnknown>(&obj),
+				Self::Error(e) => VARIANT::from_raw(co::VT::ERROR, &e.to_ne_bytes()),
+				Self::ByRefI4(p) => VARIANT::from_raw(
+					co::VT::I4 | co::VT::BYREF, &(p as *mut i32 as usize).to_ne_bytes()),
+			}
+		}
+	}
+}
@@ -1,6 +1,7 @@
 #![cfg(feature = "oleaut")]
 
 mod com_interfaces;
+mod enums;
 mod funcs;
 mod structs;
 
@@ -12,6 +13,7 @@ pub mod co;
 
 pub mod decl {
 	pub use super::com_interfaces::decl::*;
+	pub use super::enums::*;
 	pub use super::funcs::*;
 	pub use super::structs::decl::*;
 }
@@ -1,6 +1,33 @@
+#![allow(non_snake_case)]
+
+use std::marker::PhantomData;
+
 use crate::decl::*;
 use crate::oleaut::privs::*;
 
+/// [`DISPPARAMS`](https://learn.microsoft.com/en-us/windows/win32/api/oaidl/ns-oaidl-dispparams)
+/// struct.
+///
+/// Note that the arguments in `rgvarg` are stored in reverse order: the last
+/// argument comes first.
+#[repr(C)]
+pub struct DISPPARAMS<'a, 'b> {
+	rgvarg: *mut VARIANT,
+	rgdispidNamedArgs: *mut i32,
+	cArgs: u32,
+	cNamedArgs: u32,
+
+	_rgvarg: PhantomData<&'a mut VARIANT>,
+	_rgdispidNamedArgs: PhantomData<&'b mut i32>,
+}
+
+impl_default!(DISPPARAMS, 'a, 'b);
+
+impl<'a, 'b> DISPPARAMS<'a, 'b> {
+	pub_fn_array_buf_get_set!('a, rgvarg, set_rgvarg, cArgs, VARIANT);
+	pub_fn_array_buf_get_set!('b, rgdispidNamedArgs, set_rgdispidNamedArgs, cNamedArgs, i32);
+}
+
 /// [`PROPERTYKEY`](https://learn.microsoft.com/en-us/windows/win32/api/wtypes/ns-wtypes-propertykey)
 /// struct.
 #[repr(C)]
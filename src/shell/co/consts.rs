@@ -2,6 +2,31 @@
 
 use crate::co::*;
 
+const_bitflag! { BIF: u32;
+	/// [`BROWSEINFO`](crate::BROWSEINFO) `ulFlags` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	RETURNONLYFSDIRS 0x0000_0001
+	DONTGOBELOWDOMAIN 0x0000_0002
+	STATUSTEXT 0x0000_0004
+	RETURNFSANCESTORS 0x0000_0008
+	EDITBOX 0x0000_0010
+	VALIDATE 0x0000_0020
+	NEWDIALOGSTYLE 0x0000_0040
+	USENEWUI Self::NEWDIALOGSTYLE.0 | Self::EDITBOX.0
+	BROWSEINCLUDEURLS 0x0000_0080
+	UAHINT 0x0000_0100
+	NONEWFOLDERBUTTON 0x0000_0200
+	NOTRANSLATETARGETS 0x0000_0400
+	BROWSEFORCOMPUTER 0x0000_1000
+	BROWSEFORPRINTER 0x0000_2000
+	BROWSEINCLUDEFILES 0x0000_4000
+	SHAREABLE 0x0000_8000
+	BROWSEFILEJUNCTIONS 0x0001_0000
+}
+
 const_ordinary! { FDEOR: u32;
 	/// [`FDE_OVERWRITE_RESPONSE`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/ne-shobjidl_core-fde_overwrite_response)
 	/// enumeration (`u32`).
@@ -188,20 +213,6 @@ const_bitflag! { GPS: u32;
 	MASK_VALID 0x1fff
 }
 
-const_bitflag! { HOTKEYF: u16;
-	/// [`wm::GetHotkey`](crate::msg::wm::GetHotKey) and
-	/// [`wm::SetHotKey`](crate::msg::wm::SetHotKey) modifiers;
-	/// [`SHELLEXECUTEINFO`](crate::SHELLEXECUTEINFO) `dwHotKey` (`u16`).
-	=>
-	=>
-	/// None of the actual values (zero).
-	NoValue 0
-	ALT 0x04
-	CONTROL 0x02
-	EXT 0x08
-	SHIFT 0x01
-}
-
 const_bitflag! { KF: u32;
 	/// [`KNOWN_FOLDER_FLAG`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/ne-shlobj_core-known_folder_flag)
 	/// enumeration (`u32`).
@@ -273,6 +284,20 @@ const_bitflag! { NIS: u32;
 	SHAREDICON 0x0000_0002
 }
 
+const_bitflag! { PATHCCH: u32;
+	/// [`PathCchCanonicalizeEx`](crate::PathCchCanonicalizeEx) `flags` (`u32`).
+	=>
+	=>
+	NONE 0x0000_0000
+	ALLOW_LONG_PATHS 0x0000_0001
+	FORCE_ENABLE_LONG_NAME_PROCESS 0x0000_0002
+	FORCE_DISABLE_LONG_NAME_PROCESS 0x0000_0004
+	DO_NOT_NORMALIZE_SEGMENTS 0x0000_0008
+	ENSURE_IS_EXTENDED_LENGTH_PATH 0x0000_0010
+	ENSURE_TRAILING_SLASH 0x0000_0020
+	CANONICALIZE_SLASHES 0x0000_0040
+}
+
 const_bitflag! { SFGAO: u32;
 	/// [`SFGAO`](https://learn.microsoft.com/en-us/windows/win32/shell/sfgao)
 	/// constants (`u32`).
@@ -424,6 +449,21 @@ const_ordinary! { SIGDN: u32;
 	PARENTRELATIVEFORUI 0x8009_4001
 }
 
+impl SIGDN {
+	/// Returns `true` if the name retrieved with this value is suitable for
+	/// display to the user; that is, it is not one of the parsing names
+	/// [`PARENTRELATIVEPARSING`](crate::co::SIGDN::PARENTRELATIVEPARSING) or
+	/// [`DESKTOPABSOLUTEPARSING`](crate::co::SIGDN::DESKTOPABSOLUTEPARSING).
+	///
+	/// Note that [`FILESYSPATH`](crate::co::SIGDN::FILESYSPATH) and
+	/// [`URL`](crate::co::SIGDN::URL) are considered suitable, although not
+	/// all items have them.
+	#[must_use]
+	pub const fn is_ui_suitable(&self) -> bool {
+		!matches!(*self, Self::PARENTRELATIVEPARSING | Self::DESKTOPABSOLUTEPARSING)
+	}
+}
+
 const_ordinary! { SIID: u32;
 	/// [`SHSTOCKICONID`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/ne-shellapi-shstockiconid)
 	/// enumeration, [`SHGetStockIconInfo`](crate::SHGetStockIconInfo) `siid`
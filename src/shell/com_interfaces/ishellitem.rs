@@ -43,6 +43,56 @@ impl shell_IShellItem for IShellItem {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait shell_IShellItem: ole_IUnknown {
+	/// Calls [`IShellItem::GetDisplayName`](crate::prelude::shell_IShellItem::GetDisplayName),
+	/// returning `None` if the item has no name of the given kind.
+	///
+	/// Not all [`SIGDN`](crate::co::SIGDN) values apply to all items: virtual
+	/// items like the Control Panel, which don't have the
+	/// [`SFGAO::FILESYSTEM`](crate::co::SFGAO::FILESYSTEM) attribute, have no
+	/// [`SIGDN::FILESYSPATH`](crate::co::SIGDN::FILESYSPATH) nor
+	/// [`SIGDN::URL`](crate::co::SIGDN::URL). Only in this case, when the
+	/// shell returns
+	/// [`HRESULT::E_INVALIDARG`](crate::co::HRESULT::E_INVALIDARG) or
+	/// [`HRESULT::E_NOTIMPL`](crate::co::HRESULT::E_NOTIMPL), `None` is
+	/// returned. Any other error is returned as usual.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let shi: w::IShellItem; // initialized somewhere
+	/// # let shi = unsafe { w::IShellItem::null() };
+	///
+	/// match shi.display_name_if_any(co::SIGDN::FILESYSPATH)? {
+	///     Some(path) => println!("File: {}", path),
+	///     None => println!("Not a file system item."),
+	/// }
+	/// # w::HrResult::Ok(())
+	/// ```
+	#[must_use]
+	fn display_name_if_any(&self,
+		sigdn_name: co::SIGDN,
+	) -> HrResult<Option<String>>
+	{
+		match self.GetDisplayName(sigdn_name) {
+			Ok(name) => Ok(Some(name)),
+			Err(e @ (co::HRESULT::E_INVALIDARG | co::HRESULT::E_NOTIMPL)) => {
+				let needs_fs = sigdn_name == co::SIGDN::FILESYSPATH
+					|| sigdn_name == co::SIGDN::URL;
+				if needs_fs
+					&& !self.GetAttributes(co::SFGAO::FILESYSTEM)?
+						.has(co::SFGAO::FILESYSTEM)
+				{
+					Ok(None) // the item has no name of this kind
+				} else {
+					Err(e)
+				}
+			},
+			Err(e) => Err(e),
+		}
+	}
+
 	/// [`IShellItem::BindToHandler`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitem-bindtohandler)
 	/// method.
 	///
@@ -127,6 +177,13 @@ pub trait shell_IShellItem: ole_IUnknown {
 	/// [`IShellItem::GetDisplayName`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ishellitem-getdisplayname)
 	/// method.
 	///
+	/// The string allocated by the shell is copied and then released with
+	/// [`CoTaskMemFree`](https://learn.microsoft.com/en-us/windows/win32/api/combaseapi/nf-combaseapi-cotaskmemfree).
+	///
+	/// Fails if the item has no name of the given kind; to handle this case,
+	/// use
+	/// [`IShellItem::display_name_if_any`](crate::prelude::shell_IShellItem::display_name_if_any).
+	///
 	/// # Examples
 	///
 	/// ```no_run
@@ -154,9 +211,8 @@ pub trait shell_IShellItem: ole_IUnknown {
 				)
 			},
 		).map(|_| {
-			let name = unsafe { WString::from_wchars_nullt(pstr) };
-			let _ = unsafe { CoTaskMemFreeGuard::new(pstr as _, 0) };
-			name.to_string()
+			let _pstr_guard = unsafe { CoTaskMemFreeGuard::new(pstr as _, 0) }; // freed even if a panic occurs
+			unsafe { WString::from_wchars_nullt(pstr) }.to_string()
 		})
 	}
 
@@ -182,3 +238,48 @@ pub trait shell_IShellItem: ole_IUnknown {
 		/// ```
 	}
 }
+
+#[cfg(all(test, windows))]
+mod tests {
+	use crate::{self as w, co, prelude::*};
+
+	const CONTROL_PANEL: &str = "::{26EE0668-A00A-44D7-9371-BEB064C98683}";
+
+	#[test]
+	fn virtual_item_has_no_path_nor_url() -> w::HrResult<()> {
+		let _com_guard = w::CoInitializeEx(
+			co::COINIT::APARTMENTTHREADED | co::COINIT::DISABLE_OLE1DDE)?;
+		let shi = w::SHCreateItemFromParsingName::<w::IShellItem>(
+			CONTROL_PANEL, None::<&w::IBindCtx>)?;
+
+		assert!(shi.display_name_if_any(co::SIGDN::NORMALDISPLAY)?.is_some());
+		assert_eq!(shi.display_name_if_any(co::SIGDN::FILESYSPATH)?, None);
+		assert_eq!(shi.display_name_if_any(co::SIGDN::URL)?, None);
+		assert!(shi.GetDisplayName(co::SIGDN::URL).is_err());
+		Ok(())
+	}
+
+	#[test]
+	fn display_name_does_not_leak() -> w::HrResult<()> {
+		let _com_guard = w::CoInitializeEx(
+			co::COINIT::APARTMENTTHREADED | co::COINIT::DISABLE_OLE1DDE)?;
+		let shi = w::SHCreateItemFromParsingName::<w::IShellItem>(
+			&w::GetTempPath().unwrap(), None::<&w::IBindCtx>)?;
+
+		let private_usage = || w::HPROCESS::GetCurrentProcess()
+			.GetProcessMemoryInfo().unwrap().PrivateUsage;
+		for _ in 0..1_000 { // warm up the shell caches
+			let _ = shi.GetDisplayName(co::SIGDN::FILESYSPATH)?;
+		}
+		let before = private_usage();
+		for _ in 0..100_000 {
+			let _ = shi.GetDisplayName(co::SIGDN::FILESYSPATH)?;
+		}
+		let after = private_usage();
+
+		// Each leaked string would take at least 2 bytes per char, so 100,000
+		// leaks would easily exceed this threshold.
+		assert!(after.saturating_sub(before) < 1024 * 1024);
+		Ok(())
+	}
+}
@@ -193,4 +193,17 @@ pub trait shell_ITaskbarList3: shell_ITaskbarList2 {
 			},
 		)
 	}
+
+	/// [`ITaskbarList3::UnregisterTab`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-itaskbarlist3-unregistertab)
+	/// method.
+	fn UnregisterTab(&self, hwnd_tab: &HWND) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe {
+				(vt::<ITaskbarList3VT>(self).UnregisterTab)(
+					self.ptr(),
+					hwnd_tab.ptr(),
+				)
+			},
+		)
+	}
 }
@@ -0,0 +1,108 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::decl::*;
+use crate::kernel::ffi_types::*;
+use crate::ole::privs::*;
+use crate::prelude::*;
+use crate::shell::vts::*;
+
+com_interface! { IVirtualDesktopManager: "a5cd92ff-29be-454c-8d04-d82879fb3f1b";
+	/// [`IVirtualDesktopManager`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nn-shobjidl_core-ivirtualdesktopmanager)
+	/// COM interface.
+	///
+	/// Automatically calls
+	/// [`IUnknown::Release`](https://learn.microsoft.com/en-us/windows/win32/api/unknwn/nf-unknwn-iunknown-release)
+	/// when the object goes out of scope.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let obj = w::CoCreateInstance::<w::IVirtualDesktopManager>(
+	///     &co::CLSID::VirtualDesktopManager,
+	///     None,
+	///     co::CLSCTX::INPROC_SERVER,
+	/// )?;
+	/// # w::HrResult::Ok(())
+	/// ```
+}
+
+impl shell_IVirtualDesktopManager for IVirtualDesktopManager {}
+
+/// This trait is enabled with the `shell` feature, and provides methods for
+/// [`IVirtualDesktopManager`](crate::IVirtualDesktopManager).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait shell_IVirtualDesktopManager: ole_IUnknown {
+	/// [`IVirtualDesktopManager::GetWindowDesktopId`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ivirtualdesktopmanager-getwindowdesktopid)
+	/// method.
+	#[must_use]
+	fn GetWindowDesktopId(&self, hwnd: &HWND) -> HrResult<GUID> {
+		let mut desktop_id = GUID::default();
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IVirtualDesktopManagerVT>(self).GetWindowDesktopId)(
+					self.ptr(),
+					hwnd.ptr(),
+					&mut desktop_id as *mut _ as _,
+				)
+			},
+		).map(|_| desktop_id)
+	}
+
+	/// [`IVirtualDesktopManager::IsWindowOnCurrentVirtualDesktop`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ivirtualdesktopmanager-iswindowoncurrentvirtualdesktop)
+	/// method.
+	///
+	/// # Examples
+	///
+	/// Bringing back a window which was left on a background virtual desktop:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let vdm: w::IVirtualDesktopManager; // initialized somewhere
+	/// # let vdm = unsafe { w::IVirtualDesktopManager::null() };
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	/// let hwnd_here: w::HWND; // initialized somewhere
+	/// # let hwnd_here = w::HWND::NULL;
+	///
+	/// if !vdm.IsWindowOnCurrentVirtualDesktop(&hwnd)? {
+	///     let current_desktop = vdm.GetWindowDesktopId(&hwnd_here)?;
+	///     vdm.MoveWindowToDesktop(&hwnd, &current_desktop)?;
+	/// }
+	/// # w::HrResult::Ok(())
+	/// ```
+	#[must_use]
+	fn IsWindowOnCurrentVirtualDesktop(&self, hwnd: &HWND) -> HrResult<bool> {
+		let mut on_current: BOOL = 0;
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IVirtualDesktopManagerVT>(self).IsWindowOnCurrentVirtualDesktop)(
+					self.ptr(),
+					hwnd.ptr(),
+					&mut on_current,
+				)
+			},
+		).map(|_| on_current != 0)
+	}
+
+	/// [`IVirtualDesktopManager::MoveWindowToDesktop`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-ivirtualdesktopmanager-movewindowtodesktop)
+	/// method.
+	fn MoveWindowToDesktop(&self, hwnd: &HWND, desktop_id: &GUID) -> HrResult<()> {
+		ok_to_hrresult(
+			unsafe {
+				(vt::<IVirtualDesktopManagerVT>(self).MoveWindowToDesktop)(
+					self.ptr(),
+					hwnd.ptr(),
+					desktop_id as *const _ as _,
+				)
+			},
+		)
+	}
+}
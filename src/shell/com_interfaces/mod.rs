@@ -4,6 +4,8 @@ mod ifiledialogevents;
 mod ifileopendialog;
 mod ifilesavedialog;
 mod imodalwindow;
+mod iobjectarray;
+mod iobjectcollection;
 mod ishellitem;
 mod ishellitem2;
 mod ishellitemarray;
@@ -12,6 +14,7 @@ mod itaskbarlist;
 mod itaskbarlist2;
 mod itaskbarlist3;
 mod itaskbarlist4;
+mod ivirtualdesktopmanager;
 
 pub mod decl {
 	pub use super::ienumshellitems::IEnumShellItems;
@@ -20,6 +23,8 @@ pub mod decl {
 	pub use super::ifileopendialog::IFileOpenDialog;
 	pub use super::ifilesavedialog::IFileSaveDialog;
 	pub use super::imodalwindow::IModalWindow;
+	pub use super::iobjectarray::IObjectArray;
+	pub use super::iobjectcollection::IObjectCollection;
 	pub use super::ishellitem::IShellItem;
 	pub use super::ishellitem2::IShellItem2;
 	pub use super::ishellitemarray::IShellItemArray;
@@ -28,6 +33,7 @@ pub mod decl {
 	pub use super::itaskbarlist2::ITaskbarList2;
 	pub use super::itaskbarlist3::ITaskbarList3;
 	pub use super::itaskbarlist4::ITaskbarList4;
+	pub use super::ivirtualdesktopmanager::IVirtualDesktopManager;
 }
 
 pub mod traits {
@@ -36,6 +42,8 @@ pub mod traits {
 	pub use super::ifileopendialog::shell_IFileOpenDialog;
 	pub use super::ifilesavedialog::shell_IFileSaveDialog;
 	pub use super::imodalwindow::shell_IModalWindow;
+	pub use super::iobjectarray::shell_IObjectArray;
+	pub use super::iobjectcollection::shell_IObjectCollection;
 	pub use super::ishellitem::shell_IShellItem;
 	pub use super::ishellitem2::shell_IShellItem2;
 	pub use super::ishellitemarray::shell_IShellItemArray;
@@ -44,4 +52,5 @@ pub mod traits {
 	pub use super::itaskbarlist2::shell_ITaskbarList2;
 	pub use super::itaskbarlist3::shell_ITaskbarList3;
 	pub use super::itaskbarlist4::shell_ITaskbarList4;
+	pub use super::ivirtualdesktopmanager::shell_IVirtualDesktopManager;
 }
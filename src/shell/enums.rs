@@ -17,3 +17,15 @@ impl<'a> Default for IcoMon<'a> {
 		Self::None
 	}
 }
+
+/// The document for:
+///
+/// * [`SHAddToRecentDocs`](crate::SHAddToRecentDocs).
+pub enum RecentDoc<'a> {
+	/// Clears all documents from the list.
+	Clear,
+	/// Path to a file.
+	Path(&'a str),
+	/// A shell item.
+	ShellItem(&'a IShellItem),
+}
@@ -7,16 +7,19 @@ extern_sys! { "shell32";
 	DragQueryFileW(HANDLE, u32, PSTR, u32) -> u32
 	DragQueryPoint(HANDLE, PVOID) -> BOOL
 	SHAddToRecentDocs(u32, PCVOID)
+	SHBrowseForFolderW(PVOID) -> PVOID
 	SHCreateItemFromParsingName(PCSTR, PVOID, PCVOID, *mut COMPTR) -> HRES
 	Shell_NotifyIconW(u32, PCVOID) -> BOOL
 	ShellAboutW(HANDLE, PCSTR, PCSTR, HANDLE) -> i32
 	ShellExecuteW(HANDLE, PCSTR, PCSTR, PCSTR, PCSTR, i32) -> HANDLE
 	SHFileOperationW(PVOID) -> i32
 	SHGetFileInfoW(PCSTR, u32, PVOID, u32, u32) -> usize
+	SHGetPathFromIDListW(PCVOID, PSTR) -> BOOL
 	SHGetStockIconInfo(u32, u32, PVOID) -> HRES
 }
 
 extern_sys! { "shlwapi";
+	PathCanonicalizeW(PSTR, PCSTR) -> BOOL
 	PathCombineW(PSTR, PCSTR, PCSTR) -> PSTR
 	PathCommonPrefixW(PCSTR, PCSTR, PSTR) -> i32
 	PathSkipRootW(PCSTR) -> PCSTR
@@ -26,7 +29,13 @@ extern_sys! { "shlwapi";
 	SHCreateMemStream(*const u8, u32) -> COMPTR
 }
 
+#[cfg(feature = "advapi")]
 extern_sys! { "userenv";
+	CreateEnvironmentBlock(*mut PVOID, HANDLE, BOOL) -> BOOL
+}
+
+extern_sys! { "userenv";
+	DestroyEnvironmentBlock(PVOID) -> BOOL
 	GetAllUsersProfileDirectoryW(PSTR, *mut u32) -> BOOL
 	GetDefaultUserProfileDirectoryW(PSTR, *mut u32) -> BOOL
 	GetProfilesDirectoryW(PSTR, *mut u32) -> BOOL
@@ -3,7 +3,7 @@
 use crate::co;
 use crate::decl::*;
 use crate::guard::*;
-use crate::kernel::privs::*;
+use crate::kernel::{ffi_types::*, privs::*};
 use crate::ole::privs::*;
 use crate::prelude::*;
 use crate::shell::ffi;
@@ -11,6 +11,15 @@ use crate::shell::ffi;
 /// [`CommandLineToArgv`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-commandlinetoargvw)
 /// function.
 ///
+/// Any command line can be parsed, like one received from another process.
+/// Backslashes are literal unless they precede a double quote: `2n`
+/// backslashes followed by a quote produce `n` backslashes and toggle quoting,
+/// while `2n+1` backslashes produce `n` backslashes and a literal quote. So
+/// `a\\\"b` (three backslashes) is parsed as `a\"b`.
+///
+/// Note that, if `cmd_line` is empty, the function returns the path of the
+/// current executable.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -20,6 +29,9 @@ use crate::shell::ffi;
 /// for arg in args.iter() {
 ///     println!("{}", arg);
 /// }
+///
+/// let args = w::CommandLineToArgv(r#"app.exe "C:\Temp\\" a\\\"b"#)?;
+/// assert_eq!(args, ["app.exe", r"C:\Temp\", r#"a\"b"#]);
 /// # w::SysResult::Ok(())
 /// ```
 #[must_use]
@@ -110,6 +122,61 @@ pub fn GetProfilesDirectory() -> SysResult<String> {
 	).map(|_| buf.to_string())
 }
 
+/// [`PathCchCanonicalizeEx`](https://learn.microsoft.com/en-us/windows/win32/api/pathcch/nf-pathcch-pathcchcanonicalizeex)
+/// function.
+///
+/// Removes the `.` and `..` segments from the path.
+///
+/// The function is loaded at runtime, since it exists only in Windows 8 and
+/// later. On older versions,
+/// [`PathCanonicalize`](https://learn.microsoft.com/en-us/windows/win32/api/shlwapi/nf-shlwapi-pathcanonicalizew)
+/// is called instead, which ignores `flags` and is limited to `MAX_PATH`
+/// (260) chars: longer paths fail with
+/// [`ERROR::FILENAME_EXCED_RANGE`](crate::co::ERROR::FILENAME_EXCED_RANGE).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let path = w::PathCchCanonicalizeEx(
+///     "C:\\One\\Two\\..\\Three\\.\\file.txt",
+///     co::PATHCCH::ALLOW_LONG_PATHS,
+/// )?;
+///
+/// // path = "C:\\One\\Three\\file.txt"
+/// # w::HrResult::Ok(())
+/// ```
+#[must_use]
+pub fn PathCchCanonicalizeEx(
+	path: &str,
+	flags: co::PATHCCH,
+) -> HrResult<String>
+{
+	const PATHCCH_MAX_CCH: usize = 0x8000;
+	let wpath = WString::from_str(path);
+	let mut buf = WString::new_alloc_buf(PATHCCH_MAX_CCH);
+
+	match unsafe {
+		call_dyn("kernelbase", "PathCchCanonicalizeEx",
+			|f: extern "system" fn(PSTR, usize, PCSTR, u32) -> HRES| {
+				f(buf.as_mut_ptr(), buf.buf_len(), wpath.as_ptr(), flags.raw())
+			})
+	} {
+		Some(hr) => ok_to_hrresult(hr).map(|_| buf.to_string()),
+		None => {
+			if wpath.str_len() >= MAX_PATH {
+				return Err(co::ERROR::FILENAME_EXCED_RANGE.to_hresult()); // would overflow the buffer
+			}
+			let mut buf = WString::new_alloc_buf(MAX_PATH);
+			bool_to_sysresult(
+				unsafe { ffi::PathCanonicalizeW(buf.as_mut_ptr(), wpath.as_ptr()) },
+			).map(|_| buf.to_string())
+				.map_err(|err| err.to_hresult())
+		},
+	}
+}
+
 /// [`PathCombine`](https://learn.microsoft.com/en-us/windows/win32/api/shlwapi/nf-shlwapi-pathcombinew)
 /// function.
 ///
@@ -207,12 +274,91 @@ pub fn PathUnquoteSpaces(str_path: &str) -> String {
 /// [`SHAddToRecentDocs`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shaddtorecentdocs)
 /// function.
 ///
-/// # Safety
+/// The `uFlags` parameter is set according to the
+/// [`RecentDoc`](crate::RecentDoc) variant.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// w::SHAddToRecentDocs(w::RecentDoc::Path("C:\\Temp\\foo.txt"));
+///
+/// let shi = w::SHCreateItemFromParsingName::<w::IShellItem>(
+///     "C:\\Temp\\bar.txt",
+///     None::<&w::IBindCtx>,
+/// )?;
+/// w::SHAddToRecentDocs(w::RecentDoc::ShellItem(&shi));
+///
+/// w::SHAddToRecentDocs(w::RecentDoc::Clear);
+/// # w::HrResult::Ok(())
+/// ```
+pub fn SHAddToRecentDocs(doc: RecentDoc) {
+	let wbuf: WString;
+	let (flags, pv) = match doc {
+		RecentDoc::Clear => (co::SHARD::PIDL, std::ptr::null()),
+		RecentDoc::Path(path) => {
+			wbuf = WString::from_str(path);
+			(co::SHARD::PATHW, wbuf.as_ptr() as _)
+		},
+		RecentDoc::ShellItem(shi) => (co::SHARD::SHELLITEM, shi.ptr() as _),
+	};
+	unsafe { ffi::SHAddToRecentDocs(flags.raw(), pv); }
+}
+
+/// [`SHBrowseForFolder`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shbrowseforfolderw)
+/// function.
+///
+/// Returns the file system path of the chosen folder, converted with
+/// [`SHGetPathFromIDList`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/nf-shlobj_core-shgetpathfromidlistw),
+/// or `None` if the user cancelled the dialog or chose a folder which is not
+/// part of the file system – use
+/// [`BIF::RETURNONLYFSDIRS`](crate::co::BIF::RETURNONLYFSDIRS) to prevent
+/// this. The item ID list returned by the dialog is freed internally.
+///
+/// COM must be initialized in the calling thread with
+/// [`CoInitializeEx`](crate::CoInitializeEx) and
+/// [`COINIT::APARTMENTTHREADED`](crate::co::COINIT::APARTMENTTHREADED). On
+/// Windows Vista and later,
+/// [`IFileOpenDialog`](crate::IFileOpenDialog) with
+/// [`FOS::PICKFOLDERS`](crate::co::FOS::PICKFOLDERS) is preferred.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let parent_hwnd: w::HWND; // initialized somewhere
+/// # let parent_hwnd = w::HWND::NULL;
+///
+/// let _com_guard = w::CoInitializeEx(
+///     co::COINIT::APARTMENTTHREADED | co::COINIT::DISABLE_OLE1DDE)?;
+///
+/// let mut title = w::WString::from_str("Choose the output folder");
 ///
-/// The `pv` type varies according to `uFlags`. If you set it wrong, you're
-/// likely to cause a buffer overrun.
-pub unsafe fn SHAddToRecentDocs<T>(flags: co::SHARD, pv: &T) {
-	ffi::SHAddToRecentDocs(flags.raw(), pv as *const _ as _);
+/// let mut bi = w::BROWSEINFO::default();
+/// bi.hwndOwner = parent_hwnd;
+/// bi.ulFlags = co::BIF::RETURNONLYFSDIRS | co::BIF::USENEWUI;
+/// bi.set_lpszTitle(Some(&mut title));
+///
+/// if let Some(folder) = w::SHBrowseForFolder(&mut bi) {
+///     println!("{}", folder);
+/// }
+/// # w::HrResult::Ok(())
+/// ```
+#[must_use]
+pub fn SHBrowseForFolder(bi: &mut BROWSEINFO) -> Option<String> {
+	let pidl = unsafe { ffi::SHBrowseForFolderW(bi as *mut _ as _) };
+	if pidl.is_null() {
+		return None; // user cancelled
+	}
+	let _pidl_guard = unsafe { CoTaskMemFreeGuard::new(pidl, 0) };
+
+	let mut buf = WString::new_alloc_buf(MAX_PATH + 1);
+	match unsafe { ffi::SHGetPathFromIDListW(pidl, buf.as_mut_ptr()) } {
+		0 => None, // not a file system folder
+		_ => Some(buf.to_string()),
+	}
 }
 
 /// [`SHCreateItemFromParsingName`](https://learn.microsoft.com/en-us/windows/win32/api/shobjidl_core/nf-shobjidl_core-shcreateitemfromparsingname)
@@ -295,11 +441,57 @@ pub fn SHFileOperation(file_op: &mut SHFILEOPSTRUCT) -> SysResult<()> {
 
 /// [`SHGetFileInfo`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shgetfileinfow)
 /// function.
+///
+/// Returns the raw value returned by the function – whose meaning depends on
+/// `flags`, like the handle to the system image list when
+/// [`SHGFI::SYSICONINDEX`](crate::co::SHGFI::SYSICONINDEX) is passed – and
+/// the [`SHFILEINFO`](crate::SHFILEINFO) struct. If
+/// [`SHGFI::ICON`](crate::co::SHGFI::ICON) is passed, the returned guard will
+/// destroy the `hIcon` when it goes out of scope.
+///
+/// With [`SHGFI::USEFILEATTRIBUTES`](crate::co::SHGFI::USEFILEATTRIBUTES),
+/// the file doesn't need to exist, and `file_attrs` is used instead.
+///
+/// # Examples
+///
+/// Retrieving the small icon and the type name of the ".docx" files, without
+/// an actual file:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let (_, shfi) = w::SHGetFileInfo(
+///     "*.docx",
+///     co::FILE_ATTRIBUTE::NORMAL,
+///     co::SHGFI::USEFILEATTRIBUTES | co::SHGFI::ICON
+///         | co::SHGFI::SMALLICON | co::SHGFI::TYPENAME,
+/// )?;
+///
+/// println!("Type: {}", shfi.szTypeName());
+/// println!("HICON: {}", shfi.hIcon);
+/// # w::SysResult::Ok(())
+/// ```
+///
+/// Retrieving the display name and the system image list index of an
+/// existing file:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let (_, shfi) = w::SHGetFileInfo(
+///     "C:\\Temp\\foo.txt",
+///     co::FILE_ATTRIBUTE::NORMAL, // ignored without USEFILEATTRIBUTES
+///     co::SHGFI::DISPLAYNAME | co::SHGFI::SYSICONINDEX,
+/// )?;
+///
+/// println!("{} {}", shfi.szDisplayName(), shfi.iIcon);
+/// # w::SysResult::Ok(())
+/// ```
 pub fn SHGetFileInfo(
 	path: &str,
 	file_attrs: co::FILE_ATTRIBUTE,
 	flags: co::SHGFI,
-) -> SysResult<(u32, DestroyIconShfiGuard)>
+) -> SysResult<(usize, DestroyIconShfiGuard)>
 {
 	let mut shfi = SHFILEINFO::default();
 	unsafe {
@@ -319,6 +511,9 @@ pub fn SHGetFileInfo(
 /// [`SHGetStockIconInfo`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shgetstockiconinfo)
 /// function.
 ///
+/// If [`SHGSI::ICON`](crate::co::SHGSI::ICON) is passed, the returned guard
+/// will destroy the `hIcon` when it goes out of scope.
+///
 /// # Examples
 ///
 /// Loading the small (16x16 pixels) camera icon from the system:
@@ -334,6 +529,21 @@ pub fn SHGetFileInfo(
 /// println!("HICON handle: {}", sii.hIcon);
 /// # w::AnyResult::Ok(())
 /// ```
+///
+/// Retrieving only the system image list index of the folder icon, so no
+/// icon handle is created:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let sii = w::SHGetStockIconInfo(
+///     co::SIID::FOLDER,
+///     co::SHGSI::SYSICONINDEX,
+/// )?;
+///
+/// println!("Index: {}", sii.iSysImageIndex);
+/// # w::AnyResult::Ok(())
+/// ```
 pub fn SHGetStockIconInfo(
 	siid: co::SIID,
 	flags: co::SHGSI,
@@ -350,3 +560,37 @@ pub fn SHGetStockIconInfo(
 		).map(|_| DestroyIconSiiGuard::new(sii))
 	}
 }
+
+#[cfg(all(test, windows))]
+mod tests {
+	use super::*;
+
+	fn args(cmd_line: &str) -> Vec<String> {
+		let mut args = CommandLineToArgv(&format!("app.exe {}", cmd_line))
+			.unwrap();
+		assert_eq!(args.remove(0), "app.exe");
+		args
+	}
+
+	#[test]
+	fn command_line_to_argv_quotes_and_backslashes() {
+		assert_eq!(args(r#""abc" d e"#), ["abc", "d", "e"]);
+		assert_eq!(args(r#"a\\b d"e f"g h"#), [r"a\\b", "de fg", "h"]);
+		assert_eq!(args(r#"a\\\"b c d"#), [r#"a\"b"#, "c", "d"]);
+		assert_eq!(args(r#"a\\\\"b c" d e"#), [r"a\\b c", "d", "e"]);
+	}
+
+	#[test]
+	fn command_line_to_argv_trailing_backslashes() {
+		assert_eq!(args(r"C:\Temp\"), [r"C:\Temp\"]);
+		assert_eq!(args(r#""C:\Temp\\""#), [r"C:\Temp\"]);
+		assert_eq!(args(r#""C:\Temp\\" x"#), [r"C:\Temp\", "x"]);
+	}
+
+	#[test]
+	fn command_line_to_argv_empty_returns_exe_path() {
+		let args = CommandLineToArgv("").unwrap();
+		assert_eq!(args.len(), 1);
+		assert!(!args[0].is_empty());
+	}
+}
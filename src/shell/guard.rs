@@ -2,7 +2,69 @@ use std::ops::{Deref, DerefMut};
 
 use crate::decl::*;
 use crate::guard::*;
+use crate::kernel::privs::*;
 use crate::prelude::*;
+use crate::shell::ffi;
+
+/// RAII implementation for an environment block which automatically calls
+/// [`DestroyEnvironmentBlock`](https://learn.microsoft.com/en-us/windows/win32/api/userenv/nf-userenv-destroyenvironmentblock)
+/// when the object goes out of scope.
+pub struct DestroyEnvironmentBlockGuard {
+	ptr: *mut std::ffi::c_void,
+}
+
+impl Drop for DestroyEnvironmentBlockGuard {
+	fn drop(&mut self) {
+		if !self.ptr.is_null() {
+			unsafe { ffi::DestroyEnvironmentBlock(self.ptr); } // ignore errors
+		}
+	}
+}
+
+impl DestroyEnvironmentBlockGuard {
+	/// Constructs the guard by taking ownership of the pointer.
+	///
+	/// # Safety
+	///
+	/// Be sure the pointer must be freed with
+	/// [`DestroyEnvironmentBlock`](https://learn.microsoft.com/en-us/windows/win32/api/userenv/nf-userenv-destroyenvironmentblock)
+	/// at the end of scope.
+	#[must_use]
+	pub const unsafe fn new(ptr: *mut std::ffi::c_void) -> Self {
+		Self { ptr }
+	}
+
+	/// Returns the pointer to the UTF-16 environment block.
+	///
+	/// This method can be used as an escape hatch to interoperate with other
+	/// libraries.
+	#[must_use]
+	pub const fn ptr(&self) -> *mut std::ffi::c_void {
+		self.ptr
+	}
+
+	/// Parses the environment block into its key/value pairs.
+	#[must_use]
+	pub fn entries(&self) -> Vec<(String, String)> {
+		if self.ptr.is_null() {
+			Vec::new()
+		} else {
+			parse_env_block(self.ptr as _)
+		}
+	}
+
+	/// Ejects the underlying pointer, leaving a null pointer in its place.
+	///
+	/// Since the internal pointer will be invalidated, the destructor will not
+	/// run. It's your responsibility to run it, otherwise you'll cause a memory
+	/// leak.
+	#[must_use]
+	pub fn leak(&mut self) -> *mut std::ffi::c_void {
+		std::mem::replace(&mut self.ptr, std::ptr::null_mut())
+	}
+}
+
+//------------------------------------------------------------------------------
 
 /// RAII implementation for [`SHFILEINFO`](crate::SHFILEINFO) which
 /// automatically calls
@@ -51,6 +51,37 @@ pub trait shell_Hwnd: ole_Hwnd {
 
 	/// [`ShellExecute`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/nf-shellapi-shellexecutew)
 	/// function.
+	///
+	/// The `operation` is a verb like `"open"`, `"edit"`, `"print"` or
+	/// `"runas"`. For finer control, including retrieving the handle of the
+	/// launched process, see [`ShellExecuteEx`](crate::ShellExecuteEx).
+	///
+	/// # Examples
+	///
+	/// Opening a file, chosen by the user, in its default application:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let chosen_path = "C:\\Temp\\report.pdf"; // returned by the file dialog
+	/// hwnd.ShellExecute("open", chosen_path, None, None, co::SW::SHOWNORMAL)?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	///
+	/// Opening a URL in the default browser:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.ShellExecute("open", "https://github.com", None, None, co::SW::SHOWNORMAL)?;
+	/// # w::SysResult::Ok(())
+	/// ```
 	fn ShellExecute(&self,
 		operation: &str,
 		file: &str,
@@ -64,16 +95,20 @@ pub trait shell_Hwnd: ole_Hwnd {
 				self.ptr(),
 				WString::from_str(operation).as_ptr(),
 				WString::from_str(file).as_ptr(),
-				parameters.map_or(std::ptr::null(), |lp| WString::from_str(lp).as_ptr()),
-				directory.map_or(std::ptr::null(), |lp| WString::from_str(lp).as_ptr()),
+				WString::from_opt_str(parameters).as_ptr(),
+				WString::from_opt_str(directory).as_ptr(),
 				show_cmd.raw(),
 			)
-		};
+		} as usize;
 
-		if ret as usize > 32 {
-			Ok(())
-		} else {
-			Err(GetLastError())
+		match ret {
+			0 => Err(co::ERROR::OUTOFMEMORY),
+			26 => Err(co::ERROR::SHARING_VIOLATION), // SE_ERR_SHARE
+			27 | 31 => Err(co::ERROR::NO_ASSOCIATION), // SE_ERR_ASSOCINCOMPLETE, SE_ERR_NOASSOC
+			28..=30 => Err(co::ERROR::DDE_FAIL), // SE_ERR_DDETIMEOUT, SE_ERR_DDEFAIL, SE_ERR_DDEBUSY
+			32 => Err(co::ERROR::DLL_NOT_FOUND), // SE_ERR_DLLNOTFOUND
+			1..=25 => Err(unsafe { co::ERROR::from_raw(ret as _) }), // same as ERROR codes
+			_ => Ok(()),
 		}
 	}
 }
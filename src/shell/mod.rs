@@ -6,9 +6,9 @@ mod funcs;
 mod handles;
 mod structs;
 
-pub(in crate::shell) mod ffi;
 pub(in crate::shell) mod iterators;
 pub(in crate::shell) mod vts;
+pub(crate) mod ffi; // userenv functions are also used by advapi_shell
 pub(crate) mod privs;
 pub mod co;
 pub mod guard;
@@ -6,6 +6,54 @@ use crate::co;
 use crate::decl::*;
 use crate::kernel::{ffi_types::*, privs::*};
 
+/// [`BROWSEINFO`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/ns-shlobj_core-browseinfow)
+/// struct.
+#[repr(C)]
+pub struct BROWSEINFO<'a, 'b> {
+	pub hwndOwner: HWND,
+	pidlRoot: *mut std::ffi::c_void,
+	pszDisplayName: *mut u16,
+	lpszTitle: *mut u16,
+	pub ulFlags: co::BIF,
+	lpfn: *mut std::ffi::c_void,
+	pub lParam: isize,
+	pub iImage: i32,
+
+	_pszDisplayName: PhantomData<&'a mut u16>,
+	_lpszTitle: PhantomData<&'b mut u16>,
+}
+
+impl_default!(BROWSEINFO, 'a, 'b);
+
+impl<'a, 'b> BROWSEINFO<'a, 'b> {
+	/// Returns the `pszDisplayName` field, if any.
+	#[must_use]
+	pub fn pszDisplayName(&self) -> Option<String> {
+		unsafe { self.pszDisplayName.as_mut() }.map(|psz| {
+			unsafe { WString::from_wchars_nullt(psz) }.to_string()
+		})
+	}
+
+	/// Sets the `pszDisplayName` field.
+	///
+	/// # Panics
+	///
+	/// Panics if the buffer has less than `MAX_PATH` (260) chars, since
+	/// [`SHBrowseForFolder`](crate::SHBrowseForFolder) writes up to this
+	/// length.
+	pub fn set_pszDisplayName(&mut self, buf: Option<&'a mut WString>) {
+		self.pszDisplayName = buf.map_or(std::ptr::null_mut(), |buf| {
+			if buf.buf_len() < MAX_PATH {
+				panic!("pszDisplayName buffer must have at least {} chars, got {}.",
+					MAX_PATH, buf.buf_len());
+			}
+			unsafe { buf.as_mut_ptr() }
+		});
+	}
+
+	pub_fn_string_ptr_get_set!('b, lpszTitle, set_lpszTitle);
+}
+
 /// [`COMDLG_FILTERSPEC`](https://learn.microsoft.com/en-us/windows/win32/api/shtypes/ns-shtypes-comdlg_filterspec)
 /// struct.
 #[repr(C)]
@@ -67,6 +115,14 @@ pub struct SHFILEINFO {
 impl_default!(SHFILEINFO);
 
 impl SHFILEINFO {
+	/// Returns the `dwAttributes` field, filled when
+	/// [`SHGFI::ATTRIBUTES`](crate::co::SHGFI::ATTRIBUTES) is passed to
+	/// [`SHGetFileInfo`](crate::SHGetFileInfo).
+	#[must_use]
+	pub const fn dwAttributes(&self) -> co::SFGAO {
+		unsafe { co::SFGAO::from_raw(self.dwAttributes) }
+	}
+
 	pub_fn_string_arr_get_set!(szDisplayName, set_szDisplayName);
 	pub_fn_string_arr_get_set!(szTypeName, set_szTypeName);
 }
@@ -75,6 +75,22 @@ pub struct IModalWindowVT {
 	pub Show: fn(COMPTR, HANDLE) -> u32,
 }
 
+#[repr(C)]
+pub struct IObjectArrayVT {
+	pub IUnknownVT: IUnknownVT,
+	pub GetCount: fn(COMPTR, *mut u32) -> HRES,
+	pub GetAt: fn(COMPTR, u32, PCVOID, *mut COMPTR) -> HRES,
+}
+
+#[repr(C)]
+pub struct IObjectCollectionVT {
+	pub IObjectArrayVT: IObjectArrayVT,
+	pub AddObject: fn(COMPTR, COMPTR) -> HRES,
+	pub AddFromArray: fn(COMPTR, COMPTR) -> HRES,
+	pub RemoveObjectAt: fn(COMPTR, u32) -> HRES,
+	pub Clear: fn(COMPTR) -> HRES,
+}
+
 #[repr(C)]
 pub struct IShellItemVT {
 	pub IUnknownVT: IUnknownVT,
@@ -176,3 +192,11 @@ pub struct ITaskbarList4VT {
 	pub ITaskbarList3VT: ITaskbarList3VT,
 	pub SetTabProperties: fn(COMPTR, HANDLE, u32) -> HRES,
 }
+
+#[repr(C)]
+pub struct IVirtualDesktopManagerVT {
+	pub IUnknownVT: IUnknownVT,
+	pub IsWindowOnCurrentVirtualDesktop: fn(COMPTR, HANDLE, *mut BOOL) -> HRES,
+	pub GetWindowDesktopId: fn(COMPTR, HANDLE, PVOID) -> HRES,
+	pub MoveWindowToDesktop: fn(COMPTR, HANDLE, PCVOID) -> HRES,
+}
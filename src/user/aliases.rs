@@ -44,6 +44,17 @@ pub type HOOKPROC =
 		lParam: isize,
 	) -> isize;
 
+/// Type alias to
+/// [`OFNHOOKPROC`](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/nc-commdlg-lpofnhookproc)
+/// callback function.
+pub type OFNHOOKPROC =
+	extern "system" fn(
+		hWnd: HWND,
+		uMsg: u32,
+		wParam: usize,
+		lParam: isize,
+	) -> usize;
+
 /// Type alias to
 /// [`TIMERPROC`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nc-winuser-timerproc)
 /// callback function.
@@ -55,6 +66,20 @@ pub type TIMERPROC =
 		nSeconds: u32,
 	);
 
+/// Type alias to
+/// [`WINEVENTPROC`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nc-winuser-wineventproc)
+/// callback function.
+pub type WINEVENTPROC =
+	extern "system" fn(
+		hWinEventHook: HWINEVENTHOOK,
+		event: co::EVENT,
+		hwnd: HWND,
+		idObject: i32,
+		idChild: i32,
+		idEventThread: u32,
+		dwmsEventTime: u32,
+	);
+
 /// Type alias to
 /// [`WNDPROC`](https://learn.microsoft.com/en-us/previous-versions/windows/desktop/legacy/ms633573(v=vs.85))
 /// callback function.
@@ -0,0 +1,373 @@
+#![allow(non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::{ffi_types::*, privs::*};
+use crate::prelude::*;
+
+/// Number of times [`open`](crate::clipboard::open) will try to open the
+/// clipboard before giving up.
+const OPEN_ATTEMPTS: u32 = 10;
+
+/// Milliseconds [`open`](crate::clipboard::open) will wait between attempts.
+const OPEN_RETRY_MS: u32 = 20;
+
+/// [`DROPFILES`](https://learn.microsoft.com/en-us/windows/win32/api/shlobj_core/ns-shlobj_core-dropfiles)
+/// header which precedes the file names of a `CF_HDROP` memory block.
+#[repr(C)]
+#[derive(Default)]
+struct DROPFILES {
+	pFiles: u32,
+	pt: POINT,
+	fNC: BOOL,
+	fWide: BOOL,
+}
+
+/// Opens the clipboard with
+/// [`HWND::OpenClipboard`](crate::prelude::user_Hwnd::OpenClipboard),
+/// retrying for a short while if another application is currently holding it.
+///
+/// The clipboard is closed when the returned guard goes out of scope.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// let _clip_guard = w::clipboard::open(&hwnd)?; // keep guard alive
+/// let is_text = w::IsClipboardFormatAvailable(co::CF::UNICODETEXT);
+/// # w::SysResult::Ok(())
+/// ```
+pub fn open(hwnd: &HWND) -> SysResult<CloseClipboardGuard<'_>> {
+	let mut attempt = 1;
+	loop {
+		match hwnd.OpenClipboard() {
+			Ok(guard) => return Ok(guard),
+			Err(e) => {
+				if attempt == OPEN_ATTEMPTS {
+					return Err(e);
+				}
+				attempt += 1;
+				Sleep(OPEN_RETRY_MS);
+			},
+		}
+	}
+}
+
+/// Removes all data from the clipboard, making `hwnd` its owner.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// w::clipboard::clear(&hwnd)?;
+/// # w::SysResult::Ok(())
+/// ```
+pub fn clear(hwnd: &HWND) -> SysResult<()> {
+	let _clip_guard = open(hwnd)?;
+	EmptyClipboard()
+}
+
+/// Replaces the clipboard contents with the given text, as
+/// [`CF::UNICODETEXT`](crate::co::CF::UNICODETEXT).
+///
+/// The clipboard owner will be `hwnd`, which should be a valid window;
+/// otherwise `SetClipboardData` may fail.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// w::clipboard::set_text(&hwnd, "Hello world")?;
+/// # w::SysResult::Ok(())
+/// ```
+pub fn set_text(hwnd: &HWND, text: &str) -> SysResult<()> {
+	let wchars = text.encode_utf16()
+		.chain(std::iter::once(0)) // terminating null
+		.collect::<Vec<_>>();
+	let hglobal = alloc_block(&wchars, &[])?;
+
+	let _clip_guard = open(hwnd)?;
+	EmptyClipboard()?;
+	unsafe { set_block(co::CF::UNICODETEXT, hglobal) }
+}
+
+/// Retrieves the clipboard contents as text, if
+/// [`CF::UNICODETEXT`](crate::co::CF::UNICODETEXT) is available.
+///
+/// Returns `None` if the clipboard has no text.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// if let Some(text) = w::clipboard::get_text(&hwnd)? {
+///     println!("{}", text);
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn get_text(hwnd: &HWND) -> SysResult<Option<String>> {
+	let _clip_guard = open(hwnd)?;
+	if !IsClipboardFormatAvailable(co::CF::UNICODETEXT) {
+		return Ok(None);
+	}
+
+	let hglobal = unsafe { get_block(co::CF::UNICODETEXT)? }; // owned by the clipboard
+	let block = hglobal.GlobalLock()?;
+	let wchars = unsafe { block.as_slice_aligned::<u16>() };
+	let len = wchars.iter().position(|ch| *ch == 0).unwrap_or(wchars.len());
+	Ok(Some(WString::from_wchars_slice(&wchars[..len]).to_string()))
+}
+
+/// Replaces the clipboard contents with the given list of file paths, as
+/// [`CF::HDROP`](crate::co::CF::HDROP).
+///
+/// The file list can be pasted into Windows Explorer, or any application which
+/// accepts dropped files.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// w::clipboard::set_file_list(&hwnd, &["C:\\Temp\\a.txt", "C:\\Temp\\b.txt"])?;
+/// # w::SysResult::Ok(())
+/// ```
+pub fn set_file_list(hwnd: &HWND, files: &[impl AsRef<str>]) -> SysResult<()> {
+	let wchars = files.iter()
+		.flat_map(|file| file.as_ref().encode_utf16().chain(std::iter::once(0)))
+		.chain(std::iter::once(0)) // list ends with two nulls
+		.collect::<Vec<_>>();
+
+	let header = DROPFILES {
+		pFiles: std::mem::size_of::<DROPFILES>() as _,
+		fWide: 1,
+		..Default::default()
+	};
+	let header_bytes = unsafe {
+		std::slice::from_raw_parts(
+			&header as *const _ as *const u8,
+			std::mem::size_of::<DROPFILES>(),
+		)
+	};
+	let hglobal = alloc_block(&wchars, header_bytes)?;
+
+	let _clip_guard = open(hwnd)?;
+	EmptyClipboard()?;
+	unsafe { set_block(co::CF::HDROP, hglobal) }
+}
+
+/// Retrieves the list of file paths in the clipboard, if
+/// [`CF::HDROP`](crate::co::CF::HDROP) is available.
+///
+/// Returns `None` if the clipboard has no file list.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// if let Some(files) = w::clipboard::get_file_list(&hwnd)? {
+///     for file in files.iter() {
+///         println!("{}", file);
+///     }
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn get_file_list(hwnd: &HWND) -> SysResult<Option<Vec<String>>> {
+	let _clip_guard = open(hwnd)?;
+	if !IsClipboardFormatAvailable(co::CF::HDROP) {
+		return Ok(None);
+	}
+
+	let hglobal = unsafe { get_block(co::CF::HDROP)? }; // owned by the clipboard
+	let block = hglobal.GlobalLock()?;
+	let bytes = block.as_slice();
+	if bytes.len() < std::mem::size_of::<DROPFILES>() {
+		return Err(co::ERROR::INVALID_DATA);
+	}
+	let header = unsafe { &*(bytes.as_ptr() as *const DROPFILES) };
+	if header.pFiles as usize > bytes.len() {
+		return Err(co::ERROR::INVALID_DATA);
+	}
+	let names = &bytes[header.pFiles as usize..];
+
+	if header.fWide != 0 {
+		let wchars = names.chunks_exact(2)
+			.map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+			.collect::<Vec<_>>();
+		Ok(Some(parse_multi_z_slice(&wchars)))
+	} else {
+		let len = names.windows(2)
+			.position(|pair| pair == [0, 0])
+			.map_or(names.len(), |pos| pos + 2);
+		let wchars = MultiByteToWideChar(
+			co::CP::ACP, co::MBC::NoValue, &names[..len])?;
+		Ok(Some(parse_multi_z_slice(&wchars)))
+	}
+}
+
+/// Replaces the clipboard contents with the given raw bytes, in the given
+/// format – usually one registered with
+/// [`RegisterClipboardFormat`](crate::RegisterClipboardFormat).
+///
+/// The format must be backed by a global memory block; formats backed by GDI
+/// handles, like [`CF::BITMAP`](crate::co::CF::BITMAP), return
+/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// let format = w::RegisterClipboardFormat("MyApp.Shapes")?;
+/// w::clipboard::set_data(&hwnd, format, &[1, 2, 3, 4])?;
+/// # w::SysResult::Ok(())
+/// ```
+pub fn set_data(hwnd: &HWND, format: co::CF, data: &[u8]) -> SysResult<()> {
+	if !is_hglobal_format(format) {
+		return Err(co::ERROR::INVALID_PARAMETER);
+	}
+	let hglobal = alloc_block(data, &[])?;
+
+	let _clip_guard = open(hwnd)?;
+	EmptyClipboard()?;
+	unsafe { set_block(format, hglobal) }
+}
+
+/// Retrieves the clipboard contents as raw bytes, if the given format is
+/// available.
+///
+/// Returns `None` if the clipboard has no data in this format. Note that the
+/// returned bytes may be padded, since the system can round up the size of
+/// the memory block.
+///
+/// The format must be backed by a global memory block; formats backed by GDI
+/// handles, like [`CF::BITMAP`](crate::co::CF::BITMAP), return
+/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// let format = w::RegisterClipboardFormat("MyApp.Shapes")?;
+/// if let Some(data) = w::clipboard::get_data(&hwnd, format)? {
+///     println!("{} bytes", data.len());
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn get_data(hwnd: &HWND, format: co::CF) -> SysResult<Option<Vec<u8>>> {
+	if !is_hglobal_format(format) {
+		return Err(co::ERROR::INVALID_PARAMETER);
+	}
+
+	let _clip_guard = open(hwnd)?;
+	if !IsClipboardFormatAvailable(format) {
+		return Ok(None);
+	}
+
+	let hglobal = unsafe { get_block(format)? }; // owned by the clipboard
+	let block = hglobal.GlobalLock()?;
+	Ok(Some(block.as_slice().to_vec()))
+}
+
+/// Tells whether the clipboard data of the given format is stored in a global
+/// memory block, as opposed to a GDI handle.
+#[must_use]
+fn is_hglobal_format(format: co::CF) -> bool {
+	match format {
+		co::CF::BITMAP
+			| co::CF::PALETTE
+			| co::CF::ENHMETAFILE
+			| co::CF::OWNERDISPLAY
+			| co::CF::DSPBITMAP
+			| co::CF::DSPENHMETAFILE => false,
+		_ => format.raw() < co::CF::GDIOBJFIRST.raw()
+			|| format.raw() > co::CF::GDIOBJLAST.raw(),
+	}
+}
+
+/// Allocates a moveable global memory block, copying `header` followed by
+/// `data` into it.
+#[must_use]
+fn alloc_block<T>(data: &[T], header: &[u8]) -> SysResult<GlobalFreeGuard> {
+	let data_bytes = unsafe {
+		std::slice::from_raw_parts(
+			data.as_ptr() as *const u8,
+			std::mem::size_of_val(data),
+		)
+	};
+	let hglobal = HGLOBAL::GlobalAlloc(
+		Some(co::GMEM::MOVEABLE),
+		header.len() + data_bytes.len(),
+	)?;
+	{
+		let mut block = hglobal.GlobalLock()?;
+		let dest = block.as_mut_slice();
+		dest[..header.len()].copy_from_slice(header);
+		dest[header.len()..header.len() + data_bytes.len()]
+			.copy_from_slice(data_bytes);
+	}
+	Ok(hglobal)
+}
+
+/// Retrieves the global memory block of the given format. The returned handle
+/// is owned by the clipboard, and must not be freed.
+///
+/// # Safety
+///
+/// The clipboard must be open, and `format` must be backed by an `HGLOBAL`.
+#[must_use]
+unsafe fn get_block(format: co::CF) -> SysResult<HGLOBAL> {
+	GetClipboardData(format)
+		.map(|p| HGLOBAL::from_ptr(p as _))
+}
+
+/// Hands the global memory block over to the clipboard. If the operation
+/// succeeds, the system owns the block, so it's not freed; otherwise, it's
+/// freed by the guard.
+///
+/// # Safety
+///
+/// The clipboard must be open, and the block must contain data of the given
+/// format.
+unsafe fn set_block(
+	format: co::CF,
+	mut hglobal: GlobalFreeGuard,
+) -> SysResult<()>
+{
+	SetClipboardData(format, hglobal.ptr() as _)
+		.map(|_| { let _ = hglobal.leak(); })
+}
@@ -544,6 +544,16 @@ const_bitflag! { DESKTOP_RIGHTS: u32;
 	GENERIC_ALL Self::CREATEMENU.0 | Self::CREATEWINDOW.0 | Self::ENUMERATE.0 | Self::HOOKCONTROL.0 | Self::JOURNALPLAYBACK.0 | Self::JOURNALRECORD.0 | Self::READOBJECTS.0 | Self::SWITCHDESKTOP.0 | Self::WRITEOBJECTS.0 | STANDARD_RIGHTS::REQUIRED.raw()
 }
 
+const_bitflag! { DEVICE_NOTIFY: u32;
+	/// [`HWND::RegisterDeviceNotification`](crate::prelude::user_Hwnd::RegisterDeviceNotification)
+	/// `flags` (`u32`).
+	=>
+	=>
+	WINDOW_HANDLE 0x0000_0000
+	SERVICE_HANDLE 0x0000_0001
+	ALL_INTERFACE_CLASSES 0x0000_0004
+}
+
 const_ordinary! { DF: u32;
 	/// [`HDESK::OpenDesktop`](crate::prelude::user_Hdesk::OpenDesktop) `flags`
 	/// (`u32`).
@@ -1069,7 +1079,7 @@ const_ordinary! { DMTT: i16;
 	DOWNLOAD_OUTLINE 4
 }
 
-const_ordinary! { DLGC: u16;
+const_bitflag! { DLGC: u16;
 	/// [`wm::GetDlgCode`](crate::msg::wm::GetDlgCode) return value (`u16`).
 	=>
 	=>
@@ -1105,6 +1115,18 @@ const_ordinary! { DLGID: u16;
 	CONTINUE 11
 }
 
+const_ordinary! { DPI_AWARENESS_CONTEXT: isize;
+	/// [`DPI_AWARENESS_CONTEXT`](https://learn.microsoft.com/en-us/windows/win32/hidpi/dpi-awareness-context)
+	/// handle values (`isize`).
+	=>
+	=>
+	UNAWARE -1
+	SYSTEM_AWARE -2
+	PER_MONITOR_AWARE -3
+	PER_MONITOR_AWARE_V2 -4
+	UNAWARE_GDISCALED -5
+}
+
 const_ordinary! { DPI_HOSTING_BEHAVIOR: u32;
 	/// [`DPI_HOSTING_BEHAVIOR`](https://learn.microsoft.com/en-us/windows/win32/api/windef/ne-windef-dpi_hosting_behavior)
 	/// enumeration (`u32`).
@@ -1341,6 +1363,66 @@ const_ordinary! { ESB: u32;
 	DISABLE_RTDN Self::DISABLE_RIGHT.0
 }
 
+const_ordinary! { EVENT: u32;
+	/// [`SetWinEventHook`](crate::prelude::user_Hwineventhook::SetWinEventHook)
+	/// [event constants](https://learn.microsoft.com/en-us/windows/win32/winauto/event-constants)
+	/// (`u32`).
+	=>
+	=>
+	MIN 0x0000_0001
+	MAX 0x7fff_ffff
+
+	SYSTEM_SOUND 0x0001
+	SYSTEM_ALERT 0x0002
+	SYSTEM_FOREGROUND 0x0003
+	SYSTEM_MENUSTART 0x0004
+	SYSTEM_MENUEND 0x0005
+	SYSTEM_MENUPOPUPSTART 0x0006
+	SYSTEM_MENUPOPUPEND 0x0007
+	SYSTEM_CAPTURESTART 0x0008
+	SYSTEM_CAPTUREEND 0x0009
+	SYSTEM_MOVESIZESTART 0x000a
+	SYSTEM_MOVESIZEEND 0x000b
+	SYSTEM_CONTEXTHELPSTART 0x000c
+	SYSTEM_CONTEXTHELPEND 0x000d
+	SYSTEM_DRAGDROPSTART 0x000e
+	SYSTEM_DRAGDROPEND 0x000f
+	SYSTEM_DIALOGSTART 0x0010
+	SYSTEM_DIALOGEND 0x0011
+	SYSTEM_SCROLLINGSTART 0x0012
+	SYSTEM_SCROLLINGEND 0x0013
+	SYSTEM_SWITCHSTART 0x0014
+	SYSTEM_SWITCHEND 0x0015
+	SYSTEM_MINIMIZESTART 0x0016
+	SYSTEM_MINIMIZEEND 0x0017
+	SYSTEM_DESKTOPSWITCH 0x0020
+
+	OBJECT_CREATE 0x8000
+	OBJECT_DESTROY 0x8001
+	OBJECT_SHOW 0x8002
+	OBJECT_HIDE 0x8003
+	OBJECT_REORDER 0x8004
+	OBJECT_FOCUS 0x8005
+	OBJECT_SELECTION 0x8006
+	OBJECT_SELECTIONADD 0x8007
+	OBJECT_SELECTIONREMOVE 0x8008
+	OBJECT_SELECTIONWITHIN 0x8009
+	OBJECT_STATECHANGE 0x800a
+	OBJECT_LOCATIONCHANGE 0x800b
+	OBJECT_NAMECHANGE 0x800c
+	OBJECT_DESCRIPTIONCHANGE 0x800d
+	OBJECT_VALUECHANGE 0x800e
+	OBJECT_PARENTCHANGE 0x800f
+	OBJECT_HELPCHANGE 0x8010
+	OBJECT_DEFACTIONCHANGE 0x8011
+	OBJECT_ACCELERATORCHANGE 0x8012
+	OBJECT_INVOKED 0x8013
+	OBJECT_TEXTSELECTIONCHANGED 0x8014
+	OBJECT_CONTENTSCROLLED 0x8015
+	OBJECT_CLOAKED 0x8017
+	OBJECT_UNCLOAKED 0x8018
+}
+
 const_bitflag! { EWX: u32;
 	/// [`ExitWindowsEx`](crate::ExitWindowsEx) `flags` (`u32`).
 	=>
@@ -1365,6 +1447,18 @@ const_ordinary! { FAPPCOMMAND: u16;
 	OEM 0x1000
 }
 
+const_bitflag! { FLASHW: u32;
+	/// [`FLASHWINFO`](crate::FLASHWINFO) `dwFlags` (`u32`).
+	=>
+	=>
+	STOP 0
+	CAPTION 0x0000_0001
+	TRAY 0x0000_0002
+	ALL Self::CAPTION.0 | Self::TRAY.0
+	TIMER 0x0000_0004
+	TIMERNOFG 0x0000_000c
+}
+
 const_ordinary! { GA: u32;
 	/// [`HWND::GetAncestor`](crate::prelude::user_Hwnd::GetAncestor) `flags`
 	/// (`u32`).
@@ -1479,6 +1573,38 @@ const_ordinary! { GWLP: i32;
 	DWLP_USER Self::DWLP_DLGPROC.0 + std::mem::size_of::<isize>() as i32
 }
 
+const_ordinary! { HCBT: i32;
+	/// [`HookEvent::Cbt`](crate::HookEvent::Cbt) `code` (`i32`).
+	=>
+	=>
+	MOVESIZE 0
+	MINMAX 1
+	QS 2
+	CREATEWND 3
+	DESTROYWND 4
+	ACTIVATE 5
+	CLICKSKIPPED 6
+	KEYSKIPPED 7
+	SYSCOMMAND 8
+	SETFOCUS 9
+}
+
+const_bitflag! { HCF: u32;
+	/// [`HIGHCONTRAST`](crate::HIGHCONTRAST) `dwFlags` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	HIGHCONTRASTON 0x0000_0001
+	AVAILABLE 0x0000_0002
+	HOTKEYACTIVE 0x0000_0004
+	CONFIRMHOTKEY 0x0000_0008
+	HOTKEYSOUND 0x0000_0010
+	INDICATOR 0x0000_0020
+	HOTKEYAVAILABLE 0x0000_0040
+	OPTION_NOTHEMECHANGE 0x0000_1000
+}
+
 const_ordinary! { HELPINFO: i32;
 	/// [`HELPINFO`](crate::HELPINFO) `iContextType` (`i32`).
 	=>
@@ -1517,6 +1643,25 @@ const_ordinary! { HELPW: u32;
 	TCARD_OTHER_CALLER 0x0011
 }
 
+const_bitflag! { HOTKEYF: u16;
+	/// [`WM_GETHOTKEY`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-gethotkey)
+	/// and
+	/// [`WM_SETHOTKEY`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-sethotkey)
+	/// modifiers;
+	/// [`SHELLEXECUTEINFO`](https://learn.microsoft.com/en-us/windows/win32/api/shellapi/ns-shellapi-shellexecuteinfow)
+	/// `dwHotKey`;
+	/// [`HKL::VkKeyScanEx`](crate::prelude::user_Hkl::VkKeyScanEx) shift state
+	/// (`u16`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	ALT 0x04
+	CONTROL 0x02
+	EXT 0x08
+	SHIFT 0x01
+}
+
 const_ordinary! { HT: u16;
 	/// [`wm::NcHitTest`](crate::msg::wm::NcHitTest),
 	/// [`wm::SetCursor`](crate::msg::wm::SetCursor) `hit_test` (`u16`).
@@ -1660,6 +1805,25 @@ const_bitflag! { KEYEVENTF: u32;
 	SCANCODE 0x0008
 }
 
+const_bitflag! { KLF: u32;
+	/// [`HKL::LoadKeyboardLayout`](crate::prelude::user_Hkl::LoadKeyboardLayout)
+	/// and
+	/// [`HKL::ActivateKeyboardLayout`](crate::prelude::user_Hkl::ActivateKeyboardLayout)
+	/// `flags` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	ACTIVATE 0x0000_0001
+	SUBSTITUTE_OK 0x0000_0002
+	REORDER 0x0000_0008
+	REPLACELANG 0x0000_0010
+	NOTELLSHELL 0x0000_0080
+	SETFORPROCESS 0x0000_0100
+	SHIFTLOCK 0x0001_0000
+	RESET 0x4000_0000
+}
+
 const_wm! { LB;
 	/// List box control
 	/// [messages](https://learn.microsoft.com/en-us/windows/win32/controls/bumper-list-box-control-reference-messages)
@@ -1750,6 +1914,25 @@ const_ws! { LBS: u32;
 	STANDARD Self::NOTIFY.0 | Self::SORT.0 | WS::VSCROLL.0 | WS::BORDER.0
 }
 
+const_bitflag! { LLKHF: u32;
+	/// [`KBDLLHOOKSTRUCT`](crate::KBDLLHOOKSTRUCT) `flags` (`u32`).
+	=>
+	=>
+	EXTENDED 0x0000_0001
+	LOWER_IL_INJECTED 0x0000_0002
+	INJECTED 0x0000_0010
+	ALTDOWN 0x0000_0020
+	UP 0x0000_0080
+}
+
+const_bitflag! { LLMHF: u32;
+	/// [`MSLLHOOKSTRUCT`](crate::MSLLHOOKSTRUCT) `flags` (`u32`).
+	=>
+	=>
+	INJECTED 0x0000_0001
+	LOWER_IL_INJECTED 0x0000_0002
+}
+
 const_ordinary! { LSFW: u32;
 	/// [`LockSetForegroundWindow`](crate::LockSetForegroundWindow) `lock_code`
 	/// (`u32`).
@@ -1761,9 +1944,13 @@ const_ordinary! { LSFW: u32;
 
 const_bitflag! { LWA: u32;
 	/// [`HWND::SetLayeredWindowAttributes`](crate::prelude::user_Hwnd::SetLayeredWindowAttributes)
+	/// and
+	/// [`HWND::GetLayeredWindowAttributes`](crate::prelude::user_Hwnd::GetLayeredWindowAttributes)
 	/// `flags` (`u32`).
 	=>
 	=>
+	/// None of the actual values (zero).
+	NoValue 0
 	ALPHA 0x0000_0002
 	COLORKEY 0x0000_0001
 }
@@ -1902,6 +2089,18 @@ const_bitflag! { MB: u32;
 	SERVICE_NOTIFICATION 0x0020_0000
 }
 
+const_ordinary! { MAPVK: u32;
+	/// [`HKL::MapVirtualKeyEx`](crate::prelude::user_Hkl::MapVirtualKeyEx)
+	/// `map_type` (`u32`).
+	=>
+	=>
+	VK_TO_VSC 0
+	VSC_TO_VK 1
+	VK_TO_CHAR 2
+	VSC_TO_VK_EX 3
+	VK_TO_VSC_EX 4
+}
+
 const_ordinary! { MDITILE: u32;
 	/// [`HWND::TileWindows`](crate::prelude::user_Hwnd::TileWindows) `how`
 	/// (`u32`).
@@ -1912,6 +2111,17 @@ const_ordinary! { MDITILE: u32;
 	SKIPDISABLED 0x0002
 }
 
+const_ordinary! { MDT: u32;
+	/// [`MONITOR_DPI_TYPE`](https://learn.microsoft.com/en-us/windows/win32/api/shellscalingapi/ne-shellscalingapi-monitor_dpi_type)
+	/// enumeration (`u32`).
+	=>
+	=>
+	EFFECTIVE_DPI 0
+	ANGULAR_DPI 1
+	RAW_DPI 2
+	DEFAULT Self::EFFECTIVE_DPI.0
+}
+
 const_bitflag! { MIIM: u32;
 	/// [`MENUITEMINFO`](crate::MENUITEMINFO) `fMask` (`u32`).
 	=>
@@ -2104,12 +2314,25 @@ const_ordinary! { MSGF: u8;
 
 const_ordinary! { OBJID: u32;
 	/// [`HWND::GetMenuBarInfo`](crate::prelude::user_Hwnd::GetMenuBarInfo)
-	/// `idObject` (`i32`).
+	/// `idObject` and
+	/// [`hook::set_win_event`](crate::hook::set_win_event) object identifier
+	/// (`u32`).
 	=>
 	=>
-	CLIENT 0xffff_fffc
-	MENU 0xffff_fffd
+	WINDOW 0x0000_0000
 	SYSMENU 0xffff_ffff
+	TITLEBAR 0xffff_fffe
+	MENU 0xffff_fffd
+	CLIENT 0xffff_fffc
+	VSCROLL 0xffff_fffb
+	HSCROLL 0xffff_fffa
+	SIZEGRIP 0xffff_fff9
+	CARET 0xffff_fff8
+	CURSOR 0xffff_fff7
+	ALERT 0xffff_fff6
+	SOUND 0xffff_fff5
+	QUERYCLASSNAMEIDX 0xffff_fff4
+	NATIVEOM 0xffff_fff0
 }
 
 const_ordinary! { OBM: u32;
@@ -2228,6 +2451,49 @@ const_ordinary! { ODT_C: u32;
 	COMBOBOX ODT::COMBOBOX.0
 }
 
+const_bitflag! { OFN: u32;
+	/// [`OPENFILENAME`](crate::OPENFILENAME) `Flags` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	READONLY 0x0000_0001
+	OVERWRITEPROMPT 0x0000_0002
+	HIDEREADONLY 0x0000_0004
+	NOCHANGEDIR 0x0000_0008
+	SHOWHELP 0x0000_0010
+	ENABLEHOOK 0x0000_0020
+	ENABLETEMPLATE 0x0000_0040
+	ENABLETEMPLATEHANDLE 0x0000_0080
+	NOVALIDATE 0x0000_0100
+	ALLOWMULTISELECT 0x0000_0200
+	EXTENSIONDIFFERENT 0x0000_0400
+	PATHMUSTEXIST 0x0000_0800
+	FILEMUSTEXIST 0x0000_1000
+	CREATEPROMPT 0x0000_2000
+	SHAREAWARE 0x0000_4000
+	NOREADONLYRETURN 0x0000_8000
+	NOTESTFILECREATE 0x0001_0000
+	NONETWORKBUTTON 0x0002_0000
+	NOLONGNAMES 0x0004_0000
+	EXPLORER 0x0008_0000
+	NODEREFERENCELINKS 0x0010_0000
+	LONGNAMES 0x0020_0000
+	ENABLEINCLUDENOTIFY 0x0040_0000
+	ENABLESIZING 0x0080_0000
+	DONTADDTORECENT 0x0200_0000
+	FORCESHOWHIDDEN 0x1000_0000
+}
+
+const_bitflag! { OFN_EX: u32;
+	/// [`OPENFILENAME`](crate::OPENFILENAME) `FlagsEx` (`u32`).
+	=>
+	=>
+	/// None of the actual values (zero).
+	NoValue 0
+	NOPLACESBAR 0x0000_0001
+}
+
 const_ordinary! { OIC: u32;
 	/// [`HINSTANCE::LoadImageIcon`](crate::prelude::gdi_Hinstance::LoadImageIcon)
 	/// OEM icon identifier (`u32`).
@@ -2259,6 +2525,16 @@ const_bitflag! { PM: u32;
 	QS_SENDMESSAGE QS::SENDMESSAGE.0 << 16
 }
 
+const_ordinary! { PROCESS_DPI_AWARENESS: u32;
+	/// [`PROCESS_DPI_AWARENESS`](https://learn.microsoft.com/en-us/windows/win32/api/shellscalingapi/ne-shellscalingapi-process_dpi_awareness)
+	/// enumeration (`u32`).
+	=>
+	=>
+	DPI_UNAWARE 0
+	SYSTEM_DPI_AWARE 1
+	PER_MONITOR_DPI_AWARE 2
+}
+
 const_bitflag! { QS: u32;
 	/// [`GetQueueStatus`](crate::GetQueueStatus) `flags` (`u32`).
 	=>
@@ -3226,6 +3502,141 @@ const_ordinary! { VK: u16;
 	PA1 0xfd
 	OEM_CLEAR 0xfe
 }
+impl VK {
+	/// Returns the key code of a letter or digit, which is the same in all
+	/// keyboard layouts. Lowercase letters are mapped to their keys as well.
+	///
+	/// Other characters depend on the keyboard layout, thus `None` is returned.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::co;
+	///
+	/// assert_eq!(co::VK::from_char('S'), Some(co::VK::CHAR_S));
+	/// assert_eq!(co::VK::from_char('s'), Some(co::VK::CHAR_S));
+	/// ```
+	#[must_use]
+	pub const fn from_char(ch: char) -> Option<VK> {
+		match ch {
+			'0'..='9' | 'A'..='Z' => Some(Self(ch as _)),
+			'a'..='z' => Some(Self(ch.to_ascii_uppercase() as _)),
+			' ' => Some(Self::SPACE),
+			_ => None,
+		}
+	}
+
+	/// Returns a human-readable English name for the key, to be displayed in
+	/// keymap and accelerator UIs, like `"Ctrl"`, `"Page Up"` or `"F5"`.
+	///
+	/// Returns an empty string for keys without a name. For names in the user
+	/// language, use
+	/// [`GetKeyNameText`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getkeynametextw)
+	/// instead.
+	#[must_use]
+	pub fn name(&self) -> &'static str {
+		const CHARS: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+		const FKEYS: [&str; 24] = [
+			"F1", "F2", "F3", "F4", "F5", "F6", "F7", "F8", "F9", "F10", "F11",
+			"F12", "F13", "F14", "F15", "F16", "F17", "F18", "F19", "F20", "F21",
+			"F22", "F23", "F24",
+		];
+
+		const NUMPADS: [&str; 10] = [
+			"Num 0", "Num 1", "Num 2", "Num 3", "Num 4", "Num 5", "Num 6",
+			"Num 7", "Num 8", "Num 9",
+		];
+
+		let idx = |first: VK| (self.0 - first.0) as usize;
+		if (Self::CHAR_0.0..=Self::CHAR_9.0).contains(&self.0) {
+			return &CHARS[idx(Self::CHAR_0)..idx(Self::CHAR_0) + 1];
+		} else if (Self::CHAR_A.0..=Self::CHAR_Z.0).contains(&self.0) {
+			return &CHARS[idx(Self::CHAR_A) + 10..idx(Self::CHAR_A) + 11];
+		} else if (Self::F1.0..=Self::F24.0).contains(&self.0) {
+			return FKEYS[idx(Self::F1)];
+		} else if (Self::NUMPAD0.0..=Self::NUMPAD9.0).contains(&self.0) {
+			return NUMPADS[idx(Self::NUMPAD0)];
+		}
+
+		match *self {
+			Self::LBUTTON => "Left Button",
+			Self::RBUTTON => "Right Button",
+			Self::CANCEL => "Break",
+			Self::MBUTTON => "Middle Button",
+			Self::XBUTTON1 => "X1 Button",
+			Self::XBUTTON2 => "X2 Button",
+			Self::BACK => "Backspace",
+			Self::TAB => "Tab",
+			Self::CLEAR => "Clear",
+			Self::RETURN => "Enter",
+			Self::SHIFT | Self::LSHIFT | Self::RSHIFT => "Shift",
+			Self::CONTROL | Self::LCONTROL | Self::RCONTROL => "Ctrl",
+			Self::MENU | Self::LMENU | Self::RMENU => "Alt",
+			Self::PAUSE => "Pause",
+			Self::CAPITAL => "Caps Lock",
+			Self::ESCAPE => "Esc",
+			Self::SPACE => "Space",
+			Self::PRIOR => "Page Up",
+			Self::NEXT => "Page Down",
+			Self::END => "End",
+			Self::HOME => "Home",
+			Self::LEFT => "Left",
+			Self::UP => "Up",
+			Self::RIGHT => "Right",
+			Self::DOWN => "Down",
+			Self::SELECT => "Select",
+			Self::PRINT => "Print",
+			Self::EXECUTE => "Execute",
+			Self::SNAPSHOT => "Print Screen",
+			Self::INSERT => "Insert",
+			Self::DELETE => "Delete",
+			Self::HELP => "Help",
+			Self::LWIN | Self::RWIN => "Win",
+			Self::APPS => "Menu",
+			Self::SLEEP => "Sleep",
+			Self::MULTIPLY => "Num *",
+			Self::ADD => "Num +",
+			Self::SEPARATOR => "Num Separator",
+			Self::SUBTRACT => "Num -",
+			Self::DECIMAL => "Num .",
+			Self::DIVIDE => "Num /",
+			Self::NUMLOCK => "Num Lock",
+			Self::SCROLL => "Scroll Lock",
+			Self::BROWSER_BACK => "Browser Back",
+			Self::BROWSER_FORWARD => "Browser Forward",
+			Self::BROWSER_REFRESH => "Browser Refresh",
+			Self::BROWSER_STOP => "Browser Stop",
+			Self::BROWSER_SEARCH => "Browser Search",
+			Self::BROWSER_FAVORITES => "Browser Favorites",
+			Self::BROWSER_HOME => "Browser Home",
+			Self::VOLUME_MUTE => "Volume Mute",
+			Self::VOLUME_DOWN => "Volume Down",
+			Self::VOLUME_UP => "Volume Up",
+			Self::MEDIA_NEXT_TRACK => "Next Track",
+			Self::MEDIA_PREV_TRACK => "Previous Track",
+			Self::MEDIA_STOP => "Stop",
+			Self::MEDIA_PLAY_PAUSE => "Play/Pause",
+			Self::LAUNCH_MAIL => "Mail",
+			Self::LAUNCH_MEDIA_SELECT => "Media",
+			Self::LAUNCH_APP1 => "App 1",
+			Self::LAUNCH_APP2 => "App 2",
+			Self::OEM_1 => ";",
+			Self::OEM_PLUS => "=",
+			Self::OEM_COMMA => ",",
+			Self::OEM_MINUS => "-",
+			Self::OEM_PERIOD => ".",
+			Self::OEM_2 => "/",
+			Self::OEM_3 => "`",
+			Self::OEM_4 => "[",
+			Self::OEM_5 => "\\",
+			Self::OEM_6 => "]",
+			Self::OEM_7 => "'",
+			Self::PLAY => "Play",
+			Self::ZOOM => "Zoom",
+			_ => "",
+		}
+	}
+}
 
 const_ordinary! { WA: u16;
 	/// [`wm::Activate`](crate::msg::wm::Activate) activation state (`u16`).
@@ -3272,6 +3683,17 @@ const_ordinary! { WH: i32;
 	MOUSE_LL 14
 }
 
+const_bitflag! { WINEVENT: u32;
+	/// [`SetWinEventHook`](crate::prelude::user_Hwineventhook::SetWinEventHook)
+	/// `flags` (`u32`).
+	=>
+	=>
+	OUTOFCONTEXT 0x0000
+	SKIPOWNTHREAD 0x0001
+	SKIPOWNPROCESS 0x0002
+	INCONTEXT 0x0004
+}
+
 const_bitflag! { WPF: u32;
 	/// [`WINDOWPLACEMENT`](crate::WINDOWPLACEMENT) `flags` (`u32`).
 	=>
@@ -3311,6 +3733,7 @@ const_ordinary! { WM: u32;
 	SYSCOLORCHANGE 0x0015
 	SHOWWINDOW 0x0018
 	WININICHANGE 0x001a
+	SETTINGCHANGE Self::WININICHANGE.0
 	DEVMODECHANGE 0x001b
 	ACTIVATEAPP 0x001c
 	FONTCHANGE 0x001d
@@ -3840,6 +4263,64 @@ const_bitflag! { WS_EX: u32;
 	NOACTIVATE 0x0800_0000
 }
 
+const_ordinary! { WTS_CONNECTSTATE_CLASS: u32;
+	/// [`WTS_CONNECTSTATE_CLASS`](https://learn.microsoft.com/en-us/windows/win32/api/wtsapi32/ne-wtsapi32-wts_connectstate_class)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `WTS` prefix.
+	=>
+	=>
+	Active 0
+	Connected 1
+	ConnectQuery 2
+	Shadow 3
+	Disconnected 4
+	Idle 5
+	Listen 6
+	Reset 7
+	Down 8
+	Init 9
+}
+
+const_ordinary! { WTS_INFO_CLASS: u32;
+	/// [`WTS_INFO_CLASS`](https://learn.microsoft.com/en-us/windows/win32/api/wtsapi32/ne-wtsapi32-wts_info_class)
+	/// enumeration (`u32`).
+	///
+	/// Originally has `WTS` prefix.
+	=>
+	=>
+	InitialProgram 0
+	ApplicationName 1
+	WorkingDirectory 2
+	OEMId 3
+	SessionId 4
+	UserName 5
+	WinStationName 6
+	DomainName 7
+	ConnectState 8
+	ClientBuildNumber 9
+	ClientName 10
+	ClientDirectory 11
+	ClientProductId 12
+	ClientHardwareId 13
+	ClientAddress 14
+	ClientDisplay 15
+	ClientProtocolType 16
+	IdleTime 17
+	LogonTime 18
+	IncomingBytes 19
+	OutgoingBytes 20
+	IncomingFrames 21
+	OutgoingFrames 22
+	ClientInfo 23
+	SessionInfo 24
+	SessionInfoEx 25
+	ConfigInfo 26
+	ValidationInfo 27
+	SessionAddressV4 28
+	IsRemoteSession 29
+}
+
 const_bitflag! { WVR: u32;
 	/// [`wm::NcCalcSize`](crate::msg::wm::NcCalcSize) return flags (`u32`).
 	=>
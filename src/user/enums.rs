@@ -128,6 +128,18 @@ impl BmpPtrStr {
 	}
 }
 
+/// Variant parameter for:
+///
+/// * [`HWND::RegisterDeviceNotification`](crate::prelude::user_Hwnd::RegisterDeviceNotification).
+pub enum DevNotify<'a> {
+	/// Notifications for devices of the given interface class. Ignored if
+	/// [`co::DEVICE_NOTIFY::ALL_INTERFACE_CLASSES`](crate::co::DEVICE_NOTIFY::ALL_INTERFACE_CLASSES)
+	/// is passed.
+	DeviceInterfaceClass(co::DEVINTERFACE),
+	/// Notifications for the device opened with the given file handle.
+	Handle(&'a HFILE),
+}
+
 /// Variant parameter for:
 ///
 /// * [`DEVMODE`](crate::DEVMODE).
@@ -159,6 +171,27 @@ impl From<GmidxEnum> for u32 {
 	}
 }
 
+/// Hook data received by the closure passed to
+/// [`hook::set`](crate::hook::set).
+pub enum HookEvent<'a> {
+	/// [`WH::KEYBOARD_LL`](crate::co::WH::KEYBOARD_LL) event. Contains the
+	/// keyboard message and its data.
+	KeyboardLl(co::WM, &'a KBDLLHOOKSTRUCT),
+	/// [`WH::MOUSE_LL`](crate::co::WH::MOUSE_LL) event. Contains the mouse
+	/// message and its data.
+	MouseLl(co::WM, &'a MSLLHOOKSTRUCT),
+	/// [`WH::CBT`](crate::co::WH::CBT) event. Contains the hook code, and the
+	/// raw `wParam` and `lParam`, whose meaning depends on the code.
+	Cbt(co::HCBT, usize, isize),
+	/// [`WH::MSGFILTER`](crate::co::WH::MSGFILTER) event. Contains the
+	/// `MSGF_*` code of the input event and the message.
+	MsgFilter(i32, &'a MSG),
+	/// [`WH::GETMESSAGE`](crate::co::WH::GETMESSAGE) event. Contains whether
+	/// the message has been removed from the queue, and the message itself,
+	/// which can be modified.
+	GetMessage(bool, &'a mut MSG),
+}
+
 /// Variant parameter for:
 ///
 /// * [`INPUT`](crate::INPUT).
@@ -405,6 +438,50 @@ impl IdPos {
 	}
 }
 
+/// An input event to be synthesized by
+/// [`input::send`](crate::input::send).
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum InputEvent {
+	/// A key is pressed. Extended keys, like the arrows, are flagged
+	/// automatically.
+	KeyDown(co::VK),
+	/// A key is released. Extended keys, like the arrows, are flagged
+	/// automatically.
+	KeyUp(co::VK),
+	/// A character is typed – pressed and released – regardless of the
+	/// current keyboard layout. Characters outside the Basic Multilingual Plane
+	/// are sent as surrogate pairs.
+	UnicodeChar(char),
+	/// The mouse cursor is moved. If `absolute`, `dx` and `dy` are the
+	/// coordinates of the virtual screen, in pixels; otherwise they are relative
+	/// to the current position, and subject to mouse acceleration.
+	MouseMove { dx: i32, dy: i32, absolute: bool },
+	/// A mouse button is pressed or released. The button must be one of
+	/// [`MK::LBUTTON`](crate::co::MK::LBUTTON),
+	/// [`MK::RBUTTON`](crate::co::MK::RBUTTON),
+	/// [`MK::MBUTTON`](crate::co::MK::MBUTTON),
+	/// [`MK::XBUTTON1`](crate::co::MK::XBUTTON1) or
+	/// [`MK::XBUTTON2`](crate::co::MK::XBUTTON2).
+	MouseButton { button: co::MK, down: bool },
+	/// The mouse wheel is rotated, in multiples of `WHEEL_DELTA` (120).
+	/// Positive values rotate forward, away from the user.
+	MouseWheel(i32),
+}
+
+/// Return type of
+/// [`HKL::ToUnicodeEx`](crate::prelude::user_Hkl::ToUnicodeEx).
+#[derive(Clone, PartialEq, Eq)]
+pub enum KeyChars {
+	/// The key has no translation in the current keyboard state.
+	NoChar,
+	/// The key is a dead key; contains its spacing character, like `^`. The
+	/// next key will be combined with it.
+	Dead(String),
+	/// The characters produced by the key. There may be more than one when a
+	/// pending dead key cannot be combined with the key.
+	Chars(String),
+}
+
 /// Variant parameter for:
 ///
 /// * [`HMENU::append_item`](crate::prelude::user_Hmenu::append_item).
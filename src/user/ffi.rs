@@ -3,6 +3,8 @@ use crate::kernel::ffi_types::*;
 extern_sys! { "comdlg32";
 	ChooseColorW(PVOID) -> BOOL
 	CommDlgExtendedError() -> u32
+	GetOpenFileNameW(PVOID) -> BOOL
+	GetSaveFileNameW(PVOID) -> BOOL
 }
 
 #[cfg(target_pointer_width = "32")]
@@ -19,8 +21,8 @@ extern_sys! { "user32";
 }
 
 extern_sys! { "user32";
+	ActivateKeyboardLayout(HANDLE, u32) -> HANDLE
 	AdjustWindowRectEx(PVOID, u32, BOOL, u32) -> BOOL
-	AdjustWindowRectExForDpi(PVOID, u32, BOOL, u32, u32) -> BOOL
 	AllowSetForegroundWindow(u32) -> BOOL
 	AnyPopup() -> BOOL
 	AppendMenuW(HANDLE, u32, usize, PCSTR) -> BOOL
@@ -36,12 +38,14 @@ extern_sys! { "user32";
 	ChangeDisplaySettingsW(PVOID, u32) -> i32
 	CheckMenuItem(HANDLE, u32, u32) -> i32
 	CheckMenuRadioItem(HANDLE, u32, u32, u32, u32) -> BOOL
+	CheckRadioButton(HANDLE, i32, i32, i32) -> BOOL
 	ChildWindowFromPoint(HANDLE, i32, i32) -> HANDLE
 	ClientToScreen(HANDLE, PVOID) -> BOOL
 	ClipCursor(PCVOID) -> BOOL
 	CloseClipboard() -> BOOL
 	CloseDesktop(HANDLE) -> BOOL
 	CloseWindow(HANDLE) -> BOOL
+	CopyAcceleratorTableW(HANDLE, PVOID, i32) -> i32
 	CopyIcon(HANDLE) -> HANDLE
 	CreateAcceleratorTableW(PCVOID, i32) -> HANDLE
 	CreateDesktopExW(PCSTR, PCSTR, PCVOID, u32, u32, PVOID, u32, PVOID) -> HANDLE
@@ -85,6 +89,8 @@ extern_sys! { "user32";
 	ExitWindowsEx(u32, u32) -> BOOL
 	FindWindowExW(HANDLE, HANDLE, PCSTR, PCSTR) -> HANDLE
 	FindWindowW(PCSTR, PCSTR) -> HANDLE
+	FlashWindow(HANDLE, BOOL) -> BOOL
+	FlashWindowEx(PCVOID) -> BOOL
 	GetActiveWindow() -> HANDLE
 	GetAltTabInfoW(HANDLE, i32, PVOID, PSTR, u32) -> BOOL
 	GetAncestor(HANDLE, u32) -> HANDLE
@@ -109,14 +115,19 @@ extern_sys! { "user32";
 	GetDlgCtrlID(HANDLE) -> i32
 	GetDlgItem(HANDLE, i32) -> HANDLE
 	GetDoubleClickTime() -> u32
-	GetDpiForWindow(HANDLE) -> u32
 	GetFocus() -> HANDLE
 	GetForegroundWindow() -> HANDLE
 	GetGUIThreadInfo(u32, PVOID) -> BOOL
 	GetIconInfo(HANDLE, PVOID) -> BOOL
 	GetIconInfoExW(HANDLE, PVOID) -> BOOL
+	GetKeyNameTextW(i32, PSTR, i32) -> i32
+	GetKeyState(i32) -> i16
+	GetKeyboardLayout(u32) -> HANDLE
+	GetKeyboardLayoutList(i32, *mut HANDLE) -> i32
+	GetKeyboardState(*mut u8) -> BOOL
 	GetLastActivePopup(HANDLE) -> HANDLE
 	GetLastInputInfo(PVOID) -> BOOL
+	GetLayeredWindowAttributes(HANDLE, *mut u32, *mut u8, *mut u32) -> BOOL
 	GetMenu(HANDLE) -> HANDLE
 	GetMenuBarInfo(HANDLE, i32, i32, PVOID) -> BOOL
 	GetMenuCheckMarkDimensions() -> u32
@@ -142,7 +153,6 @@ extern_sys! { "user32";
 	GetSysColor(i32) -> u32
 	GetSystemMenu(HANDLE, BOOL) -> HANDLE
 	GetSystemMetrics(i32) -> i32
-	GetSystemMetricsForDpi(i32, u32) -> i32
 	GetThreadDesktop(u32) -> HANDLE
 	GetThreadDpiHostingBehavior() -> u32
 	GetTopWindow(HANDLE) -> HANDLE
@@ -172,10 +182,13 @@ extern_sys! { "user32";
 	InvalidateRgn(HANDLE, HANDLE, BOOL) -> BOOL
 	InvertRect(HANDLE, PCVOID) -> BOOL
 	IsChild(HANDLE, HANDLE) -> BOOL
+	IsClipboardFormatAvailable(u32) -> BOOL
 	IsDialogMessageW(HANDLE, PVOID) -> BOOL
 	IsGUIThread(BOOL) -> BOOL
+	IsHungAppWindow(HANDLE) -> BOOL
 	IsIconic(HANDLE) -> BOOL
 	IsMenu(HANDLE) -> BOOL
+	IsProcessDPIAware() -> BOOL
 	IsRectEmpty(PVOID) -> BOOL
 	IsWindow(HANDLE) -> BOOL
 	IsWindowEnabled(HANDLE) -> BOOL
@@ -187,13 +200,17 @@ extern_sys! { "user32";
 	LoadAcceleratorsW(HANDLE, PCSTR) -> HANDLE
 	LoadCursorW(HANDLE, PCSTR) -> HANDLE
 	LoadIconW(HANDLE, PCSTR) -> HANDLE
+	LoadKeyboardLayoutW(PCSTR, u32) -> HANDLE
 	LoadMenuW(HANDLE, PCSTR) -> HANDLE
 	LoadStringW(HANDLE, u32, PSTR, i32) -> i32
 	LockSetForegroundWindow(u32) -> BOOL
 	LockWindowUpdate(HANDLE) -> BOOL
 	LogicalToPhysicalPoint(HANDLE, PVOID) -> BOOL
 	MapDialogRect(HANDLE, PVOID) -> BOOL
+	MapVirtualKeyExW(u32, u32, HANDLE) -> u32
+	MapVirtualKeyW(u32, u32) -> u32
 	MapWindowPoints(HANDLE, HANDLE, PVOID, u32) -> i32
+	MessageBeep(u32) -> BOOL
 	MessageBoxW(HANDLE, PCSTR, PCSTR, u32) -> i32
 	MonitorFromPoint(i32, i32, u32) -> HANDLE
 	MonitorFromRect(PCVOID, u32) -> HANDLE
@@ -205,6 +222,7 @@ extern_sys! { "user32";
 	OpenInputDesktop(u32, BOOL, u32) -> HANDLE
 	PaintDesktop(HANDLE) -> BOOL
 	PeekMessageW(PVOID, HANDLE, u32, u32, u32) -> BOOL
+	PhysicalToLogicalPoint(HANDLE, PVOID) -> BOOL
 	PostMessageW(HANDLE, u32, usize, isize) -> BOOL
 	PostQuitMessage(i32)
 	PostThreadMessageW(u32, u32, usize, isize) -> BOOL
@@ -213,6 +231,8 @@ extern_sys! { "user32";
 	RealGetWindowClassW(HANDLE, PSTR, i32) -> u32
 	RedrawWindow(HANDLE, PCVOID, HANDLE, u32) -> BOOL
 	RegisterClassExW(PCVOID) -> u16
+	RegisterClipboardFormatW(PCSTR) -> u32
+	RegisterDeviceNotificationW(HANDLE, PCVOID, u32) -> HANDLE
 	RegisterHotKey(HANDLE, i32, u32, u32) -> BOOL
 	RegisterWindowMessageW(PCSTR) -> u32
 	ReleaseCapture() -> BOOL
@@ -228,6 +248,7 @@ extern_sys! { "user32";
 	SetCaretBlinkTime(u32) -> BOOL
 	SetCaretPos(i32, i32) -> BOOL
 	SetClipboardData(u32, HANDLE) -> HANDLE
+	SetCursor(HANDLE) -> HANDLE
 	SetCursorPos(i32, i32) -> BOOL
 	SetDialogDpiChangeBehavior(HANDLE, u32, u32) -> BOOL
 	SetDoubleClickTime(u32) -> BOOL
@@ -255,6 +276,7 @@ extern_sys! { "user32";
 	SetWindowRgn(HANDLE, HANDLE, BOOL) -> i32
 	SetWindowsHookExW(i32, PFUNC, HANDLE, u32) -> HANDLE
 	SetWindowTextW(HANDLE, PCSTR) -> BOOL
+	SetWinEventHook(u32, u32, HANDLE, PFUNC, u32, u32, u32) -> HANDLE
 	ShowCaret(HANDLE) -> BOOL
 	ShowCursor(BOOL) -> i32
 	ShowOwnedPopups(HANDLE, BOOL) -> BOOL
@@ -266,18 +288,22 @@ extern_sys! { "user32";
 	SwitchDesktop(HANDLE) -> BOOL
 	SystemParametersInfoW(u32, u32, PVOID, u32) -> BOOL
 	TileWindows(HANDLE, u32, PCVOID, u32, PCVOID) -> u16
+	ToUnicodeEx(u32, u32, *const u8, PSTR, i32, u32, HANDLE) -> i32
 	TrackMouseEvent(PVOID) -> BOOL
 	TrackPopupMenu(HANDLE, u32, i32, i32, i32, HANDLE, PCVOID) -> BOOL
 	TranslateAcceleratorW(HANDLE, HANDLE, PVOID) -> i32
 	TranslateMessage(PCVOID) -> BOOL
 	UnhookWindowsHookEx(HANDLE) -> BOOL
+	UnhookWinEvent(HANDLE) -> BOOL
 	UnionRect(PVOID, PCVOID, PCVOID) -> BOOL
 	UnregisterClassW(PCSTR, HANDLE) -> BOOL
+	UnregisterDeviceNotification(HANDLE) -> BOOL
 	UnregisterHotKey(HANDLE, i32) -> BOOL
 	UpdateLayeredWindow(HANDLE, HANDLE, PCVOID, PCVOID, HANDLE, PCVOID, u32, PCVOID, u32) -> BOOL
 	UpdateWindow(HANDLE) -> BOOL
 	ValidateRect(HANDLE, PCVOID) -> BOOL
 	ValidateRgn(HANDLE, HANDLE) -> BOOL
+	VkKeyScanExW(u16, HANDLE) -> i16
 	WaitForInputIdle(HANDLE, u32) -> u32
 	WaitMessage() -> BOOL
 	WindowFromDC(HANDLE) -> HANDLE
@@ -285,3 +311,11 @@ extern_sys! { "user32";
 	WindowFromPoint(i32, i32) -> HANDLE
 	WinHelpW(HANDLE, PCSTR, u32, usize) -> BOOL
 }
+
+extern_sys! { "wtsapi32";
+	WTSEnumerateSessionsW(HANDLE, u32, u32, *mut PVOID, *mut u32) -> BOOL
+	WTSFreeMemory(PVOID)
+	WTSQuerySessionInformationW(HANDLE, u32, u32, *mut PSTR, *mut u32) -> BOOL
+	WTSRegisterSessionNotification(HANDLE, u32) -> BOOL
+	WTSUnRegisterSessionNotification(HANDLE) -> BOOL
+}
@@ -2,7 +2,8 @@
 
 use crate::co;
 use crate::decl::*;
-use crate::kernel::privs::*;
+use crate::guard::*;
+use crate::kernel::{ffi_types::*, privs::*};
 use crate::prelude::*;
 use crate::user::{ffi, iterators::*, privs::*, proc};
 
@@ -36,6 +37,11 @@ pub fn AdjustWindowRectEx(
 /// [`AdjustWindowRectExForDpi`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-adjustwindowrectexfordpi)
 /// function.
 ///
+/// The function is loaded at runtime. On Windows versions prior to 10 1607,
+/// where it doesn't exist, falls back to
+/// [`AdjustWindowRectEx`](crate::AdjustWindowRectEx), which uses the system
+/// DPI.
+///
 /// # Related functions
 ///
 /// * [`AdjustWindowRectEx`](crate::AdjustWindowRectEx)
@@ -49,21 +55,22 @@ pub fn AdjustWindowRectExForDpi(
 ) -> SysResult<RECT>
 {
 	let mut buf = rc;
-	bool_to_sysresult(
-		unsafe {
-			ffi::AdjustWindowRectExForDpi(
-				&mut buf as *mut _ as _,
-				style.raw(),
-				has_menu as _,
-				ex_style.raw(),
-				dpi,
-			)
-		},
-	).map(|_| buf)
+	match unsafe {
+		call_dyn("user32", "AdjustWindowRectExForDpi",
+			|f: extern "system" fn(PVOID, u32, BOOL, u32, u32) -> BOOL| {
+				f(&mut buf as *mut _ as _, style.raw(), has_menu as _,
+					ex_style.raw(), dpi)
+			})
+	} {
+		Some(ret) => bool_to_sysresult(ret).map(|_| buf),
+		None => AdjustWindowRectEx(rc, style, has_menu, ex_style),
+	}
 }
 
 /// [`AllowSetForegroundWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-allowsetforegroundwindow)
-/// function
+/// function.
+///
+/// If `process_id` is `None`, all processes are allowed.
 pub fn AllowSetForegroundWindow(process_id: Option<u32>) -> SysResult<()> {
 	bool_to_sysresult(
 		unsafe { ffi::AllowSetForegroundWindow(process_id.unwrap_or(ASFW_ANY)) },
@@ -242,8 +249,11 @@ pub fn CommDlgExtendedError() -> co::CDERR {
 ///
 /// # Safety
 ///
-/// This function is used internally in window loops. Avoid using it in other
-/// situations.
+/// The message is delivered straight to the window procedure, which will
+/// interpret its parameters – often pointers. Only dispatch messages retrieved
+/// by [`GetMessage`](crate::GetMessage) or [`PeekMessage`](crate::PeekMessage).
+///
+/// For an example, see [`GetMessage`](crate::GetMessage).
 pub unsafe fn DispatchMessage(msg: &MSG) -> isize {
 	ffi::DispatchMessageW(msg as *const _ as _)
 }
@@ -580,6 +590,20 @@ pub fn GetDoubleClickTime() -> u32 {
 	unsafe { ffi::GetDoubleClickTime() }
 }
 
+/// [`GetDpiForSystem`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdpiforsystem)
+/// function.
+///
+/// The function is loaded at runtime. On Windows versions prior to 10 1607,
+/// where it doesn't exist, falls back to `GetDeviceCaps` with `LOGPIXELSX` on
+/// the screen DC.
+#[must_use]
+pub fn GetDpiForSystem() -> u32 {
+	unsafe {
+		call_dyn("user32", "GetDpiForSystem",
+			|f: extern "system" fn() -> u32| f())
+	}.unwrap_or_else(legacy_system_dpi)
+}
+
 /// [`GetGUIThreadInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getguithreadinfo)
 /// function.
 ///
@@ -605,6 +629,83 @@ pub fn GetGUIThreadInfo(thread_id: u32) -> SysResult<GUITHREADINFO> {
 	).map(|_| gti)
 }
 
+/// [`GetKeyNameText`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getkeynametextw)
+/// function.
+///
+/// The `scan_code` is in the format returned by
+/// [`HKL::MapVirtualKeyEx`](crate::prelude::user_Hkl::MapVirtualKeyEx) with
+/// [`MAPVK::VK_TO_VSC_EX`](crate::co::MAPVK::VK_TO_VSC_EX): the scan code in
+/// the low byte, and `0xe0` or `0xe1` in the high byte for extended keys. The
+/// name is given by the keyboard layout of the current thread.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let name = w::GetKeyNameText(0x1d)?; // left Ctrl
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn GetKeyNameText(scan_code: u32) -> SysResult<String> {
+	let mut lparam = ((scan_code & 0xff) << 16) as i32;
+	if scan_code & 0xff00 != 0 {
+		lparam |= 1 << 24; // extended key
+	}
+
+	let mut buf = WString::new_alloc_buf(64);
+	match unsafe {
+		ffi::GetKeyNameTextW(lparam, buf.as_mut_ptr(), buf.buf_len() as _)
+	} {
+		0 => Err(GetLastError()),
+		_ => Ok(buf.to_string()),
+	}
+}
+
+/// [`GetKeyState`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getkeystate)
+/// function.
+///
+/// Returns whether the key was down, and whether it was toggled – like Caps
+/// Lock – when the current message was generated. Unlike
+/// [`GetAsyncKeyState`](crate::GetAsyncKeyState), this reflects the state of
+/// the message queue, not of the hardware.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let (_, caps_lock_on) = w::GetKeyState(co::VK::CAPITAL);
+/// ```
+#[must_use]
+pub fn GetKeyState(virt_key: co::VK) -> (bool, bool) {
+	let state = unsafe { ffi::GetKeyState(virt_key.raw() as _) } as u16;
+	(state & 0x8000 != 0, state & 0x0001 != 0)
+}
+
+/// [`GetKeyboardState`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getkeyboardstate)
+/// function.
+///
+/// The returned array is indexed by the [`VK`](crate::co::VK) value; the high
+/// bit of each entry means the key is down, and the low bit means it is
+/// toggled.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let state = w::GetKeyboardState()?;
+/// let shift_down = state[co::VK::SHIFT.raw() as usize] & 0x80 != 0;
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn GetKeyboardState() -> SysResult<[u8; 256]> {
+	let mut state = [0u8; 256];
+	bool_to_sysresult(unsafe { ffi::GetKeyboardState(state.as_mut_ptr()) })
+		.map(|_| state)
+}
+
 /// [`GetLastInputInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getlastinputinfo)
 /// function.
 #[must_use]
@@ -615,6 +716,47 @@ pub fn GetLastInputInfo() -> SysResult<LASTINPUTINFO> {
 
 /// [`GetMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getmessagew)
 /// function.
+///
+/// Returns `false` when
+/// [`WM_QUIT`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-quit)
+/// is retrieved.
+///
+/// Along with [`TranslateMessage`](crate::TranslateMessage),
+/// [`DispatchMessage`](crate::DispatchMessage) and
+/// [`HWND::TranslateAccelerator`](crate::prelude::user_Hwnd::TranslateAccelerator),
+/// this allows you to write your own message loop. A nested loop – like a
+/// modal sub-loop – can safely run inside an event of a window created with
+/// the `gui` module, while the loop of
+/// [`WindowMain::run_main`](crate::gui::WindowMain::run_main) is blocked.
+/// If the nested loop retrieves `WM_QUIT`, it must post it again with
+/// [`PostQuitMessage`](crate::PostQuitMessage), so the outer loop can
+/// terminate the application too.
+///
+/// # Examples
+///
+/// A modal sub-loop, which runs until the given modeless window is
+/// destroyed:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// let hdlg: w::HWND; // initialized somewhere
+/// # let hdlg = w::HWND::NULL;
+///
+/// let mut msg = w::MSG::default();
+/// while hdlg.IsWindow() {
+///     if !w::GetMessage(&mut msg, None, 0, 0)? {
+///         w::PostQuitMessage(msg.wParam as _); // let the outer loop quit too
+///         break;
+///     }
+///     if hdlg.IsDialogMessage(&mut msg) {
+///         continue;
+///     }
+///     w::TranslateMessage(&msg);
+///     unsafe { w::DispatchMessage(&msg); }
+/// }
+/// # w::SysResult::Ok(())
+/// ```
 pub fn GetMessage(
 	msg: &mut MSG,
 	hwnd: Option<&HWND>,
@@ -649,6 +791,92 @@ pub fn GetMessagePos() -> POINT {
 	POINT::from(unsafe { ffi::GetMessagePos() })
 }
 
+/// [`GetProcessDpiAwareness`](https://learn.microsoft.com/en-us/windows/win32/api/shellscalingapi/nf-shellscalingapi-getprocessdpiawareness)
+/// function.
+///
+/// If `hprocess` is `None`, the current process is queried.
+///
+/// The function is loaded at runtime from shcore. On Windows versions prior to
+/// 8.1, where it doesn't exist, the current process is queried with
+/// [`IsProcessDPIAware`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isprocessdpiaware),
+/// and querying other processes fails with
+/// [`ERROR::CALL_NOT_IMPLEMENTED`](crate::co::ERROR::CALL_NOT_IMPLEMENTED).
+#[must_use]
+pub fn GetProcessDpiAwareness(
+	hprocess: Option<&HPROCESS>,
+) -> SysResult<co::PROCESS_DPI_AWARENESS>
+{
+	let mut value = u32::default();
+	match unsafe {
+		call_dyn("shcore", "GetProcessDpiAwareness",
+			|f: extern "system" fn(HANDLE, *mut u32) -> i32| {
+				f(hprocess.map_or(std::ptr::null_mut(), |h| h.ptr()), &mut value)
+			})
+	} {
+		Some(hr) => shcore_hr_to_sysresult(hr)
+			.map(|_| unsafe { co::PROCESS_DPI_AWARENESS::from_raw(value) }),
+		None => match hprocess {
+			Some(_) => Err(co::ERROR::CALL_NOT_IMPLEMENTED),
+			None => Ok(match unsafe { ffi::IsProcessDPIAware() } {
+				0 => co::PROCESS_DPI_AWARENESS::DPI_UNAWARE,
+				_ => co::PROCESS_DPI_AWARENESS::SYSTEM_DPI_AWARE,
+			}),
+		},
+	}
+}
+
+/// [`GetOpenFileName`](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/nf-commdlg-getopenfilenamew)
+/// function.
+///
+/// Returns the full paths of the chosen files, as returned by
+/// [`OPENFILENAME::selected_files`](crate::OPENFILENAME::selected_files), or
+/// `None` if the user cancelled the dialog. This legacy dialog doesn't require
+/// COM; on Windows Vista and later, prefer
+/// [`IFileOpenDialog`](crate::IFileOpenDialog).
+///
+/// # Examples
+///
+/// Choosing multiple text files:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let parent_hwnd: w::HWND; // initialized somewhere
+/// # let parent_hwnd = w::HWND::NULL;
+///
+/// let mut filter = w::WString::from_str_vec(&[
+///     "Text files", "*.txt",
+///     "All files", "*.*",
+/// ]);
+/// let mut buf = w::WString::new_alloc_buf(4096);
+///
+/// let mut ofn = w::OPENFILENAME::default();
+/// ofn.hwndOwner = parent_hwnd;
+/// ofn.Flags = co::OFN::EXPLORER | co::OFN::ALLOWMULTISELECT
+///     | co::OFN::FILEMUSTEXIST;
+/// ofn.set_lpstrFilter(Some(&mut filter));
+/// ofn.set_lpstrFile(Some(&mut buf));
+///
+/// if let Some(paths) = w::GetOpenFileName(&mut ofn)? {
+///     for path in paths.iter() {
+///         println!("{}", path);
+///     }
+/// }
+/// # Ok::<_, co::CDERR>(())
+/// ```
+pub fn GetOpenFileName(
+	ofn: &mut OPENFILENAME,
+) -> Result<Option<Vec<String>>, co::CDERR>
+{
+	match unsafe { ffi::GetOpenFileNameW(ofn as *mut _ as _) } {
+		0 => match CommDlgExtendedError() {
+			co::CDERR::NoValue => Ok(None),
+			err => Err(err),
+		},
+		_ => Ok(Some(ofn.selected_files())),
+	}
+}
+
 /// [`GetQueueStatus`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getqueuestatus)
 /// function.
 #[must_use]
@@ -656,8 +884,63 @@ pub fn GetQueueStatus(flags: co::QS) -> u32 {
 	unsafe { ffi::GetQueueStatus(flags.raw()) }
 }
 
+/// [`GetSaveFileName`](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/nf-commdlg-getsavefilenamew)
+/// function.
+///
+/// Returns the full path of the chosen file, or `None` if the user cancelled
+/// the dialog. This legacy dialog doesn't require COM; on Windows Vista and
+/// later, prefer [`IFileSaveDialog`](crate::IFileSaveDialog).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut filter = w::WString::from_str_vec(&["Text files", "*.txt"]);
+/// let mut def_ext = w::WString::from_str("txt");
+/// let mut buf = w::WString::new_alloc_buf(260);
+///
+/// let mut ofn = w::OPENFILENAME::default();
+/// ofn.Flags = co::OFN::EXPLORER | co::OFN::OVERWRITEPROMPT;
+/// ofn.set_lpstrFilter(Some(&mut filter));
+/// ofn.set_lpstrDefExt(Some(&mut def_ext));
+/// ofn.set_lpstrFile(Some(&mut buf));
+///
+/// if let Some(path) = w::GetSaveFileName(&mut ofn)? {
+///     println!("{}", path);
+/// }
+/// # Ok::<_, co::CDERR>(())
+/// ```
+pub fn GetSaveFileName(
+	ofn: &mut OPENFILENAME,
+) -> Result<Option<String>, co::CDERR>
+{
+	match unsafe { ffi::GetSaveFileNameW(ofn as *mut _ as _) } {
+		0 => match CommDlgExtendedError() {
+			co::CDERR::NoValue => Ok(None),
+			err => Err(err),
+		},
+		_ => Ok(ofn.lpstrFile()),
+	}
+}
+
 /// [`GetSysColor`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getsyscolor)
 /// function.
+///
+/// Useful in custom painting, so the colors match the current Windows theme.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hdc: w::HDC; // initialized somewhere
+/// # let hdc = w::HDC::NULL;
+///
+/// hdc.SetTextColor(w::GetSysColor(co::COLOR::WINDOWTEXT))?;
+/// hdc.SetBkColor(w::GetSysColor(co::COLOR::WINDOW))?;
+/// # w::SysResult::Ok(())
+/// ```
 #[must_use]
 pub fn GetSysColor(index: co::COLOR) -> COLORREF {
 	unsafe { COLORREF::from_raw(ffi::GetSysColor(index.raw())) }
@@ -672,9 +955,17 @@ pub fn GetSystemMetrics(index: co::SM) -> i32 {
 
 /// [`GetSystemMetricsForDpi`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getsystemmetricsfordpi)
 /// function.
+///
+/// The function is loaded at runtime. On Windows versions prior to 10 1607,
+/// where it doesn't exist, falls back to
+/// [`GetSystemMetrics`](crate::GetSystemMetrics), which uses the system DPI.
 #[must_use]
 pub fn GetSystemMetricsForDpi(index: co::SM, dpi: u32) -> SysResult<i32> {
-	match unsafe { ffi::GetSystemMetricsForDpi(index.raw(), dpi) } {
+	SetLastError(co::ERROR::SUCCESS);
+	match unsafe {
+		call_dyn("user32", "GetSystemMetricsForDpi",
+			|f: extern "system" fn(i32, u32) -> i32| f(index.raw(), dpi))
+	}.unwrap_or_else(|| GetSystemMetrics(index)) {
 		0 => match GetLastError() {
 			co::ERROR::SUCCESS => Ok(0), // actual value is zero
 			err => Err(err),
@@ -735,6 +1026,18 @@ pub fn IntersectRect(src1: RECT, src2: RECT) -> SysResult<RECT> {
 	).map(|_| dest)
 }
 
+/// [`IsClipboardFormatAvailable`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isclipboardformatavailable)
+/// function.
+///
+/// # Related functions
+///
+/// * [`GetClipboardData`](crate::GetClipboardData)
+/// * [`SetClipboardData`](crate::SetClipboardData)
+#[must_use]
+pub fn IsClipboardFormatAvailable(format: co::CF) -> bool {
+	unsafe { ffi::IsClipboardFormatAvailable(format.raw()) != 0 }
+}
+
 /// [`IsGUIThread`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isguithread)
 /// function.
 pub fn IsGUIThread(convert_to_gui_thread: bool) -> SysResult<bool> {
@@ -770,6 +1073,33 @@ pub fn LockSetForegroundWindow(lock_code: co::LSFW) -> SysResult<()> {
 	bool_to_sysresult(unsafe { ffi::LockSetForegroundWindow(lock_code.raw()) })
 }
 
+/// [`MessageBeep`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-messagebeep)
+/// function.
+///
+/// Plays the sound associated to one of the
+/// [`MB::ICONASTERISK`](crate::co::MB::ICONASTERISK),
+/// [`MB::ICONEXCLAMATION`](crate::co::MB::ICONEXCLAMATION),
+/// [`MB::ICONHAND`](crate::co::MB::ICONHAND),
+/// [`MB::ICONQUESTION`](crate::co::MB::ICONQUESTION) or
+/// [`MB::OK`](crate::co::MB::OK) message box types. If `None`, plays a simple
+/// beep.
+///
+/// The sound is played asynchronously.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, co};
+///
+/// w::MessageBeep(Some(co::MB::ICONEXCLAMATION))?;
+/// # w::SysResult::Ok(())
+/// ```
+pub fn MessageBeep(sound_type: Option<co::MB>) -> SysResult<()> {
+	bool_to_sysresult(
+		unsafe { ffi::MessageBeep(sound_type.map_or(0xffff_ffff, |t| t.raw())) },
+	)
+}
+
 /// [`OffsetRect`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-offsetrect)
 /// function.
 #[must_use]
@@ -782,6 +1112,29 @@ pub fn OffsetRect(rc: RECT, dx: i32, dy: i32) -> SysResult<RECT> {
 
 /// [`PeekMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-peekmessagew)
 /// function.
+///
+/// Unlike [`GetMessage`](crate::GetMessage), it doesn't block, so it can be
+/// used in a polling loop which renders a frame whenever the queue is empty.
+///
+/// # Examples
+///
+/// A game loop:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let mut msg = w::MSG::default();
+/// loop {
+///     while w::PeekMessage(&mut msg, None, 0, 0, co::PM::REMOVE) {
+///         if msg.message == co::WM::QUIT {
+///             return;
+///         }
+///         w::TranslateMessage(&msg);
+///         unsafe { w::DispatchMessage(&msg); }
+///     }
+///     // render the next frame...
+/// }
+/// ```
 pub fn PeekMessage(
 	msg: &mut MSG,
 	hwnd: Option<&HWND>,
@@ -810,10 +1163,35 @@ pub fn PostQuitMessage(exit_code: i32) {
 /// [`PostThreadMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-postthreadmessagew)
 /// function.
 ///
+/// Useful for cross-thread communication with threads which have no windows.
+/// The target thread must already have a message queue, which is created
+/// when it first calls a message function like [`GetMessage`](crate::GetMessage)
+/// or [`PeekMessage`](crate::PeekMessage); otherwise, returns
+/// [`ERROR::INVALID_THREAD_ID`](crate::co::ERROR::INVALID_THREAD_ID).
+///
 /// # Safety
 ///
 /// Messages manipulate pointers, copies and window states. Improper use may
 /// lead to undefined behavior.
+///
+/// # Examples
+///
+/// Posting a custom message to a worker thread:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co, msg};
+///
+/// let thread_id: u32; // initialized somewhere
+/// # let thread_id = 0;
+///
+/// unsafe {
+///     w::PostThreadMessage(
+///         thread_id,
+///         msg::WndMsg::new(co::WM::APP, 0, 0),
+///     )?;
+/// }
+/// # w::SysResult::Ok(())
+/// ```
 pub unsafe fn PostThreadMessage<M>(thread_id: u32, msg: M) -> SysResult<()>
 	where M: MsgSend + Send + Copy + 'static,
 {
@@ -846,6 +1224,21 @@ pub unsafe fn RegisterClassEx(wcx: &WNDCLASSEX) -> SysResult<ATOM> {
 	}
 }
 
+/// [`RegisterClipboardFormat`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerclipboardformatw)
+/// function.
+///
+/// If a format with the same name was already registered, returns the same
+/// value.
+#[must_use]
+pub fn RegisterClipboardFormat(format: &str) -> SysResult<co::CF> {
+	match unsafe {
+		ffi::RegisterClipboardFormatW(WString::from_str(format).as_ptr())
+	} {
+		0 => Err(GetLastError()),
+		id => Ok(unsafe { co::CF::from_raw(id) }),
+	}
+}
+
 /// [`RegisterWindowMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerwindowmessagew)
 /// function.
 #[must_use]
@@ -968,6 +1361,56 @@ pub fn SetProcessDPIAware() -> SysResult<()> {
 	bool_to_sysresult(unsafe { ffi::SetProcessDPIAware() })
 }
 
+/// [`SetProcessDpiAwarenessContext`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setprocessdpiawarenesscontext)
+/// function.
+///
+/// The function is loaded at runtime. On Windows versions prior to 10 1703,
+/// where it doesn't exist, falls back to
+/// [`SetProcessDpiAwareness`](https://learn.microsoft.com/en-us/windows/win32/api/shellscalingapi/nf-shellscalingapi-setprocessdpiawareness)
+/// from shcore and then to [`SetProcessDPIAware`](crate::SetProcessDPIAware),
+/// choosing the closest awareness level.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, co};
+///
+/// w::SetProcessDpiAwarenessContext(
+///     co::DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE_V2)?;
+/// # w::SysResult::Ok(())
+/// ```
+pub fn SetProcessDpiAwarenessContext(
+	value: co::DPI_AWARENESS_CONTEXT,
+) -> SysResult<()>
+{
+	if let Some(ret) = unsafe {
+		call_dyn("user32", "SetProcessDpiAwarenessContext",
+			|f: extern "system" fn(isize) -> BOOL| f(value.raw()))
+	} {
+		return bool_to_sysresult(ret);
+	}
+
+	let awareness = match value {
+		co::DPI_AWARENESS_CONTEXT::SYSTEM_AWARE =>
+			co::PROCESS_DPI_AWARENESS::SYSTEM_DPI_AWARE,
+		co::DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE
+			| co::DPI_AWARENESS_CONTEXT::PER_MONITOR_AWARE_V2 =>
+			co::PROCESS_DPI_AWARENESS::PER_MONITOR_DPI_AWARE,
+		_ => co::PROCESS_DPI_AWARENESS::DPI_UNAWARE, // including GDI scaled
+	};
+
+	match unsafe {
+		call_dyn("shcore", "SetProcessDpiAwareness",
+			|f: extern "system" fn(u32) -> i32| f(awareness.raw()))
+	} {
+		Some(hr) => shcore_hr_to_sysresult(hr),
+		None => match awareness {
+			co::PROCESS_DPI_AWARENESS::DPI_UNAWARE => Ok(()), // default behavior
+			_ => SetProcessDPIAware(),
+		},
+	}
+}
+
 /// [`SetThreadDpiHostingBehavior`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setthreaddpihostingbehavior)
 /// function.
 pub fn SetThreadDpiHostingBehavior(
@@ -1083,3 +1526,95 @@ pub fn UnregisterClass(
 pub fn WaitMessage() -> SysResult<()> {
 	bool_to_sysresult(unsafe { ffi::WaitMessage() })
 }
+
+/// [`WTSEnumerateSessions`](https://learn.microsoft.com/en-us/windows/win32/api/wtsapi32/nf-wtsapi32-wtsenumeratesessionsw)
+/// function, for the local server.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// for session in w::WTSEnumerateSessions()?.iter() {
+///     println!("{} {} {}",
+///         session.SessionId,
+///         session.pWinStationName().unwrap_or_default(),
+///         session.State);
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn WTSEnumerateSessions() -> SysResult<WTSFreeMemoryGuard<WTS_SESSION_INFO>> {
+	let mut ptr_infos = std::ptr::null_mut::<WTS_SESSION_INFO>();
+	let mut count = u32::default();
+
+	unsafe {
+		bool_to_sysresult(
+			ffi::WTSEnumerateSessionsW(
+				std::ptr::null_mut(), // WTS_CURRENT_SERVER_HANDLE
+				0,
+				1,
+				&mut ptr_infos as *mut _ as _,
+				&mut count,
+			),
+		).map(|_| WTSFreeMemoryGuard::new(ptr_infos, count as _))
+	}
+}
+
+/// [`WTSQuerySessionInformation`](https://learn.microsoft.com/en-us/windows/win32/api/wtsapi32/nf-wtsapi32-wtsquerysessioninformationw)
+/// function, for the local server.
+///
+/// If `session_id` is `None`, queries the current session.
+///
+/// Only the information classes which return strings are supported, like
+/// [`WTS_INFO_CLASS::UserName`](crate::co::WTS_INFO_CLASS::UserName),
+/// [`WTS_INFO_CLASS::DomainName`](crate::co::WTS_INFO_CLASS::DomainName) and
+/// [`WTS_INFO_CLASS::ClientName`](crate::co::WTS_INFO_CLASS::ClientName);
+/// any other class returns
+/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let user = w::WTSQuerySessionInformation(None, co::WTS_INFO_CLASS::UserName)?;
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn WTSQuerySessionInformation(
+	session_id: Option<u32>,
+	info_class: co::WTS_INFO_CLASS,
+) -> SysResult<String>
+{
+	match info_class {
+		co::WTS_INFO_CLASS::InitialProgram
+			| co::WTS_INFO_CLASS::ApplicationName
+			| co::WTS_INFO_CLASS::WorkingDirectory
+			| co::WTS_INFO_CLASS::OEMId
+			| co::WTS_INFO_CLASS::UserName
+			| co::WTS_INFO_CLASS::WinStationName
+			| co::WTS_INFO_CLASS::DomainName
+			| co::WTS_INFO_CLASS::ClientName
+			| co::WTS_INFO_CLASS::ClientDirectory => {},
+		_ => return Err(co::ERROR::INVALID_PARAMETER),
+	}
+
+	let mut pstr = std::ptr::null_mut::<u16>();
+	let mut num_bytes = u32::default();
+
+	bool_to_sysresult(
+		unsafe {
+			ffi::WTSQuerySessionInformationW(
+				std::ptr::null_mut(), // WTS_CURRENT_SERVER_HANDLE
+				session_id.unwrap_or(0xffff_ffff), // WTS_CURRENT_SESSION
+				info_class.raw(),
+				&mut pstr,
+				&mut num_bytes,
+			)
+		},
+	)?;
+
+	let buf = unsafe { WTSFreeMemoryGuard::new(pstr, num_bytes as usize / 2) };
+	Ok(WString::from_wchars_slice(&buf).to_string())
+}
@@ -250,3 +250,131 @@ impl<'a, H> ReleaseDCGuard<'a, H>
 		std::mem::replace(&mut self.hdc, HDC::INVALID)
 	}
 }
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation for [`HHOOK`](crate::HHOOK) which automatically calls
+/// [`UnhookWindowsHookEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unhookwindowshookex)
+/// when the object goes out of scope, also dropping the closure passed to
+/// [`hook::set`](crate::hook::set), if any.
+///
+/// The guard must be dropped in the same thread which installed the hook.
+pub struct UnhookWindowsHookExGuard {
+	hhook: HHOOK,
+	_thread: PhantomData<*const ()>, // closure is stored per thread
+}
+
+impl Drop for UnhookWindowsHookExGuard {
+	fn drop(&mut self) {
+		if let Some(h) = self.hhook.as_opt() {
+			unsafe { ffi::UnhookWindowsHookEx(h.ptr()); } // ignore errors
+			crate::user::hook::remove(h);
+		}
+	}
+}
+
+impl Deref for UnhookWindowsHookExGuard {
+	type Target = HHOOK;
+
+	fn deref(&self) -> &Self::Target {
+		&self.hhook
+	}
+}
+
+impl UnhookWindowsHookExGuard {
+	/// Constructs the guard by taking ownership of the handle.
+	///
+	/// # Safety
+	///
+	/// Be sure the handle must be freed with
+	/// [`UnhookWindowsHookEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unhookwindowshookex)
+	/// at the end of scope.
+	#[must_use]
+	pub(in crate::user) const unsafe fn new(hhook: HHOOK) -> Self {
+		Self { hhook, _thread: PhantomData }
+	}
+}
+
+//------------------------------------------------------------------------------
+
+handle_guard! { UnhookWinEventGuard: HWINEVENTHOOK;
+	crate::user::hook::unhook_win_event;
+	/// RAII implementation for [`HWINEVENTHOOK`](crate::HWINEVENTHOOK) which
+	/// automatically calls
+	/// [`UnhookWinEvent`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unhookwinevent)
+	/// when the object goes out of scope, also dropping the closure passed to
+	/// [`hook::set_win_event`](crate::hook::set_win_event), if any.
+	///
+	/// The closure is stored per thread, so the guard should be dropped in the
+	/// same thread which installed the hook.
+}
+
+//------------------------------------------------------------------------------
+
+handle_guard! { UnregisterDeviceNotificationGuard: HDEVNOTIFY;
+	ffi::UnregisterDeviceNotification;
+	/// RAII implementation for [`HDEVNOTIFY`](crate::HDEVNOTIFY) which
+	/// automatically calls
+	/// [`UnregisterDeviceNotification`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unregisterdevicenotification)
+	/// when the object goes out of scope.
+}
+
+//------------------------------------------------------------------------------
+
+/// RAII implementation for an array allocated by the WTS functions – like the
+/// [`WTS_SESSION_INFO`](crate::WTS_SESSION_INFO) array returned by
+/// [`WTSEnumerateSessions`](crate::WTSEnumerateSessions) – which
+/// automatically calls
+/// [`WTSFreeMemory`](https://learn.microsoft.com/en-us/windows/win32/api/wtsapi32/nf-wtsapi32-wtsfreememory)
+/// when the object goes out of scope.
+pub struct WTSFreeMemoryGuard<T> {
+	ptr: *mut T,
+	count: usize,
+}
+
+impl<T> Drop for WTSFreeMemoryGuard<T> {
+	fn drop(&mut self) {
+		if !self.ptr.is_null() {
+			unsafe { ffi::WTSFreeMemory(self.ptr as _); }
+		}
+	}
+}
+
+impl<T> Deref for WTSFreeMemoryGuard<T> {
+	type Target = [T];
+
+	fn deref(&self) -> &Self::Target {
+		if self.ptr.is_null() {
+			&[]
+		} else {
+			unsafe { std::slice::from_raw_parts(self.ptr, self.count) }
+		}
+	}
+}
+
+impl<T> WTSFreeMemoryGuard<T> {
+	/// Constructs the guard by taking ownership of the array.
+	///
+	/// # Safety
+	///
+	/// Be sure the array has `count` elements, and must be freed with
+	/// [`WTSFreeMemory`](https://learn.microsoft.com/en-us/windows/win32/api/wtsapi32/nf-wtsapi32-wtsfreememory)
+	/// at the end of scope.
+	#[must_use]
+	pub const unsafe fn new(ptr: *mut T, count: usize) -> Self
+	{
+		Self { ptr, count }
+	}
+}
+
+//------------------------------------------------------------------------------
+
+handle_guard! { WTSUnRegisterSessionNotificationGuard: HWND;
+	ffi::WTSUnRegisterSessionNotification;
+	/// RAII implementation for [`HWND`](crate::HWND) which automatically
+	/// calls
+	/// [`WTSUnRegisterSessionNotification`](https://learn.microsoft.com/en-us/windows/win32/api/wtsapi32/nf-wtsapi32-wtsunregistersessionnotification)
+	/// when the object goes out of scope.
+	///
+	/// The guard must be dropped before the window is destroyed.
+}
@@ -1,5 +1,6 @@
 #![allow(non_camel_case_types, non_snake_case)]
 
+use crate::co;
 use crate::decl::*;
 use crate::guard::*;
 use crate::kernel::privs::*;
@@ -22,13 +23,59 @@ impl user_Haccel for HACCEL {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait user_Haccel: Handle {
+	/// [`CopyAcceleratorTable`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-copyacceleratortablew)
+	/// function.
+	///
+	/// Returns all the entries of the accelerator table.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let haccel: w::HACCEL; // initialized somewhere
+	/// # let haccel = w::HACCEL::NULL;
+	///
+	/// for accel in haccel.CopyAcceleratorTable().iter() {
+	///     println!("{} {}", accel.key, accel.cmd);
+	/// }
+	/// ```
+	#[must_use]
+	fn CopyAcceleratorTable(&self) -> Vec<ACCEL> {
+		let num_entries = unsafe {
+			ffi::CopyAcceleratorTableW(self.ptr(), std::ptr::null_mut(), 0)
+		};
+		let mut entries = vec![ACCEL::default(); num_entries as _];
+		if num_entries > 0 {
+			let num_copied = unsafe {
+				ffi::CopyAcceleratorTableW(
+					self.ptr(),
+					entries.as_mut_ptr() as _,
+					num_entries,
+				)
+			};
+			entries.truncate(num_copied as _);
+		}
+		entries
+	}
+
 	/// [`CreateAcceleratorTable`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-createacceleratortablew)
 	/// function.
+	///
+	/// Fails with
+	/// [`co::ERROR::ALREADY_EXISTS`](crate::co::ERROR::ALREADY_EXISTS) if the
+	/// same key combination appears more than once, since only the first entry
+	/// would ever be triggered. The conflicting entries can be listed with
+	/// [`ACCEL::duplicates`](crate::ACCEL::duplicates).
 	#[must_use]
 	fn CreateAcceleratorTable(
 		accel: &[ACCEL],
 	) -> SysResult<DestroyAcceleratorTableGuard>
 	{
+		if !ACCEL::duplicates(accel).is_empty() {
+			return Err(co::ERROR::ALREADY_EXISTS);
+		}
+
 		unsafe {
 			ptr_to_sysresult_handle(
 				ffi::CreateAcceleratorTableW(
@@ -40,6 +40,35 @@ pub trait user_Hcursor: Handle {
 		ptr_to_option_handle(unsafe { ffi::GetCursor() })
 	}
 
+	/// [`SetCursor`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setcursor)
+	/// function.
+	///
+	/// If `hcursor` is `None`, the cursor is removed from the screen. Returns
+	/// the previous cursor, if any.
+	///
+	/// Since the window class cursor is restored whenever the mouse moves, this
+	/// function is usually called when handling
+	/// [`WM_SETCURSOR`](crate::gui::events::WindowEvents::wm_set_cursor).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hcur = w::HINSTANCE::NULL
+	///     .LoadCursor(w::IdIdcStr::Idc(co::IDC::WAIT))?;
+	///
+	/// w::HCURSOR::SetCursor(Some(&hcur));
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn SetCursor(hcursor: Option<&HCURSOR>) -> Option<HCURSOR> {
+		ptr_to_option_handle(
+			unsafe {
+				ffi::SetCursor(hcursor.map_or(std::ptr::null_mut(), |h| h.ptr()))
+			},
+		)
+	}
+
 	/// [`SetSystemCursor`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setsystemcursor)
 	/// function.
 	fn SetSystemCursor(&self, id: co::OCR) -> SysResult<()> {
@@ -36,13 +36,43 @@ pub trait user_Hdwp: Handle {
 	/// [`EndDeferWindowPosGuard`](crate::guard::EndDeferWindowPosGuard), which
 	/// automatically calls `EndDeferWindowPos` when the guard goes out of
 	/// scope.
+	///
+	/// The `num_windows_hint` is only used to preallocate the internal
+	/// structure: if more windows are passed to
+	/// [`HDWP::DeferWindowPos`](crate::prelude::user_Hdwp::DeferWindowPos),
+	/// the system grows it automatically. Avoid passing huge values, since the
+	/// whole structure is allocated upfront. Zero is also valid, for an
+	/// initially empty set of windows.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnds: Vec<w::HWND>; // initialized somewhere
+	/// # let hwnds = Vec::<w::HWND>::new();
+	///
+	/// let mut hdwp = w::HDWP::BeginDeferWindowPos(hwnds.len() as _)?;
+	/// for hwnd in hwnds.iter() {
+	///     hdwp.DeferWindowPos(
+	///         hwnd,
+	///         w::HwndPlace::None,
+	///         w::POINT::new(0, 0),
+	///         w::SIZE::new(100, 20),
+	///         co::SWP::NOZORDER | co::SWP::NOMOVE,
+	///     )?;
+	/// }
+	///
+	/// // EndDeferWindowPos() called automatically
+	/// # w::SysResult::Ok(())
+	/// ```
 	#[must_use]
 	fn BeginDeferWindowPos(
-		num_windows: u32,
+		num_windows_hint: u32,
 	) -> SysResult<EndDeferWindowPosGuard>
 	{
 		unsafe {
-			ptr_to_sysresult_handle(ffi::BeginDeferWindowPos(num_windows as _))
+			ptr_to_sysresult_handle(ffi::BeginDeferWindowPos(num_windows_hint as _))
 				.map(|h| EndDeferWindowPosGuard::new(h))
 		}
 	}
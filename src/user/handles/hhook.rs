@@ -2,6 +2,7 @@
 
 use crate::co;
 use crate::decl::*;
+use crate::guard::*;
 use crate::kernel::privs::*;
 use crate::prelude::*;
 use crate::user::ffi;
@@ -35,36 +36,50 @@ pub trait user_Hhook: Handle {
 
 	/// [`SetWindowsHookEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwindowshookexw)
 	/// function.
+	///
+	/// To run a closure instead of a raw procedure, consider using
+	/// [`hook::set`](crate::hook::set).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// extern "system" fn keyboard_proc(
+	///     code: i32, wparam: usize, lparam: isize) -> isize
+	/// {
+	///     // process the event...
+	///     w::HHOOK::NULL.CallNextHookEx(
+	///         unsafe { co::WH::from_raw(code) }, wparam, lparam)
+	/// }
+	///
+	/// let hhook = w::HHOOK::SetWindowsHookEx(
+	///     co::WH::KEYBOARD_LL,
+	///     keyboard_proc,
+	///     Some(&w::HINSTANCE::GetModuleHandle(None)?),
+	///     None,
+	/// )?;
+	///
+	/// // UnhookWindowsHookEx() called automatically
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
 	fn SetWindowsHookEx(
 		hook_id: co::WH,
 		proc: HOOKPROC,
 		module: Option<&HINSTANCE>,
 		thread_id: Option<u32>,
-	) -> SysResult<HHOOK>
+	) -> SysResult<UnhookWindowsHookExGuard>
 	{
-		ptr_to_sysresult_handle(
-			unsafe {
+		unsafe {
+			ptr_to_sysresult_handle(
 				ffi::SetWindowsHookExW(
 					hook_id.raw(),
 					proc as _,
 					module.map_or(std::ptr::null_mut(), |h| h.ptr()),
 					thread_id.unwrap_or_default(),
-				)
-			},
-		)
-	}
-
-	/// [`UnhookWindowsHookEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unhookwindowshookex)
-	/// function.
-	///
-	/// After calling this method, the handle will be invalidated and further
-	/// operations will fail with
-	/// [`ERROR::INVALID_HANDLE`](crate::co::ERROR::INVALID_HANDLE) error code.
-	fn UnhookWindowsHookEx(&mut self) -> SysResult<()> {
-		let ret = bool_to_sysresult(
-			unsafe { ffi::UnhookWindowsHookEx(self.ptr()) },
-		);
-		*self = Self::INVALID;
-		ret
+				),
+			).map(|h| UnhookWindowsHookExGuard::new(h))
+		}
 	}
 }
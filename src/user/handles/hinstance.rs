@@ -198,6 +198,21 @@ pub trait user_Hinstance: kernel_Hinstance {
 
 	/// [`LoadString`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadstringw)
 	/// function.
+	///
+	/// The string is read straight from the resource, without a fixed-size
+	/// buffer, so it's never truncated.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// const IDS_TITLE: u16 = 201;
+	///
+	/// let hinst = w::HINSTANCE::GetModuleHandle(None)?;
+	/// let title = hinst.LoadString(IDS_TITLE)?;
+	/// # w::SysResult::Ok(())
+	/// ```
 	#[must_use]
 	fn LoadString(&self, id: u16) -> SysResult<String> {
 		let mut pdata: *const u16 = std::ptr::null_mut();
@@ -0,0 +1,214 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::privs::*;
+use crate::prelude::*;
+use crate::user::ffi;
+
+impl_handle! { HKL;
+	/// Handle to a
+	/// [keyboard layout](https://learn.microsoft.com/en-us/windows/win32/winprog/windows-data-types#hkl).
+}
+
+impl user_Hkl for HKL {}
+
+/// This trait is enabled with the `user` feature, and provides methods for
+/// [`HKL`](crate::HKL).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait user_Hkl: Handle {
+	/// [`ActivateKeyboardLayout`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-activatekeyboardlayout)
+	/// function.
+	///
+	/// Returns the previously active layout.
+	fn ActivateKeyboardLayout(&self, flags: co::KLF) -> SysResult<HKL> {
+		ptr_to_sysresult_handle(
+			unsafe { ffi::ActivateKeyboardLayout(self.ptr(), flags.raw()) },
+		)
+	}
+
+	/// [`GetKeyboardLayout`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getkeyboardlayout)
+	/// function.
+	///
+	/// If `thread_id` is `None`, returns the layout of the current thread.
+	#[must_use]
+	fn GetKeyboardLayout(thread_id: Option<u32>) -> HKL {
+		unsafe {
+			HKL::from_ptr(ffi::GetKeyboardLayout(thread_id.unwrap_or_default()))
+		}
+	}
+
+	/// [`GetKeyboardLayoutList`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getkeyboardlayoutlist)
+	/// function.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// for hkl in w::HKL::GetKeyboardLayoutList()?.iter() {
+	///     println!("Layout: {}", hkl);
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn GetKeyboardLayoutList() -> SysResult<Vec<HKL>> {
+		let count = unsafe {
+			ffi::GetKeyboardLayoutList(0, std::ptr::null_mut())
+		};
+		if count == 0 {
+			return Err(GetLastError());
+		}
+
+		let mut buf = vec![std::ptr::null_mut(); count as _];
+		match unsafe { ffi::GetKeyboardLayoutList(count, buf.as_mut_ptr()) } {
+			0 => Err(GetLastError()),
+			n => Ok(
+				buf.iter()
+					.take(n as _)
+					.map(|p| unsafe { HKL::from_ptr(*p) })
+					.collect(),
+			),
+		}
+	}
+
+	/// [`LoadKeyboardLayout`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-loadkeyboardlayoutw)
+	/// function.
+	///
+	/// The `klid` is the hexadecimal layout identifier, like `"00000407"` for
+	/// German.
+	fn LoadKeyboardLayout(klid: &str, flags: co::KLF) -> SysResult<HKL> {
+		ptr_to_sysresult_handle(
+			unsafe {
+				ffi::LoadKeyboardLayoutW(
+					WString::from_str(klid).as_ptr(),
+					flags.raw(),
+				)
+			},
+		)
+	}
+
+	/// [`MapVirtualKeyEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-mapvirtualkeyexw)
+	/// function.
+	///
+	/// Returns `None` if there is no translation.
+	#[must_use]
+	fn MapVirtualKeyEx(&self, code: u32, map_type: co::MAPVK) -> Option<u32> {
+		match unsafe {
+			ffi::MapVirtualKeyExW(code, map_type.raw(), self.ptr())
+		} {
+			0 => None,
+			n => Some(n),
+		}
+	}
+
+	/// [`ToUnicodeEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-tounicodeex)
+	/// function.
+	///
+	/// The `key_state` is indexed by virtual key, as returned by
+	/// [`GetKeyboardState`](crate::GetKeyboardState). In `flags`, bit 0 means
+	/// a menu is active, and bit 2 keeps the keyboard state – including a
+	/// pending dead key – unchanged.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hkl = w::HKL::GetKeyboardLayout(None);
+	/// let state = w::GetKeyboardState()?;
+	///
+	/// match hkl.ToUnicodeEx(co::VK::CHAR_A, 0x1e, &state, 0b100) {
+	///     w::KeyChars::Chars(s) => println!("Typed: {}", s),
+	///     w::KeyChars::Dead(s) => println!("Dead key: {}", s),
+	///     w::KeyChars::NoChar => println!("No character."),
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn ToUnicodeEx(&self,
+		virt_key: co::VK,
+		scan_code: u32,
+		key_state: &[u8; 256],
+		flags: u32,
+	) -> KeyChars
+	{
+		let mut buf = [0u16; 16];
+		match unsafe {
+			ffi::ToUnicodeEx(
+				virt_key.raw() as _,
+				scan_code,
+				key_state.as_ptr(),
+				buf.as_mut_ptr(),
+				buf.len() as _,
+				flags,
+				self.ptr(),
+			)
+		} {
+			0 => KeyChars::NoChar,
+			n if n < 0 => KeyChars::Dead(
+				WString::from_wchars_slice(&buf[..1]).to_string(),
+			),
+			n => KeyChars::Chars(
+				WString::from_wchars_slice(&buf[..(n as usize).min(buf.len())])
+					.to_string(),
+			),
+		}
+	}
+
+	/// [`VkKeyScanEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-vkkeyscanexw)
+	/// function.
+	///
+	/// Returns the virtual key and the modifiers which produce the given
+	/// character in this layout, or `None` if it cannot be typed with a single
+	/// keystroke.
+	///
+	/// Only the [`HOTKEYF::SHIFT`](crate::co::HOTKEYF::SHIFT),
+	/// [`HOTKEYF::CONTROL`](crate::co::HOTKEYF::CONTROL) and
+	/// [`HOTKEYF::ALT`](crate::co::HOTKEYF::ALT) modifiers are returned; the
+	/// Hankaku bit, which shares its value with
+	/// [`HOTKEYF::EXT`](crate::co::HOTKEYF::EXT), and the reserved bits are
+	/// discarded.
+	///
+	/// # Examples
+	///
+	/// Displaying the name of the key which types `ü`, as the current layout
+	/// shows it:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hkl = w::HKL::GetKeyboardLayout(None);
+	///
+	/// if let Some((vk, _)) = hkl.VkKeyScanEx('ü') {
+	///     if let Some(scan_code) = hkl.MapVirtualKeyEx(
+	///         vk.raw() as _, co::MAPVK::VK_TO_VSC_EX)
+	///     {
+	///         println!("Ctrl+{}", w::GetKeyNameText(scan_code)?);
+	///     }
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn VkKeyScanEx(&self, ch: char) -> Option<(co::VK, co::HOTKEYF)> {
+		let mut buf = [0u16; 2];
+		if ch.encode_utf16(&mut buf).len() > 1 {
+			return None; // outside the Basic Multilingual Plane
+		}
+
+		match unsafe { ffi::VkKeyScanExW(buf[0], self.ptr()) } {
+			-1 => None,
+			n => unsafe {
+				Some((
+					co::VK::from_raw(LOBYTE(n as _) as _),
+					co::HOTKEYF::from_raw(HIBYTE(n as _) as u16 & 0x07), // discard Hankaku and reserved bits
+				))
+			},
+		}
+	}
+}
@@ -2,9 +2,9 @@
 
 use crate::co;
 use crate::decl::*;
-use crate::kernel::privs::*;
+use crate::kernel::{ffi_types::*, privs::*};
 use crate::prelude::*;
-use crate::user::ffi;
+use crate::user::{ffi, privs::*};
 
 impl_handle! { HMONITOR;
 	/// Handle to a
@@ -22,6 +22,30 @@ impl user_Hmonitor for HMONITOR {}
 /// use winsafe::prelude::*;
 /// ```
 pub trait user_Hmonitor: Handle {
+	/// [`GetDpiForMonitor`](https://learn.microsoft.com/en-us/windows/win32/api/shellscalingapi/nf-shellscalingapi-getdpiformonitor)
+	/// function.
+	///
+	/// Returns the horizontal and vertical DPI values.
+	///
+	/// The function is loaded at runtime from shcore. On Windows versions prior
+	/// to 8.1, where it doesn't exist, falls back to the system DPI.
+	#[must_use]
+	fn GetDpiForMonitor(&self, dpi_type: co::MDT) -> SysResult<(u32, u32)> {
+		let (mut x, mut y) = (u32::default(), u32::default());
+		match unsafe {
+			call_dyn("shcore", "GetDpiForMonitor",
+				|f: extern "system" fn(HANDLE, u32, *mut u32, *mut u32) -> i32| {
+					f(self.ptr(), dpi_type.raw(), &mut x, &mut y)
+				})
+		} {
+			Some(hr) => shcore_hr_to_sysresult(hr).map(|_| (x, y)),
+			None => {
+				let dpi = legacy_system_dpi();
+				Ok((dpi, dpi))
+			},
+		}
+	}
+
 	/// [`GetMonitorInfo`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getmonitorinfow)
 	/// function.
 	fn GetMonitorInfo(&self) -> SysResult<MONITORINFOEX> {
@@ -0,0 +1,70 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::privs::*;
+use crate::prelude::*;
+use crate::user::ffi;
+
+impl_handle! { HWINEVENTHOOK;
+	/// Handle to an
+	/// [event hook](https://learn.microsoft.com/en-us/windows/win32/winprog/windows-data-types#hwineventhook).
+}
+
+impl user_Hwineventhook for HWINEVENTHOOK {}
+
+/// This trait is enabled with the `user` feature, and provides methods for
+/// [`HWINEVENTHOOK`](crate::HWINEVENTHOOK).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait user_Hwineventhook: Handle {
+	/// [`SetWinEventHook`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setwineventhook)
+	/// function.
+	///
+	/// To run a closure instead of a raw callback, see
+	/// [`hook::set_win_event`](crate::hook::set_win_event).
+	#[must_use]
+	fn SetWinEventHook(
+		event_min: co::EVENT,
+		event_max: co::EVENT,
+		module: Option<&HINSTANCE>,
+		proc: WINEVENTPROC,
+		process_id: Option<u32>,
+		thread_id: Option<u32>,
+		flags: co::WINEVENT,
+	) -> SysResult<UnhookWinEventGuard>
+	{
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::SetWinEventHook(
+					event_min.raw(),
+					event_max.raw(),
+					module.map_or(std::ptr::null_mut(), |h| h.ptr()),
+					proc as _,
+					process_id.unwrap_or_default(),
+					thread_id.unwrap_or_default(),
+					flags.raw(),
+				),
+			).map(|h| UnhookWinEventGuard::new(h))
+		}
+	}
+
+	/// [`UnhookWinEvent`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-unhookwinevent)
+	/// function.
+	///
+	/// After calling this method, the handle will be invalidated and further
+	/// operations will fail with
+	/// [`ERROR::INVALID_HANDLE`](crate::co::ERROR::INVALID_HANDLE) error code.
+	fn UnhookWinEvent(&mut self) -> SysResult<()> {
+		let ret = bool_to_sysresult(
+			unsafe { ffi::UnhookWinEvent(self.ptr()) },
+		);
+		*self = Self::INVALID;
+		ret
+	}
+}
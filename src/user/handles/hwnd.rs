@@ -5,7 +5,7 @@ use std::marker::PhantomData;
 use crate::co;
 use crate::decl::*;
 use crate::guard::*;
-use crate::kernel::privs::*;
+use crate::kernel::{ffi_types::*, privs::*};
 use crate::prelude::*;
 use crate::user::{ffi, privs::*, proc};
 
@@ -26,14 +26,111 @@ impl user_Hwnd for HWND {}
 /// ```
 pub trait user_Hwnd: Handle {
 	/// Represents all top-level windows in
-	/// [`HWND::PostMessage`](crate::prelude::user_Hwnd::PostMessage) and
-	/// [`HWND::SendMessage`](crate::prelude::user_Hwnd::SendMessage).
+	/// [`HWND::PostMessage`](crate::prelude::user_Hwnd::PostMessage),
+	/// [`HWND::SendMessage`](crate::prelude::user_Hwnd::SendMessage) and
+	/// [`HWND::SendMessageTimeout`](crate::prelude::user_Hwnd::SendMessageTimeout).
+	///
+	/// When broadcasting, prefer `SendMessageTimeout`, so a hung window won't
+	/// block the caller.
 	const BROADCAST: HWND = HWND(0xffff as _);
 
 	/// Represents the desktop window in
 	/// [`HWND::GetDC`](crate::prelude::user_Hwnd::GetDC).
 	const DESKTOP: HWND = HWND(std::ptr::null_mut());
 
+	/// Brings the window to the foreground and activates it, working around
+	/// the foreground lock which makes a plain
+	/// [`HWND::SetForegroundWindow`](crate::prelude::user_Hwnd::SetForegroundWindow)
+	/// fail when the calling process is in the background – like when a tray
+	/// icon is clicked.
+	///
+	/// The window is restored if minimized. If `SetForegroundWindow` fails,
+	/// the thread input is temporarily attached to the foreground window
+	/// thread with [`AttachThreadInput`](crate::AttachThreadInput), and the
+	/// call is retried. As a last resort – also if the thread input cannot be
+	/// attached – the window is minimized and restored.
+	///
+	/// Returns `true` if the window is in the foreground at the end.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// if !hwnd.bring_to_front() {
+	///     hwnd.request_attention(); // flash the taskbar button instead
+	/// }
+	/// ```
+	fn bring_to_front(&self) -> bool {
+		if self.IsIconic() {
+			self.ShowWindow(co::SW::RESTORE);
+		}
+		if self.SetForegroundWindow() {
+			return true;
+		}
+
+		if let Some(hfore) = HWND::GetForegroundWindow() {
+			let cur_thread_id = GetCurrentThreadId();
+			let (fore_thread_id, _) = hfore.GetWindowThreadProcessId();
+			if fore_thread_id != cur_thread_id
+				&& AttachThreadInput(cur_thread_id, fore_thread_id, true).is_ok()
+			{
+				let brought = self.SetForegroundWindow();
+				self.BringWindowToTop().ok();
+				AttachThreadInput(cur_thread_id, fore_thread_id, false).ok();
+				if brought {
+					return true;
+				}
+			}
+		}
+
+		self.ShowWindow(co::SW::MINIMIZE);
+		self.ShowWindow(co::SW::RESTORE);
+		self.SetForegroundWindow()
+	}
+
+	/// Calls
+	/// [`HWND::EnumChildWindows`](crate::prelude::user_Hwnd::EnumChildWindows)
+	/// to collect all the child windows – including the children of the
+	/// children, recursively.
+	///
+	/// To retrieve only the immediate children, use
+	/// [`HWND::GetWindow`](crate::prelude::user_Hwnd::GetWindow).
+	///
+	/// # Examples
+	///
+	/// Changing the font of all controls of a dialog:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, msg};
+	///
+	/// let hdlg: w::HWND; // initialized somewhere
+	/// # let hdlg = w::HWND::NULL;
+	/// let hfont: w::HFONT; // initialized somewhere
+	/// # let hfont = w::HFONT::NULL;
+	///
+	/// for hchild in hdlg.enum_child_windows().iter() {
+	///     unsafe {
+	///         hchild.SendMessage(msg::wm::SetFont {
+	///             hfont: hfont.raw_copy(),
+	///             redraw: true,
+	///         });
+	///     }
+	/// }
+	/// ```
+	#[must_use]
+	fn enum_child_windows(&self) -> Vec<HWND> {
+		let mut hchildren = Vec::<HWND>::new();
+		self.EnumChildWindows(|hchild| {
+			hchildren.push(hchild);
+			true
+		});
+		hchildren
+	}
+
 	/// Calls
 	/// [`HWND::GetWindowLongPtr`](crate::prelude::user_Hwnd::GetWindowLongPtr)
 	/// to retrieve the window [`HINSTANCE`](crate::HINSTANCE).
@@ -53,6 +150,31 @@ pub trait user_Hwnd: Handle {
 		self.GetClassLongPtr(co::GCLP::ATOM) as u16 == WC_DIALOG
 	}
 
+	/// Calls
+	/// [`HWND::FlashWindowEx`](crate::prelude::user_Hwnd::FlashWindowEx) with
+	/// [`FLASHW::ALL`](crate::co::FLASHW::ALL) and
+	/// [`FLASHW::TIMERNOFG`](crate::co::FLASHW::TIMERNOFG), so the caption and
+	/// the taskbar button flash until the window comes to the foreground.
+	///
+	/// # Examples
+	///
+	/// Flashing the window when a new message arrives, if it's not focused:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// if w::HWND::GetForegroundWindow().as_ref() != Some(&hwnd) {
+	///     hwnd.request_attention();
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn request_attention(&self) -> bool {
+		self.FlashWindowEx(co::FLASHW::ALL | co::FLASHW::TIMERNOFG, 0, 0)
+	}
+
 	/// Calls
 	/// [`HWND::SetWindowLongPtr`](crate::prelude::user_Hwnd::SetWindowLongPtr)
 	/// to set the window styles.
@@ -69,6 +191,14 @@ pub trait user_Hwnd: Handle {
 		unsafe { self.SetWindowLongPtr(co::GWLP::EXSTYLE, ex_style.raw() as _); }
 	}
 
+	/// Calls
+	/// [`HWND::FlashWindowEx`](crate::prelude::user_Hwnd::FlashWindowEx) with
+	/// [`FLASHW::STOP`](crate::co::FLASHW::STOP), restoring the window to its
+	/// original state.
+	fn stop_flashing(&self) -> bool {
+		self.FlashWindowEx(co::FLASHW::STOP, 0, 0)
+	}
+
 	/// Calls
 	/// [`HWND::GetWindowLongPtr`](crate::prelude::user_Hwnd::GetWindowLongPtr)
 	/// to retrieve the window styles.
@@ -87,6 +217,79 @@ pub trait user_Hwnd: Handle {
 		}
 	}
 
+	/// Calls
+	/// [`HWND::UpdateLayeredWindow`](crate::prelude::user_Hwnd::UpdateLayeredWindow)
+	/// to update a [`WS_EX::LAYERED`](crate::co::WS_EX::LAYERED) window with
+	/// per-pixel alpha, taken from a 32-bit bitmap selected into `hdc_src`.
+	///
+	/// The color channels of each pixel must be premultiplied by its alpha,
+	/// otherwise the translucent areas will look wrong. The `alpha` is applied
+	/// to the whole window, on top of the per-pixel alpha; pass `255` to use
+	/// only the bitmap alpha.
+	///
+	/// # Examples
+	///
+	/// A splash window showing BGRA pixels decoded from a PNG:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere, with WS_EX::LAYERED
+	/// let bgra: Vec<u8>; // top-down pixels, 4 bytes each
+	/// # let hwnd = w::HWND::NULL;
+	/// # let bgra = vec![0u8; 400 * 300 * 4];
+	/// let (cx, cy) = (400, 300);
+	///
+	/// let mut bmi = w::BITMAPINFO::default();
+	/// bmi.bmiHeader.biWidth = cx;
+	/// bmi.bmiHeader.biHeight = -cy; // top-down
+	/// bmi.bmiHeader.biPlanes = 1;
+	/// bmi.bmiHeader.biBitCount = 32;
+	/// bmi.bmiHeader.biCompression = co::BI::RGB;
+	///
+	/// let hdc_screen = w::HWND::NULL.GetDC()?;
+	/// let hdc_mem = hdc_screen.CreateCompatibleDC()?;
+	/// let (hbmp, bits) = hdc_mem.CreateDIBSection(&bmi, co::DIB::RGB_COLORS)?;
+	///
+	/// let pixels = unsafe {
+	///     std::slice::from_raw_parts_mut(bits, (cx * cy * 4) as _)
+	/// };
+	/// for (dest, src) in pixels.chunks_exact_mut(4).zip(bgra.chunks_exact(4)) {
+	///     let a = src[3] as u32;
+	///     dest[0] = (src[0] as u32 * a / 255) as _; // premultiply
+	///     dest[1] = (src[1] as u32 * a / 255) as _;
+	///     dest[2] = (src[2] as u32 * a / 255) as _;
+	///     dest[3] = src[3];
+	/// }
+	///
+	/// let _old_bmp = hdc_mem.SelectObject(&*hbmp)?;
+	/// hwnd.update_layered_window(
+	///     &hdc_mem,
+	///     w::POINT::new(100, 100),
+	///     w::SIZE::new(cx, cy),
+	///     255,
+	/// )?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn update_layered_window(&self,
+		hdc_src: &HDC,
+		pos: POINT,
+		size: SIZE,
+		alpha: u8,
+	) -> SysResult<()>
+	{
+		self.UpdateLayeredWindow(
+			None,
+			Some(&pos),
+			Some(&size),
+			Some(hdc_src),
+			Some(&POINT::default()),
+			COLORREF::new(0, 0, 0),
+			&BLENDFUNCTION::new_alpha(alpha),
+			co::ULW::ALPHA,
+		)
+	}
+
 	/// [`ArrangeIconicWindows`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-arrangeiconicwindows)
 	/// function.
 	fn ArrangeIconicWindows(&self) -> SysResult<u32> {
@@ -159,6 +362,48 @@ pub trait user_Hwnd: Handle {
 		bool_to_sysresult(unsafe { ffi::BringWindowToTop(self.ptr()) })
 	}
 
+	/// [`CheckRadioButton`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-checkradiobutton)
+	/// function.
+	///
+	/// Checks the radio button `check_id` and unchecks all others in the
+	/// `first_id` to `last_id` range, which must be the IDs of sequential
+	/// controls. This is useful for radio groups defined in a dialog resource;
+	/// for radios created programmatically, see
+	/// [`gui::RadioGroup`](crate::gui::RadioGroup).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// const ID_RAD_SMALL: u16 = 1001; // as defined in the .rc file
+	/// const ID_RAD_MEDIUM: u16 = 1002;
+	/// const ID_RAD_LARGE: u16 = 1003;
+	///
+	/// let hdlg: w::HWND; // initialized somewhere
+	/// # let hdlg = w::HWND::NULL;
+	///
+	/// hdlg.CheckRadioButton(ID_RAD_SMALL, ID_RAD_LARGE, ID_RAD_MEDIUM)?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn CheckRadioButton(&self,
+		first_id: u16,
+		last_id: u16,
+		check_id: u16,
+	) -> SysResult<()>
+	{
+		bool_to_sysresult(
+			unsafe {
+				ffi::CheckRadioButton(
+					self.ptr(),
+					first_id as _,
+					last_id as _,
+					check_id as _,
+				)
+			},
+		)
+	}
+
 	/// [`ChildWindowFromPoint`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-childwindowfrompoint)
 	/// function.
 	#[must_use]
@@ -336,6 +581,29 @@ pub trait user_Hwnd: Handle {
 
 	/// [`EnableWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-enablewindow)
 	/// function.
+	///
+	/// Like the native function, returns `true` if the window was previously
+	/// *disabled*, and `false` if it was previously enabled. To simply query
+	/// the state, use
+	/// [`HWND::IsWindowEnabled`](crate::prelude::user_Hwnd::IsWindowEnabled).
+	///
+	/// If the window being disabled has the keyboard focus, the focus is lost
+	/// and no window will have it – not even in a dialog box. So, before
+	/// disabling it, move the focus to the next control with
+	/// [`HWND::SetFocus`](crate::prelude::user_Hwnd::SetFocus) or, in a dialog
+	/// box, with [`wm::NextDlgCtl`](crate::msg::wm::NextDlgCtl).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hbtn: w::HWND; // initialized somewhere
+	/// # let hbtn = w::HWND::NULL;
+	///
+	/// let was_disabled = hbtn.EnableWindow(false);
+	/// assert!(!hbtn.IsWindowEnabled());
+	/// ```
 	fn EnableWindow(&self, enable: bool) -> bool {
 		unsafe { ffi::EnableWindow(self.ptr(), enable as _) != 0 }
 	}
@@ -376,6 +644,20 @@ pub trait user_Hwnd: Handle {
 
 	/// [`FindWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-findwindoww)
 	/// function.
+	///
+	/// Returns `None` if no window was found, which is not an error.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd = w::HWND::FindWindow(None, Some("Untitled - Notepad"))?;
+	/// if let Some(hwnd) = hwnd {
+	///     println!("Found: {}", hwnd);
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
 	#[must_use]
 	fn FindWindow(
 		class_name: Option<AtomStr>,
@@ -401,6 +683,28 @@ pub trait user_Hwnd: Handle {
 
 	/// [`FindWindowEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-findwindowexw)
 	/// function.
+	///
+	/// Returns `None` if no window was found, which is not an error.
+	///
+	/// # Examples
+	///
+	/// Finding all the child windows of a given class:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hparent: w::HWND; // initialized somewhere
+	/// # let hparent = w::HWND::NULL;
+	///
+	/// let mut hafter: Option<w::HWND> = None;
+	/// while let Some(hedit) = hparent.FindWindowEx(
+	///     hafter.as_ref(), w::AtomStr::from_str("Edit"), None)?
+	/// {
+	///     println!("Edit: {}", hedit);
+	///     hafter = Some(hedit);
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
 	#[must_use]
 	fn FindWindowEx(&self,
 		hwnd_child_after: Option<&HWND>,
@@ -427,6 +731,39 @@ pub trait user_Hwnd: Handle {
 		}
 	}
 
+	/// [`FlashWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-flashwindow)
+	/// function.
+	///
+	/// Flashes the window once, returning whether it was active before the
+	/// call.
+	fn FlashWindow(&self, invert: bool) -> bool {
+		unsafe { ffi::FlashWindow(self.ptr(), invert as _) != 0 }
+	}
+
+	/// [`FlashWindowEx`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-flashwindowex)
+	/// function.
+	///
+	/// Flashes the window `count` times, at `timeout` milliseconds; if zero,
+	/// the default cursor blink rate is used. Returns whether the window was
+	/// active before the call.
+	///
+	/// To flash until the window is focused, see
+	/// [`HWND::request_attention`](crate::prelude::user_Hwnd::request_attention).
+	fn FlashWindowEx(&self,
+		flags: co::FLASHW,
+		count: u32,
+		timeout: u32,
+	) -> bool
+	{
+		let mut fwi = FLASHWINFO::default();
+		fwi.hwnd = unsafe { HWND::from_ptr(self.ptr()) };
+		fwi.dwFlags = flags;
+		fwi.uCount = count;
+		fwi.dwTimeout = timeout;
+
+		unsafe { ffi::FlashWindowEx(&fwi as *const _ as _) != 0 }
+	}
+
 	/// [`GetActiveWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getactivewindow)
 	/// function.
 	#[must_use]
@@ -519,6 +856,16 @@ pub trait user_Hwnd: Handle {
 	/// [`GetDC`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdc)
 	/// function.
 	///
+	/// Allows drawing on the client area outside of a
+	/// [`WM_PAINT`](https://learn.microsoft.com/en-us/windows/win32/gdi/wm-paint)
+	/// handler. The returned guard dereferences to the [`HDC`](crate::HDC), and
+	/// calls
+	/// [`ReleaseDC`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-releasedc)
+	/// with this same `HWND` when it goes out of scope.
+	///
+	/// Note that anything drawn this way is erased when the window is
+	/// repainted.
+	///
 	/// # Examples
 	///
 	/// Retrieving the device context of the desktop window:
@@ -529,6 +876,21 @@ pub trait user_Hwnd: Handle {
 	/// let hdc_desktop = w::HWND::DESKTOP.GetDC()?;
 	/// # w::SysResult::Ok(())
 	/// ```
+	///
+	/// Drawing some text on a window, outside of the `WM_PAINT` handler:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// {
+	///     let hdc = hwnd.GetDC()?;
+	///     hdc.TextOut(10, 10, "Hello")?;
+	/// } // ReleaseDC() called automatically
+	/// # w::SysResult::Ok(())
+	/// ```
 	#[must_use]
 	fn GetDC(&self) -> SysResult<ReleaseDCGuard<'_, Self>> {
 		unsafe {
@@ -561,6 +923,21 @@ pub trait user_Hwnd: Handle {
 
 	/// [`GetDlgCtrlID`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdlgctrlid)
 	/// function.
+	///
+	/// This is the inverse of
+	/// [`HWND::GetDlgItem`](crate::prelude::user_Hwnd::GetDlgItem).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hctrl: w::HWND; // initialized somewhere
+	/// # let hctrl = w::HWND::NULL;
+	///
+	/// let ctrl_id = hctrl.GetDlgCtrlID()?;
+	/// # w::SysResult::Ok(())
+	/// ```
 	#[must_use]
 	fn GetDlgCtrlID(&self) -> SysResult<u16> {
 		SetLastError(co::ERROR::SUCCESS);
@@ -575,6 +952,25 @@ pub trait user_Hwnd: Handle {
 
 	/// [`GetDlgItem`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdlgitem)
 	/// function.
+	///
+	/// Retrieves a child control by its ID, which is how controls created from
+	/// a dialog resource are addressed. Works for any parent window, not only
+	/// dialogs.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// const ID_TXT_NAME: u16 = 1001; // as defined in the .rc file
+	///
+	/// let hdlg: w::HWND; // initialized somewhere
+	/// # let hdlg = w::HWND::NULL;
+	///
+	/// let htxt = hdlg.GetDlgItem(ID_TXT_NAME)?;
+	/// htxt.SetWindowText("John")?;
+	/// # w::SysResult::Ok(())
+	/// ```
 	#[must_use]
 	fn GetDlgItem(&self, ctrl_id: u16) -> SysResult<HWND> {
 		ptr_to_sysresult_handle(
@@ -584,9 +980,28 @@ pub trait user_Hwnd: Handle {
 
 	/// [`GetDpiForWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getdpiforwindow)
 	/// function.
+	///
+	/// The function is loaded at runtime. On Windows versions prior to 10 1607,
+	/// where it doesn't exist, falls back to the system DPI, retrieved with
+	/// `GetDeviceCaps` and `LOGPIXELSX` on the screen DC.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let dpi = w::Dpi(hwnd.GetDpiForWindow());
+	/// let margin = dpi.scale(10);
+	/// ```
 	#[must_use]
 	fn GetDpiForWindow(&self) -> u32 {
-		unsafe { ffi::GetDpiForWindow(self.ptr()) }
+		unsafe {
+			call_dyn("user32", "GetDpiForWindow",
+				|f: extern "system" fn(HANDLE) -> u32| f(self.ptr()))
+		}.unwrap_or_else(legacy_system_dpi)
 	}
 
 	/// [`GetFocus`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getfocus)
@@ -603,6 +1018,34 @@ pub trait user_Hwnd: Handle {
 		ptr_to_option_handle(unsafe { ffi::GetForegroundWindow() })
 	}
 
+	/// [`GetLayeredWindowAttributes`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getlayeredwindowattributes)
+	/// function.
+	///
+	/// Returns the transparency color key and the alpha, each one only if
+	/// currently set.
+	#[must_use]
+	fn GetLayeredWindowAttributes(&self,
+	) -> SysResult<(Option<COLORREF>, Option<u8>)>
+	{
+		let mut key = u32::default();
+		let mut alpha = u8::default();
+		let mut flags = u32::default();
+
+		bool_to_sysresult(
+			unsafe {
+				ffi::GetLayeredWindowAttributes(
+					self.ptr(), &mut key, &mut alpha, &mut flags)
+			},
+		).map(|_| {
+			let flags = unsafe { co::LWA::from_raw(flags) };
+			(
+				flags.has(co::LWA::COLORKEY)
+					.then(|| unsafe { COLORREF::from_raw(key) }),
+				flags.has(co::LWA::ALPHA).then_some(alpha),
+			)
+		})
+	}
+
 	/// [`GetLastActivePopup`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getlastactivepopup)
 	/// function.
 	#[must_use]
@@ -671,9 +1114,29 @@ pub trait user_Hwnd: Handle {
 
 	/// [`GetNextDlgTabItem`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getnextdlgtabitem)
 	/// function.
+	///
+	/// If `hwnd_ctrl` is `None`, the search starts at the last control – or
+	/// the first one, if `previous` is `true` –, so the first – or last –
+	/// control in the tab order is returned.
+	///
+	/// # Examples
+	///
+	/// Moving the focus to the control after the focused one:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hdlg: w::HWND; // initialized somewhere
+	/// # let hdlg = w::HWND::NULL;
+	///
+	/// let hfocus = w::HWND::GetFocus();
+	/// let hnext = hdlg.GetNextDlgTabItem(hfocus.as_ref(), false)?;
+	/// hnext.SetFocus();
+	/// # w::SysResult::Ok(())
+	/// ```
 	#[must_use]
 	fn GetNextDlgTabItem(&self,
-		hwnd_ctrl: &HWND,
+		hwnd_ctrl: Option<&HWND>,
 		previous: bool,
 	) -> SysResult<HWND>
 	{
@@ -681,7 +1144,7 @@ pub trait user_Hwnd: Handle {
 			unsafe {
 				ffi::GetNextDlgTabItem(
 					self.ptr(),
-					hwnd_ctrl.ptr(),
+					hwnd_ctrl.map_or(std::ptr::null_mut(), |h| h.ptr()),
 					previous as _,
 				)
 			},
@@ -690,6 +1153,11 @@ pub trait user_Hwnd: Handle {
 
 	/// [`GetParent`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getparent)
 	/// function.
+	///
+	/// Note that, for a top-level window, this function returns the owner
+	/// window, if any. To retrieve strictly the parent, use
+	/// [`HWND::GetAncestor`](crate::prelude::user_Hwnd::GetAncestor) with
+	/// [`co::GA::PARENT`](crate::co::GA::PARENT).
 	#[must_use]
 	fn GetParent(&self) -> SysResult<HWND> {
 		ptr_to_sysresult_handle(unsafe { ffi::GetParent(self.ptr()) })
@@ -779,6 +1247,23 @@ pub trait user_Hwnd: Handle {
 
 	/// [`GetWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getwindow)
 	/// function.
+	///
+	/// # Examples
+	///
+	/// Iterating over the immediate children of a window:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let mut hchild = hwnd.GetWindow(co::GW::CHILD).ok();
+	/// while let Some(h) = hchild {
+	///     println!("Child HWND: {}", h);
+	///     hchild = h.GetWindow(co::GW::HWNDNEXT).ok();
+	/// }
+	/// ```
 	#[must_use]
 	fn GetWindow(&self, cmd: co::GW) -> SysResult<HWND> {
 		ptr_to_sysresult_handle( unsafe { ffi::GetWindow(self.ptr(), cmd.raw()) })
@@ -786,6 +1271,9 @@ pub trait user_Hwnd: Handle {
 
 	/// [`GetWindowDC`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-getwindowdc)
 	/// function.
+	///
+	/// Like [`HWND::GetDC`](crate::prelude::user_Hwnd::GetDC), but the device
+	/// context covers the whole window, including the title bar and borders.
 	#[must_use]
 	fn GetWindowDC(&self) -> SysResult<ReleaseDCGuard<'_, Self>> {
 		unsafe {
@@ -1050,11 +1538,34 @@ pub trait user_Hwnd: Handle {
 
 	/// [`IsDialogMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isdialogmessagew)
 	/// function.
+	///
+	/// Performs the dialog keyboard navigation – Tab, arrows, Enter and Esc –
+	/// returning `true` if the message was processed, in which case it must not
+	/// be passed to [`TranslateMessage`](crate::TranslateMessage) and
+	/// [`DispatchMessage`](crate::DispatchMessage).
+	///
+	/// It must be called on the top-level window which owns the controls, so
+	/// in a custom loop, each modeless dialog must be checked independently –
+	/// the message loops of the `gui` module do it by calling this method on
+	/// the root ancestor of the message target.
+	///
+	/// For an example, see [`GetMessage`](crate::GetMessage).
 	#[must_use]
 	fn IsDialogMessage(&self, msg: &mut MSG) -> bool {
 		unsafe { ffi::IsDialogMessageW(self.ptr(), msg as *mut _ as _) != 0 }
 	}
 
+	/// [`IsHungAppWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-ishungappwindow)
+	/// function.
+	///
+	/// A window is considered hung if its thread has not been waiting for input,
+	/// processing messages or calling
+	/// [`PeekMessage`](crate::PeekMessage) for 5 seconds.
+	#[must_use]
+	fn IsHungAppWindow(&self) -> bool {
+		unsafe { ffi::IsHungAppWindow(self.ptr()) != 0 }
+	}
+
 	/// [`IsIconic`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-isiconic)
 	/// function.
 	#[must_use]
@@ -1266,6 +1777,27 @@ pub trait user_Hwnd: Handle {
 
 	/// [`MoveWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-movewindow)
 	/// function.
+	///
+	/// Moves and resizes a single window right away. To reposition many
+	/// windows at once – like the children of a resized parent – prefer
+	/// [`HDWP::BeginDeferWindowPos`](crate::prelude::user_Hdwp::BeginDeferWindowPos),
+	/// which avoids redundant repaints.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.MoveWindow(
+	///     w::POINT::new(10, 10),
+	///     w::SIZE::new(200, 100),
+	///     true,
+	/// )?;
+	/// # w::SysResult::Ok(())
+	/// ```
 	fn MoveWindow(&self,
 		pos: POINT,
 		size: SIZE,
@@ -1325,6 +1857,31 @@ pub trait user_Hwnd: Handle {
 		}
 	}
 
+	/// [`PhysicalToLogicalPointForPerMonitorDPI`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-physicaltologicalpointforpermonitordpi)
+	/// function.
+	///
+	/// The function is loaded at runtime. On Windows versions prior to 8.1,
+	/// where it doesn't exist, falls back to
+	/// [`PhysicalToLogicalPoint`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-physicaltologicalpoint).
+	#[must_use]
+	fn PhysicalToLogicalPointForPerMonitorDPI(&self,
+		pt: POINT,
+	) -> SysResult<POINT>
+	{
+		let mut buf = pt;
+		bool_to_sysresult(
+			unsafe {
+				call_dyn("user32", "PhysicalToLogicalPointForPerMonitorDPI",
+					|f: extern "system" fn(HANDLE, PVOID) -> BOOL| {
+						f(self.ptr(), &mut buf as *mut _ as _)
+					})
+					.unwrap_or_else(|| {
+						ffi::PhysicalToLogicalPoint(self.ptr(), &mut buf as *mut _ as _)
+					})
+			},
+		).map(|_| buf)
+	}
+
 	/// [`PostMessage`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-postmessagew)
 	/// function.
 	///
@@ -1400,6 +1957,99 @@ pub trait user_Hwnd: Handle {
 		)
 	}
 
+	/// [`RegisterDeviceNotification`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerdevicenotificationw)
+	/// function.
+	///
+	/// Without registration, top-level windows receive
+	/// [`WM_DEVICECHANGE`](crate::msg::wm::DeviceChange) only for volumes and
+	/// ports; this is what makes device interface arrival and removal – USB,
+	/// HID and so on – be notified.
+	///
+	/// The notifications stop when the returned guard goes out of scope.
+	///
+	/// # Examples
+	///
+	/// Noticing when a COM port appears:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, msg};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let _notif = hwnd.RegisterDeviceNotification( // keep guard alive
+	///     w::DevNotify::DeviceInterfaceClass(co::DEVINTERFACE::COMPORT),
+	///     co::DEVICE_NOTIFY::WINDOW_HANDLE,
+	/// )?;
+	///
+	/// // Then, when processing WM_DEVICECHANGE:
+	///
+	/// let p: msg::wm::DeviceChange; // received in the window procedure
+	/// # let p = msg::wm::DeviceChange { event: co::DBT::DEVICEARRIVAL, data: None };
+	///
+	/// if p.event == co::DBT::DEVICEARRIVAL {
+	///     if let Some(w::SvcCtlDeviceEvent::Interface(di)) = unsafe { p.device_event() } {
+	///         println!("Port arrived: {}", di.dbcc_name());
+	///     }
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn RegisterDeviceNotification(&self,
+		filter: DevNotify,
+		flags: co::DEVICE_NOTIFY,
+	) -> SysResult<UnregisterDeviceNotificationGuard>
+	{
+		#[repr(C)]
+		#[derive(Default)]
+		struct DEV_BROADCAST_DEVICEINTERFACE { // name is not needed for the filter
+			hdr: DEV_BROADCAST_HDR,
+			dbcc_classguid: GUID,
+			dbcc_name: [u16; 1],
+		}
+
+		#[repr(C)]
+		struct DEV_BROADCAST_HANDLE { // name and data are not needed for the filter
+			hdr: DEV_BROADCAST_HDR,
+			dbch_handle: HANDLE,
+			dbch_hdevnotify: HANDLE,
+			dbch_eventguid: GUID,
+			dbch_nameoffset: i16,
+			dbch_data: [u8; 1],
+		}
+
+		let mut dbi = DEV_BROADCAST_DEVICEINTERFACE::default();
+		let mut dbh = DEV_BROADCAST_HANDLE {
+			hdr: DEV_BROADCAST_HDR::default(),
+			dbch_handle: std::ptr::null_mut(),
+			dbch_hdevnotify: std::ptr::null_mut(),
+			dbch_eventguid: GUID::default(),
+			dbch_nameoffset: 0,
+			dbch_data: [0; 1],
+		};
+
+		let pfilter: *const std::ffi::c_void = match filter {
+			DevNotify::DeviceInterfaceClass(class_guid) => {
+				dbi.hdr.dbch_size = std::mem::size_of::<DEV_BROADCAST_DEVICEINTERFACE>() as _;
+				dbi.hdr.dbch_devicetype = co::DBT_DEVTYP::DEVICEINTERFACE;
+				dbi.dbcc_classguid = *class_guid.as_ref();
+				&dbi as *const _ as _
+			},
+			DevNotify::Handle(hfile) => {
+				dbh.hdr.dbch_size = std::mem::size_of::<DEV_BROADCAST_HANDLE>() as _;
+				dbh.hdr.dbch_devicetype = co::DBT_DEVTYP::HANDLE;
+				dbh.dbch_handle = hfile.ptr();
+				&dbh as *const _ as _
+			},
+		};
+
+		unsafe {
+			ptr_to_sysresult_handle(
+				ffi::RegisterDeviceNotificationW(self.ptr(), pfilter, flags.raw()),
+			).map(|h| UnregisterDeviceNotificationGuard::new(h))
+		}
+	}
+
 	/// [`RegisterHotKey`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerhotkey)
 	/// function.
 	fn RegisterHotKey(&self,
@@ -1546,10 +2196,36 @@ pub trait user_Hwnd: Handle {
 	/// [`SendMessageTimeout`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-sendmessagetimeoutw)
 	/// function.
 	///
+	/// Unlike [`SendMessage`](crate::prelude::user_Hwnd::SendMessage), it
+	/// won't block forever if the receiving thread is hung. If the timeout
+	/// elapses, returns [`ERROR::TIMEOUT`](crate::co::ERROR::TIMEOUT).
+	///
 	/// # Safety
 	///
 	/// Messages manipulate pointers, copies and window states. Improper use may
 	/// lead to undefined behavior.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co, msg};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// match unsafe {
+	///     hwnd.SendMessageTimeout(
+	///         msg::wm::GetTextLength {},
+	///         co::SMTO::ABORTIFHUNG,
+	///         2000,
+	///     )
+	/// } {
+	///     Ok(len) => println!("Text length: {}", len),
+	///     Err(co::ERROR::TIMEOUT) => println!("Window is not responding."),
+	///     Err(e) => return Err(e),
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
 	unsafe fn SendMessageTimeout<M>(&self,
 		msg: M,
 		flags: co::SMTO,
@@ -1619,12 +2295,51 @@ pub trait user_Hwnd: Handle {
 
 	/// [`SetForegroundWindow`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setforegroundwindow)
 	/// function.
+	///
+	/// Returns `false` if the system refused to bring the window to the
+	/// foreground, which happens when the calling process is not allowed to do
+	/// so – usually because the user is interacting with another application.
+	/// This is not an error, so no error code is set. The process currently in
+	/// the foreground can grant the permission to another process with
+	/// [`AllowSetForegroundWindow`](crate::AllowSetForegroundWindow).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// if let Some(hwnd) = w::HWND::FindWindow(Some(w::AtomStr::from_str("Notepad")), None)? {
+	///     if !hwnd.SetForegroundWindow() {
+	///         println!("Blocked by the foreground lock.");
+	///     }
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
 	fn SetForegroundWindow(&self) -> bool {
 		unsafe { ffi::SetForegroundWindow(self.ptr()) != 0 }
 	}
 
 	/// [`SetLayeredWindowAttributes`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setlayeredwindowattributes)
 	/// function.
+	///
+	/// The window must have the [`WS_EX::LAYERED`](crate::co::WS_EX::LAYERED)
+	/// style.
+	///
+	/// # Examples
+	///
+	/// Making a window 75% opaque:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// hwnd.set_style_ex(hwnd.style_ex() | co::WS_EX::LAYERED);
+	/// hwnd.SetLayeredWindowAttributes(
+	///     w::COLORREF::new(0, 0, 0), 191, co::LWA::ALPHA)?;
+	/// # w::SysResult::Ok(())
+	/// ```
 	fn SetLayeredWindowAttributes(&self,
 		transparency_color_key: COLORREF,
 		alpha: u8,
@@ -1651,7 +2366,51 @@ pub trait user_Hwnd: Handle {
 
 	/// [`SetParent`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-setparent)
 	/// function.
+	///
+	/// Returns the previous parent, if any. Pass
+	/// [`HWND::NULL`](crate::prelude::Handle::NULL) to make the window a
+	/// top-level one.
+	///
+	/// When moving a window under a new parent, you must also set the
+	/// [`co::WS::CHILD`](crate::co::WS::CHILD) style – and when making it a
+	/// top-level window, clear `WS::CHILD` and set
+	/// [`co::WS::POPUP`](crate::co::WS::POPUP) – with
+	/// [`HWND::SetWindowLongPtr`](crate::prelude::user_Hwnd::SetWindowLongPtr).
+	/// A window with the
+	/// [`co::WS_EX::NOPARENTNOTIFY`](crate::co::WS_EX::NOPARENTNOTIFY) style
+	/// won't send
+	/// [`WM_PARENTNOTIFY`](https://learn.microsoft.com/en-us/windows/win32/inputmsg/wm-parentnotify)
+	/// to its parents.
+	///
+	/// Reparenting a window across processes is possible, but strongly
+	/// discouraged, because it attaches the input queues of both threads. To
+	/// simply set the owner of a top-level window, use
+	/// [`HWND::SetWindowLongPtr`](crate::prelude::user_Hwnd::SetWindowLongPtr)
+	/// with [`co::GWLP::HWNDPARENT`](crate::co::GWLP::HWNDPARENT) instead.
+	///
+	/// # Examples
+	///
+	/// Tearing off a panel into its own top-level window:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let hpanel: w::HWND; // initialized somewhere
+	/// # let hpanel = w::HWND::NULL;
+	///
+	/// let style = unsafe {
+	///     co::WS::from_raw(hpanel.GetWindowLongPtr(co::GWLP::STYLE) as _)
+	/// };
+	/// let new_style = (style & !co::WS::CHILD) | co::WS::POPUP | co::WS::CAPTION;
+	/// unsafe {
+	///     hpanel.SetWindowLongPtr(co::GWLP::STYLE, new_style.raw() as _);
+	/// }
+	///
+	/// let _old_parent = hpanel.SetParent(&w::HWND::NULL)?;
+	/// # w::SysResult::Ok(())
+	/// ```
 	fn SetParent(&self, hwnd_new_parent: &HWND) -> SysResult<Option<HWND>> {
+		SetLastError(co::ERROR::SUCCESS);
 		match ptr_to_option_handle(
 			unsafe { ffi::SetParent(self.ptr(), hwnd_new_parent.ptr()) },
 		) {
@@ -2032,4 +2791,36 @@ pub trait user_Hwnd: Handle {
 			},
 		)
 	}
+
+	/// [`WTSRegisterSessionNotification`](https://learn.microsoft.com/en-us/windows/win32/api/wtsapi32/nf-wtsapi32-wtsregistersessionnotification)
+	/// function.
+	///
+	/// After the call, the window receives
+	/// [`wm::WtsSessionChange`](crate::msg::wm::WtsSessionChange) messages for
+	/// the current session or, if `all_sessions` is `true`, for all sessions.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hwnd: w::HWND; // initialized somewhere
+	/// # let hwnd = w::HWND::NULL;
+	///
+	/// let _notif_guard = hwnd.WTSRegisterSessionNotification(false)?; // keep guard alive
+	///
+	/// // WTSUnRegisterSessionNotification() called automatically
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn WTSRegisterSessionNotification(&self,
+		all_sessions: bool,
+	) -> SysResult<WTSUnRegisterSessionNotificationGuard>
+	{
+		unsafe {
+			bool_to_sysresult(
+				ffi::WTSRegisterSessionNotification(self.ptr(), all_sessions as _),
+			).map(|_| WTSUnRegisterSessionNotificationGuard::new(HWND::from_ptr(self.ptr())))
+		}
+	}
 }
@@ -6,9 +6,11 @@ mod hdwp;
 mod hhook;
 mod hicon;
 mod hinstance;
+mod hkl;
 mod hmenu;
 mod hmonitor;
 mod hprocess;
+mod hwineventhook;
 mod hwnd;
 
 pub mod decl {
@@ -19,8 +21,10 @@ pub mod decl {
 	pub use super::hdwp::HDWP;
 	pub use super::hhook::HHOOK;
 	pub use super::hicon::HICON;
+	pub use super::hkl::HKL;
 	pub use super::hmenu::HMENU;
 	pub use super::hmonitor::HMONITOR;
+	pub use super::hwineventhook::HWINEVENTHOOK;
 	pub use super::hwnd::HWND;
 
 	impl_handle! { HBITMAP;
@@ -33,6 +37,12 @@ pub mod decl {
 		/// [brush](https://learn.microsoft.com/en-us/windows/win32/winprog/windows-data-types#hbrush).
 	}
 
+	impl_handle! { HDEVNOTIFY;
+		/// Handle to a
+		/// [device notification](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-registerdevicenotificationw)
+		/// registration.
+	}
+
 	impl_handle! { HRGN;
 		/// Handle to a
 		/// [region](https://learn.microsoft.com/en-us/windows/win32/winprog/windows-data-types#hrgn)
@@ -49,8 +59,10 @@ pub mod traits {
 	pub use super::hhook::user_Hhook;
 	pub use super::hicon::user_Hicon;
 	pub use super::hinstance::user_Hinstance;
+	pub use super::hkl::user_Hkl;
 	pub use super::hmenu::user_Hmenu;
 	pub use super::hmonitor::user_Hmonitor;
 	pub use super::hprocess::user_Hprocess;
+	pub use super::hwineventhook::user_Hwineventhook;
 	pub use super::hwnd::user_Hwnd;
 }
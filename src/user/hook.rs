@@ -0,0 +1,372 @@
+use std::cell::RefCell;
+
+use crate::co;
+use crate::decl::*;
+use crate::guard::*;
+use crate::kernel::ffi_types::*;
+use crate::prelude::*;
+use crate::user::ffi;
+
+/// Closure of an installed hook, along with its handle.
+struct HookFunc {
+	hook_id: co::WH,
+	hhook: HHOOK,
+	func: Option<Box<dyn FnMut(HookEvent) -> bool>>, // None while running
+}
+
+/// Closure of an installed event hook, along with its handle.
+struct WinEventFunc {
+	hwineventhook: HWINEVENTHOOK,
+	func: Option<Box<dyn FnMut(co::EVENT, HWND, co::OBJID, i32, u32, u32)>>, // None while running
+}
+
+thread_local! {
+	/// Hook procedures receive no user data, so the closures are kept per
+	/// thread, one for each hook type.
+	static HOOK_FUNCS: RefCell<Vec<HookFunc>> = RefCell::new(Vec::new());
+
+	/// Out-of-context event hooks are called in the installing thread.
+	static WIN_EVENT_FUNCS: RefCell<Vec<WinEventFunc>> = RefCell::new(Vec::new());
+}
+
+/// Installs a hook which runs the given closure, by calling
+/// [`HHOOK::SetWindowsHookEx`](crate::prelude::user_Hhook::SetWindowsHookEx).
+///
+/// The following hook types are supported, each one delivering its data with
+/// the corresponding [`HookEvent`](crate::HookEvent) variant:
+///
+/// * [`WH::KEYBOARD_LL`](crate::co::WH::KEYBOARD_LL) – system-wide, but the
+/// closure runs in the current process;
+/// * [`WH::MOUSE_LL`](crate::co::WH::MOUSE_LL) – system-wide, but the closure
+/// runs in the current process;
+/// * [`WH::CBT`](crate::co::WH::CBT) – current thread only;
+/// * [`WH::MSGFILTER`](crate::co::WH::MSGFILTER) – current thread only;
+/// * [`WH::GETMESSAGE`](crate::co::WH::GETMESSAGE) – current thread only.
+///
+/// Other hook types fail with
+/// [`ERROR::INVALID_HOOK_FILTER`](crate::co::ERROR::INVALID_HOOK_FILTER).
+/// Only one closure per hook type can be installed in a thread at a time;
+/// a second one fails with
+/// [`ERROR::ALREADY_EXISTS`](crate::co::ERROR::ALREADY_EXISTS).
+///
+/// If the closure returns `true`, the event is swallowed: it's not passed to
+/// the next hook, and the system discards it. For
+/// [`HookEvent::GetMessage`](crate::HookEvent::GetMessage), the message is
+/// replaced by [`WM::NULL`](crate::co::WM::NULL). Otherwise,
+/// [`HHOOK::CallNextHookEx`](crate::prelude::user_Hhook::CallNextHookEx) is
+/// called automatically.
+///
+/// The hook is removed, and the closure is dropped, when the returned guard
+/// goes out of scope.
+///
+/// # Message loop
+///
+/// Low-level hooks are called by sending a message to the thread which
+/// installed them, so this thread must run a message loop – if it has no
+/// windows, [`hook::run_loop`](crate::hook::run_loop) can be used. The closure
+/// must also return quickly, otherwise the system will skip it.
+///
+/// # Examples
+///
+/// A global push-to-talk key:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let _hook = w::hook::set(co::WH::KEYBOARD_LL, |ev: w::HookEvent| -> bool {
+///     if let w::HookEvent::KeyboardLl(msg, kb) = ev {
+///         if kb.vkCode() == co::VK::F13 {
+///             println!("Talking: {}", msg == co::WM::KEYDOWN);
+///             return true; // swallow the key
+///         }
+///     }
+///     false
+/// })?;
+///
+/// w::hook::run_loop()?;
+/// # w::SysResult::Ok(())
+/// ```
+pub fn set<F>(hook_id: co::WH, func: F) -> SysResult<UnhookWindowsHookExGuard>
+	where F: FnMut(HookEvent) -> bool + 'static,
+{
+	let (proc, thread_id): (HOOKPROC, u32) = match hook_id {
+		co::WH::KEYBOARD_LL => (proc_keyboard_ll, 0),
+		co::WH::MOUSE_LL => (proc_mouse_ll, 0),
+		co::WH::CBT => (proc_cbt, GetCurrentThreadId()),
+		co::WH::MSGFILTER => (proc_msg_filter, GetCurrentThreadId()),
+		co::WH::GETMESSAGE => (proc_get_message, GetCurrentThreadId()),
+		_ => return Err(co::ERROR::INVALID_HOOK_FILTER),
+	};
+
+	let already_set = HOOK_FUNCS.with(|funcs| {
+		funcs.borrow().iter().any(|f| f.hook_id == hook_id)
+	});
+	if already_set {
+		return Err(co::ERROR::ALREADY_EXISTS);
+	}
+
+	let hinst = HINSTANCE::GetModuleHandle(None)?;
+	let hhook = HHOOK::SetWindowsHookEx(hook_id, proc,
+		if thread_id == 0 { Some(&hinst) } else { None },
+		Some(thread_id))?;
+
+	HOOK_FUNCS.with(|funcs| {
+		funcs.borrow_mut().push(HookFunc {
+			hook_id,
+			hhook: unsafe { hhook.raw_copy() },
+			func: Some(Box::new(func)),
+		});
+	});
+	Ok(hhook)
+}
+
+/// Installs an event hook which runs the given closure, by calling
+/// [`HWINEVENTHOOK::SetWinEventHook`](crate::prelude::user_Hwineventhook::SetWinEventHook)
+/// with
+/// [`WINEVENT::OUTOFCONTEXT`](crate::co::WINEVENT::OUTOFCONTEXT).
+///
+/// Events from `event_min` to `event_max` are received; to receive a single
+/// event, pass it to both. If `process_id` and `thread_id` are `None`, events
+/// from all processes and threads are received.
+///
+/// The closure receives:
+///
+/// * the event;
+/// * the window which generated the event, if any;
+/// * the object identifier;
+/// * the child identifier, or `CHILDID_SELF` (zero);
+/// * the thread which generated the event;
+/// * the time, in milliseconds, when the event was generated.
+///
+/// Since the hook is out-of-context,
+/// [`WINEVENT::INCONTEXT`](crate::co::WINEVENT::INCONTEXT) is not allowed, and
+/// fails with
+/// [`ERROR::INVALID_PARAMETER`](crate::co::ERROR::INVALID_PARAMETER).
+///
+/// The hook is removed, and the closure is dropped, when the returned guard
+/// goes out of scope.
+///
+/// # Message loop
+///
+/// The events are delivered through the message queue of the thread which
+/// installed the hook, so this thread must run a message loop – if it has no
+/// windows, [`hook::run_loop`](crate::hook::run_loop) can be used.
+///
+/// # Examples
+///
+/// Tracking the foreground window:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let _hook = w::hook::set_win_event(
+///     co::EVENT::SYSTEM_FOREGROUND,
+///     co::EVENT::SYSTEM_FOREGROUND,
+///     |_: co::EVENT, hwnd: w::HWND, _: co::OBJID, _: i32, _: u32, time: u32| {
+///         let title = hwnd.GetWindowText().unwrap_or_default();
+///         println!("{} {}", time, title);
+///     },
+///     None,
+///     None,
+///     co::WINEVENT::SKIPOWNPROCESS,
+/// )?;
+///
+/// w::hook::run_loop()?;
+/// # w::SysResult::Ok(())
+/// ```
+pub fn set_win_event<F>(
+	event_min: co::EVENT,
+	event_max: co::EVENT,
+	func: F,
+	process_id: Option<u32>,
+	thread_id: Option<u32>,
+	flags: co::WINEVENT,
+) -> SysResult<UnhookWinEventGuard>
+	where F: FnMut(co::EVENT, HWND, co::OBJID, i32, u32, u32) + 'static,
+{
+	if flags.has(co::WINEVENT::INCONTEXT) {
+		return Err(co::ERROR::INVALID_PARAMETER);
+	}
+
+	let hwineventhook = HWINEVENTHOOK::SetWinEventHook(event_min, event_max,
+		None, proc_win_event, process_id, thread_id,
+		flags | co::WINEVENT::OUTOFCONTEXT)?;
+
+	WIN_EVENT_FUNCS.with(|funcs| {
+		funcs.borrow_mut().push(WinEventFunc {
+			hwineventhook: unsafe { hwineventhook.raw_copy() },
+			func: Some(Box::new(func)),
+		});
+	});
+	Ok(hwineventhook)
+}
+
+/// Runs a message loop on the current thread, until a
+/// [`WM::QUIT`](crate::co::WM::QUIT) message is received. Returns the exit
+/// code passed to [`PostQuitMessage`](crate::PostQuitMessage).
+///
+/// This is needed by hooks installed in a thread which has no windows, since
+/// hook calls are delivered through the thread's message queue.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let _hook = w::hook::set(co::WH::MOUSE_LL, |ev: w::HookEvent| -> bool {
+///     if let w::HookEvent::MouseLl(co::WM::RBUTTONDOWN, _) = ev {
+///         w::PostQuitMessage(0); // stop at the first right click
+///     }
+///     false
+/// })?;
+///
+/// let exit_code = w::hook::run_loop()?;
+/// # w::SysResult::Ok(())
+/// ```
+pub fn run_loop() -> SysResult<i32> {
+	let mut msg = MSG::default();
+	loop {
+		if !GetMessage(&mut msg, None, 0, 0)? {
+			return Ok(msg.wParam as _); // WM_QUIT was sent, exit gracefully
+		}
+		TranslateMessage(&msg);
+		unsafe { DispatchMessage(&msg); }
+	}
+}
+
+/// Removes the closure of the given hook. Called by the guard.
+pub(in crate::user) fn remove(hhook: &HHOOK) {
+	let removed = HOOK_FUNCS.try_with(|funcs| {
+		let mut funcs = funcs.borrow_mut();
+		funcs.iter()
+			.position(|f| f.hhook == *hhook)
+			.map(|idx| funcs.remove(idx))
+	});
+	drop(removed); // closure is dropped outside the borrow
+}
+
+/// Calls `UnhookWinEvent` and removes the closure of the given event hook, if
+/// any. Called by the guard.
+pub(in crate::user) unsafe fn unhook_win_event(hwineventhook: HANDLE) -> BOOL {
+	let ret = ffi::UnhookWinEvent(hwineventhook);
+	let hwineventhook = HWINEVENTHOOK::from_ptr(hwineventhook);
+	let removed = WIN_EVENT_FUNCS.try_with(|funcs| {
+		let mut funcs = funcs.borrow_mut();
+		funcs.iter()
+			.position(|f| f.hwineventhook == hwineventhook)
+			.map(|idx| funcs.remove(idx))
+	});
+	drop(removed); // closure is dropped outside the borrow
+	ret
+}
+
+/// Runs the closure of the given hook type, returning whether the event must
+/// be swallowed.
+fn run_func(hook_id: co::WH, ev: HookEvent) -> bool {
+	let func = HOOK_FUNCS.with(|funcs| {
+		funcs.borrow_mut().iter_mut()
+			.find(|f| f.hook_id == hook_id)
+			.and_then(|f| f.func.take())
+	});
+
+	match func {
+		None => false, // re-entrant call, or hook being removed
+		Some(mut func) => {
+			let swallow = func(ev);
+			HOOK_FUNCS.with(|funcs| {
+				if let Some(f) = funcs.borrow_mut().iter_mut()
+					.find(|f| f.hook_id == hook_id && f.func.is_none())
+				{
+					f.func = Some(func); // hook may have been removed by the closure
+				}
+			});
+			swallow
+		},
+	}
+}
+
+fn call_next(code: i32, wparam: usize, lparam: isize) -> isize {
+	unsafe { ffi::CallNextHookEx(std::ptr::null_mut(), code, wparam, lparam) }
+}
+
+extern "system" fn proc_keyboard_ll(code: i32, wparam: usize, lparam: isize) -> isize {
+	if code >= 0 {
+		let kb = unsafe { &*(lparam as *const KBDLLHOOKSTRUCT) };
+		let msg = unsafe { co::WM::from_raw(wparam as _) };
+		if run_func(co::WH::KEYBOARD_LL, HookEvent::KeyboardLl(msg, kb)) {
+			return 1;
+		}
+	}
+	call_next(code, wparam, lparam)
+}
+
+extern "system" fn proc_mouse_ll(code: i32, wparam: usize, lparam: isize) -> isize {
+	if code >= 0 {
+		let ms = unsafe { &*(lparam as *const MSLLHOOKSTRUCT) };
+		let msg = unsafe { co::WM::from_raw(wparam as _) };
+		if run_func(co::WH::MOUSE_LL, HookEvent::MouseLl(msg, ms)) {
+			return 1;
+		}
+	}
+	call_next(code, wparam, lparam)
+}
+
+extern "system" fn proc_cbt(code: i32, wparam: usize, lparam: isize) -> isize {
+	if code >= 0 {
+		let hcbt = unsafe { co::HCBT::from_raw(code) };
+		if run_func(co::WH::CBT, HookEvent::Cbt(hcbt, wparam, lparam)) {
+			return 1; // prevents the operation
+		}
+	}
+	call_next(code, wparam, lparam)
+}
+
+extern "system" fn proc_msg_filter(code: i32, wparam: usize, lparam: isize) -> isize {
+	if code >= 0 {
+		let msg = unsafe { &*(lparam as *const MSG) };
+		if run_func(co::WH::MSGFILTER, HookEvent::MsgFilter(code, msg)) {
+			return 1;
+		}
+	}
+	call_next(code, wparam, lparam)
+}
+
+extern "system" fn proc_get_message(code: i32, wparam: usize, lparam: isize) -> isize {
+	if code >= 0 {
+		let removed = wparam == co::PM::REMOVE.raw() as _;
+		let msg = unsafe { &mut *(lparam as *mut MSG) };
+		if run_func(co::WH::GETMESSAGE, HookEvent::GetMessage(removed, msg)) {
+			let msg = unsafe { &mut *(lparam as *mut MSG) };
+			msg.message = co::WM::NULL; // return value is ignored for this hook
+		}
+	}
+	call_next(code, wparam, lparam)
+}
+
+extern "system" fn proc_win_event(
+	hwineventhook: HWINEVENTHOOK,
+	event: co::EVENT,
+	hwnd: HWND,
+	id_object: i32,
+	id_child: i32,
+	event_thread: u32,
+	time: u32,
+) {
+	let func = WIN_EVENT_FUNCS.with(|funcs| {
+		funcs.borrow_mut().iter_mut()
+			.find(|f| f.hwineventhook == hwineventhook)
+			.and_then(|f| f.func.take())
+	});
+
+	if let Some(mut func) = func { // None if re-entrant call, or hook being removed
+		let obj_id = unsafe { co::OBJID::from_raw(id_object as _) };
+		func(event, hwnd, obj_id, id_child, event_thread, time);
+		WIN_EVENT_FUNCS.with(|funcs| {
+			if let Some(f) = funcs.borrow_mut().iter_mut()
+				.find(|f| f.hwineventhook == hwineventhook && f.func.is_none())
+			{
+				f.func = Some(func); // hook may have been removed by the closure
+			}
+		});
+	}
+}
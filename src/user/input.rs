@@ -0,0 +1,201 @@
+use crate::co;
+use crate::decl::*;
+use crate::user::ffi;
+
+/// Keys whose scan codes are prefixed with `0xE0`, thus requiring
+/// [`KEYEVENTF::EXTENDEDKEY`](crate::co::KEYEVENTF::EXTENDEDKEY).
+const EXTENDED_KEYS: [co::VK; 19] = [
+	co::VK::APPS, co::VK::CANCEL, co::VK::DELETE, co::VK::DIVIDE,
+	co::VK::DOWN, co::VK::END, co::VK::HOME, co::VK::INSERT, co::VK::LEFT,
+	co::VK::LWIN, co::VK::NEXT, co::VK::NUMLOCK, co::VK::PRIOR,
+	co::VK::RCONTROL, co::VK::RIGHT, co::VK::RMENU, co::VK::RWIN,
+	co::VK::SNAPSHOT, co::VK::UP,
+];
+
+/// Synthesizes the given input events, in order, by calling
+/// [`SendInput`](crate::SendInput).
+///
+/// The events are inserted into the input stream at once, so they are not
+/// interspersed with other keyboard or mouse input. If the input is blocked by
+/// another thread, returns an error.
+///
+/// # Examples
+///
+/// Moving the cursor to the top-left corner of the virtual screen, then
+/// clicking:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// w::input::send(&[
+///     w::InputEvent::MouseMove { dx: 0, dy: 0, absolute: true },
+///     w::InputEvent::MouseButton { button: co::MK::LBUTTON, down: true },
+///     w::InputEvent::MouseButton { button: co::MK::LBUTTON, down: false },
+/// ])?;
+/// # w::SysResult::Ok(())
+/// ```
+pub fn send(events: &[InputEvent]) -> SysResult<()> {
+	let mut inputs = Vec::with_capacity(events.len());
+	for event in events.iter() {
+		push_event(*event, &mut inputs)?;
+	}
+	send_inputs(&inputs)
+}
+
+/// Types the given text by calling [`SendInput`](crate::SendInput), regardless
+/// of the current keyboard layout.
+///
+/// Each character is sent as an [`InputEvent::UnicodeChar`](crate::InputEvent::UnicodeChar),
+/// except line breaks and tabs, which are sent as
+/// [`VK::RETURN`](crate::co::VK::RETURN) and [`VK::TAB`](crate::co::VK::TAB)
+/// keystrokes, since many applications ignore them otherwise. A `\r\n` pair is
+/// sent as a single `VK::RETURN`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*};
+///
+/// w::input::send_text("Olá, mundo! 🦀")?;
+/// # w::SysResult::Ok(())
+/// ```
+pub fn send_text(text: &str) -> SysResult<()> {
+	let mut inputs = Vec::with_capacity(text.len() * 2);
+	let mut prev_cr = false;
+	for ch in text.chars() {
+		match ch {
+			'\n' if prev_cr => {},
+			'\r' | '\n' => push_keystroke(co::VK::RETURN, &mut inputs),
+			'\t' => push_keystroke(co::VK::TAB, &mut inputs),
+			ch => push_event(InputEvent::UnicodeChar(ch), &mut inputs)?,
+		}
+		prev_cr = ch == '\r';
+	}
+	send_inputs(&inputs)
+}
+
+/// Sends a keyboard shortcut by calling [`SendInput`](crate::SendInput): the
+/// keys are pressed in the given order, then released in reverse order.
+///
+/// # Examples
+///
+/// Sending Ctrl+Shift+Esc to open the Task Manager:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// w::input::send_shortcut(&[co::VK::CONTROL, co::VK::SHIFT, co::VK::ESCAPE])?;
+/// # w::SysResult::Ok(())
+/// ```
+pub fn send_shortcut(keys: &[co::VK]) -> SysResult<()> {
+	let inputs = keys.iter()
+		.map(|vk| key_input(*vk, false))
+		.chain(keys.iter().rev().map(|vk| key_input(*vk, true)))
+		.collect::<Vec<_>>();
+	send_inputs(&inputs)
+}
+
+fn send_inputs(inputs: &[HwKbMouse]) -> SysResult<()> {
+	if inputs.is_empty() {
+		return Ok(());
+	}
+	match SendInput(inputs)? {
+		n if n as usize == inputs.len() => Ok(()),
+		_ => Err(GetLastError()), // partially inserted
+	}
+}
+
+fn push_event(event: InputEvent, inputs: &mut Vec<HwKbMouse>) -> SysResult<()> {
+	match event {
+		InputEvent::KeyDown(vk) => inputs.push(key_input(vk, false)),
+		InputEvent::KeyUp(vk) => inputs.push(key_input(vk, true)),
+		InputEvent::UnicodeChar(ch) => {
+			let mut buf = [0u16; 2];
+			let units = ch.encode_utf16(&mut buf);
+			inputs.extend(units.iter().map(|unit| unicode_input(*unit, false)));
+			inputs.extend(units.iter().map(|unit| unicode_input(*unit, true)));
+		},
+		InputEvent::MouseMove { dx, dy, absolute } => {
+			inputs.push(HwKbMouse::Mouse(
+				if absolute {
+					MOUSEINPUT {
+						dx: normalize(dx, co::SM::XVIRTUALSCREEN, co::SM::CXVIRTUALSCREEN),
+						dy: normalize(dy, co::SM::YVIRTUALSCREEN, co::SM::CYVIRTUALSCREEN),
+						dwFlags: co::MOUSEEVENTF::MOVE
+							| co::MOUSEEVENTF::ABSOLUTE
+							| co::MOUSEEVENTF::VIRTUALDESK,
+						..Default::default()
+					}
+				} else {
+					MOUSEINPUT {
+						dx,
+						dy,
+						dwFlags: co::MOUSEEVENTF::MOVE,
+						..Default::default()
+					}
+				},
+			));
+		},
+		InputEvent::MouseButton { button, down } => {
+			let (flag_down, flag_up, mouse_data) = match button {
+				co::MK::LBUTTON => (co::MOUSEEVENTF::LEFTDOWN, co::MOUSEEVENTF::LEFTUP, 0),
+				co::MK::RBUTTON => (co::MOUSEEVENTF::RIGHTDOWN, co::MOUSEEVENTF::RIGHTUP, 0),
+				co::MK::MBUTTON => (co::MOUSEEVENTF::MIDDLEDOWN, co::MOUSEEVENTF::MIDDLEUP, 0),
+				co::MK::XBUTTON1 => (co::MOUSEEVENTF::XDOWN, co::MOUSEEVENTF::XUP, 0x0001), // XBUTTON1
+				co::MK::XBUTTON2 => (co::MOUSEEVENTF::XDOWN, co::MOUSEEVENTF::XUP, 0x0002), // XBUTTON2
+				_ => return Err(co::ERROR::INVALID_PARAMETER),
+			};
+			inputs.push(HwKbMouse::Mouse(MOUSEINPUT {
+				mouseData: mouse_data,
+				dwFlags: if down { flag_down } else { flag_up },
+				..Default::default()
+			}));
+		},
+		InputEvent::MouseWheel(delta) => {
+			inputs.push(HwKbMouse::Mouse(MOUSEINPUT {
+				mouseData: delta as _,
+				dwFlags: co::MOUSEEVENTF::WHEEL,
+				..Default::default()
+			}));
+		},
+	}
+	Ok(())
+}
+
+fn push_keystroke(vk: co::VK, inputs: &mut Vec<HwKbMouse>) {
+	inputs.push(key_input(vk, false));
+	inputs.push(key_input(vk, true));
+}
+
+fn key_input(vk: co::VK, up: bool) -> HwKbMouse {
+	let mut flags = if up { co::KEYEVENTF::KEYUP } else { co::KEYEVENTF::NoValue };
+	if EXTENDED_KEYS.contains(&vk) {
+		flags |= co::KEYEVENTF::EXTENDEDKEY;
+	}
+	HwKbMouse::Kb(KEYBDINPUT {
+		wVk: vk,
+		wScan: unsafe { ffi::MapVirtualKeyW(vk.raw() as _, co::MAPVK::VK_TO_VSC.raw()) } as _,
+		dwFlags: flags,
+		..Default::default()
+	})
+}
+
+fn unicode_input(unit: u16, up: bool) -> HwKbMouse {
+	HwKbMouse::Kb(KEYBDINPUT {
+		wScan: unit,
+		dwFlags: if up {
+			co::KEYEVENTF::UNICODE | co::KEYEVENTF::KEYUP
+		} else {
+			co::KEYEVENTF::UNICODE
+		},
+		..Default::default()
+	})
+}
+
+/// Converts a virtual screen coordinate, in pixels, to the `0..=65535` range
+/// expected by [`MOUSEEVENTF::ABSOLUTE`](crate::co::MOUSEEVENTF::ABSOLUTE).
+fn normalize(coord: i32, origin: co::SM, extent: co::SM) -> i32 {
+	let origin = GetSystemMetrics(origin);
+	let extent = GetSystemMetrics(extent);
+	((coord - origin) as i64 * 65535 / (extent - 1).max(1) as i64) as _
+}
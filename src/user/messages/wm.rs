@@ -154,6 +154,42 @@ pub_struct_msg_char_code! { Char: co::WM::CHAR;
 	/// [`WM_CHAR`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-char)
 }
 
+/// [`WM_CHARTOITEM`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-chartoitem)
+/// message parameters.
+///
+/// Return type: `i32`.
+pub struct CharToItem {
+	pub char_code: u16,
+	pub current_pos: u16,
+	pub hwnd_list_box: HWND,
+}
+
+unsafe impl MsgSend for CharToItem {
+	type RetType = i32;
+
+	fn convert_ret(&self, v: isize) -> Self::RetType {
+		v as _
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::CHARTOITEM,
+			wparam: MAKEDWORD(self.char_code, self.current_pos) as _,
+			lparam: self.hwnd_list_box.ptr() as _,
+		}
+	}
+}
+
+unsafe impl MsgSendRecv for CharToItem {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			char_code: LOWORD(p.wparam as _),
+			current_pos: HIWORD(p.wparam as _),
+			hwnd_list_box: unsafe { HWND::from_ptr(p.lparam as _) },
+		}
+	}
+}
+
 pub_struct_msg_empty_handleable! { ChildActivate: co::WM::CHILDACTIVATE;
 	/// [`WM_CHILDACTIVATE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-childactivate)
 }
@@ -341,6 +377,24 @@ unsafe impl<'a> MsgSend for DeviceChange<'a> {
 	}
 }
 
+#[cfg(feature = "advapi")]
+impl<'a> DeviceChange<'a> {
+	/// Returns the typed device notification content, if any.
+	///
+	/// **Note:** To use this method, enable the `advapi`
+	/// [Cargo feature](https://doc.rust-lang.org/cargo/reference/features.html#the-features-section).
+	///
+	/// # Safety
+	///
+	/// The header must be the beginning of a complete struct of the type given
+	/// by its `dbch_devicetype`, which is the case for messages received in a
+	/// window procedure.
+	#[must_use]
+	pub unsafe fn device_event(&self) -> Option<SvcCtlDeviceEvent<'a>> {
+		self.data.map(|hdr| SvcCtlDeviceEvent::from_raw(hdr))
+	}
+}
+
 unsafe impl<'a> MsgSendRecv for DeviceChange<'a> {
 	fn from_generic_wm(p: WndMsg) -> Self {
 		Self {
@@ -354,6 +408,40 @@ unsafe impl<'a> MsgSendRecv for DeviceChange<'a> {
 	}
 }
 
+/// [`WM_DPICHANGED`](https://learn.microsoft.com/en-us/windows/win32/hidpi/wm-dpichanged)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct DpiChanged {
+	pub new_dpi: Dpi,
+	pub suggested_rect: RECT,
+}
+
+unsafe impl MsgSend for DpiChanged {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::DPICHANGED,
+			wparam: MAKEDWORD(self.new_dpi.0 as _, self.new_dpi.0 as _) as _,
+			lparam: &mut self.suggested_rect as *mut _ as _,
+		}
+	}
+}
+
+unsafe impl MsgSendRecv for DpiChanged {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			new_dpi: Dpi(LOWORD(p.wparam as _) as _), // X and Y are always the same
+			suggested_rect: unsafe { *(p.lparam as *const RECT) },
+		}
+	}
+}
+
 /// [`WM_ENABLE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-enable)
 /// message parameters.
 ///
@@ -807,6 +895,42 @@ unsafe impl<'a> MsgSendRecv for Help<'a> {
 	}
 }
 
+/// [`WM_HOTKEY`](https://learn.microsoft.com/en-us/windows/win32/inputdev/wm-hotkey)
+/// message parameters.
+///
+/// Return type: `()`.
+pub struct HotKey {
+	pub id: i32,
+	pub modifiers: co::MOD,
+	pub vkey_code: co::VK,
+}
+
+unsafe impl MsgSend for HotKey {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::HOTKEY,
+			wparam: self.id as _,
+			lparam: MAKEDWORD(self.modifiers.raw(), self.vkey_code.raw()) as _,
+		}
+	}
+}
+
+unsafe impl MsgSendRecv for HotKey {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			id: p.wparam as _,
+			modifiers: unsafe { co::MOD::from_raw(LOWORD(p.lparam as _)) },
+			vkey_code: unsafe { co::VK::from_raw(HIWORD(p.lparam as _)) },
+		}
+	}
+}
+
 /// [`WM_HSCROLL`](https://learn.microsoft.com/en-us/windows/win32/controls/wm-hscroll)
 /// message parameters.
 ///
@@ -1579,6 +1703,37 @@ unsafe impl MsgSendRecv for SetFocus {
 /// message parameters.
 ///
 /// Return type: `Option<HICON>`.
+///
+/// The window does not take ownership of `hicon`, it only keeps the handle.
+/// So the icon must stay alive while the window uses it – that is, until it's
+/// replaced by another icon or the window is destroyed –, and only then it can
+/// be destroyed by its owner. Likewise, the returned previous icon is still
+/// owned by whoever set it.
+///
+/// Icons loaded with [`co::LR::SHARED`](crate::co::LR::SHARED) are owned by
+/// the system, and must never be destroyed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co, msg};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// let hicon: w::guard::DestroyIconGuard; // loaded somewhere, not shared
+/// # let hwnd = w::HWND::NULL;
+/// # let hicon = unsafe { w::guard::DestroyIconGuard::new(w::HICON::NULL) };
+///
+/// unsafe {
+///     hwnd.SendMessage(msg::wm::SetIcon {
+///         size: co::ICON_SZ::SMALL,
+///         hicon: hicon.raw_copy(), // the guard keeps the ownership
+///     });
+/// }
+///
+/// // ... the window is used, then destroyed ...
+///
+/// drop(hicon); // only now the icon can be destroyed
+/// ```
 pub struct SetIcon {
 	pub size: co::ICON_SZ,
 	pub hicon: HICON,
@@ -1665,6 +1820,66 @@ unsafe impl MsgSendRecv for SetText {
 	}
 }
 
+/// [`WM_SETTINGCHANGE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-settingchange)
+/// message parameters.
+///
+/// Return type: `()`.
+///
+/// # Examples
+///
+/// Notifying all top-level windows that the environment variables have
+/// changed, without hanging if any of them is not responding:
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co, msg};
+///
+/// let section = w::WString::from_str("Environment");
+///
+/// unsafe {
+///     w::HWND::BROADCAST.SendMessageTimeout(
+///         msg::wm::SettingChange {
+///             action: None,
+///             section: section.as_ptr(),
+///         },
+///         co::SMTO::ABORTIFHUNG,
+///         5000,
+///     )?;
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+pub struct SettingChange {
+	pub action: Option<co::SPI>,
+	pub section: *const u16, // can't be WString because this message can be received
+}
+
+unsafe impl MsgSend for SettingChange {
+	type RetType = ();
+
+	fn convert_ret(&self, _: isize) -> Self::RetType {
+		()
+	}
+
+	fn as_generic_wm(&mut self) -> WndMsg {
+		WndMsg {
+			msg_id: co::WM::SETTINGCHANGE,
+			wparam: self.action.map_or(0, |a| a.raw()) as _,
+			lparam: self.section as _,
+		}
+	}
+}
+
+unsafe impl MsgSendRecv for SettingChange {
+	fn from_generic_wm(p: WndMsg) -> Self {
+		Self {
+			action: match p.wparam {
+				0 => None,
+				a => Some(unsafe { co::SPI::from_raw(a as _) }),
+			},
+			section: p.lparam as _,
+		}
+	}
+}
+
 /// [`WM_SHOWWINDOW`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-showwindow)
 /// message parameters.
 ///
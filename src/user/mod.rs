@@ -7,13 +7,18 @@ mod handles;
 mod msg_traits;
 mod proc;
 mod structs;
+mod utilities;
 
 pub(in crate::user) mod ffi;
 pub(in crate::user) mod iterators;
 pub(crate) mod privs;
+pub mod clipboard;
 pub mod co;
 pub mod guard;
+pub mod hook;
+pub mod input;
 pub mod messages;
+pub mod spi;
 
 pub mod decl {
 	pub use super::aliases::*;
@@ -21,6 +26,7 @@ pub mod decl {
 	pub use super::funcs::*;
 	pub use super::handles::decl::*;
 	pub use super::structs::*;
+	pub use super::utilities::*;
 }
 
 pub mod traits {
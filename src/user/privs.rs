@@ -2,6 +2,8 @@
 
 use crate::co;
 use crate::decl::*;
+use crate::kernel::{ffi_types::*, privs::*};
+use crate::prelude::*;
 
 pub(crate) const ASFW_ANY: u32 = -1i32 as _;
 pub(crate) const CB_ERR: i32 = -1;
@@ -14,6 +16,8 @@ pub(crate) const FAPPCOMMAND_MASK: u16 = 0xf000;
 pub(crate) const HWND_MESSAGE: isize = -3;
 pub(crate) const LB_ERR: i32 = -1;
 pub(crate) const LB_ERRSPACE: i32 = -2;
+pub(crate) const LOGPIXELSX: i32 = 88;
+pub(crate) const USER_DEFAULT_SCREEN_DPI: u32 = 96;
 pub(crate) const WC_DIALOG: u16 = 0x8002;
 
 /// Takes an `isize` and returns `Err` if `-1`.
@@ -51,3 +55,27 @@ pub(crate) const fn zero_as_none(v: isize) -> Option<isize> {
 		v => Some(v),
 	}
 }
+
+/// Retrieves the system DPI with `GetDeviceCaps`, which is loaded at runtime
+/// so the `gdi` feature is not required. Used as a fallback in Windows versions
+/// prior to 10.
+#[must_use]
+pub(crate) fn legacy_system_dpi() -> u32 {
+	HWND::NULL.GetDC().ok()
+		.and_then(|hdc| unsafe {
+			call_dyn("gdi32", "GetDeviceCaps",
+				|f: extern "system" fn(HANDLE, i32) -> i32| f(hdc.ptr(), LOGPIXELSX))
+		})
+		.filter(|dpi| *dpi > 0)
+		.map(|dpi| dpi as _)
+		.unwrap_or(USER_DEFAULT_SCREEN_DPI)
+}
+
+/// Converts an `HRESULT` returned by the shcore functions, whose errors are
+/// always Win32 ones.
+pub(crate) fn shcore_hr_to_sysresult(hr: i32) -> SysResult<()> {
+	match hr {
+		0 => Ok(()),
+		hr => Err(unsafe { co::ERROR::from_raw(hr as u32 & 0xffff) }),
+	}
+}
@@ -0,0 +1,239 @@
+#![allow(non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::kernel::{ffi_types::*, privs::*};
+use crate::user::ffi;
+
+/// [`ANIMATIONINFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-animationinfo)
+/// struct.
+#[repr(C)]
+struct ANIMATIONINFO {
+	cbSize: u32,
+	iMinAnimate: i32,
+}
+
+impl_default_with_size!(ANIMATIONINFO, cbSize);
+
+/// Calls `SystemParametersInfo` for actions which take the value in
+/// `ui_param`, with a null `pv_param`. The setting is written to the user
+/// profile, and `WM_SETTINGCHANGE` is broadcast.
+fn set_by_ui_param(action: co::SPI, ui_param: u32) -> SysResult<()> {
+	bool_to_sysresult(
+		unsafe {
+			ffi::SystemParametersInfoW(action.raw(), ui_param,
+				std::ptr::null_mut(),
+				(co::SPIF::UPDATEINIFILE | co::SPIF::SENDCHANGE).raw())
+		},
+	)
+}
+
+/// Calls `SystemParametersInfo` for actions which return a `BOOL`.
+fn get_bool(action: co::SPI) -> SysResult<bool> {
+	let mut val = BOOL::default();
+	unsafe {
+		SystemParametersInfo(action, 0, &mut val, co::SPIF::NoValue)
+	}.map(|_| val != 0)
+}
+
+/// Returns whether minimize and restore animations are enabled, with
+/// [`SPI::GETANIMATION`](crate::co::SPI::GETANIMATION).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe as w;
+///
+/// if w::spi::animation_enabled()? {
+///     // animate custom transitions
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn animation_enabled() -> SysResult<bool> {
+	let mut ai = ANIMATIONINFO::default();
+	unsafe {
+		SystemParametersInfo(co::SPI::GETANIMATION,
+			std::mem::size_of::<ANIMATIONINFO>() as _, &mut ai, co::SPIF::NoValue)
+	}.map(|_| ai.iMinAnimate != 0)
+}
+
+/// Returns whether dragging of full windows is enabled, with
+/// [`SPI::GETDRAGFULLWINDOWS`](crate::co::SPI::GETDRAGFULLWINDOWS).
+#[must_use]
+pub fn drag_full_windows() -> SysResult<bool> {
+	get_bool(co::SPI::GETDRAGFULLWINDOWS)
+}
+
+/// Retrieves the high contrast accessibility settings, with
+/// [`SPI::GETHIGHCONTRAST`](crate::co::SPI::GETHIGHCONTRAST).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hc = w::spi::high_contrast()?;
+/// if hc.dwFlags.has(co::HCF::HIGHCONTRASTON) {
+///     println!("Scheme: {}", hc.lpszDefaultScheme().unwrap_or_default());
+/// }
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn high_contrast() -> SysResult<HIGHCONTRAST<'static>> {
+	let mut hc = HIGHCONTRAST::default();
+	unsafe {
+		SystemParametersInfo(co::SPI::GETHIGHCONTRAST,
+			std::mem::size_of::<HIGHCONTRAST>() as _, &mut hc, co::SPIF::NoValue)
+	}.map(|_| hc)
+}
+
+/// Retrieves the keyboard repeat delay, from 0 (approximately 250 ms) to 3
+/// (approximately 1 second), and the keyboard repeat speed, from 0
+/// (approximately 2.5 repetitions per second) to 31 (approximately 30
+/// repetitions per second), with
+/// [`SPI::GETKEYBOARDDELAY`](crate::co::SPI::GETKEYBOARDDELAY) and
+/// [`SPI::GETKEYBOARDSPEED`](crate::co::SPI::GETKEYBOARDSPEED).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe as w;
+///
+/// let (delay, speed) = w::spi::keyboard_delay_and_speed()?;
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn keyboard_delay_and_speed() -> SysResult<(u32, u32)> {
+	let (mut delay, mut speed) = (0u32, 0u32);
+	unsafe {
+		SystemParametersInfo(co::SPI::GETKEYBOARDDELAY, 0, &mut delay,
+			co::SPIF::NoValue)?;
+		SystemParametersInfo(co::SPI::GETKEYBOARDSPEED, 0, &mut speed,
+			co::SPIF::NoValue)?;
+	}
+	Ok((delay, speed))
+}
+
+/// Returns whether pop-up menus are right-aligned to their menu bar items,
+/// with [`SPI::GETMENUDROPALIGNMENT`](crate::co::SPI::GETMENUDROPALIGNMENT).
+#[must_use]
+pub fn menu_drop_alignment() -> SysResult<bool> {
+	get_bool(co::SPI::GETMENUDROPALIGNMENT)
+}
+
+/// Returns whether the screen saver is enabled, with
+/// [`SPI::GETSCREENSAVEACTIVE`](crate::co::SPI::GETSCREENSAVEACTIVE).
+#[must_use]
+pub fn screen_saver_active() -> SysResult<bool> {
+	get_bool(co::SPI::GETSCREENSAVEACTIVE)
+}
+
+/// Enables or disables minimize and restore animations, with
+/// [`SPI::SETANIMATION`](crate::co::SPI::SETANIMATION).
+///
+/// The setting is written to the user profile, and
+/// [`WM_SETTINGCHANGE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-settingchange)
+/// is broadcast.
+pub fn set_animation_enabled(enabled: bool) -> SysResult<()> {
+	let mut ai = ANIMATIONINFO::default();
+	ai.iMinAnimate = enabled as _;
+	unsafe {
+		SystemParametersInfo(co::SPI::SETANIMATION,
+			std::mem::size_of::<ANIMATIONINFO>() as _, &mut ai,
+			co::SPIF::UPDATEINIFILE | co::SPIF::SENDCHANGE)
+	}
+}
+
+/// Enables or disables dragging of full windows, with
+/// [`SPI::SETDRAGFULLWINDOWS`](crate::co::SPI::SETDRAGFULLWINDOWS).
+///
+/// The setting is written to the user profile, and
+/// [`WM_SETTINGCHANGE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-settingchange)
+/// is broadcast.
+pub fn set_drag_full_windows(enabled: bool) -> SysResult<()> {
+	set_by_ui_param(co::SPI::SETDRAGFULLWINDOWS, enabled as _)
+}
+
+/// Sets the keyboard repeat delay and speed, with
+/// [`SPI::SETKEYBOARDDELAY`](crate::co::SPI::SETKEYBOARDDELAY) and
+/// [`SPI::SETKEYBOARDSPEED`](crate::co::SPI::SETKEYBOARDSPEED). See
+/// [`keyboard_delay_and_speed`](crate::spi::keyboard_delay_and_speed) for the
+/// ranges.
+///
+/// The settings are written to the user profile, and
+/// [`WM_SETTINGCHANGE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-settingchange)
+/// is broadcast.
+pub fn set_keyboard_delay_and_speed(delay: u32, speed: u32) -> SysResult<()> {
+	set_by_ui_param(co::SPI::SETKEYBOARDDELAY, delay)?;
+	set_by_ui_param(co::SPI::SETKEYBOARDSPEED, speed)
+}
+
+/// Enables or disables the screen saver, with
+/// [`SPI::SETSCREENSAVEACTIVE`](crate::co::SPI::SETSCREENSAVEACTIVE).
+///
+/// The setting is written to the user profile, and
+/// [`WM_SETTINGCHANGE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-settingchange)
+/// is broadcast.
+pub fn set_screen_saver_active(active: bool) -> SysResult<()> {
+	set_by_ui_param(co::SPI::SETSCREENSAVEACTIVE, active as _)
+}
+
+/// Sets the number of lines to scroll when the mouse wheel is rotated, with
+/// [`SPI::SETWHEELSCROLLLINES`](crate::co::SPI::SETWHEELSCROLLLINES).
+///
+/// The setting is written to the user profile, and
+/// [`WM_SETTINGCHANGE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-settingchange)
+/// is broadcast.
+pub fn set_wheel_scroll_lines(lines: u32) -> SysResult<()> {
+	set_by_ui_param(co::SPI::SETWHEELSCROLLLINES, lines)
+}
+
+/// Sets the work area of the primary monitor, with
+/// [`SPI::SETWORKAREA`](crate::co::SPI::SETWORKAREA).
+///
+/// The work area is not stored in the user profile, but
+/// [`WM_SETTINGCHANGE`](https://learn.microsoft.com/en-us/windows/win32/winmsg/wm-settingchange)
+/// is broadcast.
+pub fn set_work_area(rc: &RECT) -> SysResult<()> {
+	let mut rc = *rc;
+	unsafe {
+		SystemParametersInfo(co::SPI::SETWORKAREA, 0, &mut rc,
+			co::SPIF::SENDCHANGE)
+	}
+}
+
+/// Returns the number of lines to scroll when the mouse wheel is rotated, with
+/// [`SPI::GETWHEELSCROLLLINES`](crate::co::SPI::GETWHEELSCROLLLINES).
+///
+/// A value of `u32::MAX` means that a whole page should be scrolled.
+#[must_use]
+pub fn wheel_scroll_lines() -> SysResult<u32> {
+	let mut lines = 0u32;
+	unsafe {
+		SystemParametersInfo(co::SPI::GETWHEELSCROLLLINES, 0, &mut lines,
+			co::SPIF::NoValue)
+	}.map(|_| lines)
+}
+
+/// Retrieves the work area of the primary monitor – the portion of the screen
+/// not obscured by the taskbar or desktop toolbars – with
+/// [`SPI::GETWORKAREA`](crate::co::SPI::GETWORKAREA).
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe as w;
+///
+/// let rc = w::spi::work_area()?;
+/// println!("{}x{}", rc.right - rc.left, rc.bottom - rc.top);
+/// # w::SysResult::Ok(())
+/// ```
+#[must_use]
+pub fn work_area() -> SysResult<RECT> {
+	let mut rc = RECT::default();
+	unsafe {
+		SystemParametersInfo(co::SPI::GETWORKAREA, 0, &mut rc,
+			co::SPIF::NoValue)
+	}.map(|_| rc)
+}
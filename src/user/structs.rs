@@ -18,6 +18,138 @@ pub struct ACCEL {
 	pub cmd: u16,
 }
 
+impl ACCEL {
+	/// Finds the entries whose key combination – the `key` and the
+	/// `VIRTKEY`, `SHIFT`, `CONTROL` and `ALT` flags of `fVirt` – was already
+	/// used by a previous entry. Returns the `(previous, duplicated)` index
+	/// pairs.
+	///
+	/// When an accelerator table has duplicated combinations, only the first
+	/// entry is ever triggered, so
+	/// [`HACCEL::CreateAcceleratorTable`](crate::prelude::user_Haccel::CreateAcceleratorTable)
+	/// refuses them.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let accels = [
+	///     w::ACCEL::parse("Ctrl+S", 1001).unwrap(),
+	///     w::ACCEL::parse("Ctrl+O", 1002).unwrap(),
+	///     w::ACCEL::parse("ctrl+s", 1003).unwrap(), // dead
+	/// ];
+	///
+	/// for (prev, dup) in w::ACCEL::duplicates(&accels) {
+	///     println!("Entry {} is shadowed by entry {}", dup, prev);
+	/// }
+	/// ```
+	#[must_use]
+	pub fn duplicates(accel: &[ACCEL]) -> Vec<(usize, usize)> {
+		let mask = co::ACCELF::VIRTKEY | co::ACCELF::SHIFT
+			| co::ACCELF::CONTROL | co::ACCELF::ALT;
+		let combo = |a: &ACCEL| (a.fVirt & mask, a.key);
+
+		accel.iter().enumerate()
+			.filter_map(|(i, a)| {
+				accel[..i].iter()
+					.position(|prev| combo(prev) == combo(a))
+					.map(|prev| (prev, i))
+			})
+			.collect()
+	}
+
+	/// Parses a textual shortcut, like `"Ctrl+Shift+S"`, into an `ACCEL` which
+	/// sends the given command ID. Returns `None` if the text cannot be parsed.
+	///
+	/// The text is made of zero or more modifiers – `Ctrl`, `Shift` and `Alt`
+	/// – followed by a key, all separated by `+` and case-insensitive. The key
+	/// can be a letter, a digit, a function key from `F1` to `F24`, or one of:
+	/// `Backspace`, `Tab`, `Enter`, `Esc`, `Space`, `PgUp`, `PgDn`, `End`,
+	/// `Home`, `Left`, `Up`, `Right`, `Down`, `Ins`, `Del`, `Pause`, and the
+	/// numeric keypad `Num0` to `Num9`.
+	///
+	/// The resulting `fVirt` always includes
+	/// [`ACCELF::VIRTKEY`](crate::co::ACCELF::VIRTKEY).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// const ID_SAVE_AS: u16 = 1001;
+	///
+	/// let accel = w::ACCEL::parse("Ctrl+Shift+S", ID_SAVE_AS).unwrap();
+	/// assert_eq!(accel.key, co::VK::CHAR_S);
+	/// ```
+	#[must_use]
+	pub fn parse(shortcut: &str, cmd: u16) -> Option<ACCEL> {
+		let mut tokens = shortcut.split('+').map(|t| t.trim()).collect::<Vec<_>>();
+		let key_name = tokens.pop()?;
+
+		let mut fVirt = co::ACCELF::VIRTKEY;
+		for modifier in tokens.iter() {
+			fVirt |= match modifier.to_ascii_lowercase().as_str() {
+				"ctrl" | "control" => co::ACCELF::CONTROL,
+				"shift" => co::ACCELF::SHIFT,
+				"alt" => co::ACCELF::ALT,
+				_ => return None,
+			};
+		}
+
+		Some(Self { fVirt, key: vk_from_key_name(key_name)?, cmd })
+	}
+}
+
+/// Converts a key name, as accepted by
+/// [`ACCEL::parse`](crate::ACCEL::parse), into a virtual key code.
+#[must_use]
+fn vk_from_key_name(key_name: &str) -> Option<co::VK> {
+	let name = key_name.to_ascii_uppercase();
+	let mut chars = name.chars();
+	match (chars.next(), chars.next()) {
+		(Some(ch), None) if ch.is_ascii_alphanumeric() => // letters and digits share their ASCII codes
+			return Some(unsafe { co::VK::from_raw(ch as _) }),
+		_ => {},
+	}
+
+	if let Some(num) = name.strip_prefix('F').and_then(|n| n.parse::<u16>().ok()) {
+		return if (1..=24).contains(&num) {
+			Some(unsafe { co::VK::from_raw(co::VK::F1.raw() + num - 1) })
+		} else {
+			None
+		};
+	}
+
+	if let Some(num) = name.strip_prefix("NUM").and_then(|n| n.parse::<u16>().ok()) {
+		return if num <= 9 {
+			Some(unsafe { co::VK::from_raw(co::VK::NUMPAD0.raw() + num) })
+		} else {
+			None
+		};
+	}
+
+	Some(match name.as_str() {
+		"BACKSPACE" | "BACK" => co::VK::BACK,
+		"TAB" => co::VK::TAB,
+		"ENTER" | "RETURN" => co::VK::RETURN,
+		"ESC" | "ESCAPE" => co::VK::ESCAPE,
+		"SPACE" => co::VK::SPACE,
+		"PGUP" | "PAGEUP" => co::VK::PRIOR,
+		"PGDN" | "PAGEDOWN" => co::VK::NEXT,
+		"END" => co::VK::END,
+		"HOME" => co::VK::HOME,
+		"LEFT" => co::VK::LEFT,
+		"UP" => co::VK::UP,
+		"RIGHT" => co::VK::RIGHT,
+		"DOWN" => co::VK::DOWN,
+		"INS" | "INSERT" => co::VK::INSERT,
+		"DEL" | "DELETE" => co::VK::DELETE,
+		"PAUSE" => co::VK::PAUSE,
+		_ => return None,
+	})
+}
+
 /// [`ALTTABINFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-alttabinfo)
 /// struct.
 #[repr(C)]
@@ -84,6 +216,22 @@ impl Default for BLENDFUNCTION {
 	}
 }
 
+impl BLENDFUNCTION {
+	/// Creates a `BLENDFUNCTION` which blends a bitmap with per-pixel alpha –
+	/// whose color channels must be premultiplied by the alpha – applying
+	/// `source_constant_alpha` on top of it. Pass `255` to use only the
+	/// per-pixel alpha.
+	#[must_use]
+	pub const fn new_alpha(source_constant_alpha: u8) -> Self {
+		Self {
+			BlendOp: co::AC_SRC::OVER,
+			BlendFlags: 0,
+			SourceConstantAlpha: source_constant_alpha,
+			AlphaFormat: co::AC_SRC::ALPHA,
+		}
+	}
+}
+
 /// [`CHOOSECOLOR`](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/ns-commdlg-choosecolorw-r1)
 /// struct.
 #[repr(C)]
@@ -563,6 +711,19 @@ pub struct MSG {
 
 impl_default!(MSG);
 
+/// [`FLASHWINFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-flashwinfo)
+/// struct.
+#[repr(C)]
+pub struct FLASHWINFO {
+	cbSize: u32,
+	pub hwnd: HWND,
+	pub dwFlags: co::FLASHW,
+	pub uCount: u32,
+	pub dwTimeout: u32,
+}
+
+impl_default_with_size!(FLASHWINFO, cbSize);
+
 /// [`GUITHREADINFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-guithreadinfo)
 /// struct.
 #[repr(C)]
@@ -619,6 +780,23 @@ impl HELPINFO {
 	}
 }
 
+/// [`HIGHCONTRAST`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-highcontrastw)
+/// struct.
+#[repr(C)]
+pub struct HIGHCONTRAST<'a> {
+	cbSize: u32,
+	pub dwFlags: co::HCF,
+	lpszDefaultScheme: *mut u16,
+
+	_lpszDefaultScheme: PhantomData<&'a mut u16>,
+}
+
+impl_default_with_size!(HIGHCONTRAST, cbSize, 'a);
+
+impl<'a> HIGHCONTRAST<'a> {
+	pub_fn_string_ptr_get_set!('a, lpszDefaultScheme, set_lpszDefaultScheme);
+}
+
 /// [`ICONINFO`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/nf-winuser-geticoninfo)
 /// struct.
 #[repr(C)]
@@ -716,6 +894,31 @@ impl INPUT {
 	}
 }
 
+/// [`KBDLLHOOKSTRUCT`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-kbdllhookstruct)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct KBDLLHOOKSTRUCT {
+	vkCode: u32,
+	pub scanCode: u32,
+	pub flags: co::LLKHF,
+	pub time: u32,
+	pub dwExtraInfo: usize,
+}
+
+impl KBDLLHOOKSTRUCT {
+	/// Returns the `vkCode` field.
+	#[must_use]
+	pub const fn vkCode(&self) -> co::VK {
+		unsafe { co::VK::from_raw(self.vkCode as _) }
+	}
+
+	/// Sets the `vkCode` field.
+	pub fn set_vkCode(&mut self, val: co::VK) {
+		self.vkCode = val.raw() as _;
+	}
+}
+
 /// [`KEYBDINPUT`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-keybdinput)
 /// struct.
 #[repr(C)]
@@ -852,6 +1055,18 @@ pub struct MOUSEINPUT {
 	pub dwExtraInfo: usize,
 }
 
+/// [`MSLLHOOKSTRUCT`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-msllhookstruct)
+/// struct.
+#[repr(C)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+pub struct MSLLHOOKSTRUCT {
+	pub pt: POINT,
+	pub mouseData: u32,
+	pub flags: co::LLMHF,
+	pub time: u32,
+	pub dwExtraInfo: usize,
+}
+
 /// [`NCCALCSIZE_PARAMS`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-nccalcsize_params)
 /// struct.
 #[repr(C)]
@@ -868,6 +1083,82 @@ impl<'a> NCCALCSIZE_PARAMS<'a> {
 	pub_fn_ptr_get_set!('a, lppos, set_lppos, WINDOWPOS);
 }
 
+/// [`OPENFILENAME`](https://learn.microsoft.com/en-us/windows/win32/api/commdlg/ns-commdlg-openfilenamew)
+/// struct.
+///
+/// The `lpstrFilter` string must be a sequence of description and pattern
+/// pairs, which can be built with
+/// [`WString::from_str_vec`](crate::WString::from_str_vec).
+#[repr(C)]
+pub struct OPENFILENAME<'a> {
+	lStructSize: u32,
+	pub hwndOwner: HWND,
+	pub hInstance: HINSTANCE,
+	lpstrFilter: *mut u16,
+	lpstrCustomFilter: *mut u16,
+	nMaxCustFilter: u32,
+	pub nFilterIndex: u32,
+	lpstrFile: *mut u16,
+	nMaxFile: u32,
+	lpstrFileTitle: *mut u16,
+	nMaxFileTitle: u32,
+	lpstrInitialDir: *mut u16,
+	lpstrTitle: *mut u16,
+	pub Flags: co::OFN,
+	pub nFileOffset: u16,
+	pub nFileExtension: u16,
+	lpstrDefExt: *mut u16,
+	pub lCustData: isize,
+	pub lpfnHook: Option<OFNHOOKPROC>,
+	lpTemplateName: *mut u16, // u16 resource ID
+	pvReserved: *mut std::ffi::c_void,
+	dwReserved: u32,
+	pub FlagsEx: co::OFN_EX,
+
+	_lpstrFilter: PhantomData<&'a mut u16>,
+}
+
+impl_default_with_size!(OPENFILENAME, lStructSize, 'a);
+
+impl<'a> OPENFILENAME<'a> {
+	pub_fn_string_ptr_get_set!('a, lpstrFilter, set_lpstrFilter);
+	pub_fn_string_buf_get_set!('a, lpstrCustomFilter, set_lpstrCustomFilter, raw_lpstrCustomFilter, nMaxCustFilter);
+	pub_fn_string_buf_get_set!('a, lpstrFile, set_lpstrFile, raw_lpstrFile, nMaxFile);
+	pub_fn_string_buf_get_set!('a, lpstrFileTitle, set_lpstrFileTitle, raw_lpstrFileTitle, nMaxFileTitle);
+	pub_fn_string_ptr_get_set!('a, lpstrInitialDir, set_lpstrInitialDir);
+	pub_fn_string_ptr_get_set!('a, lpstrTitle, set_lpstrTitle);
+	pub_fn_string_ptr_get_set!('a, lpstrDefExt, set_lpstrDefExt);
+	pub_fn_resource_id_get_set!(lpTemplateName, set_lpTemplateName);
+
+	/// Returns the full paths of the files chosen by the user, parsed from the
+	/// `lpstrFile` buffer.
+	///
+	/// When [`OFN::ALLOWMULTISELECT`](crate::co::OFN::ALLOWMULTISELECT) is
+	/// set, [`OFN::EXPLORER`](crate::co::OFN::EXPLORER) must also be set, so
+	/// the buffer contains the directory followed by the null-delimited file
+	/// names. The buffer must have been zeroed before the call, which is the
+	/// case of [`WString::new_alloc_buf`](crate::WString::new_alloc_buf).
+	///
+	/// The buffer is never read beyond `nMaxFile` chars.
+	#[must_use]
+	pub fn selected_files(&self) -> Vec<String> {
+		if self.lpstrFile.is_null() {
+			return Vec::new();
+		}
+		let buf = unsafe {
+			std::slice::from_raw_parts(self.lpstrFile, self.nMaxFile as _)
+		};
+		let mut strs = parse_multi_z_slice(buf);
+		if strs.len() <= 1 {
+			return strs; // single file, full path
+		}
+		let dir = strs.remove(0);
+		strs.iter()
+			.map(|name| format!("{}\\{}", dir.trim_end_matches('\\'), name))
+			.collect()
+	}
+}
+
 /// [`PAINTSTRUCT`](https://learn.microsoft.com/en-us/windows/win32/api/winuser/ns-winuser-paintstruct)
 /// struct.
 #[repr(C)]
@@ -1145,3 +1436,24 @@ impl<'a> WNDCLASSEX<'a> {
 	pub_fn_resource_id_get_set!(lpszMenuName, set_lpszMenuName);
 	pub_fn_string_ptr_get_set!('a, lpszClassName, set_lpszClassName);
 }
+
+/// [`WTS_SESSION_INFO`](https://learn.microsoft.com/en-us/windows/win32/api/wtsapi32/ns-wtsapi32-wts_session_infow)
+/// struct.
+///
+/// Returned by [`WTSEnumerateSessions`](crate::WTSEnumerateSessions).
+#[repr(C)]
+pub struct WTS_SESSION_INFO {
+	pub SessionId: u32,
+	pWinStationName: *mut u16,
+	pub State: co::WTS_CONNECTSTATE_CLASS,
+}
+
+impl WTS_SESSION_INFO {
+	/// Returns the `pWinStationName` field.
+	#[must_use]
+	pub fn pWinStationName(&self) -> Option<String> {
+		unsafe { self.pWinStationName.as_ref() }.map(|psz| {
+			unsafe { WString::from_wchars_nullt(psz) }.to_string()
+		})
+	}
+}
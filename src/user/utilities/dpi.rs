@@ -0,0 +1,76 @@
+use crate::decl::*;
+use crate::prelude::*;
+use crate::user::privs::*;
+
+/// A DPI value, used to scale coordinates designed at the default 96 DPI.
+///
+/// # Examples
+///
+/// ```no_run
+/// use winsafe::{self as w, prelude::*, co};
+///
+/// let hwnd: w::HWND; // initialized somewhere
+/// # let hwnd = w::HWND::NULL;
+///
+/// let dpi = w::Dpi::for_window(&hwnd);
+/// hwnd.SetWindowPos(
+///     w::HwndPlace::None,
+///     dpi.scale_point(w::POINT::new(10, 10)),
+///     dpi.scale_size(w::SIZE::new(300, 200)),
+///     co::SWP::NOZORDER,
+/// )?;
+/// # w::SysResult::Ok(())
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Dpi(pub u32);
+
+impl Default for Dpi {
+	fn default() -> Self {
+		Self(USER_DEFAULT_SCREEN_DPI)
+	}
+}
+
+impl Dpi {
+	/// Returns the DPI of the window with
+	/// [`HWND::GetDpiForWindow`](crate::prelude::user_Hwnd::GetDpiForWindow).
+	#[must_use]
+	pub fn for_window(hwnd: &HWND) -> Self {
+		Self(hwnd.GetDpiForWindow())
+	}
+
+	/// Returns the system DPI with
+	/// [`GetDpiForSystem`](crate::GetDpiForSystem).
+	#[must_use]
+	pub fn system() -> Self {
+		Self(GetDpiForSystem())
+	}
+
+	/// Scales a value designed at 96 DPI.
+	#[must_use]
+	pub fn scale(&self, px: i32) -> i32 {
+		MulDiv(px, self.0 as _, USER_DEFAULT_SCREEN_DPI as _)
+	}
+
+	/// Scales both coordinates of a point designed at 96 DPI.
+	#[must_use]
+	pub fn scale_point(&self, pt: POINT) -> POINT {
+		POINT::new(self.scale(pt.x), self.scale(pt.y))
+	}
+
+	/// Scales all coordinates of a rectangle designed at 96 DPI.
+	#[must_use]
+	pub fn scale_rect(&self, rc: RECT) -> RECT {
+		RECT {
+			left: self.scale(rc.left),
+			top: self.scale(rc.top),
+			right: self.scale(rc.right),
+			bottom: self.scale(rc.bottom),
+		}
+	}
+
+	/// Scales both dimensions of a size designed at 96 DPI.
+	#[must_use]
+	pub fn scale_size(&self, sz: SIZE) -> SIZE {
+		SIZE::new(self.scale(sz.cx), self.scale(sz.cy))
+	}
+}
@@ -0,0 +1,3 @@
+mod dpi;
+
+pub use dpi::Dpi;
@@ -2,6 +2,7 @@
 
 use crate::decl::*;
 use crate::guard::*;
+use crate::ole::privs::*;
 use crate::prelude::*;
 use crate::uxtheme::ffi;
 
@@ -28,4 +29,48 @@ pub trait uxtheme_Hwnd: ole_Hwnd {
 				.map(|ptr| CloseThemeDataGuard::new(HTHEME::from_ptr(ptr)))
 		}
 	}
+
+	/// [`SetWindowTheme`](https://learn.microsoft.com/en-us/windows/win32/api/uxtheme/nf-uxtheme-setwindowtheme)
+	/// function.
+	///
+	/// Passing `Some("")` to both `sub_app_name` and `sub_id_list` disables the
+	/// visual styles for the window.
+	///
+	/// # Examples
+	///
+	/// Applying the Explorer visual style to a list view, which gives it the
+	/// modern selection highlight:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hlist_view: w::HWND; // initialized somewhere
+	/// # let hlist_view = w::HWND::NULL;
+	///
+	/// hlist_view.SetWindowTheme(Some("Explorer"), None)?;
+	/// # w::HrResult::Ok(())
+	/// ```
+	fn SetWindowTheme(&self,
+		sub_app_name: Option<&str>,
+		sub_id_list: Option<&str>,
+	) -> HrResult<()>
+	{
+		// An empty WString is not allocated, and a null pointer has a
+		// different meaning here, so the buffers are built manually.
+		let to_wide = |s: Option<&str>| s.map(|s| s.encode_utf16()
+			.chain(std::iter::once(0))
+			.collect::<Vec<_>>());
+		let app_buf = to_wide(sub_app_name);
+		let id_buf = to_wide(sub_id_list);
+
+		ok_to_hrresult(
+			unsafe {
+				ffi::SetWindowTheme(
+					self.ptr(),
+					app_buf.as_ref().map_or(std::ptr::null(), |b| b.as_ptr()),
+					id_buf.as_ref().map_or(std::ptr::null(), |b| b.as_ptr()),
+				)
+			},
+		)
+	}
 }
@@ -0,0 +1,121 @@
+#![allow(non_camel_case_types, non_snake_case)]
+
+use crate::co;
+use crate::decl::*;
+use crate::prelude::*;
+
+impl version_Hupdatersrc for HUPDATERSRC {}
+
+/// This trait is enabled with the `version` feature, and provides methods for
+/// [`HUPDATERSRC`](crate::HUPDATERSRC).
+///
+/// Prefer importing this trait through the prelude:
+///
+/// ```no_run
+/// use winsafe::prelude::*;
+/// ```
+pub trait version_Hupdatersrc: kernel_Hupdatersrc {
+	/// Builds a
+	/// [`VS_VERSIONINFO`](https://learn.microsoft.com/en-us/windows/win32/menurc/vs-versioninfo)
+	/// block out of the given [`VersionInfo`](crate::VersionInfo), and writes
+	/// it as the [`RT::VERSION`](crate::co::RT::VERSION) resource with ID 1,
+	/// using
+	/// [`HUPDATERSRC::UpdateResource`](crate::prelude::kernel_Hupdatersrc::UpdateResource).
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*, co};
+	///
+	/// let mut vi = w::VersionInfo::default();
+	/// vi.fixed_file_info.set_dwFileVersion([1, 2, 0, 0]);
+	/// vi.fixed_file_info.set_dwProductVersion([1, 2, 0, 0]);
+	/// vi.fixed_file_info.dwFileType = co::VFT::APP;
+	/// vi.language = w::LANGID::new(co::LANG::ENGLISH, co::SUBLANG::ENGLISH_US);
+	/// vi.code_page = co::CP::UTF16;
+	/// vi.strings = vec![
+	///     ("FileDescription".to_owned(), "My app".to_owned()),
+	///     ("FileVersion".to_owned(), "1.2.0.0".to_owned()),
+	/// ];
+	///
+	/// let hupd = w::HUPDATERSRC::BeginUpdateResource("app.exe", false)?;
+	/// hupd.set_version_info(&vi)?;
+	/// hupd.commit()?;
+	/// # w::SysResult::Ok(())
+	/// ```
+	fn set_version_info(&self, vi: &VersionInfo) -> SysResult<()> {
+		let fixed = unsafe {
+			std::slice::from_raw_parts(
+				&vi.fixed_file_info as *const _ as *const u8,
+				std::mem::size_of::<VS_FIXEDFILEINFO>(),
+			)
+		};
+		let lang: u16 = vi.language.into();
+		let cp: u16 = vi.code_page.raw();
+
+		let strings = vi.strings.iter()
+			.map(|(key, val)| {
+				let val16 = WString::from_str(val);
+				let val_len = u16::try_from(val16.str_len() + 1)
+					.map_err(|_| co::ERROR::INVALID_DATA)?;
+				ver_block(key, &wstr_bytes(&val16), val_len, 1, &[])
+			})
+			.collect::<SysResult<Vec<_>>>()?;
+		let string_table = ver_block(&format!("{:04x}{:04x}", lang, cp),
+			&[], 0, 1, &strings)?;
+		let string_file_info = ver_block("StringFileInfo",
+			&[], 0, 1, &[string_table])?;
+
+		let mut translation = lang.to_le_bytes().to_vec();
+		translation.extend_from_slice(&cp.to_le_bytes());
+		let var = ver_block("Translation", &translation, 4, 0, &[])?;
+		let var_file_info = ver_block("VarFileInfo", &[], 0, 1, &[var])?;
+
+		let root = ver_block("VS_VERSION_INFO", fixed, fixed.len() as _, 0,
+			&[string_file_info, var_file_info])?;
+
+		self.UpdateResource(RtStr::Rt(co::RT::VERSION), IdStr::Id(1),
+			vi.language, Some(&root))
+	}
+}
+
+/// Builds a single version block: `wLength`, `wValueLength`, `wType`, the
+/// null-terminated key, the value and the children, each one aligned to 32
+/// bits. The trailing padding is not counted in `wLength`.
+///
+/// Fails with [`ERROR::INVALID_DATA`](crate::co::ERROR::INVALID_DATA) if the
+/// block doesn't fit the 16-bit `wLength`.
+fn ver_block(
+	key: &str,
+	value: &[u8],
+	value_len: u16,
+	ty: u16,
+	children: &[Vec<u8>],
+) -> SysResult<Vec<u8>>
+{
+	let pad32 = |buf: &mut Vec<u8>| while buf.len() % 4 != 0 { buf.push(0); };
+
+	let mut buf = vec![0u8; 6]; // wLength is written at the end
+	buf[2..4].copy_from_slice(&value_len.to_le_bytes());
+	buf[4..6].copy_from_slice(&ty.to_le_bytes());
+	buf.extend_from_slice(&wstr_bytes(&WString::from_str(key)));
+	pad32(&mut buf);
+	buf.extend_from_slice(value);
+
+	for child in children.iter() {
+		pad32(&mut buf);
+		buf.extend_from_slice(child);
+	}
+
+	let len = u16::try_from(buf.len()).map_err(|_| co::ERROR::INVALID_DATA)?;
+	buf[0..2].copy_from_slice(&len.to_le_bytes());
+	Ok(buf)
+}
+
+/// Returns the bytes of the string, including the terminating null.
+fn wstr_bytes(s: &WString) -> Vec<u8> {
+	s.as_slice().iter()
+		.take(s.str_len() + 1)
+		.flat_map(|ch| ch.to_le_bytes())
+		.collect()
+}
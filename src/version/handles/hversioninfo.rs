@@ -119,6 +119,7 @@ pub trait version_Hversioninfo: Handle {
 	/// This function is rather tricky, consider using the high-level methods:
 	/// * [`langs_and_cps`](crate::prelude::version_Hversioninfo::langs_and_cps);
 	/// * [`str_val`](crate::prelude::version_Hversioninfo::str_val);
+	/// * [`str_val_any`](crate::prelude::version_Hversioninfo::str_val_any);
 	/// * [`version_info`](crate::prelude::version_Hversioninfo::version_info).
 	///
 	/// # Examples
@@ -213,9 +214,65 @@ pub trait version_Hversioninfo: Handle {
 		}
 	}
 
+	/// Calls
+	/// [`HVERSIONINFO::str_val`](crate::prelude::version_Hversioninfo::str_val)
+	/// with each language and code page returned by
+	/// [`langs_and_cps`](crate::prelude::version_Hversioninfo::langs_and_cps),
+	/// returning the first value found.
+	///
+	/// If none is found – or the translation table is missing, which happens
+	/// with some binaries –, falls back to U.S. English and UTF-16, the
+	/// `040904b0` block. Fails with
+	/// [`ERROR::RESOURCE_TYPE_NOT_FOUND`](crate::co::ERROR::RESOURCE_TYPE_NOT_FOUND)
+	/// if the value doesn't exist in any of them.
+	///
+	/// # Examples
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let hversion = w::HVERSIONINFO::GetFileVersionInfo("C:\\Temp\\foo.exe")?;
+	/// println!("{}", hversion.str_val_any("FileDescription")?);
+	/// # w::SysResult::Ok(())
+	/// ```
+	#[must_use]
+	fn str_val_any(&self, name: &str) -> SysResult<String> {
+		if let Ok(langs_cps) = self.langs_and_cps() {
+			for (lang_id, code_page) in langs_cps.iter() {
+				if let Ok(s) = self.str_val(*lang_id, *code_page, name) {
+					return Ok(s);
+				}
+			}
+		}
+
+		self.str_val(
+			LANGID::new(co::LANG::ENGLISH, co::SUBLANG::ENGLISH_US),
+			co::CP::UTF16,
+			name,
+		).map_err(|_| co::ERROR::RESOURCE_TYPE_NOT_FOUND)
+	}
+
 	/// Calls
 	/// [`HVERSIONINFO::VerQueryValue`](crate::prelude::version_Hversioninfo::VerQueryValue)
 	/// to retrieve a reference to the fixed version block, if any.
+	///
+	/// # Examples
+	///
+	/// Checking whether a downloaded file is newer than the installed one:
+	///
+	/// ```no_run
+	/// use winsafe::{self as w, prelude::*};
+	///
+	/// let installed = w::HVERSIONINFO::GetFileVersionInfo("C:\\App\\app.exe")?;
+	/// let downloaded = w::HVERSIONINFO::GetFileVersionInfo("C:\\Temp\\app.exe")?;
+	///
+	/// if downloaded.version_info()?.dwFileVersion()
+	///     > installed.version_info()?.dwFileVersion()
+	/// {
+	///     println!("Update available.");
+	/// }
+	/// # w::SysResult::Ok(())
+	/// ```
 	#[must_use]
 	fn version_info(&self) -> SysResult<&VS_FIXEDFILEINFO> {
 		unsafe {
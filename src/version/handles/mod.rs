@@ -1,3 +1,4 @@
+mod hupdatersrc;
 mod hversioninfo;
 
 pub mod decl {
@@ -5,5 +6,6 @@ pub mod decl {
 }
 
 pub mod traits {
+	pub use super::hupdatersrc::version_Hupdatersrc;
 	pub use super::hversioninfo::version_Hversioninfo;
 }
@@ -69,3 +69,35 @@ impl VS_FIXEDFILEINFO {
 		self.dwFileDateMS = HIDWORD(val);
 	}
 }
+
+/// Version information to be written with
+/// [`HUPDATERSRC::set_version_info`](crate::prelude::version_Hupdatersrc::set_version_info),
+/// which builds the native
+/// [`VS_VERSIONINFO`](https://learn.microsoft.com/en-us/windows/win32/menurc/vs-versioninfo)
+/// block.
+///
+/// By default, the string table uses
+/// [`LANGID::USER_DEFAULT`](crate::LANGID::USER_DEFAULT) and
+/// [`CP::UTF16`](crate::co::CP::UTF16).
+pub struct VersionInfo {
+	/// Fixed part of the version information.
+	pub fixed_file_info: VS_FIXEDFILEINFO,
+	/// Language of the string table, also written to the `Translation` entry.
+	pub language: LANGID,
+	/// Code page of the string table, also written to the `Translation` entry.
+	pub code_page: co::CP,
+	/// Key/value pairs of the string table, like `("FileDescription", "My app")`
+	/// or `("ProductName", "My product")`.
+	pub strings: Vec<(String, String)>,
+}
+
+impl Default for VersionInfo {
+	fn default() -> Self {
+		Self {
+			fixed_file_info: VS_FIXEDFILEINFO::default(),
+			language: LANGID::USER_DEFAULT,
+			code_page: co::CP::UTF16,
+			strings: Vec::new(),
+		}
+	}
+}